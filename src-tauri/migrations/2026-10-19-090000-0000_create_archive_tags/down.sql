@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_archive_tags_tag;
+DROP TABLE archive_tags;
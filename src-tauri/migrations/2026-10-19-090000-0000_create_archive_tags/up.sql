@@ -0,0 +1,6 @@
+CREATE TABLE archive_tags (
+  archive_id INTEGER NOT NULL REFERENCES archives(id) ON DELETE CASCADE,
+  tag TEXT NOT NULL,
+  PRIMARY KEY (archive_id, tag)
+);
+CREATE INDEX idx_archive_tags_tag ON archive_tags(tag);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_alert_log_fired_at;
+DROP TABLE alert_log;
@@ -0,0 +1,16 @@
+CREATE TABLE alert_log (
+  rule_id TEXT NOT NULL,
+  rule_name TEXT NOT NULL,
+  event_id TEXT NOT NULL,
+  event TEXT NOT NULL,
+  currency TEXT NOT NULL,
+  impact TEXT NOT NULL,
+  event_time_utc TEXT NOT NULL,
+  pair TEXT NOT NULL,
+  lead_minutes INTEGER NOT NULL,
+  confidence_score REAL NOT NULL,
+  whipsaw_percent REAL,
+  fired_at TEXT NOT NULL,
+  PRIMARY KEY (rule_id, event_id, lead_minutes)
+);
+CREATE INDEX idx_alert_log_fired_at ON alert_log(fired_at);
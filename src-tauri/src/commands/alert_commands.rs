@@ -0,0 +1,110 @@
+// commands/alert_commands.rs - Règles d'alerte, journal et surveillance des annonces à venir
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use crate::commands::calendar_commands::CalendarState;
+use crate::models::alerting::{AlertRecord, AlertRule};
+use crate::services::alerting::{AlertLog, AlertMonitor, CHECK_INTERVAL_SECONDS};
+use crate::services::ConfigService;
+use chrono::Utc;
+use tauri::{AppHandle, Emitter, State};
+use tauri_plugin_notification::NotificationExt;
+use tracing::{info, warn};
+
+/// Alertes lues par défaut dans le journal
+const DEFAULT_LOG_LIMIT: i64 = 200;
+
+/// Lance la tâche de fond : évaluation des règles toutes les minutes, notification
+/// système et événement "alert-fired" pour chaque nouvelle alerte
+pub fn demarrer_surveillance(app: AppHandle, monitor: AlertMonitor) {
+    tauri::async_runtime::spawn(async move {
+        info!(
+            "🔔 Surveillance des alertes démarrée ({} s)",
+            CHECK_INTERVAL_SECONDS
+        );
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECONDS));
+        loop {
+            interval.tick().await;
+            match monitor.verifier(Utc::now()).await {
+                Ok(fired) => notifier(&app, &fired),
+                Err(e) => warn!("⚠️ Évaluation des alertes impossible: {}", e),
+            }
+        }
+    });
+}
+
+fn notifier(app: &AppHandle, records: &[AlertRecord]) {
+    for record in records {
+        let whipsaw = record
+            .whipsaw_percent
+            .map(|w| format!(", whipsaw {:.0}%", w))
+            .unwrap_or_default();
+        let shown = app
+            .notification()
+            .builder()
+            .title(format!(
+                "{} — {} {} dans {} min",
+                record.rule_name, record.currency, record.event, record.lead_minutes
+            ))
+            .body(format!(
+                "{} : confiance {:.0}{}",
+                record.pair, record.confidence_score, whipsaw
+            ))
+            .show();
+        if let Err(e) = shown {
+            warn!("⚠️ Notification impossible: {}", e);
+        }
+        let _ = app.emit("alert-fired", record);
+    }
+}
+
+fn journal(state: &CalendarState) -> Result<AlertLog, String> {
+    let pool = state
+        .pool
+        .lock()
+        .map_err(|_| "Failed to lock calendar pool".to_string())?
+        .clone()
+        .ok_or("Calendar DB not initialized".to_string())?;
+    Ok(AlertLog::new(pool))
+}
+
+#[tauri::command]
+pub async fn get_alert_rules() -> Result<Vec<AlertRule>, String> {
+    ConfigService::get_alert_rules().map_err(|e| e.to_string())
+}
+
+/// Ajoute ou remplace (même identifiant) une règle d'alerte
+#[tauri::command]
+pub async fn save_alert_rule(rule: AlertRule) -> Result<(), String> {
+    ConfigService::save_alert_rule(rule).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_alert_rule(id: String) -> Result<bool, String> {
+    ConfigService::delete_alert_rule(&id).map_err(|e| e.to_string())
+}
+
+/// Dernières alertes déclenchées, les plus récentes en premier
+#[tauri::command]
+pub async fn get_alert_log(
+    limit: Option<i64>,
+    calendar_state: State<'_, CalendarState>,
+) -> Result<Vec<AlertRecord>, String> {
+    journal(&calendar_state)?.lister(limit.unwrap_or(DEFAULT_LOG_LIMIT))
+}
+
+#[tauri::command]
+pub async fn clear_alert_log(calendar_state: State<'_, CalendarState>) -> Result<usize, String> {
+    journal(&calendar_state)?.effacer()
+}
+
+/// Évalue les règles immédiatement (sans attendre la tâche de fond)
+#[tauri::command]
+pub async fn check_alerts_now(
+    app: AppHandle,
+    monitor: State<'_, AlertMonitor>,
+) -> Result<Vec<AlertRecord>, String> {
+    let fired = monitor.verifier(Utc::now()).await?;
+    notifier(&app, &fired);
+    Ok(fired)
+}
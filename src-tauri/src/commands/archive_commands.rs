@@ -1,4 +1,9 @@
-use crate::models::archive::{Archive, NewArchive};
+use crate::models::archive::{
+    Archive, ArchiveFilter, ArchiveSearchResult, ArchiveTagCount, NewArchive,
+};
+use crate::commands::pair_data::PairDataState;
+use crate::models::archive_bundle::{BundleExportResult, BundleImportReport, ConflictStrategy};
+use crate::models::archive_comparison::ArchiveComparison;
 use crate::services::ArchiveService;
 use tauri::State;
 
@@ -53,3 +58,62 @@ pub async fn delete_archive(
 ) -> Result<usize, String> {
     archive_service.delete_archive(archive_id)
 }
+
+#[tauri::command]
+pub async fn search_archives(
+    archive_service: State<'_, ArchiveService>,
+    filter: ArchiveFilter,
+) -> Result<Vec<ArchiveSearchResult>, String> {
+    archive_service.search_archives(&filter)
+}
+
+#[tauri::command]
+pub async fn set_archive_tags(
+    archive_service: State<'_, ArchiveService>,
+    archive_id: i32,
+    tags: Vec<String>,
+) -> Result<Vec<String>, String> {
+    archive_service.set_tags(archive_id, &tags)
+}
+
+#[tauri::command]
+pub async fn list_archive_tags(
+    archive_service: State<'_, ArchiveService>,
+) -> Result<Vec<ArchiveTagCount>, String> {
+    archive_service.list_tags()
+}
+
+#[tauri::command]
+pub async fn compare_archives(
+    archive_service: State<'_, ArchiveService>,
+    archive_ids: Vec<i32>,
+) -> Result<ArchiveComparison, String> {
+    archive_service.compare_archives(&archive_ids)
+}
+
+/// Exporte les archives choisies dans un lot portable (JSON) à partager
+#[tauri::command]
+pub async fn export_archive_bundle(
+    archive_service: State<'_, ArchiveService>,
+    pair_state: State<'_, PairDataState>,
+    archive_ids: Vec<i32>,
+    include_events: bool,
+    path: String,
+) -> Result<BundleExportResult, String> {
+    let pairs_pool = pair_state
+        .pool
+        .lock()
+        .map_err(|_| "Failed to lock pair data state".to_string())?
+        .clone();
+    archive_service.export_bundle(&archive_ids, include_events, pairs_pool.as_ref(), &path)
+}
+
+#[tauri::command]
+pub async fn import_archive_bundle(
+    archive_service: State<'_, ArchiveService>,
+    path: String,
+    conflict_strategy: Option<ConflictStrategy>,
+    import_events: bool,
+) -> Result<BundleImportReport, String> {
+    archive_service.import_bundle(&path, conflict_strategy.unwrap_or_default(), import_events)
+}
@@ -0,0 +1,65 @@
+// commands/archive_rerun/mod.rs - Recalcul d'une archive avec les données actuelles
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+mod recalcul;
+
+use crate::commands::calendar_commands::CalendarState;
+use crate::commands::pair_data::PairDataState;
+use crate::models::archive_payload::ArchiveSummary;
+use crate::models::input_fingerprint::ArchiveRerunReport;
+use crate::services::archive_schema::ArchiveSchema;
+use crate::services::input_fingerprint::Fingerprinter;
+use crate::services::ArchiveService;
+use recalcul::{recalculer_backtest, recalculer_impact, recalculer_volatilite};
+use serde_json::Value;
+use tauri::State;
+use tracing::info;
+
+/// Relance l'analyse d'une archive (impact d'événement, backtest ou volatilité) sur les
+/// données actuelles et explique les écarts. L'archive n'est pas modifiée.
+#[tauri::command]
+pub async fn rerun_archive(
+    archive_id: i32,
+    archive_service: State<'_, ArchiveService>,
+    calendar_state: State<'_, CalendarState>,
+    pair_state: State<'_, PairDataState>,
+) -> Result<ArchiveRerunReport, String> {
+    let archive = archive_service.get_archive(archive_id)?;
+    let data: Value = serde_json::from_str(&archive.data_json)
+        .map_err(|e| format!("Archive #{} illisible: {}", archive_id, e))?;
+    let archived = ArchiveSchema::summary(&archive)?;
+    let previous = Fingerprinter::extraire(&data);
+
+    let (recomputed, current) = match &archived {
+        ArchiveSummary::EventImpact(summary) => recalculer_impact(summary, &calendar_state).await?,
+        ArchiveSummary::Backtest(summary) => {
+            recalculer_backtest(summary, &data, &calendar_state).await?
+        }
+        ArchiveSummary::Volatility(summary) => {
+            recalculer_volatilite(summary, previous.as_ref(), &calendar_state, &pair_state)?
+        }
+        other => {
+            return Err(format!(
+                "Recalcul non disponible pour les archives {:?}",
+                other.kind()
+            ))
+        }
+    };
+
+    let report = Fingerprinter::rapport(&archive, archived, recomputed, previous, current)?;
+    info!(
+        "🔁 Archive {} recalculée : entrées {}, résultats {}",
+        archive_id,
+        if report.inputs_changed {
+            "modifiées"
+        } else {
+            "identiques"
+        },
+        if report.results_changed {
+            "modifiés"
+        } else {
+            "identiques"
+        }
+    );
+    Ok(report)
+}
@@ -0,0 +1,162 @@
+// commands/archive_rerun/recalcul.rs - Recalcul de chaque type d'archive et empreinte des entrées
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use crate::commands::backtest::{backtest_parameters, run_with_regime};
+use crate::commands::calendar_commands::CalendarState;
+use crate::commands::pair_data::PairDataState;
+use crate::commands::retrospective_analysis::helpers::{load_events_by_type, setup_databases};
+use crate::services::retrospective::RetroAnalysisService;
+use crate::commands::volatility::analyser_symbole;
+use crate::models::archive_payload::{
+    ArchiveSummary, BacktestSummary, EventImpactSummary, VolatilitySummary,
+};
+use crate::models::input_fingerprint::InputFingerprint;
+use crate::models::RegimeFilter;
+use crate::services::backtest::{BacktestConfig, StrategyMode};
+use crate::services::input_fingerprint::Fingerprinter;
+use crate::services::ConfigService;
+use serde_json::Value;
+use tauri::State;
+
+pub(super) async fn recalculer_impact(
+    summary: &EventImpactSummary,
+    calendar_state: &State<'_, CalendarState>,
+) -> Result<(ArchiveSummary, InputFingerprint), String> {
+    let (conn, loader) = setup_databases(calendar_state).await?;
+    let events = load_events_by_type(conn, &summary.event_type).await?;
+    if events.is_empty() {
+        return Err(format!("No events: {}", summary.event_type));
+    }
+    let decay = ConfigService::time_decay();
+    let result = RetroAnalysisService::calculer_impact_evenement(
+        &summary.pair,
+        &summary.event_type,
+        &events,
+        &loader,
+        &decay,
+    )
+    .await?;
+    let fingerprint = Fingerprinter::capturer(
+        &loader,
+        &[&summary.pair],
+        &events,
+        Some(&summary.event_type),
+        [("time_decay".to_string(), Fingerprinter::parametre(&decay)?)].into(),
+    )?;
+
+    let recomputed = EventImpactSummary {
+        pair: result.pair,
+        event_type: result.event_type,
+        offset: result.offset,
+        trailing_stop: result.trailing_stop,
+        stop_loss: result.stop_loss,
+        offset_simultaneous: Some(result.offset_simultaneous),
+        trailing_stop_simultaneous: Some(result.trailing_stop_simultaneous),
+        stop_loss_simultaneous: Some(result.stop_loss_simultaneous),
+        timeout_minutes: Some(result.timeout),
+        event_count: Some(result.event_count),
+        volatility_increase_percent: Some(result.volatility_increase_percent),
+        // Comme l'analyse rétrospective archivée : score recalculé à la lecture
+        confidence_score: None,
+        confidence_score_ci: None,
+        regime: None,
+    };
+    Ok((ArchiveSummary::EventImpact(recomputed), fingerprint))
+}
+
+pub(super) async fn recalculer_backtest(
+    summary: &BacktestSummary,
+    data: &Value,
+    calendar_state: &State<'_, CalendarState>,
+) -> Result<(ArchiveSummary, InputFingerprint), String> {
+    if summary.event_name.starts_with("Horaire") {
+        return Err("Recalcul non disponible pour un backtest horaire".to_string());
+    }
+    let config: BacktestConfig = data
+        .get("config")
+        .cloned()
+        .ok_or("Configuration du backtest absente de l'archive")
+        .and_then(|c| {
+            serde_json::from_value(c).map_err(|_| "Configuration du backtest illisible")
+        })?;
+    let mode: StrategyMode = data
+        .get("mode")
+        .or_else(|| data.get("result").and_then(|r| r.get("strategy_mode")))
+        .and_then(|m| serde_json::from_value(m.clone()).ok())
+        .unwrap_or(StrategyMode::Directionnel);
+    let regime = data
+        .get("result")
+        .and_then(|r| r.get("regime"))
+        .and_then(|r| serde_json::from_value(r.clone()).ok())
+        .map(|regime| RegimeFilter::Only { regime });
+
+    let (conn, loader) = setup_databases(calendar_state).await?;
+    let events = load_events_by_type(conn, &summary.event_name).await?;
+    if events.is_empty() {
+        return Err(format!("No events found for type: {}", summary.event_name));
+    }
+    let parameters = backtest_parameters(&config, mode, regime)?;
+    let fingerprint = Fingerprinter::capturer(
+        &loader,
+        &[&summary.symbol],
+        &events,
+        Some(&summary.event_name),
+        parameters,
+    )?;
+    let result = run_with_regime(
+        &summary.symbol,
+        events,
+        config.clone(),
+        mode,
+        regime,
+        &loader,
+    )?;
+
+    let recomputed = BacktestSummary {
+        symbol: result.symbol,
+        event_name: result.event_name,
+        strategy_mode: summary.strategy_mode.clone(),
+        offset: Some(config.offset_pips),
+        stop_loss: Some(config.stop_loss_pips),
+        trailing_stop: Some(config.trailing_stop_pips),
+        timeout_minutes: Some(config.timeout_minutes),
+        total_trades: result.total_trades,
+        win_rate_percent: result.win_rate_percent,
+        total_pips: result.total_pips,
+        unit: result.unit,
+    };
+    Ok((ArchiveSummary::Backtest(recomputed), fingerprint))
+}
+
+pub(super) fn recalculer_volatilite(
+    summary: &VolatilitySummary,
+    previous: Option<&InputFingerprint>,
+    calendar_state: &CalendarState,
+    pair_state: &PairDataState,
+) -> Result<(ArchiveSummary, InputFingerprint), String> {
+    let calendar_id = previous.and_then(|fp| fp.calendar_import_ids.first().copied());
+    let regime = summary.regime.map(|regime| RegimeFilter::Only { regime });
+    let result = analyser_symbole(
+        &summary.symbol,
+        calendar_id,
+        regime,
+        calendar_state,
+        pair_state,
+    )
+    .map_err(|e| e.message)?;
+    let fingerprint = result
+        .fingerprint
+        .clone()
+        .ok_or("Historique des paires indisponible")?;
+
+    let recomputed = VolatilitySummary {
+        symbol: result.symbol,
+        confidence_score: result.confidence_score,
+        confidence_score_ci: result.confidence_score_ci,
+        best_hours: summary.best_hours.clone(),
+        mean_volatility: Some(result.global_metrics.mean_volatility),
+        regime: result.regime,
+        unit: result.unit,
+    };
+    Ok((ArchiveSummary::Volatility(recomputed), fingerprint))
+}
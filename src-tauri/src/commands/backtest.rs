@@ -1,7 +1,11 @@
 use crate::services::backtest::{BacktestConfig, BacktestEngine, BacktestResult, StrategyMode};
 use crate::commands::retrospective_analysis::helpers::{setup_databases, load_events_by_type};
-use chrono::{NaiveDate, NaiveTime, Duration, Datelike, Utc};
-use crate::models::calendar_event::CalendarEvent;
+use crate::services::volatility_regime::RegimeClassifier;
+use crate::services::input_fingerprint::Fingerprinter;
+use crate::services::DatabaseLoader;
+use crate::models::{CalendarEvent, RegimeFilter};
+use chrono::{NaiveDate, NaiveTime};
+use std::collections::BTreeMap;
 
 #[tauri::command]
 pub async fn run_backtest(
@@ -9,6 +13,7 @@ pub async fn run_backtest(
     event_type: String,
     config: BacktestConfig,
     mode: StrategyMode,
+    regime: Option<RegimeFilter>,
     state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
 ) -> Result<BacktestResult, String> {
     let (conn, loader) = setup_databases(&state).await?;
@@ -18,10 +23,20 @@ pub async fn run_backtest(
         return Err(format!("No events found for type: {}", event_type));
     }
 
-    BacktestEngine::run(&pair, &events, config, mode, &loader)
+    let parameters = backtest_parameters(&config, mode, regime)?;
+    let mut result = run_with_regime(&pair, events.clone(), config, mode, regime, &loader)?;
+    result.fingerprint = Some(Fingerprinter::capturer(
+        &loader,
+        &[&pair],
+        &events,
+        Some(&event_type),
+        parameters,
+    )?);
+    Ok(result)
 }
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn run_backtest_time(
     pair: String,
     time: String,
@@ -29,6 +44,7 @@ pub async fn run_backtest_time(
     end_date: String,
     config: BacktestConfig,
     mode: StrategyMode,
+    regime: Option<RegimeFilter>,
     state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
 ) -> Result<BacktestResult, String> {
     let (_, loader) = setup_databases(&state).await?;
@@ -40,35 +56,79 @@ pub async fn run_backtest_time(
     let end = NaiveDate::parse_from_str(&end_date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid end date: {}", e))?;
 
-    let mut events = Vec::new();
-    let mut current_date = start;
+    let events = BacktestEngine::time_slot_events(time, start, end);
 
-    while current_date <= end {
-        // Skip weekends (Saturday=6, Sunday=7)
-        let weekday = current_date.weekday().number_from_monday();
-        if weekday <= 5 {
-            let event_time = current_date.and_time(time);
-            
-            events.push(CalendarEvent {
-                id: 0, // Dummy ID
-                symbol: "USD".to_string(), // Dummy currency
-                event_time,
-                description: format!("Time Backtest {}", time),
-                impact: "Medium".to_string(),
-                actual: None,
-                forecast: None,
-                previous: None,
-                created_at: Utc::now().naive_utc(),
-                calendar_import_id: 0, // Dummy import ID
-            });
+    if events.is_empty() {
+        return Err("No valid weekdays found in range".to_string());
+    }
+
+    // Créneaux générés : aucun calendrier en jeu, la plage de dates fait partie des paramètres
+    let mut parameters = backtest_parameters(&config, mode, regime)?;
+    parameters.insert("start_date".to_string(), start_date);
+    parameters.insert("end_date".to_string(), end_date);
+    let slot = format!("Horaire {}", time.format("%H:%M"));
+    let mut result = run_with_regime(&pair, events, config, mode, regime, &loader)?;
+    result.fingerprint = Some(Fingerprinter::capturer(
+        &loader,
+        &[&pair],
+        &[],
+        Some(&slot),
+        parameters,
+    )?);
+    Ok(result)
+}
+
+pub(crate) fn backtest_parameters(
+    config: &BacktestConfig,
+    mode: StrategyMode,
+    regime: Option<RegimeFilter>,
+) -> Result<BTreeMap<String, String>, String> {
+    Ok([
+        ("config".to_string(), Fingerprinter::parametre(config)?),
+        ("mode".to_string(), Fingerprinter::parametre(&mode)?),
+        ("regime".to_string(), Fingerprinter::parametre(&regime)?),
+    ]
+    .into())
+}
+
+/// Restreint les événements au régime demandé puis ventile le résultat par régime
+pub(crate) fn run_with_regime(
+    pair: &str,
+    mut events: Vec<CalendarEvent>,
+    config: BacktestConfig,
+    mode: StrategyMode,
+    regime: Option<RegimeFilter>,
+    loader: &DatabaseLoader,
+) -> Result<BacktestResult, String> {
+    let calendar = match RegimeClassifier::load(loader, pair) {
+        Ok(calendar) => Some(calendar),
+        // Sans filtre demandé, l'absence de régimes ne bloque pas le backtest
+        Err(e) if regime.is_none() => {
+            tracing::warn!("⚠️ Régimes indisponibles pour {}: {}", pair, e);
+            None
         }
+        Err(e) => return Err(e),
+    };
 
-        current_date += Duration::days(1);
+    let target = match (regime, &calendar) {
+        (Some(filter), Some(calendar)) => Some(
+            filter
+                .resolve(calendar)
+                .ok_or(format!("Historique trop court pour classer les régimes de {}", pair))?,
+        ),
+        _ => None,
+    };
+    if let (Some(target), Some(calendar)) = (target, &calendar) {
+        events.retain(|e| calendar.regime_on(e.event_time.date()) == Some(target));
+        if events.is_empty() {
+            return Err(format!("No events in regime {:?} for {}", target, pair));
+        }
     }
 
-    if events.is_empty() {
-        return Err("No valid weekdays found in range".to_string());
+    let mut result = BacktestEngine::run(pair, &events, config, mode, loader)?;
+    result.regime = target;
+    if let Some(calendar) = &calendar {
+        result.regime_breakdown = BacktestEngine::ventiler_par_regime(&result.trades, calendar);
     }
-
-    BacktestEngine::run(&pair, &events, config, mode, &loader)
+    Ok(result)
 }
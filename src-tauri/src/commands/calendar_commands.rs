@@ -3,6 +3,9 @@
 
 use crate::db::DbPool;
 use crate::models::CalendarEvent;
+use crate::schema::calendar_events;
+use chrono::{Duration, Utc};
+use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
 use tauri::State;
@@ -22,18 +25,32 @@ impl From<String> for CalendarCommandError {
     }
 }
 
+/// Horizon par défaut des annonces à venir (heures)
+const DEFAULT_UPCOMING_HOURS: i64 = 24;
+
+/// Annonces du calendrier entre maintenant et `hours_ahead` heures (24 par défaut)
 #[tauri::command]
 pub async fn get_upcoming_events(
+    hours_ahead: Option<i64>,
     state: State<'_, CalendarState>,
 ) -> Result<Vec<CalendarEvent>, CalendarCommandError> {
-    let pool_guard = state
+    let pool = state
         .pool
         .lock()
-        .map_err(|e| format!("Failed to lock pool: {}", e))?;
-
-    let _pool = pool_guard
-        .as_ref()
+        .map_err(|e| format!("Failed to lock pool: {}", e))?
+        .clone()
         .ok_or_else(|| "Database not initialized".to_string())?;
+    let mut conn = pool
+        .get()
+        .map_err(|e| format!("Connection failed: {}", e))?;
 
-    Ok(vec![])
+    let now = Utc::now().naive_utc();
+    let until = now + Duration::hours(hours_ahead.unwrap_or(DEFAULT_UPCOMING_HOURS).max(0));
+    let events = calendar_events::table
+        .filter(calendar_events::event_time.between(now, until))
+        .order(calendar_events::event_time.asc())
+        .select(CalendarEvent::as_select())
+        .load(&mut conn)
+        .map_err(|e| format!("Lecture du calendrier impossible: {}", e))?;
+    Ok(events)
 }
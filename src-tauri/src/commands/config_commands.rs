@@ -1,6 +1,7 @@
 //! Commandes Tauri pour la gestion de la configuration
 
-use crate::services::ConfigService;
+use crate::models::{BrokerClock, TimeDecay};
+use crate::services::{ColumnMappingProfile, ConfigService};
 
 /// Obtient le fichier calendrier sélectionné
 #[tauri::command]
@@ -15,3 +16,57 @@ pub async fn set_selected_calendar_file(filename: String) -> Result<(), String>
     ConfigService::set_selected_calendar_file(filename)
         .map_err(|e| format!("Erreur lors de la sauvegarde de la configuration: {}", e))
 }
+
+/// Liste les profils de colonnes d'import enregistrés
+#[tauri::command]
+pub async fn list_import_profiles() -> Result<Vec<ColumnMappingProfile>, String> {
+    ConfigService::get_import_profiles()
+        .map_err(|e| format!("Erreur lors de la lecture des profils d'import: {}", e))
+}
+
+/// Enregistre un profil de colonnes d'import (remplace un profil du même nom)
+#[tauri::command]
+pub async fn save_import_profile(profile: ColumnMappingProfile) -> Result<(), String> {
+    ConfigService::save_import_profile(profile)
+        .map_err(|e| format!("Erreur lors de la sauvegarde du profil d'import: {}", e))
+}
+
+/// Supprime un profil de colonnes d'import
+#[tauri::command]
+pub async fn delete_import_profile(name: String) -> Result<bool, String> {
+    ConfigService::delete_import_profile(&name)
+        .map_err(|e| format!("Erreur lors de la suppression du profil d'import: {}", e))
+}
+
+/// Obtient la pondération par ancienneté des agrégations historiques
+#[tauri::command]
+pub async fn get_time_decay() -> Result<TimeDecay, String> {
+    ConfigService::load_config()
+        .map(|config| config.time_decay)
+        .map_err(|e| format!("Erreur lors de la lecture de la configuration: {}", e))
+}
+
+/// Définit la demi-vie (en jours) des agrégations historiques ; None la désactive
+#[tauri::command]
+pub async fn set_time_decay(half_life_days: Option<f64>) -> Result<(), String> {
+    ConfigService::set_time_decay(TimeDecay::new(half_life_days))
+        .map_err(|e| format!("Erreur lors de la sauvegarde de la demi-vie: {}", e))
+}
+
+/// Obtient l'horloge serveur du courtier (décalage UTC, heure d'été US)
+#[tauri::command]
+pub async fn get_broker_clock() -> Result<BrokerClock, String> {
+    ConfigService::load_config()
+        .map(|config| config.broker_clock)
+        .map_err(|e| format!("Erreur lors de la lecture de la configuration: {}", e))
+}
+
+/// Définit l'horloge serveur du courtier utilisée par les plans et les historiques MT5
+#[tauri::command]
+pub async fn set_broker_clock(utc_offset_minutes: i32, us_dst: bool) -> Result<(), String> {
+    ConfigService::set_broker_clock(BrokerClock {
+        utc_offset_minutes,
+        us_dst,
+    })
+    .map_err(|e| format!("Erreur lors de la sauvegarde de l'horloge courtier: {}", e))
+}
@@ -0,0 +1,155 @@
+// commands/correlation/cross_asset.rs
+// Carte de réaction multi-actifs : une ligne par occurrence, une colonne par instrument
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use chrono::{Duration, TimeZone, Utc};
+use rusqlite::{Connection, Result as SqliteResult};
+use tauri::State;
+
+use super::utils::parse_sqlite_datetime;
+use super::volatility_helpers::calculer_volatilites_optimise;
+use crate::commands::candle_index_commands::CandleIndexState;
+use crate::models::CrossAssetReactionMap;
+use crate::services::cross_asset_reaction::{CrossAssetAnalyzer, REACTION_WINDOW_MINUTES};
+use crate::services::input_fingerprint::Fingerprinter;
+
+#[tauri::command]
+pub async fn get_cross_asset_reaction(
+    event_type: String,
+    symbols: Vec<String>,
+    calendar_id: Option<i32>,
+    months_back: Option<i32>,
+    state: State<'_, CandleIndexState>,
+) -> Result<CrossAssetReactionMap, String> {
+    if symbols.is_empty() {
+        return Err("No symbols provided".to_string());
+    }
+
+    let db_path = dirs::data_local_dir()
+        .ok_or("Failed to get data directory")?
+        .join("volatility-analyzer")
+        .join("volatility.db");
+    if !db_path.exists() {
+        return Err("Database not found".to_string());
+    }
+    let conn = Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+
+    let cutoff_date = months_back
+        .and_then(|m| Utc::now().checked_sub_signed(Duration::days(m as i64 * 30)))
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "0000-01-01".to_string());
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, datetime(event_time), symbol, calendar_import_id
+             FROM calendar_events
+             WHERE description = ?1 AND date(event_time) >= ?2
+               AND (?3 IS NULL OR calendar_import_id = ?3)
+             ORDER BY event_time DESC",
+        )
+        .map_err(|e| format!("Failed to prepare events: {}", e))?;
+    let events: Vec<(i32, String, String, i32)> = stmt
+        .query_map(
+            rusqlite::params![event_type, cutoff_date, calendar_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .map_err(|e| format!("Failed to query events: {}", e))?
+        .collect::<SqliteResult<Vec<_>>>()
+        .map_err(|e| format!("Failed to collect events: {}", e))?;
+
+    let mut index_state = state
+        .index
+        .lock()
+        .map_err(|e| format!("Failed to lock candle index state: {}", e))?;
+    let candle_index = index_state
+        .as_mut()
+        .ok_or("CandleIndex not initialized. Call init_candle_index first.")?;
+    for symbol in &symbols {
+        candle_index.load_pair_candles(symbol)?;
+    }
+
+    let mut occurrences = Vec::new();
+    for (event_id, datetime_str, currency, _) in &events {
+        let event_datetime = match parse_sqlite_datetime(datetime_str) {
+            Ok(dt) => dt,
+            Err(e) => {
+                tracing::warn!("⚠️ Skipping event {}: {}", event_id, e);
+                continue;
+            }
+        };
+        let event_dt = Utc.from_utc_datetime(&event_datetime);
+        let window_end = event_dt + Duration::minutes(REACTION_WINDOW_MINUTES);
+
+        let reactions = symbols
+            .iter()
+            .map(|symbol| {
+                let candles = candle_index
+                    .get_full_candles_in_range(
+                        symbol,
+                        event_dt.date_naive(),
+                        window_end.date_naive(),
+                    )
+                    .unwrap_or_default();
+                let multiplier = calculer_volatilites_optimise(
+                    candle_index,
+                    symbol,
+                    event_datetime,
+                    REACTION_WINDOW_MINUTES,
+                    7,
+                    super::utils::get_pip_value(symbol),
+                )
+                .ok()
+                .filter(|m| m.baseline_volatility > 0.0)
+                .map(|m| m.event_volatility / m.baseline_volatility)
+                .unwrap_or(0.0);
+                CrossAssetAnalyzer::mesurer_reaction(symbol, currency, event_dt, &candles, multiplier)
+            })
+            .collect();
+
+        occurrences.push(CrossAssetAnalyzer::assembler_occurrence(
+            *event_id,
+            datetime_str.clone(),
+            currency.clone(),
+            reactions,
+        ));
+    }
+
+    tracing::info!(
+        "🌐 Réaction multi-actifs '{}' : {} occurrences × {} instruments",
+        event_type,
+        occurrences.len(),
+        symbols.len()
+    );
+
+    let fingerprint = match candle_index.db_loader() {
+        Some(loader) => {
+            let candles = symbols
+                .iter()
+                .map(|s| loader.candle_range(s, "M1"))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Empreinte des données impossible: {}", e))?;
+            let parameters = [
+                ("months_back".to_string(), Fingerprinter::parametre(&months_back)?),
+                ("calendar_id".to_string(), Fingerprinter::parametre(&calendar_id)?),
+            ]
+            .into();
+            Some(Fingerprinter::construire(
+                candles,
+                events.iter().map(|e| e.3).collect(),
+                Some(&event_type),
+                events.len(),
+                parameters,
+            )?)
+        }
+        None => None,
+    };
+
+    let summary = CrossAssetAnalyzer::resumer(&symbols, &occurrences);
+    Ok(CrossAssetReactionMap {
+        event_type,
+        symbols,
+        occurrences,
+        summary,
+        fingerprint,
+    })
+}
@@ -6,6 +6,9 @@ use super::heatmap_helpers::{
     calculer_volatilite_moyenne_evenement_paire_optimise, get_event_types, HeatmapData,
 };
 use crate::commands::candle_index_commands::CandleIndexState;
+use crate::models::RegimeFilter;
+use crate::services::heatmap_cells::HeatmapGrid;
+use crate::services::volatility_regime::RegimeClassifier;
 
 fn format_date_fr(date_str: &str) -> String {
     if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(date_str, "%Y-%m-%d %H:%M:%S") {
@@ -55,6 +58,7 @@ fn format_date_fr(date_str: &str) -> String {
 pub async fn get_correlation_heatmap(
     calendar_id: Option<i32>,
     pairs: Vec<String>,
+    regime: Option<RegimeFilter>,
     state: State<'_, CandleIndexState>,
 ) -> Result<HeatmapData, String> {
     let data_dir = dirs::data_local_dir()
@@ -117,12 +121,11 @@ pub async fn get_correlation_heatmap(
             pairs,
             event_types: vec![],
             data: std::collections::HashMap::new(),
+            intervals: std::collections::HashMap::new(),
+            regimes: std::collections::HashMap::new(),
         });
     }
 
-    let mut data: std::collections::HashMap<String, std::collections::HashMap<String, f64>> =
-        std::collections::HashMap::new();
-
     let mut index_state = state
         .index
         .lock()
@@ -136,30 +139,42 @@ pub async fn get_correlation_heatmap(
         candle_index.load_pair_candles(pair)?;
     }
 
+    let decay = crate::services::ConfigService::time_decay();
+
+    // Régimes classés depuis les bougies déjà indexées (une fois par paire)
+    let regime_calendars = regime
+        .map(|filter| RegimeClassifier::cibles(&pairs, filter, |p| candle_index.daily_bars(p)))
+        .unwrap_or_default();
+
+    let mut grid = HeatmapGrid::default();
     for pair in &pairs {
+        let pair_regime = regime_calendars
+            .get(pair)
+            .map(|(calendar, target)| (calendar, *target));
+        if regime.is_some() && pair_regime.is_none() {
+            // Historique trop court pour classer : aucune cellule comparable
+            tracing::warn!("⚠️ Régimes non classables pour {}", pair);
+            for event_type in &event_types {
+                grid.sans_donnees(&event_type.name, pair);
+            }
+            continue;
+        }
         for event_type in &mut event_types {
-            let vol_result = calculer_volatilite_moyenne_evenement_paire_optimise(
+            let cell = calculer_volatilite_moyenne_evenement_paire_optimise(
                 &conn,
                 &event_type.name,
                 &pair,
                 calendar_id,
                 &candle_index,
+                pair_regime,
+                &decay,
             )?;
 
-            let avg_vol_rounded = if vol_result.has_data {
-                (vol_result.value * 10.0).round() / 10.0
-            } else {
-                -1.0 // Indicateur de "Pas de données"
-            };
-
             // Marquer has_data au moins une fois si vrai
-            if vol_result.has_data {
+            if cell.has_data {
                 event_type.has_data = Some(true);
             }
-
-            data.entry(event_type.name.clone())
-                .or_default()
-                .insert(pair.clone(), avg_vol_rounded);
+            grid.inserer(&event_type.name, pair, cell);
         }
     }
 
@@ -169,6 +184,11 @@ pub async fn get_correlation_heatmap(
         period_end,
         pairs,
         event_types,
-        data,
+        data: grid.data,
+        intervals: grid.intervals,
+        regimes: regime_calendars
+            .into_iter()
+            .map(|(pair, (_, target))| (pair, target))
+            .collect(),
     })
 }
@@ -1,14 +1,9 @@
+use crate::models::{ConfidenceInterval, RegimeCalendar, TimeDecay, VolatilityRegime};
+use crate::services::heatmap_cells::{agreger_cellule, HeatmapCell};
 use rusqlite::{Connection, Result as SqliteResult};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-/// Résultat du calcul de volatilité avec indicateur de disponibilité des données
-#[derive(Debug, Clone)]
-pub struct VolatilityResult {
-    pub value: f64,
-    pub has_data: bool,
-}
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EventTypeInfo {
     pub name: String,
@@ -25,6 +20,12 @@ pub struct HeatmapData {
     pub pairs: Vec<String>,
     pub event_types: Vec<EventTypeInfo>,
     pub data: HashMap<String, HashMap<String, f64>>,
+    /// Intervalles de confiance par cellule (mêmes clés que `data`, cellules sans données absentes)
+    #[serde(default)]
+    pub intervals: HashMap<String, HashMap<String, ConfidenceInterval>>,
+    /// Régime retenu par paire quand la heatmap est restreinte à un régime
+    #[serde(default)]
+    pub regimes: HashMap<String, VolatilityRegime>,
 }
 
 pub fn get_event_types(
@@ -75,7 +76,9 @@ pub fn calculer_volatilite_moyenne_evenement_paire_optimise(
     pair: &str,
     calendar_id: Option<i32>,
     candle_index: &crate::services::candle_index::CandleIndex,
-) -> Result<VolatilityResult, String> {
+    regime: Option<(&RegimeCalendar, VolatilityRegime)>,
+    decay: &TimeDecay,
+) -> Result<HeatmapCell, String> {
     use super::utils::parse_sqlite_datetime;
     use super::volatility_helpers::calculer_volatilites_optimise;
 
@@ -109,27 +112,23 @@ pub fn calculer_volatilite_moyenne_evenement_paire_optimise(
         .map_err(|e| format!("Failed to collect events: {}", e))?;
 
     if events.is_empty() {
-        return Ok(VolatilityResult {
+        return Ok(HeatmapCell {
             value: 0.0,
             has_data: false,
+            interval: None,
         });
     }
 
-    let mut total_score = 0.0;
-    let mut valid_count = 0;
-    let mut has_data_found = false;
+    let occurrences = events
+        .iter()
+        .map(|datetime_str| parse_sqlite_datetime(datetime_str))
+        .collect::<Result<Vec<_>, String>>()?;
 
-    for datetime_str in &events {
-        let event_datetime = parse_sqlite_datetime(datetime_str)?;
-
-        // Vérifier si des candles existent pour cet événement
+    Ok(agreger_cellule(&occurrences, regime, decay, |event_datetime| {
         // Si pas de candles, SKIPER complètement cet événement
         if !super::data_availability::has_candles_for_event(candle_index, pair, event_datetime) {
-            continue;
+            return None;
         }
-
-        has_data_found = true;
-
         let metrics = calculer_volatilites_optimise(
             candle_index,
             pair,
@@ -145,23 +144,6 @@ pub fn calculer_volatilite_moyenne_evenement_paire_optimise(
             directionality: 0.0,
             whipsaw_risk: 0.0,
         });
-
-        let score = metrics.straddle_score;
-
-        if score > 0.0 {
-            total_score += score;
-            valid_count += 1;
-        }
-    }
-
-    let avg_score = if valid_count == 0 {
-        0.0
-    } else {
-        total_score / valid_count as f64
-    };
-
-    Ok(VolatilityResult {
-        value: avg_score,
-        has_data: has_data_found,
-    })
+        Some(metrics.straddle_score)
+    }))
 }
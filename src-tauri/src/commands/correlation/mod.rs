@@ -2,6 +2,7 @@
 // Point d'entrée pour les commandes de corrélation événements × paires
 // Conforme .clinerules: < 100 lignes, exports seulement
 
+mod cross_asset;
 mod data_availability;
 mod heatmap_command;
 mod heatmap_helpers;
@@ -12,6 +13,7 @@ mod utils;
 mod volatility_helpers;
 
 // Ré-exporter les commandes Tauri
+pub use cross_asset::get_cross_asset_reaction;
 pub use heatmap_command::get_correlation_heatmap;
 pub use pair_history::get_pair_event_history;
 pub use past_events::get_past_events;
@@ -47,6 +47,17 @@ pub async fn delete_pair_from_db(
                 timeframe
             );
 
+            // 1b. Supprimer la provenance multi-sources de la paire
+            for table in ["candle_alternates", "candle_provenance", "candle_divergence"] {
+                diesel::sql_query(format!(
+                    "DELETE FROM {} WHERE symbol = ? AND timeframe = ?",
+                    table
+                ))
+                .bind::<diesel::sql_types::Text, _>(&symbol)
+                .bind::<diesel::sql_types::Text, _>(&timeframe)
+                .execute(conn)?;
+            }
+
             // 2. Supprimer les métadonnées
             tracing::debug!("🗑️ [Delete Pair] Deleting metadata...");
             let metadata_deleted = diesel::sql_query("DELETE FROM pair_metadata WHERE symbol = ? AND timeframe = ?")
@@ -75,6 +86,19 @@ pub async fn delete_pair_from_db(
     .map_err(|e| format!("Task join error: {}", e))?
 }
 
+/// Supprime les bougies d'un fichier source importé ; les heures couvertes
+/// par une autre source sont restaurées au lieu de laisser un trou
+#[tauri::command]
+pub async fn delete_pair_source(
+    source_file: String,
+) -> Result<crate::services::SourceRemoval, String> {
+    tracing::info!("🗑️ [Delete Source] Request received for {}", source_file);
+
+    tokio::task::spawn_blocking(move || crate::services::delete_source_file(&source_file))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
+
 use crate::commands::calendar_commands::CalendarState;
 
 /// Supprime un calendrier (calendar_imports + tous les événements) de la BD
@@ -1,7 +1,10 @@
 // commands/global_analysis_commands.rs - Commandes pour l'analyse globale
-use crate::models::{AnalysisFilters, GlobalAnalysisResult};
-use crate::services::{ArchiveService, DatabaseLoader, GlobalAnalyzer};
-use tauri::State;
+use crate::commands::calendar_commands::CalendarState;
+use crate::models::{AnalysisFilters, GlobalAnalysisProgress, GlobalAnalysisResult};
+use crate::services::{
+    ArchiveService, CalendarEventReader, DatabaseLoader, GlobalAnalyzer, GlobalHistoryAnalyzer,
+};
+use tauri::{AppHandle, Emitter, State};
 
 #[tauri::command]
 pub async fn analyze_all_archives(
@@ -17,6 +20,41 @@ pub async fn analyze_all_archives(
     global_analyzer.analyze_all_archives(filters)
 }
 
+/// Analyse globale calculée sur tout l'historique de bougies et le calendrier complet
+/// (et non sur les seules archives). Progression émise sur "global-analysis-progress".
+#[tauri::command]
+pub async fn analyze_global_history(
+    filters: Option<AnalysisFilters>,
+    app: AppHandle,
+    calendar_state: State<'_, CalendarState>,
+    pair_state: State<'_, super::pair_data::PairDataState>,
+) -> Result<GlobalAnalysisResult, String> {
+    let pairs_pool = pair_state
+        .pool
+        .lock()
+        .map_err(|_| "Failed to acquire database pool lock".to_string())?
+        .clone()
+        .ok_or("Database pool not initialized".to_string())?;
+    let calendar_pool = calendar_state
+        .pool
+        .lock()
+        .map_err(|_| "Failed to lock calendar pool".to_string())?
+        .clone()
+        .ok_or("Calendar DB not initialized".to_string())?;
+
+    // Balayage long (toutes les paires) : hors de l'executor async
+    tokio::task::spawn_blocking(move || {
+        let events = CalendarEventReader::new(calendar_pool).tous()?;
+        let emit_progress = |progress: &GlobalAnalysisProgress| {
+            let _ = app.emit("global-analysis-progress", progress);
+        };
+        GlobalHistoryAnalyzer::new(DatabaseLoader::new(pairs_pool), events)
+            .analyze_history(filters, &emit_progress)
+    })
+    .await
+    .map_err(|e| format!("Analyse globale interrompue: {}", e))?
+}
+
 #[tauri::command]
 pub async fn get_available_pairs(
     pair_state: State<'_, super::pair_data::PairDataState>,
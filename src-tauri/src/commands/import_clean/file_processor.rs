@@ -1,8 +1,7 @@
-use super::db_helpers::{insert_candles_to_db, insert_pair_metadata};
-use crate::services::process_file_with_cleaning;
-use crate::services::ProcessResult;
+use crate::services::{
+    import_file_streaming, ImportProgress, MergePolicy, MergeStats, StreamImportOutcome,
+};
 use serde::{Deserialize, Serialize};
-use std::fs;
 use std::path::Path;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -19,13 +18,16 @@ pub struct ImportCleanResult {
     pub import_status: String,
     pub lines_imported: usize,
     pub cleaning_stats: Option<FileCleaningStats>,
+    /// Arbitrage avec les bougies d'autres sources déjà présentes
+    pub merge_stats: Option<MergeStats>,
     pub error_message: Option<String>,
 }
 
+/// Nettoie et importe un fichier en un seul passage (plus de CSV temporaire)
 pub fn process_single_file(
     source_path: &str,
-    temp_dir: &Path,
-    data_dir: &Path,
+    policy: &MergePolicy,
+    progress: &dyn Fn(&ImportProgress),
 ) -> ImportCleanResult {
     let file_name = Path::new(source_path)
         .file_name()
@@ -33,31 +35,25 @@ pub fn process_single_file(
         .unwrap_or("unknown")
         .to_string();
 
-    match process_file_with_cleaning(source_path, temp_dir, data_dir) {
-        Ok(ProcessResult {
-            pair,
+    match import_file_streaming(source_path, policy, progress) {
+        Ok(StreamImportOutcome {
+            symbol,
             timeframe,
-            lines_cleaned,
+            rows_committed,
+            duplicates_skipped,
+            merge,
+            lines_processed,
             errors,
-            error_rate,
-            cleaned_file_path,
+            warnings,
+            ..
         }) => {
-            tracing::info!("✅ Fichier importé avec succès: {} ({})", pair, timeframe);
+            tracing::info!("✅ Fichier importé avec succès: {} ({})", symbol, timeframe);
 
-            if let Err(e) = insert_candles_to_db(&cleaned_file_path, &pair, &timeframe, &file_name)
-            {
-                tracing::warn!("⚠️  Erreur insertion candles: {}", e);
-            }
-
-            if let Err(e) =
-                insert_pair_metadata(&pair, &timeframe, lines_cleaned as i32, &file_name)
-            {
-                tracing::warn!("⚠️  Erreur insertion métadonnées: {}", e);
-            }
-
-            if let Err(e) = fs::remove_file(&cleaned_file_path) {
-                tracing::warn!("  ⚠️  Impossible de supprimer le fichier temporaire: {}", e);
-            }
+            let error_rate = if lines_processed > 0 {
+                errors as f64 / lines_processed as f64 * 100.0
+            } else {
+                0.0
+            };
 
             ImportCleanResult {
                 original_file: file_name,
@@ -66,14 +62,20 @@ pub fn process_single_file(
                 } else {
                     "success".to_string()
                 },
-                lines_imported: lines_cleaned,
+                lines_imported: rows_committed,
                 cleaning_stats: Some(FileCleaningStats {
-                    lines_processed: lines_cleaned + errors,
-                    lines_cleaned,
+                    lines_processed,
+                    lines_cleaned: lines_processed.saturating_sub(errors),
                     errors,
-                    warnings: Vec::new(),
+                    warnings,
+                }),
+                merge_stats: Some(merge),
+                error_message: (duplicates_skipped > 0 || merge.divergent > 0).then(|| {
+                    format!(
+                        "{} doublon(s) déjà présents ignorés, {} remplacé(s), {} divergent(s)",
+                        duplicates_skipped, merge.replaced, merge.divergent
+                    )
                 }),
-                error_message: None,
             }
         }
         Err(e) => {
@@ -84,6 +86,7 @@ pub fn process_single_file(
                 import_status: "failed".to_string(),
                 lines_imported: 0,
                 cleaning_stats: None,
+                merge_stats: None,
                 error_message: Some(e),
             }
         }
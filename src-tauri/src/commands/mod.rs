@@ -1,5 +1,7 @@
 // commands/mod.rs - Exports des commandes Tauri
+pub mod alert_commands;
 pub mod archive_commands;
+pub mod archive_rerun;
 pub mod backtest;
 pub mod calendar_commands;
 pub mod calendar_db_helper;
@@ -27,11 +29,20 @@ pub mod pair_importer;
 pub mod planning;
 pub mod retrospective_analysis;
 
+pub mod score_calibration_commands;
 pub mod session_commands;
+pub mod symbol_registry_commands;
+pub mod trade_journal_commands;
 pub mod volatility;
 pub mod volatility_duration_commands;
+pub mod whipsaw_commands;
 
+pub use alert_commands::{
+    check_alerts_now, clear_alert_log, delete_alert_rule, get_alert_log, get_alert_rules,
+    save_alert_rule,
+};
 pub use archive_commands::*;
+pub use archive_rerun::*;
 pub use backtest::*;
 pub use calendar_commands::get_upcoming_events;
 pub use calendar_import_commands::*;
@@ -64,15 +75,26 @@ pub use metadata::{
     get_pair_metadata_from_db, get_pairs_metadata,
 };
 pub use movement_analysis_commands::{analyze_movement_quality, get_movement_qualities};
-pub use pair_data::{import_pair_data, get_symbol_properties};
+pub use pair_data::{
+    detect_pair_file_format, get_pair_sources, get_symbol_properties, import_pair_data,
+};
+pub use planning::plan_export::{export_trading_plan, reconcile_trading_plan};
 pub use planning::projection::project_stats_on_calendar;
 // Phase 7: Retrospective analysis commands (fully integrated)
-pub use retrospective_analysis::{analyze_decay_profile, analyze_peak_delay, get_event_types};
+pub use retrospective_analysis::{
+    analyze_decay_profile, analyze_peak_delay, analyze_post_event_path, analyze_pre_event_drift,
+    get_event_types,
+};
+pub use score_calibration_commands::*;
 pub use session_commands::*;
+pub use symbol_registry_commands::*;
+pub use trade_journal_commands::import_trade_journal;
 pub use volatility::{
     analyze_quarter_entry_timing, analyze_slice_metrics, analyze_straddle_metrics, analyze_symbol,
+    analyze_symbol_by_regime, analyze_seasonality,
     analyze_volatility_duration_for_slice, calculer_offset_optimal, calculer_frequence_whipsaw,
     calculer_taux_reussite, get_best_hours, get_cached_candles_for_hour, get_hourly_stats,
     get_quarter_events, load_candles_for_hour, load_symbols, ping,
 };
 pub use volatility_duration_commands::analyze_volatility_duration;
+pub use whipsaw_commands::{analyze_event_whipsaw, analyze_slot_whipsaw};
@@ -4,6 +4,7 @@ use crate::db::DbPool;
 pub use processor::process_single_file;
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
+use tauri::Emitter;
 use tracing::info;
 
 pub struct PairDataState {
@@ -23,6 +24,7 @@ pub struct ImportSummary {
 
 #[tauri::command]
 pub async fn import_pair_data(
+    app: tauri::AppHandle,
     state: tauri::State<'_, PairDataState>,
     paths: Vec<String>,
 ) -> Result<ImportSummary, String> {
@@ -44,17 +46,17 @@ pub async fn import_pair_data(
     };
 
     info!("🔐 Tentative d'accès au pool DB paires...");
-    let pool = {
+    {
+        // Le pool garantit que les migrations de pairs.db ont été appliquées
         let pool_opt = state.pool.lock().map_err(|_| "Failed to lock pool")?;
-        match pool_opt.clone() {
-            Some(p) => {
-                info!("✅ Pool DB obtenu avec succès");
-                p
-            }
-            None => {
-                return Err("DB pool not initialized".to_string());
-            }
+        if pool_opt.is_none() {
+            return Err("DB pool not initialized".to_string());
         }
+        info!("✅ Pool DB disponible");
+    }
+
+    let emit_progress = |progress: &crate::services::ImportProgress| {
+        let _ = app.emit("pair-import-progress", progress);
     };
 
     for (file_idx, path) in paths.into_iter().enumerate() {
@@ -65,8 +67,10 @@ pub async fn import_pair_data(
             path
         );
 
-        match process_single_file(&path, &pool) {
-            Ok((pair, timeframe, row_count)) => {
+        match process_single_file(&path, &emit_progress) {
+            Ok(outcome) => {
+                let (pair, timeframe, row_count) =
+                    (outcome.symbol, outcome.timeframe, outcome.rows_committed);
                 summary.successful += 1;
 
                 if !summary.pairs_updated.contains(&pair) {
@@ -114,6 +118,23 @@ pub async fn import_pair_data(
     Ok(summary)
 }
 
+/// Détecte le format d'un fichier de paire (score par format candidat) sans l'importer
+#[tauri::command]
+pub async fn detect_pair_file_format(
+    path: String,
+) -> Result<crate::services::FormatDetection, String> {
+    crate::services::PairDataConverter::detect_file_format(&path)
+}
+
+/// Fichiers sources d'une paire : plages couvertes, bougies retenues et divergences
+#[tauri::command]
+pub async fn get_pair_sources(
+    symbol: String,
+    timeframe: String,
+) -> Result<Vec<crate::services::SourceCoverage>, String> {
+    crate::services::list_sources(&symbol, &timeframe)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SymbolProperties {
     pub point_value: f64,
@@ -1 +1,2 @@
+pub mod plan_export;
 pub mod projection;
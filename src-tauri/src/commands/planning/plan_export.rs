@@ -0,0 +1,67 @@
+// commands/planning/plan_export.rs - Export du plan hebdomadaire pour l'EA et rapprochement des exécutions
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::projection::{parse_period, projeter_periode};
+use crate::commands::calendar_commands::CalendarState;
+use crate::commands::pair_data::PairDataState;
+use crate::models::trading_plan::{PlanExportResult, PlanReconciliation, TradingPlan};
+use crate::services::archive_service::ArchiveService;
+use crate::services::planning::live_projection::ProjectionCache;
+use crate::services::planning::plan_export::PlanExporter;
+use crate::services::planning::plan_reconciliation::{lire_transactions, rapprocher};
+use crate::services::ConfigService;
+use chrono::Utc;
+use std::collections::HashMap;
+use tauri::State;
+
+/// Exporte les annonces projetées de la période (JSON, CSV, .set) dans `output_dir`.
+/// `symbol_map` force le symbole courtier d'une paire (sinon registre des symboles).
+#[allow(clippy::too_many_arguments)]
+#[tauri::command]
+pub async fn export_trading_plan(
+    start_date: String,
+    end_date: String,
+    output_dir: String,
+    symbol_map: Option<HashMap<String, String>>,
+    calendar_state: State<'_, CalendarState>,
+    pair_state: State<'_, PairDataState>,
+    archive_service: State<'_, ArchiveService>,
+    projection_cache: State<'_, ProjectionCache>,
+) -> Result<PlanExportResult, String> {
+    let period = parse_period(&start_date, &end_date)?;
+    let events = projeter_periode(
+        period,
+        &calendar_state,
+        &pair_state,
+        &archive_service,
+        &projection_cache,
+    )
+    .await?;
+
+    let plan = PlanExporter::construire(
+        &events,
+        period,
+        ConfigService::broker_clock(),
+        &symbol_map.unwrap_or_default(),
+        Utc::now(),
+    )?;
+    PlanExporter::ecrire(&plan, &output_dir)
+}
+
+/// Rapproche un plan exporté (JSON) de l'historique des transactions MT5 (CSV)
+#[tauri::command]
+pub async fn reconcile_trading_plan(
+    plan_path: String,
+    history_path: String,
+) -> Result<PlanReconciliation, String> {
+    let plan_json = std::fs::read_to_string(&plan_path)
+        .map_err(|e| format!("Plan illisible ({}): {}", plan_path, e))?;
+    let plan: TradingPlan =
+        serde_json::from_str(&plan_json).map_err(|e| format!("Plan invalide: {}", e))?;
+    let history = std::fs::read_to_string(&history_path)
+        .map_err(|e| format!("Historique illisible ({}): {}", history_path, e))?;
+
+    // Heures de l'historique lues avec l'horloge du plan (celle en vigueur à l'export)
+    let (deals, skipped) = lire_transactions(&history, &plan.broker_clock)?;
+    Ok(rapprocher(&plan, deals, skipped))
+}
@@ -1,7 +1,10 @@
 use crate::models::planning::ProjectedEvent;
+use crate::services::planning::live_projection::ProjectionCache;
 use crate::services::planning::projection_engine::ProjectionEngine;
 use crate::services::archive_service::ArchiveService;
 use crate::commands::calendar_commands::CalendarState;
+use crate::commands::pair_data::PairDataState;
+use crate::services::DatabaseLoader;
 use tauri::State;
 use chrono::{DateTime, Utc};
 
@@ -10,15 +13,42 @@ pub async fn project_stats_on_calendar(
     start_date: String,
     end_date: String,
     calendar_state: State<'_, CalendarState>,
+    pair_state: State<'_, PairDataState>,
     archive_service: State<'_, ArchiveService>,
+    projection_cache: State<'_, ProjectionCache>,
 ) -> Result<Vec<ProjectedEvent>, String> {
-    let start = DateTime::parse_from_rfc3339(&start_date)
+    let period = parse_period(&start_date, &end_date)?;
+    projeter_periode(
+        period,
+        &calendar_state,
+        &pair_state,
+        &archive_service,
+        &projection_cache,
+    )
+    .await
+}
+
+pub(crate) fn parse_period(
+    start_date: &str,
+    end_date: &str,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), String> {
+    let start = DateTime::parse_from_rfc3339(start_date)
         .map_err(|e| e.to_string())?
         .with_timezone(&Utc);
-    let end = DateTime::parse_from_rfc3339(&end_date)
+    let end = DateTime::parse_from_rfc3339(end_date)
         .map_err(|e| e.to_string())?
         .with_timezone(&Utc);
+    Ok((start, end))
+}
 
+/// Projections de la période (partagé par l'affichage et l'export du plan)
+pub(crate) async fn projeter_periode(
+    (start, end): (DateTime<Utc>, DateTime<Utc>),
+    calendar_state: &CalendarState,
+    pair_state: &PairDataState,
+    archive_service: &ArchiveService,
+    projection_cache: &ProjectionCache,
+) -> Result<Vec<ProjectedEvent>, String> {
     let pool = calendar_state
         .pool
         .lock()
@@ -26,7 +56,18 @@ pub async fn project_stats_on_calendar(
         .clone()
         .ok_or("Calendar DB not initialized")?;
     
-    let engine = ProjectionEngine::new(pool, archive_service.inner().clone());
+    let pairs_pool = pair_state
+        .pool
+        .lock()
+        .map_err(|_| "Failed to lock pair data state".to_string())?
+        .clone();
+
+    let mut engine = ProjectionEngine::new(pool, archive_service.clone());
+    if let Some(pairs_pool) = pairs_pool {
+        engine = engine
+            .with_pairs_loader(DatabaseLoader::new(pairs_pool))
+            .with_live_projection(projection_cache.clone());
+    }
     
     engine.project_stats(start, end).await
 }
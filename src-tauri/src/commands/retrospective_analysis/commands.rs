@@ -1,6 +1,16 @@
 use super::helpers::setup_databases;
-use super::services::RetroAnalysisService;
 use super::types::{EventType, EventTypeList};
+use crate::models::input_fingerprint::InputFingerprint;
+use crate::models::{CalendarEvent, PostEventReport, TimeDecay};
+use crate::services::post_event_analyzer::PostEventAnalyzer;
+use crate::services::pre_event_analyzer::PreEventAnalyzer;
+use crate::services::retrospective::simple_analyzers::{
+    agreger_ponderees, DatedValues, DecayProfileAnalyzer,
+};
+use crate::services::retrospective::RetroAnalysisService;
+use crate::services::straddle_parameter_service::StraddleParameterService;
+use crate::services::input_fingerprint::Fingerprinter;
+use crate::services::{ConfigService, DatabaseLoader};
 use chrono::Timelike;
 
 #[tauri::command]
@@ -18,12 +28,16 @@ pub async fn analyze_peak_delay(
     let (peak_delays, peak_atrs) =
         RetroAnalysisService::calculer_delai_pic(&pair, &event_type, &events, &loader).await?;
 
-    let avg_delay = (peak_delays.iter().sum::<i16>() as f64 / peak_delays.len() as f64) as i16;
-    let avg_peak_atr = peak_atrs.iter().sum::<f64>() / peak_atrs.len() as f64;
+    let decay = ConfigService::time_decay();
+    let delays: DatedValues<f64> = peak_delays.iter().map(|(d, v)| (*d, *v as f64)).collect();
+    let (mean_delay, median_delay) = agreger_ponderees(&delays, &decay);
+    let avg_delay = mean_delay as i16;
+    let (avg_peak_atr, _) = agreger_ponderees(&peak_atrs, &decay);
 
     Ok(
         crate::commands::retrospective_analysis::types::PeakDelayResult {
             peak_delay_minutes: avg_delay,
+            peak_delay_median_minutes: median_delay.round() as i16,
             peak_atr: avg_peak_atr,
             event_minute: events
                 .first()
@@ -60,20 +74,16 @@ pub async fn analyze_decay_profile(
     let (decay_rates, peak_atrs) =
         RetroAnalysisService::calculer_profil_decroissance(&pair, &events, &loader).await?;
 
-    let avg_decay_rate = decay_rates.iter().sum::<f64>() / decay_rates.len() as f64;
-    let avg_peak_atr = peak_atrs.iter().sum::<f64>() / peak_atrs.len() as f64;
-    let (decay_speed, timeout) = if avg_decay_rate > 3.0 {
-        ("Très Rapide".into(), 18)
-    } else if avg_decay_rate > 1.5 {
-        ("Rapide".into(), 25)
-    } else {
-        ("Lent".into(), 32)
-    };
+    let decay = ConfigService::time_decay();
+    let (avg_decay_rate, median_decay_rate) = agreger_ponderees(&decay_rates, &decay);
+    let (avg_peak_atr, _) = agreger_ponderees(&peak_atrs, &decay);
+    let (decay_speed, timeout) = DecayProfileAnalyzer::timeout_recommande(avg_decay_rate);
 
     Ok(
         crate::commands::retrospective_analysis::types::DecayProfileResult {
             peak_atr: avg_peak_atr,
             decay_rate_pips_per_minute: avg_decay_rate,
+            decay_rate_median: median_decay_rate,
             decay_speed,
             recommended_timeout_minutes: timeout,
             event_count: events.len(),
@@ -87,14 +97,74 @@ pub async fn analyze_volatility_profile(
     pair: String,
     event_type: String,
     state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
-) -> Result<crate::commands::retrospective_analysis::types::EventImpactResult, String> {
+) -> Result<crate::models::EventImpactResult, String> {
     let (conn, loader) = setup_databases(&state).await?;
     let events = super::helpers::load_events_by_type(conn, &event_type).await?;
     if events.is_empty() {
         return Err(format!("No events: {}", event_type));
     }
 
-    RetroAnalysisService::calculer_impact_evenement(&pair, &event_type, &events, &loader).await
+    let decay = ConfigService::time_decay();
+    let mut result =
+        RetroAnalysisService::calculer_impact_evenement(&pair, &event_type, &events, &loader, &decay)
+            .await?;
+    result.fingerprint = Some(empreinte(&loader, &pair, &event_type, &events, &decay)?);
+    Ok(result)
+}
+
+/// Entrées des analyses rétrospectives : historique de la paire, occurrences, pondération
+fn empreinte(
+    loader: &DatabaseLoader,
+    pair: &str,
+    event_type: &str,
+    events: &[CalendarEvent],
+    decay: &TimeDecay,
+) -> Result<InputFingerprint, String> {
+    let parameters = [("time_decay".to_string(), Fingerprinter::parametre(decay)?)].into();
+    Fingerprinter::capturer(loader, &[pair], events, Some(event_type), parameters)
+}
+
+#[tauri::command]
+pub async fn analyze_pre_event_drift(
+    pair: String,
+    event_type: String,
+    state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+) -> Result<crate::commands::retrospective_analysis::types::PreEventAnalysisResult, String> {
+    let (conn, loader) = setup_databases(&state).await?;
+    let events = super::helpers::load_events_by_type(conn, &event_type).await?;
+    if events.is_empty() {
+        return Err(format!("No events: {}", event_type));
+    }
+
+    let decay = ConfigService::time_decay();
+    let profile = PreEventAnalyzer::analyser(&pair, &event_type, &events, &loader, &decay)?;
+    let offset_rule = StraddleParameterService::regle_offset_pre_evenement(&profile);
+
+    Ok(
+        crate::commands::retrospective_analysis::types::PreEventAnalysisResult {
+            profile,
+            offset_rule,
+            fingerprint: Some(empreinte(&loader, &pair, &event_type, &events, &decay)?),
+        },
+    )
+}
+
+#[tauri::command]
+pub async fn analyze_post_event_path(
+    pair: String,
+    event_type: String,
+    state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+) -> Result<PostEventReport, String> {
+    let (conn, loader) = setup_databases(&state).await?;
+    let events = super::helpers::load_events_by_type(conn, &event_type).await?;
+    if events.is_empty() {
+        return Err(format!("No events: {}", event_type));
+    }
+
+    let decay = ConfigService::time_decay();
+    let mut report = PostEventAnalyzer::analyser(&pair, &event_type, &events, &loader, &decay)?;
+    report.fingerprint = Some(empreinte(&loader, &pair, &event_type, &events, &decay)?);
+    Ok(report)
 }
 
 #[tauri::command]
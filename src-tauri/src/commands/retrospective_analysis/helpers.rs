@@ -43,8 +43,38 @@ pub async fn load_events_by_type(
         .map_err(|e| format!("Load failed: {}", e))
 }
 
-pub fn calculer_atr(high: f64, low: f64, close: f64) -> f64 {
-    (high - low).max((high - close.abs()).max(close - low.abs()))
+/// Occurrence précise du calendrier
+pub fn load_event_by_id(
+    state: &tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+    event_id: i32,
+) -> Result<crate::models::CalendarEvent, String> {
+    use diesel::SelectableHelper;
+    let mut conn = {
+        let pool_opt = state.pool.lock().map_err(|_| "DB lock failed")?;
+        let pool = pool_opt.as_ref().ok_or("DB not initialized")?;
+        pool.get().map_err(|e| format!("Connection failed: {}", e))?
+    };
+    calendar_events
+        .find(event_id)
+        .select(crate::models::CalendarEvent::as_select())
+        .first(&mut conn)
+        .optional()
+        .map_err(|e| format!("Load failed: {}", e))?
+        .ok_or_else(|| format!("Event {} not found", event_id))
+}
+
+/// Occurrences de plusieurs types d'événements (triées par date)
+pub async fn load_events_by_types(
+    mut conn: diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<SqliteConnection>>,
+    event_types: &[String],
+) -> Result<Vec<crate::models::CalendarEvent>, String> {
+    use diesel::SelectableHelper;
+    calendar_events
+        .filter(description.eq_any(event_types))
+        .select(crate::models::CalendarEvent::as_select())
+        .order(event_time.asc())
+        .load(&mut conn)
+        .map_err(|e| format!("Load failed: {}", e))
 }
 
 pub fn get_event_types_from_db(
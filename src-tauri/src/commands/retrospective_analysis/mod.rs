@@ -1,12 +1,8 @@
-pub mod bidi_calculator;
 pub mod commands;
 pub mod helpers;
-pub mod impact_analyzer;
-pub mod impact_data_processor;
-pub mod services;
-pub mod simple_analyzers;
 pub mod types;
 
 pub use commands::{
-    analyze_decay_profile, analyze_peak_delay, analyze_volatility_profile, get_event_types,
+    analyze_decay_profile, analyze_peak_delay, analyze_post_event_path, analyze_pre_event_drift,
+    analyze_volatility_profile, get_event_types,
 };
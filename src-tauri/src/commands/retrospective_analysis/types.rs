@@ -4,6 +4,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PeakDelayResult {
     pub peak_delay_minutes: i16,
+    /// Médiane pondérée par ancienneté (moins sensible aux pics isolés)
+    #[serde(default)]
+    pub peak_delay_median_minutes: i16,
     pub peak_atr: f64,
     pub event_minute: u8,
     pub confidence: f64,
@@ -19,6 +22,9 @@ pub struct PeakDelayResult {
 pub struct DecayProfileResult {
     pub peak_atr: f64,
     pub decay_rate_pips_per_minute: f64,
+    /// Médiane pondérée par ancienneté du taux de décroissance
+    #[serde(default)]
+    pub decay_rate_median: f64,
     pub decay_speed: String,
     pub recommended_timeout_minutes: i16,
     pub event_count: usize,
@@ -56,38 +62,13 @@ pub struct VolatilityProfileResult {
     pub pair: String,
 }
 
-/// Event impact analysis: volatility comparison before/after event
+/// Pre-event analysis: drift/compression profile and the offset rule it implies
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EventImpactResult {
-    pub atr_timeline_before: Vec<f64>, // ATR moyen T-30 à T0 (30 points)
-    pub atr_timeline_after: Vec<f64>,  // ATR moyen T0 à T+90 (90 points)
-    pub body_timeline_before: Vec<f64>, // Body% moyen T-30 à T0 (30 points)
-    pub body_timeline_after: Vec<f64>, // Body% moyen T0 à T+90 (90 points)
-    pub noise_ratio_before: f64,       // Noise Ratio moyen avant événement
-    pub noise_ratio_during: f64,       // Noise Ratio au moment de l'événement
-    pub noise_ratio_after: f64,        // Noise Ratio moyen après événement
-    pub volatility_increase_percent: f64, // % d'augmentation ATR (après vs avant)
-    pub event_count: usize,            // Nombre d'occurrences analysées
-    pub event_type: String,
-    pub pair: String,
-    pub event_datetime: String,  // ISO 8601: heure moyenne de l'événement
-    pub timezone_offset: String, // Ex: "UTC+0" ou "UTC-5"
-
-    // === PARAMÈTRES BIDI POUR STRADDLE (DIRECTIONNEL) ===
-    pub meilleur_moment: f64, // Offset optimal en minutes avant événement (T0 - meilleur_moment)
-    pub stop_loss: f64,       // Stop Loss en pips (basé sur ATR moyen)
-    pub trailing_stop: f64,   // Trailing Stop coefficient (ajusté selon noise)
-    pub timeout: i32,         // Timeout recommandé en minutes (basé sur decay de volatilité)
-    pub offset: f64,          // Offset d'entrée en points (distance du prix)
-    
-    // === PARAMÈTRES BIDI POUR STRADDLE (SIMULTANÉ) ===
-    pub stop_loss_simultaneous: f64,       // SL spécifique pour mode Simultané (souvent plus large)
-    pub trailing_stop_simultaneous: f64,   // TS spécifique pour mode Simultané
-    pub offset_simultaneous: f64,          // Offset spécifique pour mode Simultané
-    pub stop_loss_recovery_simultaneous: f64, // SL Recovery spécifique pour mode Simultané
-    pub stop_loss_recovery: f64,           // SL Recovery (Directionnel)
-
-    pub point_value: f64,     // Valeur d'un point pour normalisation (ex: 0.001 pour JPY)
+pub struct PreEventAnalysisResult {
+    pub profile: crate::models::PreEventProfile,
+    pub offset_rule: crate::models::PreEventOffsetRule,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<crate::models::input_fingerprint::InputFingerprint>, // Entrées du calcul (reproductibilité)
 }
 
 /// Available event types with count
@@ -0,0 +1,152 @@
+// commands/score_calibration_commands.rs - Calibration du score de confiance par classe d'actifs
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use crate::commands::calendar_commands::CalendarState;
+use crate::commands::retrospective_analysis::helpers::{load_events_by_types, setup_databases};
+use crate::models::asset_class::AssetType;
+use crate::models::trade_journal::JournalTrade;
+use crate::models::{AssetProperties, CalendarEvent, ScoreWeightSet};
+use crate::services::backtest::{BacktestConfig, BacktestEngine, StrategyMode};
+use crate::services::score_calibration::{
+    collect_samples, realised_sample, CalibrationReport, ScoreCalibrationService,
+};
+use crate::services::trade_journal::{
+    decoder_releve, evenement_du_trade, lire_releve, reconstituer_trades,
+};
+use crate::services::ConfigService;
+use crate::services::SymbolRegistryService;
+use chrono::{NaiveDate, NaiveTime};
+use serde::Deserialize;
+use tauri::State;
+
+#[derive(Debug, Deserialize)]
+pub struct CalibrationRequest {
+    pub pairs: Vec<String>,
+    /// Types d'événements du calendrier (toutes leurs occurrences sont simulées)
+    #[serde(default)]
+    pub event_types: Vec<String>,
+    /// Créneaux HH:MM (UTC) simulés chaque jour ouvré entre start_date et end_date
+    #[serde(default)]
+    pub slots: Vec<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    /// Paramètres d'ordres en pips ; la valeur du point est ajustée à chaque paire
+    pub config: BacktestConfig,
+    pub mode: StrategyMode,
+    /// Relevé MT5 (HTML ou CSV) : les trades réels autour des ancres s'ajoutent aux simulés
+    #[serde(default)]
+    pub trade_history_path: Option<String>,
+}
+
+/// Ajuste les poids du score sur les trades simulés et enregistre un jeu par classe d'actifs
+#[tauri::command]
+pub async fn calibrate_confidence_scorer(
+    request: CalibrationRequest,
+    state: State<'_, CalendarState>,
+) -> Result<CalibrationReport, String> {
+    if request.pairs.is_empty() {
+        return Err("Aucune paire fournie".to_string());
+    }
+    if request.event_types.is_empty() && request.slots.is_empty() {
+        return Err("Aucun événement ni créneau à simuler".to_string());
+    }
+
+    let (conn, loader) = setup_databases(&state).await?;
+    let mut anchors: Vec<(String, Vec<CalendarEvent>)> = Vec::new();
+
+    if !request.event_types.is_empty() {
+        let events = load_events_by_types(conn, &request.event_types).await?;
+        for event_type in &request.event_types {
+            let occurrences: Vec<CalendarEvent> = events
+                .iter()
+                .filter(|e| &e.description == event_type)
+                .cloned()
+                .collect();
+            anchors.push((event_type.clone(), occurrences));
+        }
+    }
+
+    if !request.slots.is_empty() {
+        let parse_date = |value: &Option<String>, label: &str| {
+            let value = value
+                .as_deref()
+                .ok_or(format!("{} requise pour les créneaux", label))?;
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map_err(|e| format!("{} invalide: {}", label, e))
+        };
+        let start = parse_date(&request.start_date, "Date de début")?;
+        let end = parse_date(&request.end_date, "Date de fin")?;
+        for slot in &request.slots {
+            let time = NaiveTime::parse_from_str(slot, "%H:%M")
+                .map_err(|e| format!("Créneau invalide {}: {}", slot, e))?;
+            anchors.push((
+                slot.clone(),
+                BacktestEngine::time_slot_events(time, start, end),
+            ));
+        }
+    }
+
+    let trades = match &request.trade_history_path {
+        Some(path) => trades_clotures(path)?,
+        None => Vec::new(),
+    };
+
+    let mut samples = Vec::new();
+    for pair in &request.pairs {
+        let config = BacktestConfig {
+            point_value: AssetProperties::from_symbol(pair).pip_value,
+            ..request.config.clone()
+        };
+        for (anchor, events) in &anchors {
+            samples.extend(collect_samples(
+                pair,
+                anchor,
+                events,
+                &config,
+                request.mode,
+                &loader,
+            )?);
+            let canonical = SymbolRegistryService::canonical(pair);
+            for trade in trades
+                .iter()
+                .filter(|t| SymbolRegistryService::canonical(&t.symbol) == canonical)
+            {
+                let Some(event) = evenement_du_trade(trade, events) else {
+                    continue;
+                };
+                samples.extend(realised_sample(
+                    pair,
+                    anchor,
+                    event.event_time.and_utc(),
+                    trade.net_profit > 0.0,
+                    &loader,
+                )?);
+            }
+        }
+    }
+
+    let report = ScoreCalibrationService::calibrate(&samples);
+    ConfigService::save_score_weight_sets(&report.weight_sets).map_err(|e| e.to_string())?;
+    Ok(report)
+}
+
+/// Positions clôturées du relevé MT5 (heure serveur convertie avec l'horloge configurée)
+fn trades_clotures(path: &str) -> Result<Vec<JournalTrade>, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("Relevé illisible {}: {}", path, e))?;
+    let (deals, _) = lire_releve(&decoder_releve(&bytes)?, &ConfigService::broker_clock())?;
+    Ok(reconstituer_trades(&deals)
+        .into_iter()
+        .filter(|t| t.exit_price.is_some())
+        .collect())
+}
+
+#[tauri::command]
+pub async fn get_score_weight_sets() -> Result<Vec<ScoreWeightSet>, String> {
+    ConfigService::get_score_weight_sets().map_err(|e| e.to_string())
+}
+
+/// Revient au barème par défaut pour une classe d'actifs (toutes si None)
+#[tauri::command]
+pub async fn reset_score_weights(asset_type: Option<AssetType>) -> Result<usize, String> {
+    ConfigService::reset_score_weights(asset_type).map_err(|e| e.to_string())
+}
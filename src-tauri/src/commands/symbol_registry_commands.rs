@@ -0,0 +1,48 @@
+// commands/symbol_registry_commands.rs - Commandes du registre des symboles courtier
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use crate::commands::pair_data::PairDataState;
+use crate::db::DbPool;
+use crate::models::SymbolSpec;
+use crate::services::symbol_registry::{SymbolImportReport, SymbolRegistryService};
+use tauri::State;
+
+fn pairs_pool(state: &State<'_, PairDataState>) -> Result<DbPool, String> {
+    let pool_guard = state
+        .pool
+        .lock()
+        .map_err(|e| format!("Failed to lock pool: {}", e))?;
+    pool_guard
+        .clone()
+        .ok_or_else(|| "DB pool not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn list_symbol_specs(state: State<'_, PairDataState>) -> Result<Vec<SymbolSpec>, String> {
+    SymbolRegistryService::list(&pairs_pool(&state)?)
+}
+
+#[tauri::command]
+pub async fn save_symbol_spec(
+    spec: SymbolSpec,
+    state: State<'_, PairDataState>,
+) -> Result<(), String> {
+    SymbolRegistryService::upsert(&pairs_pool(&state)?, &spec)
+}
+
+#[tauri::command]
+pub async fn delete_symbol_spec(
+    symbol: String,
+    state: State<'_, PairDataState>,
+) -> Result<bool, String> {
+    SymbolRegistryService::delete(&pairs_pool(&state)?, &symbol)
+}
+
+/// Importe un export de symboles MT5 (CSV du terminal ou JSON)
+#[tauri::command]
+pub async fn import_mt5_symbols(
+    path: String,
+    state: State<'_, PairDataState>,
+) -> Result<SymbolImportReport, String> {
+    SymbolRegistryService::import_mt5_export(&pairs_pool(&state)?, &path)
+}
@@ -0,0 +1,93 @@
+// commands/trade_journal_commands.rs - Import du relevé de compte MT5 et écarts avec la simulation
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use crate::commands::calendar_commands::CalendarState;
+use crate::commands::pair_data::PairDataState;
+use crate::models::trade_journal::TradeJournalReport;
+use crate::models::trading_plan::TradingPlan;
+use crate::models::CalendarEvent;
+use crate::services::planning::plan_reconciliation::{parse_utc, LATE_CLOSE_MINUTES};
+use crate::services::trade_journal::{
+    analyser_journal, couts_applicables, decoder_releve, lire_releve,
+};
+use crate::services::{CalendarEventReader, ConfigService, DatabaseLoader};
+use chrono::Duration;
+use tauri::State;
+use tracing::info;
+
+/// Importe un relevé MT5 (rapport HTML ou export CSV), rapproche les trades du plan
+/// (optionnel) et du calendrier, puis compare avec ce que `EventSimulator` prévoyait.
+/// `apply_costs` enregistre les glissements observés dans le modèle de coûts.
+#[tauri::command]
+pub async fn import_trade_journal(
+    history_path: String,
+    plan_path: Option<String>,
+    apply_costs: bool,
+    calendar_state: State<'_, CalendarState>,
+    pair_state: State<'_, PairDataState>,
+) -> Result<TradeJournalReport, String> {
+    let plan: Option<TradingPlan> = match &plan_path {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .map_err(|e| format!("Plan illisible ({}): {}", path, e))?;
+            Some(serde_json::from_str(&json).map_err(|e| format!("Plan invalide: {}", e))?)
+        }
+        None => None,
+    };
+    let bytes = std::fs::read(&history_path)
+        .map_err(|e| format!("Relevé illisible ({}): {}", history_path, e))?;
+    // Heures du relevé lues avec l'horloge du plan s'il est fourni (celle en vigueur à l'export)
+    let clock = plan
+        .as_ref()
+        .map(|p| p.broker_clock)
+        .unwrap_or_else(ConfigService::broker_clock);
+    let (deals, skipped) = lire_releve(&decoder_releve(&bytes)?, &clock)?;
+
+    let pairs_pool = pair_state
+        .pool
+        .lock()
+        .map_err(|_| "Failed to acquire database pool lock".to_string())?
+        .clone()
+        .ok_or("Database pool not initialized".to_string())?;
+    let calendar_pool = calendar_state
+        .pool
+        .lock()
+        .map_err(|_| "Failed to lock calendar pool".to_string())?
+        .clone()
+        .ok_or("Calendar DB not initialized".to_string())?;
+
+    // Annonces couvrant la période des transactions (tolérance du rapprochement incluse)
+    let times: Vec<_> = deals
+        .iter()
+        .filter_map(|d| parse_utc(&d.time_utc))
+        .collect();
+    let events: Vec<CalendarEvent> = match (times.iter().min(), times.iter().max()) {
+        (Some(first), Some(last)) => CalendarEventReader::new(calendar_pool).entre(
+            (*first - Duration::minutes(LATE_CLOSE_MINUTES)).naive_utc(),
+            (*last + Duration::minutes(LATE_CLOSE_MINUTES)).naive_utc(),
+        )?,
+        _ => Vec::new(),
+    };
+
+    let mut report = tokio::task::spawn_blocking(move || {
+        analyser_journal(
+            deals,
+            skipped,
+            plan.as_ref(),
+            &events,
+            &DatabaseLoader::new(pairs_pool),
+        )
+    })
+    .await
+    .map_err(|e| format!("Analyse du journal interrompue: {}", e))?;
+
+    if apply_costs {
+        let costs = couts_applicables(&report);
+        if !costs.is_empty() {
+            ConfigService::save_execution_costs(&costs).map_err(|e| e.to_string())?;
+            info!("💾 {} glissement(s) observé(s) enregistré(s)", costs.len());
+            report.costs_applied = true;
+        }
+    }
+    Ok(report)
+}
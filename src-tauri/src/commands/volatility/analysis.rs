@@ -1,7 +1,7 @@
 use crate::commands::calendar_commands::CalendarState;
-use crate::models::AnalysisResult;
-use crate::services::{CsvLoader, VolatilityAnalyzer};
-use chrono::{DateTime, Utc};
+use crate::models::{AnalysisResult, Candle, RegimeFilter};
+use crate::services::volatility::{analyser_symbole as analyser, charger_historique_m1};
+use crate::services::CsvLoader;
 use serde::{Deserialize, Serialize};
 use tauri::State;
 use tracing::{error, info};
@@ -91,80 +91,51 @@ pub async fn load_symbols(
 pub async fn analyze_symbol(
     symbol: String,
     calendar_id: i32,
+    regime: Option<RegimeFilter>,
     calendar_state: State<'_, CalendarState>,
     pair_state: State<'_, super::super::pair_data::PairDataState>,
 ) -> Result<AnalysisResult, CommandError> {
     info!(
-        "Command: analyze_symbol({}, calendar_id={})",
-        symbol, calendar_id
+        "Command: analyze_symbol({}, calendar_id={}, regime={:?})",
+        symbol, calendar_id, regime
     );
 
-    let mut candles = Vec::new();
-    let pool_opt = pair_state
-        .pool
-        .lock()
-        .map_err(|_| CommandError::from("Failed to acquire database pool lock".to_string()))?;
-    if let Some(pool) = pool_opt.as_ref() {
-        let db_loader = crate::services::DatabaseLoader::new(pool.clone());
-        let start = DateTime::<Utc>::from_timestamp(0, 0).ok_or(CommandError::from(
-            "Invalid Unix timestamp 0 for date range".to_string(),
-        ))?;
-        let end = Utc::now();
-        match db_loader.load_candles_by_pair(&symbol, "M1", start, end) {
-            Ok(loaded) => {
-                candles = loaded;
-                info!(
-                    "Loaded {} candles for {} from DatabaseLoader",
-                    candles.len(),
-                    symbol
-                );
-            }
-            Err(e) => {
-                info!(
-                    "DatabaseLoader failed for {}: {}, falling back to CsvLoader",
-                    symbol, e
-                );
-            }
-        }
-    }
-    drop(pool_opt);
-
-    if candles.is_empty() {
-        let loader = CsvLoader::new();
-        candles = loader.load_candles(&symbol).map_err(|e| {
-            error!(
-                "Failed to load candles for {} from both DB and CSV: {}",
-                symbol, e
-            );
-            CommandError::from(e)
-        })?;
-        info!(
-            "Loaded {} candles for {} from CsvLoader",
-            candles.len(),
-            symbol
-        );
-    }
-
-    info!("Total candles loaded for {}: {}", symbol, candles.len());
+    analyser_symbole(&symbol, Some(calendar_id), regime, &calendar_state, &pair_state)
+}
 
+/// Analyse de volatilité d'un symbole, empreinte des entrées comprise
+pub(crate) fn analyser_symbole(
+    symbol: &str,
+    calendar_id: Option<i32>,
+    regime: Option<RegimeFilter>,
+    calendar_state: &CalendarState,
+    pair_state: &super::super::pair_data::PairDataState,
+) -> Result<AnalysisResult, CommandError> {
     let pool = calendar_state
         .pool
         .lock()
         .map_err(|e| format!("Failed to lock calendar pool: {}", e))?
         .clone();
+    let pairs_pool = pair_state
+        .pool
+        .lock()
+        .map_err(|e| format!("Failed to lock pair data pool: {}", e))?
+        .clone();
 
-    let analyzer = VolatilityAnalyzer::new(candles);
-    let result = analyzer.analyze(&symbol, pool).map_err(|e| {
-        error!("Failed to analyze {}: {}", symbol, e);
-        CommandError::from(e)
-    })?;
-
-    info!(
-        "Analysis complete for {}: confidence={:.1}",
-        symbol, result.confidence_score
-    );
+    Ok(analyser(symbol, calendar_id, regime, pool, pairs_pool)?)
+}
 
-    Ok(result)
+/// Charge tout l'historique M1 d'un symbole (DatabaseLoader, sinon CsvLoader)
+pub(super) fn load_symbol_candles(
+    symbol: &str,
+    pair_state: &super::super::pair_data::PairDataState,
+) -> Result<Vec<Candle>, CommandError> {
+    let pairs_pool = pair_state
+        .pool
+        .lock()
+        .map_err(|_| CommandError::from("Failed to acquire database pool lock".to_string()))?
+        .clone();
+    Ok(charger_historique_m1(symbol, pairs_pool.as_ref())?)
 }
 
 #[tauri::command]
@@ -41,17 +41,16 @@ pub async fn get_quarter_events(
         .get()
         .map_err(|e| format!("Failed to get connection: {}", e))?;
 
-    // Determine currencies from symbol (e.g., "EURUSD" -> "EUR", "USD")
-    let base_currency = if symbol.len() >= 3 {
-        &symbol[0..3]
-    } else {
-        ""
-    };
-    let quote_currency = if symbol.len() >= 6 {
-        &symbol[3..6]
-    } else {
-        ""
-    };
+    // Determine currencies from symbol (registre courtier, sinon "EURUSD" -> "EUR", "USD")
+    let spec = crate::services::SymbolRegistryService::lookup(&symbol);
+    let base_currency = spec
+        .as_ref()
+        .and_then(|s| s.base_currency.clone())
+        .unwrap_or_else(|| symbol.get(0..3).unwrap_or("").to_string());
+    let quote_currency = spec
+        .as_ref()
+        .and_then(|s| s.quote_currency.clone())
+        .unwrap_or_else(|| symbol.get(3..6).unwrap_or("").to_string());
 
     let start_minute = quarter * 15;
     let end_minute = start_minute + 15;
@@ -6,17 +6,22 @@ mod analyze_volatility_duration_command;
 mod candles_loader;
 mod events;
 mod minute_scoring;
+mod regime_analysis;
+mod seasonality;
 mod stats;
 mod straddle_analysis;
 mod straddle_metrics;
 mod straddle_metrics_types;
 
 pub use analysis::{analyze_symbol, load_symbols, ping};
+pub(crate) use analysis::analyser_symbole;
 pub use analyze_quarter_entry_timing_command::analyze_quarter_entry_timing;
 pub use analyze_slice_metrics_command::analyze_slice_metrics;
 pub use analyze_volatility_duration_command::analyze_volatility_duration_for_slice;
 pub use candles_loader::{get_cached_candles_for_hour, load_candles_for_hour};
 pub use events::get_quarter_events;
+pub use regime_analysis::analyze_symbol_by_regime;
+pub use seasonality::analyze_seasonality;
 pub use stats::{get_best_hours, get_hourly_stats};
 pub use straddle_analysis::{calculer_offset_optimal, calculer_frequence_whipsaw, calculer_taux_reussite};
 pub use straddle_metrics::analyze_straddle_metrics;
@@ -0,0 +1,77 @@
+// commands/volatility/regime_analysis.rs - Analyse de volatilité découpée par régime
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use super::analysis::{load_symbol_candles, CommandError};
+use crate::commands::calendar_commands::CalendarState;
+use crate::commands::pair_data::PairDataState;
+use crate::models::{AnalysisResult, RegimeThresholds, VolatilityRegime};
+use crate::services::volatility_regime::RegimeClassifier;
+use crate::services::VolatilityAnalyzer;
+use serde::Serialize;
+use tauri::State;
+use tracing::{info, warn};
+
+#[derive(Debug, Serialize)]
+pub struct RegimeSlice {
+    pub regime: VolatilityRegime,
+    /// Journées de l'historique classées dans ce régime
+    pub days: usize,
+    /// None si le régime n'a pas assez de données pour l'analyse
+    pub result: Option<AnalysisResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RegimeSplitAnalysis {
+    pub symbol: String,
+    pub current_regime: Option<VolatilityRegime>,
+    pub thresholds: RegimeThresholds,
+    pub slices: Vec<RegimeSlice>,
+}
+
+/// Une analyse complète par régime, pour comparer la structure horaire calme vs agitée
+#[tauri::command]
+pub async fn analyze_symbol_by_regime(
+    symbol: String,
+    calendar_state: State<'_, CalendarState>,
+    pair_state: State<'_, PairDataState>,
+) -> Result<RegimeSplitAnalysis, CommandError> {
+    info!("Command: analyze_symbol_by_regime({})", symbol);
+
+    let candles = load_symbol_candles(&symbol, &pair_state)?;
+    let calendar = RegimeClassifier::classify(&symbol, &RegimeClassifier::daily_bars(&candles));
+    let pool = calendar_state
+        .pool
+        .lock()
+        .map_err(|e| format!("Failed to lock calendar pool: {}", e))?
+        .clone();
+
+    let mut slices = Vec::new();
+    for regime in VolatilityRegime::ALL {
+        let subset = RegimeClassifier::filter_candles(&candles, &calendar, regime);
+        let result = match VolatilityAnalyzer::new(subset).analyze(&symbol, pool.clone()) {
+            Ok(mut result) => {
+                result.regime = Some(regime);
+                Some(result)
+            }
+            Err(e) => {
+                warn!(
+                    "⚠️ Régime {:?} non analysable pour {}: {}",
+                    regime, symbol, e
+                );
+                None
+            }
+        };
+        slices.push(RegimeSlice {
+            regime,
+            days: calendar.day_count(regime),
+            result,
+        });
+    }
+
+    Ok(RegimeSplitAnalysis {
+        symbol,
+        current_regime: calendar.current,
+        thresholds: calendar.thresholds,
+        slices,
+    })
+}
@@ -0,0 +1,75 @@
+// commands/volatility/seasonality.rs - Saisonnalité calendaire d'une paire
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use super::analysis::{load_symbol_candles, CommandError};
+use crate::commands::pair_data::PairDataState;
+use crate::models::{SeasonalWindow, SeasonalityReport};
+use crate::services::input_fingerprint::Fingerprinter;
+use crate::services::seasonality_analyzer::SeasonalityAnalyzer;
+use crate::services::straddle_simulator_helpers::cout_execution;
+use crate::services::{ConfigService, DatabaseLoader};
+use tauri::State;
+use tracing::info;
+
+/// Volatilité et résultats Straddle par jour de semaine, mois, premier vendredi,
+/// fin de mois et fin de trimestre, avec tests de significativité contre le reste.
+/// Sans plage horaire, la journée entière est analysée.
+#[tauri::command]
+pub async fn analyze_seasonality(
+    symbol: String,
+    start_hour_utc: Option<u8>,
+    end_hour_utc: Option<u8>,
+    pair_state: State<'_, PairDataState>,
+) -> Result<SeasonalityReport, CommandError> {
+    info!(
+        "Command: analyze_seasonality({}, {:?}-{:?} UTC)",
+        symbol, start_hour_utc, end_hour_utc
+    );
+
+    let window = match (start_hour_utc, end_hour_utc) {
+        (Some(start), Some(end)) if start < 24 && end <= 24 && start != end => {
+            Some(SeasonalWindow {
+                start_hour_utc: start,
+                end_hour_utc: end,
+            })
+        }
+        (None, None) => None,
+        _ => {
+            return Err(CommandError::from(
+                "Plage horaire invalide (heures UTC 0-24, début ≠ fin)".to_string(),
+            ))
+        }
+    };
+
+    let candles = load_symbol_candles(&symbol, &pair_state)?;
+    let costs = cout_execution(&symbol, &ConfigService::execution_costs());
+    let mut report = SeasonalityAnalyzer::analyser(&symbol, &candles, window, &costs)?;
+
+    // Empreinte des entrées si l'historique vient de pairs.db (pas de repli CSV)
+    let pairs_pool = pair_state
+        .pool
+        .lock()
+        .map_err(|e| format!("Failed to lock pair data pool: {}", e))?
+        .clone();
+    if let Some(pairs_pool) = pairs_pool {
+        let range = DatabaseLoader::new(pairs_pool)
+            .candle_range(&symbol, "M1")
+            .map_err(|e| CommandError::from(e.to_string()))?;
+        let parameters = [
+            ("window".to_string(), Fingerprinter::parametre(&window)?),
+            (
+                "slippage_pips".to_string(),
+                Fingerprinter::parametre(&costs.slippage_pips)?,
+            ),
+        ]
+        .into();
+        report.fingerprint = Some(Fingerprinter::construire(
+            vec![range],
+            Vec::new(),
+            None,
+            0,
+            parameters,
+        )?);
+    }
+    Ok(report)
+}
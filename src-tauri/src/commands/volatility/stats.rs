@@ -25,7 +25,7 @@ pub async fn get_hourly_stats(
         });
     }
 
-    let result = analyze_symbol(symbol, calendar_id, calendar_state, pair_state).await?;
+    let result = analyze_symbol(symbol, calendar_id, None, calendar_state, pair_state).await?;
 
     let stats = result
         .hourly_stats
@@ -51,6 +51,6 @@ pub async fn get_best_hours(
         symbol, calendar_id
     );
 
-    let result = analyze_symbol(symbol, calendar_id, calendar_state, pair_state).await?;
+    let result = analyze_symbol(symbol, calendar_id, None, calendar_state, pair_state).await?;
     Ok(result.best_quarter)
 }
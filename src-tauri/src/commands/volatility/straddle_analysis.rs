@@ -1,11 +1,16 @@
 // commands/volatility/straddle_analysis.rs - Commands pour calculs Straddle
-use crate::models::{Candle, AssetProperties};
+use crate::commands::calendar_commands::CalendarState;
+use crate::commands::retrospective_analysis::helpers::load_event_by_id;
+use crate::models::{Candle, AssetProperties, ConfidenceInterval};
 use crate::services::straddle_simulator_helpers::calculer_atr_moyen;
+use crate::services::backtest::BacktestConfig;
 use crate::services::volatility::{
     calculer_frequence_whipsaw as service_calculer_frequence_whipsaw,
-    simuler_taux_reussite_straddle,
+    config_whipsaw_par_defaut, frequence_whipsaw_evenements, noise_ratio_moyen,
+    percentile_95_meches, simuler_taux_reussite_straddle,
 };
 use crate::services::StraddleParameterService;
+use chrono::NaiveTime;
 use serde::{Deserialize, Serialize};
 use tauri::command;
 
@@ -31,9 +36,16 @@ pub struct WhipsawResponse {
     pub total_trades: usize,
     pub whipsaw_count: usize,
     pub whipsaw_frequency_percentage: f64,
+    /// Intervalle de confiance de la fréquence (en %)
+    #[serde(default)]
+    pub whipsaw_frequency_ci: Option<ConfidenceInterval>,
     pub risk_level: String,
     pub risk_color: String,
     pub offset_pips: f64,
+    /// Créneau (HH:MM UTC) ou événement d'ancrage
+    pub anchor: String,
+    /// Délai médian avant le déclenchement de la jambe opposée
+    pub median_opposite_leg_delay_minutes: Option<f64>,
 }
 
 /// Calcule l'offset optimal pour éviter 95% des fausses mèches
@@ -64,28 +76,7 @@ pub fn calculer_offset_optimal(
     let raw_atr_mean = calculer_atr_moyen(&candles);
     let atr_mean = asset_props.normalize(raw_atr_mean);
 
-    // Calcul du Noise Ratio moyen
-    let noise_ratio_mean: f64 = if !candles.is_empty() {
-        let sum: f64 = candles
-            .iter()
-            .map(|c| {
-                let range = c.high - c.low;
-                let body = (c.open - c.close).abs();
-                if body < pip_value * 0.1 {
-                    if range < pip_value * 0.1 {
-                        1.0
-                    } else {
-                        5.0
-                    }
-                } else {
-                    range / body
-                }
-            })
-            .sum();
-        sum / candles.len() as f64
-    } else {
-        1.0
-    };
+    let noise_ratio_mean = noise_ratio_moyen(&candles, pip_value);
 
     // 3. Utiliser le service unifié pour calculer l'offset
     let params =
@@ -93,28 +84,8 @@ pub fn calculer_offset_optimal(
 
     let offset_pips = params.offset_pips;
 
-    // Calculer aussi les stats détaillées (Percentile 95 des mèches)
-    let wicks: Vec<f64> = candles
-        .iter()
-        .flat_map(|c| {
-            let upper = c.high - c.close.max(c.open);
-            let lower = c.open.min(c.close) - c.low;
-            vec![
-                if upper > 0.0 { upper } else { 0.0 },
-                if lower > 0.0 { lower } else { 0.0 },
-            ]
-        })
-        .filter(|w| *w > 0.0)
-        .collect();
-
-    let mut sorted_wicks = wicks.clone();
-    sorted_wicks.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-    let p95_index = ((sorted_wicks.len() as f64) * 0.95).ceil() as usize;
-    let p95_index = p95_index.min(sorted_wicks.len().saturating_sub(1));
-    let raw_percentile_95 = sorted_wicks.get(p95_index).copied().unwrap_or(0.0);
-
-    // Conversion correcte en pips via AssetProperties
-    let p95_pips = asset_props.normalize(raw_percentile_95);
+    // Calculer aussi les stats détaillées (Percentile 95 des mèches), converties en pips
+    let p95_pips = asset_props.normalize(percentile_95_meches(&candles));
 
     Ok(OptimalOffsetResponse {
         offset_pips,
@@ -150,12 +121,17 @@ pub fn calculer_taux_reussite(
     })
 }
 
-/// Calcule la fréquence des whipsaws
+/// Calcule la fréquence des whipsaws ancrée sur un créneau HH:MM (UTC) de chaque jour
+/// ou sur une annonce précise du calendrier, avec le modèle d'ordres du backtest.
+/// L'ancre est obligatoire ; sans config, les paramètres Straddle sont dérivés de l'ATR.
 #[command]
-pub fn calculer_frequence_whipsaw(
+pub async fn calculer_frequence_whipsaw(
     candles: Vec<Candle>,
     offset_pips: f64,
-    _window: tauri::Window,
+    slot: Option<String>,
+    event_id: Option<i32>,
+    config: Option<BacktestConfig>,
+    state: tauri::State<'_, CalendarState>,
 ) -> Result<WhipsawResponse, String> {
     tracing::info!(
         "Command: calculer_frequence_whipsaw for {} candles with offset {}",
@@ -164,15 +140,40 @@ pub fn calculer_frequence_whipsaw(
     );
 
     let symbol = candles.first().map(|c| c.symbol.as_str()).unwrap_or("EURUSD");
-    let analysis = service_calculer_frequence_whipsaw(&candles, offset_pips, symbol);
+    let config = match config {
+        Some(config) => BacktestConfig {
+            offset_pips,
+            ..config
+        },
+        None => config_whipsaw_par_defaut(&candles, offset_pips, symbol),
+    };
+
+    let analysis = match (slot, event_id) {
+        (Some(raw), _) => {
+            let slot = NaiveTime::parse_from_str(&raw, "%H:%M")
+                .map_err(|e| format!("Créneau invalide '{}': {}", raw, e))?;
+            service_calculer_frequence_whipsaw(&candles, slot, &config, symbol)
+        }
+        (None, Some(id)) => {
+            let event = load_event_by_id(&state, id)?;
+            let anchor = event.description.clone();
+            frequence_whipsaw_evenements(&candles, &[event], &anchor, &config, symbol)
+        }
+        (None, None) => {
+            return Err("Ancre requise : créneau HH:MM ou identifiant d'événement".to_string())
+        }
+    };
 
     Ok(WhipsawResponse {
-        total_trades: analysis.total_trades,
+        total_trades: analysis.triggered_trades,
         whipsaw_count: analysis.whipsaw_count,
         whipsaw_frequency_percentage: analysis.whipsaw_frequency * 100.0,
+        whipsaw_frequency_ci: analysis.whipsaw_frequency_ci.map(|ci| ci.scaled(100.0)),
         risk_level: analysis.risk_level.as_str().to_string(),
         risk_color: analysis.risk_level.color().to_string(),
-        offset_pips: analysis.offset_pips,
+        offset_pips: config.offset_pips,
+        anchor: analysis.anchor,
+        median_opposite_leg_delay_minutes: analysis.delay.map(|d| d.median),
     })
 }
 
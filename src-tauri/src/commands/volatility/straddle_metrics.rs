@@ -2,6 +2,7 @@
 use super::straddle_metrics_types::*;
 use tauri::command;
 use crate::models::AssetProperties;
+use crate::services::SymbolRegistryService;
 
 /// Obtenir le nombre de points par pip selon le symbole
 /// Basé sur la norme MT5:
@@ -11,6 +12,11 @@ use crate::models::AssetProperties;
 /// - Indices (USA500IDXUSD, etc): 1 pip = 1 point
 /// - Crypto (BTCUSD, ETHUSD): 1 pip = 1 point
 fn get_points_per_pip(symbol: &str) -> f64 {
+    // Symbole connu du registre : rapport pip / tick size du courtier
+    if let Some(spec) = SymbolRegistryService::lookup(symbol) {
+        return spec.pip_size / spec.tick_size;
+    }
+
     let props = AssetProperties::from_symbol(symbol);
     
     // Pour le Forex et l'Or, 1 pip = 10 points (ex: 1.0001 -> 1.0002 = 1 pip = 10 points de 0.00001)
@@ -67,7 +73,9 @@ pub async fn analyze_straddle_metrics(
 
     // Simuler la stratégie Straddle sur les bougies historiques
     use crate::services::straddle_simulator::simulate_straddle;
-    let simulation = simulate_straddle(&candles, &symbol);
+    use crate::services::straddle_simulator_helpers::cout_execution;
+    let costs = cout_execution(&symbol, &crate::services::ConfigService::execution_costs());
+    let simulation = simulate_straddle(&candles, &symbol, &costs);
 
     // Convertir les détails des whipsaws (si disponibles)
     let whipsaw_details: Vec<WhipsawDetailResponse> = simulation
@@ -0,0 +1,94 @@
+// commands/whipsaw_commands.rs - Fausses cassures ancrées sur des annonces ou un créneau horaire
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use crate::commands::retrospective_analysis::helpers::{load_events_by_type, setup_databases};
+use crate::models::CalendarEvent;
+use crate::services::backtest::{BacktestConfig, BacktestEngine, StrategyMode};
+use crate::services::input_fingerprint::Fingerprinter;
+use crate::services::volatility::{agreger_whipsaws, WhipsawAnalysis};
+use crate::services::DatabaseLoader;
+use chrono::{NaiveDate, NaiveTime};
+
+/// Whipsaws ancrés sur les occurrences réelles d'un type d'événement
+/// (même modèle d'ordres que le backtest, délai de la jambe opposée inclus)
+#[tauri::command]
+pub async fn analyze_event_whipsaw(
+    pair: String,
+    event_type: String,
+    config: BacktestConfig,
+    state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+) -> Result<WhipsawAnalysis, String> {
+    let (conn, loader) = setup_databases(&state).await?;
+    let events = load_events_by_type(conn, &event_type).await?;
+
+    if events.is_empty() {
+        return Err(format!("No events found for type: {}", event_type));
+    }
+
+    let parameters = [("config".to_string(), Fingerprinter::parametre(&config)?)].into();
+    let mut analysis = run_whipsaw(&pair, &event_type, &events, &config, &loader);
+    analysis.fingerprint = Some(Fingerprinter::capturer(
+        &loader,
+        &[&pair],
+        &events,
+        Some(&event_type),
+        parameters,
+    )?);
+    Ok(analysis)
+}
+
+/// Whipsaws ancrés sur un créneau HH:MM (UTC) de chaque jour ouvré de la période
+#[tauri::command]
+pub async fn analyze_slot_whipsaw(
+    pair: String,
+    time: String,
+    start_date: String,
+    end_date: String,
+    config: BacktestConfig,
+    state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+) -> Result<WhipsawAnalysis, String> {
+    let (_, loader) = setup_databases(&state).await?;
+
+    let slot = NaiveTime::parse_from_str(&time, "%H:%M")
+        .map_err(|e| format!("Invalid time format: {}", e))?;
+    let start = NaiveDate::parse_from_str(&start_date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid start date: {}", e))?;
+    let end = NaiveDate::parse_from_str(&end_date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid end date: {}", e))?;
+
+    let events = BacktestEngine::time_slot_events(slot, start, end);
+    if events.is_empty() {
+        return Err("No valid weekdays found in range".to_string());
+    }
+
+    // Créneaux générés : la plage de dates fait partie des paramètres (comme le backtest horaire)
+    let parameters = [
+        ("config".to_string(), Fingerprinter::parametre(&config)?),
+        ("start_date".to_string(), start_date),
+        ("end_date".to_string(), end_date),
+    ]
+    .into();
+    let mut analysis = run_whipsaw(&pair, &time, &events, &config, &loader);
+    let anchor = format!("Horaire {}", slot.format("%H:%M"));
+    analysis.fingerprint = Some(Fingerprinter::capturer(
+        &loader,
+        &[&pair],
+        &[],
+        Some(&anchor),
+        parameters,
+    )?);
+    Ok(analysis)
+}
+
+/// Simulation en mode Simultané : la jambe opposée s'active quand la première est stoppée
+fn run_whipsaw(
+    pair: &str,
+    anchor: &str,
+    events: &[CalendarEvent],
+    config: &BacktestConfig,
+    loader: &DatabaseLoader,
+) -> WhipsawAnalysis {
+    let trades =
+        BacktestEngine::simulate_events(pair, events, config, StrategyMode::Simultane, loader);
+    agreger_whipsaws(pair, anchor, &trades)
+}
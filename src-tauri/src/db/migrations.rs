@@ -62,6 +62,13 @@ pub fn ensure_pair_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error
     )
     .execute(&mut conn)?;
 
+    // Migration : spread historique et prix Ask (optionnels selon la source)
+    // (Nécessaire pour les bases existantes créées avant l'ajout de ces champs)
+    for column in ["spread", "ask_open", "ask_high", "ask_low", "ask_close"] {
+        let _ = diesel::sql_query(format!("ALTER TABLE candle_data ADD COLUMN {} REAL", column))
+            .execute(&mut conn);
+    }
+
     diesel::sql_query("CREATE INDEX IF NOT EXISTS idx_candle_data_time ON candle_data(time)")
         .execute(&mut conn)?;
 
@@ -105,6 +112,19 @@ pub fn ensure_pair_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error
     )
     .execute(&mut conn)?;
 
+    // Migration : point de reprise des imports en flux (fichier source + position)
+    for (column, sql_type) in [
+        ("source_path", "TEXT"),
+        ("resume_offset", "INTEGER"),
+        ("resume_line", "INTEGER"),
+    ] {
+        let _ = diesel::sql_query(format!(
+            "ALTER TABLE import_log ADD COLUMN {} {}",
+            column, sql_type
+        ))
+        .execute(&mut conn);
+    }
+
     diesel::sql_query(
         "CREATE INDEX IF NOT EXISTS idx_import_log_imported_at ON import_log(imported_at)",
     )
@@ -115,6 +135,87 @@ pub fn ensure_pair_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error
     )
     .execute(&mut conn)?;
 
+    // Fusion multi-sources : bougies non retenues, plages fournies par chaque source
+    // et bougies dont les prix divergent entre sources
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS candle_alternates (
+            id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+            symbol TEXT NOT NULL,
+            timeframe TEXT NOT NULL,
+            time TIMESTAMP NOT NULL,
+            open REAL NOT NULL,
+            high REAL NOT NULL,
+            low REAL NOT NULL,
+            close REAL NOT NULL,
+            volume REAL NOT NULL,
+            spread REAL,
+            ask_open REAL,
+            ask_high REAL,
+            ask_low REAL,
+            ask_close REAL,
+            source_file TEXT NOT NULL,
+            imported_at TIMESTAMP NOT NULL,
+            UNIQUE(symbol, timeframe, time, source_file)
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS candle_provenance (
+            id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+            import_log_id INTEGER,
+            source_file TEXT NOT NULL,
+            symbol TEXT NOT NULL,
+            timeframe TEXT NOT NULL,
+            start_time TIMESTAMP NOT NULL,
+            end_time TIMESTAMP NOT NULL,
+            row_count INTEGER NOT NULL,
+            rows_kept INTEGER NOT NULL,
+            merge_policy TEXT
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_candle_provenance_pair
+            ON candle_provenance(symbol, timeframe, start_time)",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS candle_divergence (
+            id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+            symbol TEXT NOT NULL,
+            timeframe TEXT NOT NULL,
+            time TIMESTAMP NOT NULL,
+            source_file TEXT NOT NULL,
+            other_source_file TEXT NOT NULL,
+            max_diff_pips REAL NOT NULL,
+            detected_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    )
+    .execute(&mut conn)?;
+
+    // Registre des spécifications courtier (digits, tick size, pip, contrat, alias)
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS symbol_registry (
+            symbol TEXT PRIMARY KEY NOT NULL,
+            description TEXT,
+            asset_type TEXT NOT NULL,
+            digits INTEGER NOT NULL,
+            tick_size REAL NOT NULL,
+            pip_size REAL NOT NULL,
+            contract_size REAL NOT NULL,
+            base_currency TEXT,
+            quote_currency TEXT,
+            trading_hours TEXT,
+            aliases TEXT NOT NULL DEFAULT '',
+            source TEXT NOT NULL DEFAULT 'manual',
+            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    )
+    .execute(&mut conn)?;
+
     Ok(())
 }
 
@@ -155,3 +256,66 @@ pub fn ensure_calendar_imports_table(pool: &DbPool) -> Result<(), Box<dyn std::e
 
     Ok(())
 }
+
+/// Crée la table archives et les étiquettes associées
+pub fn ensure_archive_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = pool.get()?;
+
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS archives (
+            id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+            title TEXT NOT NULL,
+            archive_type TEXT NOT NULL,
+            period_start TEXT NOT NULL,
+            period_end TEXT NOT NULL,
+            comment TEXT,
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            data_json TEXT NOT NULL
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS archive_tags (
+            archive_id INTEGER NOT NULL REFERENCES archives(id) ON DELETE CASCADE,
+            tag TEXT NOT NULL,
+            PRIMARY KEY (archive_id, tag)
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query("CREATE INDEX IF NOT EXISTS idx_archive_tags_tag ON archive_tags(tag)")
+        .execute(&mut conn)?;
+
+    Ok(())
+}
+
+/// Crée le journal des alertes (clé composite : une alerte par règle, annonce et délai,
+/// quel que soit l'instrument retenu à ce moment)
+pub fn ensure_alert_log_table(pool: &DbPool) -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = pool.get()?;
+
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS alert_log (
+            rule_id TEXT NOT NULL,
+            rule_name TEXT NOT NULL,
+            event_id TEXT NOT NULL,
+            event TEXT NOT NULL,
+            currency TEXT NOT NULL,
+            impact TEXT NOT NULL,
+            event_time_utc TEXT NOT NULL,
+            pair TEXT NOT NULL,
+            lead_minutes INTEGER NOT NULL,
+            confidence_score REAL NOT NULL,
+            whipsaw_percent REAL,
+            fired_at TEXT NOT NULL,
+            PRIMARY KEY (rule_id, event_id, lead_minutes)
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query("CREATE INDEX IF NOT EXISTS idx_alert_log_fired_at ON alert_log(fired_at)")
+        .execute(&mut conn)?;
+
+    Ok(())
+}
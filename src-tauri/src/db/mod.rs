@@ -9,7 +9,10 @@ use std::sync::Arc;
 
 pub type DbPool = Arc<r2d2::Pool<ConnectionManager<SqliteConnection>>>;
 
-pub use migrations::{ensure_calendar_imports_table, ensure_calendar_table, ensure_pair_tables};
+pub use migrations::{
+    ensure_alert_log_table, ensure_archive_tables, ensure_calendar_imports_table,
+    ensure_calendar_table, ensure_pair_tables,
+};
 
 /// Initialise un pool de connexions SQLite optimisé
 ///
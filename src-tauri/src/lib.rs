@@ -6,6 +6,7 @@ mod db;
 mod models;
 mod schema;
 mod services;
+mod utils;
 
 use commands::retrospective_analysis::analyze_volatility_profile;
 use commands::*;
@@ -92,6 +93,21 @@ pub fn run() {
 
     tracing::info!("✅ Table calendar_imports vérifiée/créée");
 
+    // Crée les tables archives et archive_tags si elles n'existent pas
+    if let Err(e) = db::ensure_archive_tables(&calendar_pool) {
+        tracing::error!("❌ ERREUR: Impossible de créer les tables d'archives: {}", e);
+        std::process::exit(1);
+    }
+
+    tracing::info!("✅ Tables archives/archive_tags vérifiées/créées");
+
+    if let Err(e) = db::ensure_alert_log_table(&calendar_pool) {
+        tracing::error!("❌ ERREUR: Impossible de créer le journal des alertes: {}", e);
+        std::process::exit(1);
+    }
+
+    tracing::info!("✅ Table alert_log vérifiée/créée");
+
     let calendar_state = calendar_commands::CalendarState {
         pool: Mutex::new(Some(calendar_pool.clone())),
     };
@@ -128,8 +144,13 @@ pub fn run() {
 
     tracing::info!("✅ Tables paires vérifiées/créées");
 
+    match services::SymbolRegistryService::refresh_cache(&pairs_pool) {
+        Ok(count) => tracing::info!("✅ Registre symboles chargé ({} symboles)", count),
+        Err(e) => tracing::warn!("⚠️ Registre symboles non chargé: {}", e),
+    }
+
     let pair_state = pair_data::PairDataState {
-        pool: Mutex::new(Some(pairs_pool)),
+        pool: Mutex::new(Some(pairs_pool.clone())),
     };
 
     tracing::info!("✅ PairDataState créé avec pool actif");
@@ -147,22 +168,47 @@ pub fn run() {
     tracing::info!("✅ CandleIndexState créé (vide, en attente d'initialisation)");
 
     // Initialise le service d'archivage (utilise le pool calendrier)
-    let archive_service = services::ArchiveService::new(calendar_pool.clone());
+    let archive_service = services::ArchiveService::new(calendar_pool.clone())
+        .with_pairs_loader(services::DatabaseLoader::new(pairs_pool.clone()));
     tracing::info!("✅ ArchiveService créé");
+    if let Err(e) = archive_service.migrate_payloads() {
+        tracing::warn!("⚠️ Migration des archives impossible: {}", e);
+    }
+
+    // Cache des projections calculées pour la planification (par événement, paire et données)
+    let projection_cache = services::planning::live_projection::ProjectionCache::default();
+
+    // Surveillance des règles d'alerte (mêmes projections que la planification)
+    let alert_monitor = services::alerting::AlertMonitor::new(
+        calendar_pool.clone(),
+        archive_service.clone(),
+        projection_cache.clone(),
+    )
+    .with_pairs_loader(services::DatabaseLoader::new(pairs_pool.clone()));
+    let background_monitor = alert_monitor.clone();
 
     let builder = tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_notification::init())
         .manage(calendar_state)
         .manage(pair_state)
         .manage(candles_state)
         .manage(candle_index_state)
         .manage(archive_service)
+        .manage(projection_cache)
+        .manage(alert_monitor)
+        .setup(move |app| {
+            alert_commands::demarrer_surveillance(app.handle().clone(), background_monitor);
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             // Volatility commands (Phase 1)
             ping,
             load_symbols,
             analyze_symbol,
+            analyze_symbol_by_regime,
+            analyze_seasonality,
             get_hourly_stats,
             get_best_hours,
             calculer_offset_optimal,
@@ -186,11 +232,13 @@ pub fn run() {
             get_correlation_heatmap, // Heatmap événements × paires
             get_past_events,         // Liste des événements passés pour dropdown
             get_pair_event_history,  // Historique des événements pour une paire
+            get_cross_asset_reaction, // Réaction multi-actifs par occurrence
             // Pair data import commands (Phase 4)
             import_pair_data,
             get_symbol_properties, // NEW: Récupérer point_value et pip_value
             clean_csv_files,        // Nouveau: nettoyage CSV européens
             import_and_clean_files, // Nouveau: import unifié (clean + import)
+            detect_pair_file_format, // Score de détection du format d'un fichier
             // Session analysis commands (Phase 5)
             analyze_sessions,
             // File management commands (Phase 6)
@@ -204,6 +252,8 @@ pub fn run() {
             get_pairs_metadata,          // NEW: métadonnées paires (format UI)
             import_calendar_files,       // NEW: importer calendriers
             delete_pair_from_db,         // NEW: supprimer paire de la BD
+            delete_pair_source,          // supprimer un fichier source (sans trou)
+            get_pair_sources,            // provenance des bougies par fichier source
             delete_calendar_from_db,     // NEW: supprimer calendrier de la BD
             delete_calendar_file,
             delete_pair_files,
@@ -218,6 +268,18 @@ pub fn run() {
             // Config commands (Phase 7)
             get_selected_calendar_file,
             set_selected_calendar_file,
+            list_import_profiles,
+            save_import_profile,
+            delete_import_profile,
+            get_time_decay,
+            set_time_decay,
+            get_broker_clock,
+            set_broker_clock,
+            // Symbol registry commands
+            list_symbol_specs,
+            save_symbol_spec,
+            delete_symbol_spec,
+            import_mt5_symbols,
             // Event metrics commands (Phase 1 Roadmap)
             calculer_metriques_evenement,
             load_candles_for_metrics,
@@ -243,21 +305,46 @@ pub fn run() {
             list_all_archives,
             get_archive,
             delete_archive,
+            search_archives,
+            set_archive_tags,
+            list_archive_tags,
+            compare_archives,
+            export_archive_bundle,
+            import_archive_bundle,
+            rerun_archive,
             // Global Analysis (Phase IA)
             analyze_all_archives,
+            analyze_global_history,
             get_available_pairs,
             // Retrospective analysis commands (Phase 7)
             analyze_peak_delay,
             analyze_decay_profile,
             analyze_volatility_profile,
+            analyze_pre_event_drift,
+            analyze_post_event_path,
             get_event_types,
             // PDF export commands
             exporter_formules_pdf,
             // Backtest commands
             run_backtest,
             run_backtest_time,
+            analyze_event_whipsaw,
+            analyze_slot_whipsaw,
+            calibrate_confidence_scorer,
+            get_score_weight_sets,
+            reset_score_weights,
             // Planning commands
             project_stats_on_calendar,
+            export_trading_plan,
+            reconcile_trading_plan,
+            import_trade_journal,
+            // Alert rules
+            get_alert_rules,
+            save_alert_rule,
+            delete_alert_rule,
+            get_alert_log,
+            clear_alert_log,
+            check_alerts_now,
             sync_forex_factory_week,
         ]);
 
@@ -0,0 +1,98 @@
+// models/alerting.rs - Règles d'alerte sur les annonces projetées et journal des alertes
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use crate::schema::alert_log;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Délais de prévenance par défaut (minutes avant l'annonce)
+pub const DEFAULT_LEAD_TIMES: [i64; 2] = [60, 15];
+
+/// Règle définie par l'utilisateur, évaluée sur les annonces projetées.
+/// Listes vides = pas de filtre ; seuils absents = pas de contrainte.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub id: String,
+    pub name: String,
+    #[serde(default = "enabled_default")]
+    pub enabled: bool,
+    /// Devises de l'annonce (USD, EUR…)
+    #[serde(default)]
+    pub currencies: Vec<String>,
+    /// Impacts retenus (High, Medium, Low)
+    #[serde(default)]
+    pub impacts: Vec<String>,
+    /// Instruments surveillés (symboles canoniques ou courtier)
+    #[serde(default)]
+    pub pairs: Vec<String>,
+    /// Score de confiance projeté minimum (0-100)
+    #[serde(default)]
+    pub min_confidence: Option<f64>,
+    /// Taux de whipsaw maximum de l'instrument (%)
+    #[serde(default)]
+    pub max_whipsaw_percent: Option<f64>,
+    /// Ne retenir que les projections significatives
+    #[serde(default)]
+    pub significant_only: bool,
+    /// Minutes avant l'annonce auxquelles l'alerte se déclenche
+    #[serde(default = "lead_times_default")]
+    pub lead_times_minutes: Vec<i64>,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+fn lead_times_default() -> Vec<i64> {
+    DEFAULT_LEAD_TIMES.to_vec()
+}
+
+impl AlertRule {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() || self.name.trim().is_empty() {
+            return Err("Identifiant et nom de la règle requis".to_string());
+        }
+        if self.lead_times_minutes.is_empty() || self.lead_times_minutes.iter().any(|&m| m <= 0) {
+            return Err("Délais de prévenance strictement positifs requis".to_string());
+        }
+        if self
+            .min_confidence
+            .is_some_and(|c| !(0.0..=100.0).contains(&c))
+        {
+            return Err("Confiance minimale hors de 0-100".to_string());
+        }
+        if self
+            .max_whipsaw_percent
+            .is_some_and(|w| !(0.0..=100.0).contains(&w))
+        {
+            return Err("Whipsaw maximal hors de 0-100".to_string());
+        }
+        Ok(())
+    }
+
+    /// Délai le plus long : horizon de projection nécessaire
+    pub fn horizon_minutes(&self) -> i64 {
+        self.lead_times_minutes.iter().copied().max().unwrap_or(0)
+    }
+}
+
+/// Alerte déclenchée (ligne du journal, unique par règle, annonce et délai)
+#[derive(Queryable, Insertable, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[diesel(table_name = alert_log)]
+pub struct AlertRecord {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub event_id: String,
+    pub event: String,
+    pub currency: String,
+    pub impact: String,
+    /// RFC 3339, UTC
+    pub event_time_utc: String,
+    pub pair: String,
+    pub lead_minutes: i64,
+    pub confidence_score: f64,
+    /// Taux de whipsaw de l'instrument (%), absent sans classement des candidats
+    pub whipsaw_percent: Option<f64>,
+    /// RFC 3339, UTC
+    pub fired_at: String,
+}
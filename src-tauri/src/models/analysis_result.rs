@@ -1,6 +1,7 @@
 // models/analysis_result.rs - Résultat d'analyse complète
 use super::calendar_event::CalendarEvent;
-use super::{HourlyStats, Stats15Min};
+use super::input_fingerprint::InputFingerprint;
+use super::{ConfidenceInterval, HourlyStats, Stats15Min, VolatilityRegime};
 use crate::models::trading_recommendation::{RiskLevel, TradingRecommendation};
 use serde::{Deserialize, Serialize};
 
@@ -14,11 +15,20 @@ pub struct AnalysisResult {
     pub stats_15min: Vec<Stats15Min>,
     pub best_quarter: (u8, u8), // (hour, quarter) - meilleur quarter de la journée
     pub confidence_score: f64,
+    /// Intervalle bootstrap du score (heures rééchantillonnées)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence_score_ci: Option<ConfidenceInterval>,
     pub recommendation: TradingRecommendation,
     pub risk_level: RiskLevel,
     pub global_metrics: GlobalMetrics,
     pub point_value: f64, // Valeur d'un point pour normalisation (ex: 0.001 pour JPY)
     pub unit: String,     // Unité d'affichage (pips, points, $)
+    /// Régime auquel l'analyse a été restreinte (None = tout l'historique)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regime: Option<VolatilityRegime>,
+    /// Données et paramètres d'entrée (reproductibilité)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<InputFingerprint>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1,4 +1,4 @@
-use crate::schema::archives;
+use crate::schema::{archive_tags, archives};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -24,3 +24,43 @@ pub struct NewArchive {
     pub comment: Option<String>,
     pub data_json: String,
 }
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = archive_tags)]
+pub struct NewArchiveTag {
+    pub archive_id: i32,
+    pub tag: String,
+}
+
+/// Critères de recherche ; un critère vide n'est pas appliqué
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ArchiveFilter {
+    /// Mots recherchés dans le titre, le commentaire, l'événement, la paire et les étiquettes
+    pub query: Option<String>,
+    pub archive_types: Vec<String>,
+    pub pair: Option<String>,
+    pub event: Option<String>,
+    /// L'archive doit porter toutes ces étiquettes
+    pub tags: Vec<String>,
+    /// Bornes incluses sur la date de création (YYYY-MM-DD)
+    pub created_from: Option<String>,
+    pub created_to: Option<String>,
+}
+
+/// Archive renvoyée par la recherche, avec ses étiquettes et ce que le résumé en dit
+#[derive(Serialize, Debug, Clone)]
+pub struct ArchiveSearchResult {
+    #[serde(flatten)]
+    pub archive: Archive,
+    pub tags: Vec<String>,
+    pub pairs: Vec<String>,
+    pub events: Vec<String>,
+}
+
+/// Étiquette et nombre d'archives qui la portent
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ArchiveTagCount {
+    pub tag: String,
+    pub count: usize,
+}
@@ -0,0 +1,91 @@
+// models/archive_bundle.rs - Lot d'archives portable (partage entre postes) et son manifeste
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use serde::{Deserialize, Serialize};
+
+pub use super::input_fingerprint::DataRange;
+
+/// Identifiant du format, vérifié à l'import
+pub const ARCHIVE_BUNDLE_FORMAT: &str = "volatility-analyzer/archive-bundle";
+pub const ARCHIVE_BUNDLE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub app_version: String,
+    pub exported_at: String,
+    pub archive_count: usize,
+    pub event_count: usize,
+    /// Période couverte par l'ensemble des archives exportées
+    pub period_start: Option<String>,
+    pub period_end: Option<String>,
+    pub data_ranges: Vec<DataRange>,
+    /// CRC32 (hex) des archives et événements sérialisés
+    pub checksum: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundledArchive {
+    pub title: String,
+    pub archive_type: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub comment: Option<String>,
+    pub created_at: String,
+    pub data_json: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Événement du calendrier (heure UTC "YYYY-MM-DD HH:MM:SS")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundledEvent {
+    pub symbol: String,
+    pub event_time: String,
+    pub impact: String,
+    pub description: String,
+    pub actual: Option<f64>,
+    pub forecast: Option<f64>,
+    pub previous: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveBundle {
+    pub format: String,
+    pub version: u32,
+    pub manifest: BundleManifest,
+    pub archives: Vec<BundledArchive>,
+    #[serde(default)]
+    pub events: Vec<BundledEvent>,
+}
+
+/// Conduite à tenir quand une archive du lot porte le même titre, type et période
+/// qu'une archive locale au contenu différent (un contenu identique est toujours ignoré)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    #[default]
+    Skip,
+    Replace,
+    /// Importe sous un titre suffixé
+    KeepBoth,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BundleExportResult {
+    pub path: String,
+    pub archives: usize,
+    pub events: usize,
+    pub checksum: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BundleImportReport {
+    pub manifest: BundleManifest,
+    pub imported: usize,
+    pub replaced: usize,
+    pub renamed: usize,
+    /// Archives non importées, avec la raison
+    pub skipped: Vec<String>,
+    pub events_imported: usize,
+    pub events_skipped: usize,
+}
@@ -0,0 +1,36 @@
+// models/archive_comparison.rs - Évolution des paramètres et métriques entre archives comparables
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use super::archive_payload::ArchiveKind;
+use serde::Serialize;
+
+/// Archive comparée (ordre chronologique de création)
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparedArchive {
+    pub id: i32,
+    pub title: String,
+    pub created_at: String,
+    pub period_start: String,
+    pub period_end: String,
+}
+
+/// Valeurs d'un paramètre ou d'une métrique, une par archive comparée
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricDelta {
+    pub name: String,
+    /// Paramètre recommandé (offset, SL...) plutôt que mesure
+    pub parameter: bool,
+    pub values: Vec<Option<f64>>,
+    /// Écart entre la plus récente et la plus ancienne valeur renseignée
+    pub change: Option<f64>,
+    pub change_percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveComparison {
+    pub kind: ArchiveKind,
+    pub pair: String,
+    pub event: Option<String>,
+    pub archives: Vec<ComparedArchive>,
+    pub metrics: Vec<MetricDelta>,
+}
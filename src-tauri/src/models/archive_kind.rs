@@ -0,0 +1,38 @@
+// models/archive_kind.rs - Famille d'archive déduite du libellé affiché
+// Conforme .clinerules : < 50L, pas d'unwrap()
+
+use serde::{Deserialize, Serialize};
+
+/// Famille d'archive, déduite du libellé `archive_type` affiché par le frontend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveKind {
+    /// Métriques de volatilité brute d'une paire sur une période
+    Volatility,
+    /// Impact d'un type d'événement sur une paire (analyse rétrospective)
+    EventImpact,
+    Heatmap,
+    Backtest,
+    /// Impact d'un événement sur plusieurs paires (ancien format)
+    EventCorrelation,
+    /// Événements marquants d'une paire (ancien format)
+    PairCorrelation,
+}
+
+impl ArchiveKind {
+    pub fn from_archive_type(archive_type: &str) -> Option<Self> {
+        match archive_type.trim() {
+            "Volatilité" | "Volatilité brute" | "Volatilité brute Paire/Période" | "METRICS" => {
+                Some(Self::Volatility)
+            }
+            "Métriques Rétrospectives"
+            | "Correlation de la volatilité Paire/Evenement"
+            | "RETRO_ANALYSIS" => Some(Self::EventImpact),
+            "Heatmap" | "HEATMAP" => Some(Self::Heatmap),
+            "Backtest" => Some(Self::Backtest),
+            t if t.contains("Corrélation événement/paire") => Some(Self::EventCorrelation),
+            t if t.contains("Corrélation paire/événement") => Some(Self::PairCorrelation),
+            _ => None,
+        }
+    }
+}
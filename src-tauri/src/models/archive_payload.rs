@@ -0,0 +1,145 @@
+// models/archive_payload.rs - Résumé typé et versionné du contenu d'une archive
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+pub use super::archive_kind::ArchiveKind;
+pub use super::archive_summaries::{
+    ArchivedPairEvent, ArchivedPairImpact, BacktestSummary, EventCorrelationSummary,
+    EventImpactSummary, HeatmapSummary, PairCorrelationSummary, VolatilitySummary,
+};
+use serde::{Deserialize, Serialize};
+
+/// Version du schéma écrit dans data_json ; les archives sans version sont en v1 (libre).
+/// v3 : champs absents des anciens formats lus comme absents (None) et non plus comme zéro
+pub const ARCHIVE_SCHEMA_VERSION: u32 = 3;
+
+/// Données lues par les consommateurs backend (planification, analyse globale)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ArchiveSummary {
+    Volatility(VolatilitySummary),
+    EventImpact(EventImpactSummary),
+    Heatmap(HeatmapSummary),
+    Backtest(BacktestSummary),
+    EventCorrelation(EventCorrelationSummary),
+    PairCorrelation(PairCorrelationSummary),
+}
+
+impl ArchiveSummary {
+    pub fn kind(&self) -> ArchiveKind {
+        match self {
+            Self::Volatility(_) => ArchiveKind::Volatility,
+            Self::EventImpact(_) => ArchiveKind::EventImpact,
+            Self::Heatmap(_) => ArchiveKind::Heatmap,
+            Self::Backtest(_) => ArchiveKind::Backtest,
+            Self::EventCorrelation(_) => ArchiveKind::EventCorrelation,
+            Self::PairCorrelation(_) => ArchiveKind::PairCorrelation,
+        }
+    }
+
+    /// Paire ou symbole principal de l'archive (None pour les vues multi-paires)
+    pub fn instrument(&self) -> Option<&str> {
+        match self {
+            Self::Volatility(v) => Some(&v.symbol),
+            Self::EventImpact(e) => Some(&e.pair),
+            Self::Backtest(b) => Some(&b.symbol),
+            Self::PairCorrelation(c) => Some(&c.pair),
+            Self::Heatmap(_) | Self::EventCorrelation(_) => None,
+        }
+    }
+
+    /// Toutes les paires citées (vues multi-paires comprises)
+    pub fn instruments(&self) -> Vec<&str> {
+        match self {
+            Self::Heatmap(h) => h.pairs.iter().map(String::as_str).collect(),
+            Self::EventCorrelation(c) => c.pair_impacts.iter().map(|p| p.symbol.as_str()).collect(),
+            other => other.instrument().into_iter().collect(),
+        }
+    }
+
+    /// Événements couverts par l'archive (aucun pour la volatilité brute)
+    pub fn events(&self) -> Vec<&str> {
+        match self {
+            Self::Volatility(_) => Vec::new(),
+            Self::EventImpact(e) => vec![e.event_type.as_str()],
+            Self::Heatmap(h) => h.event_types.iter().map(String::as_str).collect(),
+            Self::Backtest(b) => vec![b.event_name.as_str()],
+            Self::EventCorrelation(c) => vec![c.event_name.as_str()],
+            Self::PairCorrelation(c) => c.events.iter().map(|e| e.name.as_str()).collect(),
+        }
+    }
+
+    /// Refuse les résumés qui feraient lire des zéros en aval
+    pub fn validate(&self) -> Result<(), String> {
+        let non_vide = |label: &str, value: &str| {
+            if value.trim().is_empty() {
+                Err(format!("{} manquant", label))
+            } else {
+                Ok(())
+            }
+        };
+        let fini = |label: &str, value: f64| {
+            if value.is_finite() {
+                Ok(())
+            } else {
+                Err(format!("{} invalide: {}", label, value))
+            }
+        };
+        match self {
+            Self::Volatility(v) => {
+                non_vide("Symbole", &v.symbol)?;
+                fini("Score de confiance", v.confidence_score)?;
+                v.mean_volatility
+                    .map_or(Ok(()), |m| fini("Volatilité moyenne", m))
+            }
+            Self::EventImpact(e) => {
+                non_vide("Paire", &e.pair)?;
+                non_vide("Type d'événement", &e.event_type)?;
+                for (label, value) in [
+                    ("Offset", e.offset),
+                    ("Trailing stop", e.trailing_stop),
+                    ("Stop loss", e.stop_loss),
+                ] {
+                    fini(label, value)?;
+                    if value <= 0.0 {
+                        return Err(format!("{} absent ou nul", label));
+                    }
+                }
+                // Champs absents des anciens formats : None, jamais zéro
+                for (label, value) in [
+                    ("Offset simultané", e.offset_simultaneous),
+                    ("Hausse de volatilité", e.volatility_increase_percent),
+                ] {
+                    value.map_or(Ok(()), |v| fini(label, v))?;
+                }
+                Ok(())
+            }
+            Self::Heatmap(h) => {
+                if h.pairs.is_empty() || h.event_types.is_empty() {
+                    return Err("Heatmap sans paire ou sans événement".to_string());
+                }
+                Ok(())
+            }
+            Self::Backtest(b) => {
+                non_vide("Symbole", &b.symbol)?;
+                non_vide("Événement", &b.event_name)?;
+                fini("Résultat total", b.total_pips)
+            }
+            Self::EventCorrelation(c) => {
+                non_vide("Événement", &c.event_name)?;
+                if c.pair_impacts.is_empty() {
+                    return Err("Aucun impact par paire".to_string());
+                }
+                Ok(())
+            }
+            Self::PairCorrelation(c) => non_vide("Paire", &c.pair),
+        }
+    }
+}
+
+/// Bilan de la mise à niveau des archives existantes
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ArchiveMigrationReport {
+    pub migrated: usize,
+    /// Archives laissées telles quelles, avec la raison (ignorées par les consommateurs)
+    pub failed: Vec<String>,
+}
@@ -0,0 +1,112 @@
+// models/archive_summaries.rs - Résumés typés par famille d'archive
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::{ConfidenceInterval, VolatilityRegime};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VolatilitySummary {
+    pub symbol: String,
+    pub confidence_score: f64,
+    #[serde(default)]
+    pub confidence_score_ci: Option<ConfidenceInterval>,
+    #[serde(default)]
+    pub best_hours: Vec<u8>,
+    /// Absente des anciennes archives
+    #[serde(default)]
+    pub mean_volatility: Option<f64>,
+    #[serde(default)]
+    pub regime: Option<VolatilityRegime>,
+    pub unit: String,
+}
+
+/// Paramètres Straddle issus de l'analyse rétrospective (points MT5)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventImpactSummary {
+    pub pair: String,
+    pub event_type: String,
+    pub offset: f64,
+    pub trailing_stop: f64,
+    pub stop_loss: f64,
+    /// Paramètres Simultané, absents des anciennes analyses
+    #[serde(default)]
+    pub offset_simultaneous: Option<f64>,
+    #[serde(default)]
+    pub trailing_stop_simultaneous: Option<f64>,
+    #[serde(default)]
+    pub stop_loss_simultaneous: Option<f64>,
+    #[serde(default)]
+    pub timeout_minutes: Option<i32>,
+    #[serde(default)]
+    pub event_count: Option<usize>,
+    #[serde(default)]
+    pub volatility_increase_percent: Option<f64>,
+    /// Score enregistré (absent des analyses rétrospectives : recalculé à la lecture)
+    #[serde(default)]
+    pub confidence_score: Option<f64>,
+    #[serde(default)]
+    pub confidence_score_ci: Option<ConfidenceInterval>,
+    #[serde(default)]
+    pub regime: Option<VolatilityRegime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeatmapSummary {
+    pub pairs: Vec<String>,
+    pub event_types: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BacktestSummary {
+    pub symbol: String,
+    pub event_name: String,
+    pub strategy_mode: String,
+    /// Configuration du backtest, absente des anciennes archives
+    #[serde(default)]
+    pub offset: Option<f64>,
+    #[serde(default)]
+    pub stop_loss: Option<f64>,
+    #[serde(default)]
+    pub trailing_stop: Option<f64>,
+    #[serde(default)]
+    pub timeout_minutes: Option<i32>,
+    pub total_trades: usize,
+    pub win_rate_percent: f64,
+    pub total_pips: f64,
+    pub unit: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedPairImpact {
+    pub symbol: String,
+    pub event_volatility: f64,
+    pub baseline_volatility: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventCorrelationSummary {
+    pub event_name: String,
+    #[serde(default)]
+    pub currency: String,
+    #[serde(default)]
+    pub event_count: usize,
+    pub pair_impacts: Vec<ArchivedPairImpact>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedPairEvent {
+    pub name: String,
+    pub count: usize,
+    #[serde(default)]
+    pub volatility_total: f64,
+    #[serde(default)]
+    pub volatility_before: f64,
+    #[serde(default)]
+    pub volatility_after: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PairCorrelationSummary {
+    pub pair: String,
+    pub events: Vec<ArchivedPairEvent>,
+}
@@ -1,6 +1,7 @@
-// models/asset_class.rs - Détection et normalisation des actifs
+// models/asset_class.rs - Classes d'actifs et normalisation des mouvements
 // Conforme .clinerules : < 150L
 
+use super::symbol_registry::SymbolSpec;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -23,60 +24,30 @@ pub struct AssetProperties {
 }
 
 impl AssetProperties {
-    pub fn from_symbol(symbol: &str) -> Self {
-        let s = symbol.to_uppercase();
+    /// Propriétés issues d'une spécification du registre (pip défini par le courtier)
+    pub fn from_spec(spec: &SymbolSpec) -> Self {
+        AssetProperties {
+            pip_value: spec.pip_size,
+            ..Self::for_asset_type(spec.asset_type)
+        }
+    }
 
-        if s.contains("JPY") {
-            AssetProperties {
-                asset_type: AssetType::ForexJpy,
-                pip_value: 0.01,
-                unit: "pips".to_string(),
-                display_digits: 1,
-            }
-        } else if s.contains("XAU") || s.contains("GOLD") {
-            AssetProperties {
-                asset_type: AssetType::Gold,
-                pip_value: 0.1, // Convention standard Gold: 0.1$ = 1 pip
-                unit: "pips".to_string(),
-                display_digits: 1,
-            }
-        } else if s.contains("XAG") || s.contains("SILVER") {
-            AssetProperties {
-                asset_type: AssetType::Silver,
-                pip_value: 0.01, // Convention Silver
-                unit: "pips".to_string(),
-                display_digits: 2,
-            }
-        } else if s.contains("BTC") || s.contains("ETH") || s.contains("CRYPTO") || s.contains("SOL") || s.contains("BNB") || s.contains("XRP") || s.contains("ADA") || s.contains("DOT") {
-            AssetProperties {
-                asset_type: AssetType::Crypto,
-                pip_value: 1.0, // Crypto: 1$ = 1 point
-                unit: "pts".to_string(),
-                display_digits: 0,
-            }
-        } else if s.contains("IDX") || s.contains("US30") || s.contains("DAX") || s.contains("NAS")
-            || s.contains("GER") || s.contains("SPX") || s.contains("US100") || s.contains("US500")
-            || s.contains("FRA40") || s.contains("UK100") || s.contains("EUSTX") || s.contains("JPN225")
-            || s.contains("USATEC") || s.contains("USAIDX") || s.contains("DEUIDX") || s.contains("USTEC")
-            || s.contains("US500") || s.contains("US30") || s.contains("HK50") || s.contains("FR40")
-            || s.contains("GR30") || s.contains("DE40") || s.contains("WS30") || s.contains("NDX")
-            || s.contains("VIX") || s.contains("DXY") || s.contains("STOXX") || s.contains("CAC")
-            || s.contains("FTSE") || s.contains("NI225") || s.contains("ASX") || s.contains("HSI")
-        {
-            AssetProperties {
-                asset_type: AssetType::Index,
-                pip_value: 1.0, // Indices: on parle en points
-                unit: "pts".to_string(),
-                display_digits: 1,
-            }
-        } else {
-            // Par défaut: Forex Major (5 digits)
-            AssetProperties {
-                asset_type: AssetType::ForexMajor,
-                pip_value: 0.0001,
-                unit: "pips".to_string(),
-                display_digits: 1,
-            }
+    /// Conventions par défaut d'une classe d'actif
+    pub fn for_asset_type(asset_type: AssetType) -> Self {
+        let (pip_value, unit, display_digits) = match asset_type {
+            AssetType::ForexMajor => (0.0001, "pips", 1),
+            AssetType::ForexJpy => (0.01, "pips", 1),
+            AssetType::Gold => (0.1, "pips", 1), // Convention standard Gold: 0.1$ = 1 pip
+            AssetType::Silver => (0.01, "pips", 2),
+            AssetType::Crypto => (1.0, "pts", 0), // Crypto: 1$ = 1 point
+            AssetType::Index => (1.0, "pts", 1),  // Indices: on parle en points
+            AssetType::Unknown => (1.0, "pts", 2),
+        };
+        AssetProperties {
+            asset_type,
+            pip_value,
+            unit: unit.to_string(),
+            display_digits,
         }
     }
 
@@ -99,9 +70,14 @@ impl AssetProperties {
 mod tests {
     use super::*;
 
+    /// Symbole absent du registre : conventions de la classe devinée sur le nom
+    fn proprietes(symbol: &str) -> AssetProperties {
+        AssetProperties::for_asset_type(AssetProperties::guess_asset_type(symbol))
+    }
+
     #[test]
     fn test_detection_eurusd() {
-        let props = AssetProperties::from_symbol("EURUSD");
+        let props = proprietes("EURUSD");
         assert_eq!(props.asset_type, AssetType::ForexMajor);
         assert_eq!(props.pip_value, 0.0001);
         assert_eq!(props.normalize(0.0005), 5.0); // 5 pips
@@ -109,7 +85,7 @@ mod tests {
 
     #[test]
     fn test_detection_usdjpy() {
-        let props = AssetProperties::from_symbol("USDJPY");
+        let props = proprietes("USDJPY");
         assert_eq!(props.asset_type, AssetType::ForexJpy);
         assert_eq!(props.pip_value, 0.01);
         assert_eq!(props.normalize(0.05), 5.0); // 5 pips
@@ -117,7 +93,7 @@ mod tests {
 
     #[test]
     fn test_detection_gold() {
-        let props = AssetProperties::from_symbol("XAUUSD");
+        let props = proprietes("XAUUSD");
         assert_eq!(props.asset_type, AssetType::Gold);
         assert_eq!(props.pip_value, 0.1);
         assert_eq!(props.normalize(1.5), 15.0); // 15 pips (1.5$)
@@ -125,19 +101,9 @@ mod tests {
 
     #[test]
     fn test_detection_btc() {
-        let props = AssetProperties::from_symbol("BTCUSD");
+        let props = proprietes("BTCUSD");
         assert_eq!(props.asset_type, AssetType::Crypto);
         assert_eq!(props.pip_value, 1.0);
         assert_eq!(props.normalize(500.0), 500.0); // 500$
     }
-
-    #[test]
-    fn test_detection_indices() {
-        let symbols = vec!["USATEC", "USAIDX", "DEUIDX", "NAS100", "US30", "GER40", "SPX500"];
-        for symbol in symbols {
-            let props = AssetProperties::from_symbol(symbol);
-            assert_eq!(props.asset_type, AssetType::Index, "Failed for {}", symbol);
-            assert_eq!(props.pip_value, 1.0, "Failed for {}", symbol);
-        }
-    }
 }
@@ -0,0 +1,128 @@
+// models/asset_detection.rs - Classe d'actif devinée sur le nom (symboles absents du registre)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::asset_class::{AssetProperties, AssetType};
+
+/// Devises cotées en paires Forex (ISO 4217)
+const FIAT_CURRENCIES: [&str; 26] = [
+    "USD", "EUR", "GBP", "JPY", "CHF", "CAD", "AUD", "NZD", "SEK", "NOK", "DKK", "PLN", "HUF",
+    "CZK", "TRY", "ZAR", "MXN", "SGD", "HKD", "CNH", "CNY", "ILS", "THB", "KRW", "INR", "BRL",
+];
+
+/// Devises cotées à 2/3 décimales (pip = 0.01)
+const TWO_DECIMAL_QUOTES: [&str; 3] = ["JPY", "HUF", "KRW"];
+
+const CRYPTO_MARKERS: [&str; 8] = ["BTC", "ETH", "CRYPTO", "SOL", "BNB", "XRP", "ADA", "DOT"];
+
+const INDEX_MARKERS: [&str; 31] = [
+    "IDX", "US30", "DAX", "NAS", "GER", "SPX", "US100", "US500", "FRA40", "UK100", "EUSTX",
+    "JPN225", "USATEC", "USTEC", "HK50", "FR40", "GR30", "DE40", "WS30", "NDX", "VIX", "DXY",
+    "STOXX", "CAC", "FTSE", "NI225", "ASX", "HSI", "DJI", "AUS200", "ESP35",
+];
+
+/// Retire les suffixes courtier : "EURUSD.r" → "EURUSD", "XAUUSDm" → "XAUUSD", "GER40_cash" → "GER40"
+pub fn strip_broker_suffix(symbol: &str) -> String {
+    let trimmed = symbol.trim();
+    let base = trimmed
+        .split(['.', '#', '_', '-', '+'])
+        .next()
+        .filter(|s| !s.is_empty())
+        .unwrap_or(trimmed);
+
+    // Suffixe en minuscules collé au nom (XAUUSDm, EURUSDpro) si le nom est en majuscules
+    let without_lower = base.trim_end_matches(|c: char| c.is_ascii_lowercase());
+    let base = if without_lower.len() >= 3 && without_lower.chars().any(|c| c.is_ascii_uppercase())
+    {
+        without_lower
+    } else {
+        base
+    };
+
+    base.to_uppercase()
+}
+
+impl AssetProperties {
+    /// Classe Forex d'une paire de devises ISO (None si l'une n'est pas une devise)
+    pub fn forex_type(base: &str, quote: &str) -> Option<AssetType> {
+        let (base, quote) = (base.to_uppercase(), quote.to_uppercase());
+        if base == quote
+            || !FIAT_CURRENCIES.contains(&base.as_str())
+            || !FIAT_CURRENCIES.contains(&quote.as_str())
+        {
+            return None;
+        }
+        Some(if TWO_DECIMAL_QUOTES.contains(&quote.as_str()) {
+            AssetType::ForexJpy
+        } else {
+            AssetType::ForexMajor
+        })
+    }
+
+    /// Devine la classe d'actif depuis le nom (symboles absents du registre)
+    pub fn guess_asset_type(symbol: &str) -> AssetType {
+        let s = strip_broker_suffix(symbol);
+
+        // Paire de deux devises ISO : Forex, y compris les exotiques
+        if s.len() == 6 && s.is_char_boundary(3) {
+            let (base, quote) = s.split_at(3);
+            if let Some(forex) = Self::forex_type(base, quote) {
+                return forex;
+            }
+        }
+
+        if s.contains("XAU") || s.contains("GOLD") {
+            AssetType::Gold
+        } else if s.contains("XAG") || s.contains("SILVER") {
+            AssetType::Silver
+        } else if CRYPTO_MARKERS.iter().any(|m| s.contains(m)) {
+            AssetType::Crypto
+        } else if INDEX_MARKERS.iter().any(|m| s.contains(m)) {
+            AssetType::Index
+        } else if s.contains("JPY") {
+            AssetType::ForexJpy
+        } else {
+            // Par défaut: Forex Major (5 digits)
+            AssetType::ForexMajor
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_broker_suffix() {
+        assert_eq!(strip_broker_suffix("EURUSD.r"), "EURUSD");
+        assert_eq!(strip_broker_suffix("XAUUSDm"), "XAUUSD");
+        assert_eq!(strip_broker_suffix("GER40_cash"), "GER40");
+        assert_eq!(strip_broker_suffix("usdjpy"), "USDJPY");
+    }
+
+    #[test]
+    fn test_detection_indices() {
+        let symbols = vec![
+            "USATEC", "USAIDX", "DEUIDX", "NAS100", "US30", "GER40", "SPX500",
+        ];
+        for symbol in symbols {
+            let asset_type = AssetProperties::guess_asset_type(symbol);
+            assert_eq!(asset_type, AssetType::Index, "Failed for {}", symbol);
+            assert_eq!(
+                AssetProperties::for_asset_type(asset_type).pip_value,
+                1.0,
+                "Failed for {}",
+                symbol
+            );
+        }
+    }
+
+    #[test]
+    fn test_detection_broker_suffix_and_exotics() {
+        let guess = AssetProperties::guess_asset_type;
+        assert_eq!(guess("EURUSD.r"), AssetType::ForexMajor);
+        assert_eq!(guess("XAUUSDm"), AssetType::Gold);
+        assert_eq!(guess("USDHUF"), AssetType::ForexJpy);
+        // Deux devises ISO : pas d'indice malgré "NAS"/"GER" ailleurs dans la liste
+        assert_eq!(guess("EURNOK"), AssetType::ForexMajor);
+    }
+}
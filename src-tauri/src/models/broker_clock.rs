@@ -0,0 +1,127 @@
+// models/broker_clock.rs - Heure serveur du courtier MT5 (décalage UTC + heure d'été US)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Format des dates affichées et exportées par MetaTrader
+pub const MT5_TIME_FORMAT: &str = "%Y.%m.%d %H:%M";
+
+/// Horloge serveur : la plupart des courtiers MT5 sont à UTC+2, UTC+3 pendant l'heure d'été US
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrokerClock {
+    /// Décalage hors heure d'été (minutes)
+    pub utc_offset_minutes: i32,
+    /// +1h entre le 2e dimanche de mars et le 1er dimanche de novembre (New York)
+    #[serde(default)]
+    pub us_dst: bool,
+}
+
+impl Default for BrokerClock {
+    fn default() -> Self {
+        Self {
+            utc_offset_minutes: 120,
+            us_dst: true,
+        }
+    }
+}
+
+impl BrokerClock {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.utc_offset_minutes.abs() > 14 * 60 {
+            return Err(format!(
+                "Décalage courtier invalide: {} minutes",
+                self.utc_offset_minutes
+            ));
+        }
+        Ok(())
+    }
+
+    /// Décalage en vigueur à un instant UTC (minutes)
+    pub fn offset_at(&self, utc: DateTime<Utc>) -> i32 {
+        if self.us_dst && is_us_dst(utc) {
+            self.utc_offset_minutes + 60
+        } else {
+            self.utc_offset_minutes
+        }
+    }
+
+    pub fn to_broker(self, utc: DateTime<Utc>) -> NaiveDateTime {
+        utc.naive_utc() + Duration::minutes(self.offset_at(utc) as i64)
+    }
+
+    /// Heure serveur → UTC (l'heure ambiguë du changement d'heure prend le décalage d'hiver)
+    pub fn to_utc(self, broker: NaiveDateTime) -> DateTime<Utc> {
+        let winter = (broker - Duration::minutes(self.utc_offset_minutes as i64)).and_utc();
+        let summer = winter - Duration::minutes(60);
+        if self.us_dst && is_us_dst(summer) {
+            summer
+        } else {
+            winter
+        }
+    }
+}
+
+/// Heure d'été de New York (2h locales ≈ 7h UTC en mars, 6h UTC en novembre)
+fn is_us_dst(utc: DateTime<Utc>) -> bool {
+    let year = utc.year();
+    let (Some(start), Some(end)) = (
+        nth_sunday(year, 3, 2).and_then(|d| d.and_hms_opt(7, 0, 0)),
+        nth_sunday(year, 11, 1).and_then(|d| d.and_hms_opt(6, 0, 0)),
+    ) else {
+        return false;
+    };
+    let naive = utc.naive_utc();
+    naive >= start && naive < end
+}
+
+fn nth_sunday(year: i32, month: u32, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0)
+            .single()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_broker_time_follows_us_dst() {
+        let clock = BrokerClock::default();
+        // NFP de janvier : 13:30 UTC → 15:30 serveur ; en juin : 12:30 UTC → 15:30 serveur
+        let winter = clock.to_broker(utc(2024, 1, 5, 13, 30));
+        assert_eq!(
+            winter.format(MT5_TIME_FORMAT).to_string(),
+            "2024.01.05 15:30"
+        );
+        let summer = clock.to_broker(utc(2024, 6, 7, 12, 30));
+        assert_eq!(
+            summer.format(MT5_TIME_FORMAT).to_string(),
+            "2024.06.07 15:30"
+        );
+        // Semaine décalée de mars (Europe encore en hiver, US déjà en été)
+        assert_eq!(clock.offset_at(utc(2024, 3, 12, 12, 0)), 180);
+        assert_eq!(clock.to_utc(summer), utc(2024, 6, 7, 12, 30));
+        assert_eq!(clock.to_utc(winter), utc(2024, 1, 5, 13, 30));
+
+        let fixed = BrokerClock {
+            utc_offset_minutes: 0,
+            us_dst: false,
+        };
+        assert_eq!(
+            fixed.to_broker(utc(2024, 6, 7, 12, 30)),
+            utc(2024, 6, 7, 12, 30).naive_utc()
+        );
+        assert!(BrokerClock {
+            utc_offset_minutes: 900,
+            us_dst: false
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -19,6 +19,9 @@ pub struct Candle {
     pub close: f64,
     #[validate(range(min = 0.0))]
     pub volume: f64,
+    /// Spread historique de la minute (en prix, Ask - Bid) si la source l'exporte
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spread: Option<f64>,
 }
 
 impl Candle {
@@ -56,6 +59,7 @@ impl Candle {
             low,
             close,
             volume,
+            spread: None,
         };
         
         candle.validate().map_err(|e| {
@@ -65,6 +69,17 @@ impl Candle {
         Ok(candle)
     }
 
+    /// Attache le spread historique (en prix) à la bougie
+    pub fn with_spread(mut self, spread: Option<f64>) -> Self {
+        self.spread = spread.filter(|s| s.is_finite() && *s >= 0.0);
+        self
+    }
+
+    /// Spread de la minute, ou la valeur de repli (constante par actif) si inconnu
+    pub fn spread_or(&self, fallback: f64) -> f64 {
+        self.spread.unwrap_or(fallback)
+    }
+
     pub fn true_range(&self, prev_close: Option<f64>) -> f64 {
         let high_low = self.high - self.low;
         match prev_close {
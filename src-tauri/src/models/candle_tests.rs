@@ -29,6 +29,7 @@ mod tests {
             high: 1.0980,
             low: 1.0940,
             volume: 1200.0,
+            spread: None,
         };
         let body_range = candle.body_range();
         assert!((body_range - 50.0).abs() < 0.1);
@@ -46,6 +47,7 @@ mod tests {
             high: 1.0980,
             low: 1.0940,
             volume: 1200.0,
+            spread: None,
         };
         let body_range = candle.body_range();
         // Body Range doit toujours être positif: |close - open| / range * 100
@@ -64,6 +66,7 @@ mod tests {
             high: 1.0980,
             low: 1.0940,
             volume: 1200.0,
+            spread: None,
         };
         let tr = candle.true_range(Some(1.0960));
         assert!(tr > 0.0);
@@ -80,6 +83,7 @@ mod tests {
             high: 1.0980,
             low: 1.0940,
             volume: 1200.0,
+            spread: None,
         };
         let ratio = candle.shadow_ratio();
         assert!(ratio >= 0.0);
@@ -110,7 +114,30 @@ mod tests {
             low: 0.9,
             close: 1.05,
             volume: 1000.0,
+            spread: None,
         };
         assert!(candle.hour_utc() < 24);
     }
+
+    #[test]
+    fn test_spread_fallback() {
+        let candle = Candle::new(
+            "EURUSD".to_string(),
+            Utc::now(),
+            1.0950,
+            1.0980,
+            1.0940,
+            1.0970,
+            1200.0,
+        )
+        .expect("candle valide");
+        assert_eq!(candle.spread_or(0.0002), 0.0002);
+
+        let candle = candle.with_spread(Some(0.00007));
+        assert_eq!(candle.spread_or(0.0002), 0.00007);
+
+        // Un spread négatif (Ask < Bid) est ignoré
+        let candle = candle.with_spread(Some(-0.0001));
+        assert!(candle.spread.is_none());
+    }
 }
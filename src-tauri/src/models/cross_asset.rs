@@ -0,0 +1,62 @@
+// models/cross_asset.rs - Réaction multi-actifs à une même publication
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::input_fingerprint::InputFingerprint;
+use serde::{Deserialize, Serialize};
+
+/// Réaction d'un instrument à une occurrence
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InstrumentReaction {
+    pub symbol: String,
+    pub has_data: bool,
+    /// Excursion maximale depuis l'ouverture T0 (pips/points)
+    pub peak_pips: f64,
+    /// Volatilité de la fenêtre / volatilité habituelle à la même heure (comparable entre actifs)
+    pub multiplier: f64,
+    /// Sens brut du mouvement à T+30 : 1, -1 ou 0
+    pub direction: i8,
+    /// Sens exprimé pour la devise de l'annonce (1 = la devise s'apprécie)
+    pub currency_direction: i8,
+    /// Minute où l'excursion atteint la moitié de son maximum
+    pub reaction_minute: Option<i64>,
+    /// Retard sur l'instrument le plus rapide de l'occurrence
+    pub lag_minutes: Option<i64>,
+}
+
+/// Tableau de réactions pour une occurrence
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CrossAssetOccurrence {
+    pub event_id: i32,
+    pub datetime: String,
+    pub currency: String,
+    pub reactions: Vec<InstrumentReaction>,
+    /// Part des instruments réactifs allant dans le sens majoritaire (devise de l'annonce)
+    pub direction_agreement: f64,
+    /// Instrument au plus fort multiplicateur
+    pub best_symbol: Option<String>,
+}
+
+/// Synthèse par instrument sur toutes les occurrences
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InstrumentReactionSummary {
+    pub symbol: String,
+    pub occurrences_with_data: usize,
+    pub mean_multiplier: f64,
+    pub mean_peak_pips: f64,
+    pub mean_lag_minutes: Option<f64>,
+    /// Nombre d'occurrences où l'instrument a le mieux réagi
+    pub best_count: usize,
+    /// Part des occurrences où l'instrument suit le sens majoritaire
+    pub agreement_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CrossAssetReactionMap {
+    pub event_type: String,
+    pub symbols: Vec<String>,
+    pub occurrences: Vec<CrossAssetOccurrence>,
+    pub summary: Vec<InstrumentReactionSummary>,
+    /// Entrées du calcul (reproductibilité)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<InputFingerprint>,
+}
@@ -0,0 +1,29 @@
+// models/daily_bar.rs - Barre journalière reconstruite depuis les bougies M1
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::Candle;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Barre journalière (UTC) reconstruite depuis les bougies M1
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DailyBar {
+    pub date: NaiveDate,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl DailyBar {
+    /// Agrège les bougies d'une même journée (triées par heure)
+    pub fn from_candles(candles: &[Candle]) -> Option<Self> {
+        let first = candles.first()?;
+        let last = candles.last()?;
+        Some(Self {
+            date: first.datetime.date_naive(),
+            high: candles.iter().map(|c| c.high).fold(f64::MIN, f64::max),
+            low: candles.iter().map(|c| c.low).fold(f64::MAX, f64::min),
+            close: last.close,
+        })
+    }
+}
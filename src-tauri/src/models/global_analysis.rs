@@ -87,3 +87,13 @@ pub struct OptimalTimeWindow {
     pub consistency_score: f64, // Score de consistance (0-100) : à quel point ces timings sont fiables
     pub affected_pairs: Vec<String>, // Paires affectées par cet événement
 }
+
+/// Progression de l'analyse globale sur l'historique brut, émise après chaque paire
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalAnalysisProgress {
+    pub symbol: String,
+    pub processed: usize,
+    pub total: usize,
+    pub percent: f64,
+    pub done: bool,
+}
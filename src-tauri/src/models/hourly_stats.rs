@@ -1,5 +1,6 @@
 // models/hourly_stats.rs - Statistiques par heure UTC
 use super::hourly_stats_thresholds::*;
+use super::ConfidenceInterval;
 use serde::{Deserialize, Serialize};
 
 /// Événement économique dans une heure spécifique
@@ -27,6 +28,11 @@ pub struct HourlyStats {
     pub noise_ratio_mean: f64,
     pub breakout_percentage: f64,
     pub events: Vec<EventInHour>,
+    /// Intervalles de confiance (95%) de l'ATR moyen et du % de cassures
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub atr_mean_ci: Option<ConfidenceInterval>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakout_percentage_ci: Option<ConfidenceInterval>,
 }
 
 impl HourlyStats {
@@ -19,6 +19,8 @@ mod tests {
             noise_ratio_mean: 1.8,
             breakout_percentage: 25.0,
             events: Vec::new(),
+            atr_mean_ci: None,
+            breakout_percentage_ci: None,
         };
 
         let score = stats.quality_score();
@@ -42,6 +44,8 @@ mod tests {
             noise_ratio_mean: 0.0,
             breakout_percentage: 0.0,
             events: Vec::new(),
+            atr_mean_ci: None,
+            breakout_percentage_ci: None,
         };
 
         assert_eq!(stats.quality_score(), 0.0);
@@ -63,6 +67,8 @@ mod tests {
             noise_ratio_mean: 1.5,
             breakout_percentage: 30.0,
             events: Vec::new(),
+            atr_mean_ci: None,
+            breakout_percentage_ci: None,
         };
 
         assert_eq!(excellent.quality_rating(), "Excellent");
@@ -0,0 +1,47 @@
+// models/input_fingerprint.rs - Empreinte des données d'entrée d'une analyse (reproductibilité)
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use super::archive_comparison::MetricDelta;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Historique de bougies d'un symbole/timeframe au moment du calcul
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataRange {
+    pub symbol: String,
+    pub timeframe: String,
+    pub candle_count: i64,
+    pub first: Option<String>,
+    pub last: Option<String>,
+}
+
+/// Entrées d'une analyse : bougies, calendriers, filtre d'événement et paramètres
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputFingerprint {
+    pub app_version: String,
+    pub candles: Vec<DataRange>,
+    pub calendar_import_ids: Vec<i32>,
+    pub event_filter: Option<String>,
+    /// Occurrences du calendrier retenues (change après un nettoyage)
+    pub event_count: usize,
+    /// Paramètres et configuration (valeurs sérialisées en JSON)
+    pub parameters: BTreeMap<String, String>,
+    /// CRC32 (hex) des entrées, version de l'application exclue
+    pub hash: String,
+}
+
+/// Résultat du recalcul d'une archive avec les données actuelles
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveRerunReport {
+    pub archive_id: i32,
+    pub title: String,
+    /// None pour les archives enregistrées avant le suivi des entrées
+    pub previous: Option<InputFingerprint>,
+    pub current: InputFingerprint,
+    pub inputs_changed: bool,
+    /// Explication des écarts d'entrées, une ligne par changement
+    pub input_changes: Vec<String>,
+    /// Valeurs archivées puis recalculées
+    pub metrics: Vec<MetricDelta>,
+    pub results_changed: bool,
+}
@@ -1,23 +1,46 @@
 // models/mod.rs - Module principal des modèles de données
 // Niveau 4 de l'architecture DAG
 
+pub mod alerting;
 pub mod analysis_result;
 pub mod archive;
+pub mod archive_bundle;
+pub mod archive_comparison;
+pub mod archive_kind;
+pub mod archive_payload;
+pub mod archive_summaries;
 pub mod calendar_event;
 pub mod candle;
+pub mod cross_asset;
+pub mod daily_bar;
 pub mod entry_window_analysis;
+pub mod event_impact;
 pub mod errors;
 pub mod event_metrics;
 pub mod event_movement_quality;
 pub mod global_analysis;
 pub mod hourly_stats;
 pub mod hourly_stats_thresholds;
+pub mod input_fingerprint;
 pub mod stats_15min;
 pub mod straddle_parameters;
 pub mod trading_recommendation;
 pub mod volatility_duration;
 pub mod asset_class;
+pub mod asset_detection;
+pub mod broker_clock;
 pub mod planning;
+pub mod post_event;
+pub mod regime_filter;
+pub mod pre_event;
+pub mod symbol_registry;
+pub mod statistics;
+pub mod score_calibration;
+pub mod seasonality;
+pub mod time_decay;
+pub mod trade_journal;
+pub mod trading_plan;
+pub mod volatility_regime;
 
 
 // Re-exports pour faciliter les imports
@@ -25,8 +48,12 @@ pub use analysis_result::{AnalysisResult, CorrelatedEvent, GlobalMetrics};
 pub use archive::Archive;
 pub use calendar_event::CalendarEvent;
 pub use candle::Candle;
+pub use cross_asset::{
+    CrossAssetOccurrence, CrossAssetReactionMap, InstrumentReaction, InstrumentReactionSummary,
+};
 pub use entry_window_analysis::{EntryOffsetMetrics, EntryWindowAnalysisResult};
 pub use errors::{Result, VolatilityError};
+pub use event_impact::EventImpactResult;
 pub use event_metrics::EventMetrics;
 pub use event_movement_quality::EventMovementQuality;
 pub use global_analysis::*;
@@ -36,3 +63,17 @@ pub use straddle_parameters::StraddleParameters;
 pub use trading_recommendation::{RiskLevel, TradingRecommendation};
 pub use volatility_duration::VolatilityDuration;
 pub use asset_class::AssetProperties;
+pub use broker_clock::{BrokerClock, MT5_TIME_FORMAT};
+pub use symbol_registry::{SymbolRegistry, SymbolSpec};
+pub use statistics::{ConfidenceInterval, IntervalMethod, SignificanceTest, TestMethod};
+pub use score_calibration::{CalibrationBin, ScoreComponents, ScoreWeightSet, ScoreWeights};
+pub use post_event::{
+    PostEventBehavior, PostEventOccurrence, PostEventPathProfile, PostEventReport, SurpriseSize,
+    SurpriseSlice,
+};
+pub use pre_event::{PreEventOccurrence, PreEventOffsetRule, PreEventProfile};
+pub use seasonality::{SeasonalBucket, SeasonalDimension, SeasonalWindow, SeasonalityReport};
+pub use time_decay::TimeDecay;
+pub use daily_bar::DailyBar;
+pub use regime_filter::RegimeFilter;
+pub use volatility_regime::{RegimeCalendar, RegimeDay, RegimeThresholds, VolatilityRegime};
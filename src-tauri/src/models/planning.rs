@@ -1,3 +1,4 @@
+use super::{ConfidenceInterval, VolatilityRegime};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,11 +12,84 @@ pub struct ProjectedEvent {
     pub offset: f64,
     pub tp: f64,
     pub sl: f64,
-    pub offset_simultaneous: f64,
-    pub tp_simultaneous: f64,
-    pub sl_simultaneous: f64,
+    /// Paramètres Simultané : None si l'archive retenue ne les a pas enregistrés
+    pub offset_simultaneous: Option<f64>,
+    pub tp_simultaneous: Option<f64>,
+    pub sl_simultaneous: Option<f64>,
     pub confidence_score: f64,
     pub source: String,
     pub has_history: bool,
     pub occurrence_count: i64,
+    /// Intervalle du score : repris de l'archive (absent des plus anciennes) ou bootstrap live
+    #[serde(default)]
+    pub confidence_score_ci: Option<ConfidenceInterval>,
+    /// Historique suffisant et score assez précis pour fonder une décision
+    #[serde(default)]
+    pub significant: bool,
+    /// Régime de volatilité actuel de la paire projetée
+    #[serde(default)]
+    pub regime: Option<VolatilityRegime>,
+    /// L'archive retenue a été calculée sur ce même régime
+    #[serde(default)]
+    pub regime_matched: bool,
+    /// Timeout recommandé par le profil de décroissance (projection calculée)
+    #[serde(default)]
+    pub timeout_minutes: Option<i32>,
+    /// Délai médian du pic de volatilité après l'annonce (projection calculée)
+    #[serde(default)]
+    pub peak_delay_minutes: Option<f64>,
+    /// Instruments candidats classés sur l'historique (le meilleur en premier)
+    #[serde(default)]
+    pub candidates: Vec<PairCandidate>,
+}
+
+/// Lien entre un instrument et la devise de l'annonce
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CandidateRelation {
+    /// Devise de base (EURUSD pour EUR, crosses EURJPY, EURGBP…)
+    Base,
+    /// Devise de cotation (EURUSD, XAUUSD pour USD)
+    Quote,
+    /// Indice, métal ou crypto sans lien déclaré : retenu sur sa réaction mesurée
+    Linked,
+}
+
+/// Décision de trading déduite des résultats historiques après coûts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradabilityVerdict {
+    InsufficientData,
+    Avoid,
+    Marginal,
+    Tradable,
+}
+
+/// Un instrument candidat pour une annonce, évalué sur ses occurrences passées
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PairCandidate {
+    pub pair: String,
+    /// Position dans le classement (1 = meilleur)
+    pub rank: usize,
+    pub relation: CandidateRelation,
+    pub unit: String,
+    /// Occurrences avec données de l'instrument
+    pub occurrences: usize,
+    /// Ordres déclenchés lors de la simulation
+    pub trades: usize,
+    /// Excursion maximale médiane T0 → T+30 (pips/points)
+    pub expected_move_pips: f64,
+    /// Part des trades déclenchés terminés en whipsaw (0-1)
+    pub whipsaw_rate: f64,
+    pub win_rate_percent: f64,
+    /// Somme des résultats nets après spread et slippage (pips/points)
+    pub net_pnl_pips: f64,
+    pub average_net_pips: f64,
+    pub average_net_ci: Option<ConfidenceInterval>,
+    pub verdict: TradabilityVerdict,
+    /// Paramètres simulés (points MT5, comme la projection)
+    pub offset: f64,
+    pub tp: f64,
+    pub sl: f64,
+    pub confidence_score: f64,
 }
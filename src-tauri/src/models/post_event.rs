@@ -0,0 +1,125 @@
+// models/post_event.rs - Trajectoire du prix après l'impulsion initiale (continuation / retour)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::input_fingerprint::InputFingerprint;
+use super::ConfidenceInterval;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Taille de la surprise (Actual - Forecast) en écarts-types de l'historique du type d'événement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SurpriseSize {
+    /// Actual ou Forecast absent
+    Unknown,
+    /// |z| < 0.5
+    InLine,
+    /// 0.5 ≤ |z| < 1.5
+    Moderate,
+    /// |z| ≥ 1.5
+    Large,
+}
+
+impl SurpriseSize {
+    pub const ALL: [SurpriseSize; 4] = [
+        SurpriseSize::InLine,
+        SurpriseSize::Moderate,
+        SurpriseSize::Large,
+        SurpriseSize::Unknown,
+    ];
+
+    pub fn from_z(z: Option<f64>) -> Self {
+        match z.map(f64::abs) {
+            None => SurpriseSize::Unknown,
+            Some(z) if z < 0.5 => SurpriseSize::InLine,
+            Some(z) if z < 1.5 => SurpriseSize::Moderate,
+            Some(_) => SurpriseSize::Large,
+        }
+    }
+}
+
+/// Comportement dominant après l'impulsion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostEventBehavior {
+    /// L'impulsion tient ou s'étend : Trailing Stop
+    Continuation,
+    /// L'impulsion est rendue : objectif fixe
+    MeanReversion,
+    /// Pas de comportement dominant
+    Mixed,
+}
+
+/// Trajectoire d'une occurrence (pips/points normalisés)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostEventOccurrence {
+    pub event_date: NaiveDate,
+    pub event_time: String,
+    pub surprise_z: Option<f64>,
+    pub surprise: SurpriseSize,
+    /// Clôture T+1 moins ouverture T0 (signée)
+    pub impulse_pips: f64,
+    /// Part de l'impulsion conservée à T+5 / T+15 / T+60 (1 = intacte, < 0 = inversée) ;
+    /// None sans cotation avant l'horizon
+    pub retained_5: Option<f64>,
+    pub retained_15: Option<f64>,
+    pub retained_60: Option<f64>,
+    /// Excursion maximale dans le sens de l'impulsion jusqu'à T+60
+    pub peak_pips: f64,
+    /// Recul après le pic, en part du pic (> 1 = retour au-delà de T0)
+    pub retracement_depth: f64,
+    /// Le prix à T+60 est repassé de l'autre côté de l'ouverture T0 (faux si T+60 non mesuré)
+    pub reversed: bool,
+}
+
+/// Profil agrégé d'un ensemble d'occurrences (pondéré par ancienneté)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostEventPathProfile {
+    pub occurrences: usize,
+    pub mean_impulse_pips: f64,
+    /// Médianes pondérées de la part conservée, sur les occurrences mesurées à cet horizon
+    pub retained_5: Option<f64>,
+    pub retained_15: Option<f64>,
+    pub retained_60: Option<f64>,
+    /// Part des occurrences mesurées à T+60 repassées sous l'ouverture T0
+    pub reversal_probability: f64,
+    pub reversal_ci: Option<ConfidenceInterval>,
+    pub median_retracement_depth: f64,
+    pub p75_retracement_depth: f64,
+    pub median_peak_pips: f64,
+    pub behavior: PostEventBehavior,
+}
+
+/// Profil par taille de surprise
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurpriseSlice {
+    pub surprise: SurpriseSize,
+    pub profile: Option<PostEventPathProfile>,
+}
+
+/// Rapport complet pour une paire et un type d'événement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostEventReport {
+    pub pair: String,
+    pub event_type: String,
+    pub unit: String,
+    pub overall: PostEventPathProfile,
+    pub by_surprise: Vec<SurpriseSlice>,
+    pub details: Vec<PostEventOccurrence>,
+    /// Entrées du calcul (reproductibilité)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<InputFingerprint>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_surprise_buckets() {
+        assert_eq!(SurpriseSize::from_z(None), SurpriseSize::Unknown);
+        assert_eq!(SurpriseSize::from_z(Some(-0.2)), SurpriseSize::InLine);
+        assert_eq!(SurpriseSize::from_z(Some(1.0)), SurpriseSize::Moderate);
+        assert_eq!(SurpriseSize::from_z(Some(-2.4)), SurpriseSize::Large);
+    }
+}
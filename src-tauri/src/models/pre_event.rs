@@ -0,0 +1,91 @@
+// models/pre_event.rs - Comportement du prix avant publication (dérive, compression)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::ConfidenceInterval;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Mesures d'une occurrence (en pips/points normalisés)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreEventOccurrence {
+    pub event_date: NaiveDate,
+    pub event_time: String,
+    /// Clôture T-1 moins ouverture T-30 (signée : > 0 = hausse avant l'annonce)
+    pub drift_pips: f64,
+    /// Range high-low de T-30 à T0
+    pub pre_range_pips: f64,
+    /// Range d'une fenêtre de même durée, T-120 à T-90 (référence « normale »)
+    pub baseline_range_pips: f64,
+    /// pre_range / baseline_range (< 1 = le marché se comprime avant l'annonce)
+    pub compression_ratio: f64,
+    /// Plus grande excursion depuis l'ouverture de T0 sur la fenêtre post-annonce
+    pub post_move_pips: f64,
+    /// Clôture fin de fenêtre post-annonce moins ouverture T0 (signée)
+    pub post_direction_pips: f64,
+}
+
+/// Profil pré-annonce agrégé par type d'événement et paire
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreEventProfile {
+    pub pair: String,
+    pub event_type: String,
+    pub unit: String,
+    pub window_minutes: i64,
+    pub post_window_minutes: i64,
+    pub occurrences: usize,
+    /// Moyennes pondérées par ancienneté (cf. demi-vie configurée)
+    pub mean_drift_pips: f64,
+    pub mean_abs_drift_pips: f64,
+    pub drift_ci: Option<ConfidenceInterval>,
+    /// Part des occurrences en hausse avant l'annonce
+    pub drift_up_rate: f64,
+    /// L'intervalle de la dérive exclut 0 : biais directionnel réel
+    pub drift_significant: bool,
+    pub mean_pre_range_pips: f64,
+    pub mean_compression_ratio: f64,
+    pub compression_ci: Option<ConfidenceInterval>,
+    /// Part des occurrences où le range se comprime (ratio < 1)
+    pub compression_rate: f64,
+    pub mean_post_move_pips: f64,
+    /// Part des occurrences où le mouvement post-annonce prolonge la dérive
+    pub continuation_rate: f64,
+    /// Corrélation de Pearson entre range pré-annonce et taille du mouvement
+    pub range_move_correlation: f64,
+    /// Pente de la régression mouvement = a + b · range pré-annonce
+    pub range_move_slope: f64,
+    pub details: Vec<PreEventOccurrence>,
+}
+
+/// Ajustement d'offset déduit du profil pré-annonce
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreEventOffsetRule {
+    /// Multiplicateur de l'offset hors marge spread (< 1 = resserrer)
+    pub offset_factor: f64,
+    /// Plancher : les ordres restent hors du demi-range pré-annonce
+    pub min_offset_pips: f64,
+    /// Ajout à l'offset du Buy Stop (négatif = plus proche)
+    pub long_bias_pips: f64,
+    /// Ajout à l'offset du Sell Stop (négatif = plus proche)
+    pub short_bias_pips: f64,
+    pub reasons: Vec<String>,
+}
+
+impl PreEventOffsetRule {
+    /// Règle sans effet (échantillon insuffisant, comportement neutre)
+    pub fn neutral() -> Self {
+        Self {
+            offset_factor: 1.0,
+            min_offset_pips: 0.0,
+            long_bias_pips: 0.0,
+            short_bias_pips: 0.0,
+            reasons: Vec::new(),
+        }
+    }
+
+    pub fn is_neutral(&self) -> bool {
+        (self.offset_factor - 1.0).abs() < f64::EPSILON
+            && self.min_offset_pips == 0.0
+            && self.long_bias_pips == 0.0
+            && self.short_bias_pips == 0.0
+    }
+}
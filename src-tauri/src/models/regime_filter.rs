@@ -0,0 +1,26 @@
+// models/regime_filter.rs - Restriction d'une analyse à un régime de volatilité
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::{RegimeCalendar, VolatilityRegime};
+use serde::{Deserialize, Serialize};
+
+/// Restriction demandée par l'interface
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum RegimeFilter {
+    /// Régime actuel du symbole
+    Current,
+    Only {
+        regime: VolatilityRegime,
+    },
+}
+
+impl RegimeFilter {
+    /// Régime ciblé (None si le calendrier est vide)
+    pub fn resolve(&self, calendar: &RegimeCalendar) -> Option<VolatilityRegime> {
+        match self {
+            RegimeFilter::Current => calendar.current,
+            RegimeFilter::Only { regime } => Some(*regime),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+// models/score_calibration.rs - Pondération et calibration du score de confiance
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::asset_class::AssetType;
+use super::ConfidenceInterval;
+use serde::{Deserialize, Serialize};
+
+/// Composantes du score, chacune en fraction (0-1) de son maximum
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreComponents {
+    pub atr: f64,
+    pub body_range: f64,
+    pub volatility: f64,
+    pub noise: f64,
+    pub breakout: f64,
+    pub data: f64,
+}
+
+impl ScoreComponents {
+    /// Composantes qui varient d'une occurrence à l'autre (sans `data`)
+    pub fn features(&self) -> [f64; 5] {
+        [
+            self.atr,
+            self.body_range,
+            self.volatility,
+            self.noise,
+            self.breakout,
+        ]
+    }
+}
+
+/// Points maximum attribués à chaque composante
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoreWeights {
+    pub atr: f64,
+    pub body_range: f64,
+    pub volatility: f64,
+    pub noise: f64,
+    pub breakout: f64,
+    pub data: f64,
+}
+
+impl Default for ScoreWeights {
+    /// Barème historique (seuils 2024) : 30 / 25 / 25 / 10 / 10 / 5
+    fn default() -> Self {
+        Self {
+            atr: 30.0,
+            body_range: 25.0,
+            volatility: 25.0,
+            noise: 10.0,
+            breakout: 10.0,
+            data: 5.0,
+        }
+    }
+}
+
+impl ScoreWeights {
+    /// Reconstruit une pondération depuis les poids des 5 composantes variables
+    pub fn from_features(features: [f64; 5], data: f64) -> Self {
+        Self {
+            atr: features[0],
+            body_range: features[1],
+            volatility: features[2],
+            noise: features[3],
+            breakout: features[4],
+            data,
+        }
+    }
+
+    /// Score brut (avant pénalités et bornage)
+    pub fn apply(&self, c: &ScoreComponents) -> f64 {
+        self.atr * c.atr
+            + self.body_range * c.body_range
+            + self.volatility * c.volatility
+            + self.noise * c.noise
+            + self.breakout * c.breakout
+            + self.data * c.data
+    }
+}
+
+/// Tranche de score de la courbe de calibration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationBin {
+    pub score_min: f64,
+    pub score_max: f64,
+    pub mean_score: f64,
+    /// Probabilité de gain moyenne prédite par la régression logistique
+    pub predicted_win_rate: f64,
+    pub observed_win_rate: f64,
+    pub sample_size: usize,
+    /// Intervalle de Wilson du taux observé
+    pub observed_ci: Option<ConfidenceInterval>,
+}
+
+/// Pondération calibrée pour une classe d'actifs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreWeightSet {
+    pub asset_type: AssetType,
+    pub weights: ScoreWeights,
+    /// Régression logistique : logit(P(gain)) = intercept + Σ coef × composante
+    pub intercept: f64,
+    pub coefficients: [f64; 5],
+    pub sample_size: usize,
+    pub win_rate: f64,
+    pub brier_score: f64,
+    /// Faux si aucune composante n'améliore le taux de gain (barème par défaut conservé)
+    pub informative: bool,
+    pub calibrated_at: String,
+    /// Taux de gain observé par tranche de score, avant et après calibration
+    pub curve_default: Vec<CalibrationBin>,
+    pub curve_calibrated: Vec<CalibrationBin>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_weights_match_legacy_scale() {
+        let full = ScoreComponents {
+            atr: 1.0,
+            body_range: 1.0,
+            volatility: 1.0,
+            noise: 1.0,
+            breakout: 1.0,
+            data: 1.0,
+        };
+        assert!((ScoreWeights::default().apply(&full) - 105.0).abs() < 1e-9);
+
+        let w = ScoreWeights::from_features([40.0, 20.0, 20.0, 10.0, 10.0], 5.0);
+        assert!((w.apply(&full) - 105.0).abs() < 1e-9);
+        assert_eq!(full.features().len(), 5);
+    }
+}
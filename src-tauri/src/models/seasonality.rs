@@ -0,0 +1,95 @@
+// models/seasonality.rs - Saisonnalité calendaire (jour, mois, premier vendredi, fins de mois/trimestre)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::input_fingerprint::InputFingerprint;
+use super::{ConfidenceInterval, SignificanceTest};
+use serde::{Deserialize, Serialize};
+
+/// Découpage calendaire d'un historique (ordre des variantes = ordre d'affichage)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeasonalDimension {
+    Weekday,
+    Month,
+    /// Premier vendredi du mois (NFP)
+    FirstFriday,
+    /// Dernier jour ouvré du mois (fixing de Londres, rééquilibrages)
+    MonthEnd,
+    /// Dernier jour ouvré de mars, juin, septembre et décembre
+    QuarterEnd,
+}
+
+/// Plage horaire UTC [start, end) analysée chaque jour ; start > end traverse minuit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SeasonalWindow {
+    pub start_hour_utc: u8,
+    pub end_hour_utc: u8,
+}
+
+impl SeasonalWindow {
+    pub fn contains(&self, hour: u8) -> bool {
+        if self.start_hour_utc <= self.end_hour_utc {
+            hour >= self.start_hour_utc && hour < self.end_hour_utc
+        } else {
+            hour >= self.start_hour_utc || hour < self.end_hour_utc
+        }
+    }
+}
+
+/// Un groupe de journées comparé au reste de l'historique
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonalBucket {
+    pub dimension: SeasonalDimension,
+    /// Jour ISO (1 = lundi), mois (1-12) ou 1 pour les indicateurs
+    pub key: u32,
+    pub label: String,
+    pub days: usize,
+    /// Range moyen des bougies de la plage (pips/points)
+    pub mean_range_pips: f64,
+    pub range_ci: Option<ConfidenceInterval>,
+    /// Écart au reste de l'historique (%)
+    pub range_delta_percent: f64,
+    pub volatility_test: Option<SignificanceTest>,
+    pub trades: usize,
+    pub win_rate_percent: f64,
+    pub win_rate_ci: Option<ConfidenceInterval>,
+    pub whipsaw_rate_percent: f64,
+    pub win_rate_test: Option<SignificanceTest>,
+    /// Un des tests passe le seuil corrigé de Bonferroni de sa dimension
+    pub significant: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonalityReport {
+    pub symbol: String,
+    pub unit: String,
+    pub window: Option<SeasonalWindow>,
+    pub days: usize,
+    pub mean_range_pips: f64,
+    pub win_rate_percent: f64,
+    /// Seuil de significativité avant correction (0.05)
+    pub alpha: f64,
+    pub buckets: Vec<SeasonalBucket>,
+    /// Entrées du calcul (reproductibilité)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<InputFingerprint>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_wraps_midnight() {
+        let london = SeasonalWindow {
+            start_hour_utc: 7,
+            end_hour_utc: 10,
+        };
+        assert!(london.contains(7) && london.contains(9) && !london.contains(10));
+        let sydney = SeasonalWindow {
+            start_hour_utc: 22,
+            end_hour_utc: 2,
+        };
+        assert!(sydney.contains(23) && sydney.contains(1) && !sydney.contains(2));
+    }
+}
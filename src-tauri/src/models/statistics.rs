@@ -0,0 +1,129 @@
+// models/statistics.rs - Intervalle de confiance attaché à une métrique
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use serde::{Deserialize, Serialize};
+
+/// Taille d'échantillon minimale pour considérer une métrique comme exploitable
+pub const MIN_SAMPLE_SIZE: usize = 20;
+
+/// Demi-largeur maximale de l'intervalle, relative à l'estimation (0.5 = ±50%)
+pub const MAX_RELATIVE_HALF_WIDTH: f64 = 0.5;
+
+/// Demi-largeur maximale d'un intervalle de proportion, en absolu (0.10 = ±10 points)
+pub const MAX_PROPORTION_HALF_WIDTH: f64 = 0.10;
+
+/// Méthode utilisée pour construire l'intervalle
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalMethod {
+    /// Proportions (taux de réussite, fréquence de whipsaw, % de cassures)
+    Wilson,
+    /// Moyennes d'échantillons à peu près symétriques
+    StudentT,
+    /// Percentiles d'un rééchantillonnage (distributions asymétriques, scores)
+    Bootstrap,
+}
+
+/// Estimation ponctuelle accompagnée de son intervalle et de la taille d'échantillon
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceInterval {
+    pub estimate: f64,
+    pub lower: f64,
+    pub upper: f64,
+    pub sample_size: usize,
+    /// Niveau de confiance (0.95 = 95%)
+    pub level: f64,
+    pub method: IntervalMethod,
+    /// Faux si l'échantillon est trop petit ou l'intervalle trop large pour conclure
+    pub reliable: bool,
+}
+
+impl ConfidenceInterval {
+    pub fn new(
+        estimate: f64,
+        lower: f64,
+        upper: f64,
+        sample_size: usize,
+        level: f64,
+        method: IntervalMethod,
+    ) -> Self {
+        let half_width = (upper - lower) / 2.0;
+        // Une proportion proche de 0 n'a pas de précision relative exploitable : seuil absolu
+        let max_half_width = match method {
+            IntervalMethod::Wilson => MAX_PROPORTION_HALF_WIDTH,
+            IntervalMethod::StudentT | IntervalMethod::Bootstrap => {
+                MAX_RELATIVE_HALF_WIDTH * estimate.abs()
+            }
+        };
+        let reliable = sample_size >= MIN_SAMPLE_SIZE && half_width <= max_half_width;
+        Self {
+            estimate,
+            lower,
+            upper,
+            sample_size,
+            level,
+            method,
+            reliable,
+        }
+    }
+
+    pub fn half_width(&self) -> f64 {
+        (self.upper - self.lower) / 2.0
+    }
+
+    /// Même intervalle exprimé dans une autre unité (ex: ×100 pour des pourcentages)
+    pub fn scaled(&self, factor: f64) -> Self {
+        Self {
+            estimate: self.estimate * factor,
+            lower: self.lower * factor,
+            upper: self.upper * factor,
+            ..self.clone()
+        }
+    }
+}
+
+/// Test statistique utilisé pour comparer un sous-échantillon au reste de l'historique
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestMethod {
+    /// Différence de moyennes à variances inégales
+    WelchT,
+    /// Différence de deux proportions (approximation normale)
+    TwoProportionZ,
+}
+
+/// Résultat d'un test bilatéral d'égalité
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignificanceTest {
+    pub method: TestMethod,
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reliability_requires_sample_and_precision() {
+        let small = ConfidenceInterval::new(0.5, 0.45, 0.55, 8, 0.95, IntervalMethod::Wilson);
+        assert!(!small.reliable);
+
+        let wide = ConfidenceInterval::new(0.1, 0.0, 0.3, 50, 0.95, IntervalMethod::Wilson);
+        assert!(!wide.reliable);
+
+        let good = ConfidenceInterval::new(0.5, 0.4, 0.6, 80, 0.95, IntervalMethod::Wilson);
+        assert!(good.reliable);
+
+        // Proportion rare mais précise : fiable malgré une demi-largeur relative > 50%
+        let rare = ConfidenceInterval::new(0.02, 0.01, 0.05, 500, 0.95, IntervalMethod::Wilson);
+        assert!(rare.reliable);
+        let noisy_mean =
+            ConfidenceInterval::new(0.02, 0.01, 0.05, 500, 0.95, IntervalMethod::StudentT);
+        assert!(!noisy_mean.reliable);
+
+        let pct = good.scaled(100.0);
+        assert!((pct.upper - 60.0).abs() < 1e-9);
+        assert!(pct.reliable);
+    }
+}
@@ -1,5 +1,5 @@
 // models/stats_15min.rs - Statistiques par 15 minutes (pour scalping)
-use crate::models::{EventInHour, StraddleParameters};
+use crate::models::{ConfidenceInterval, EventInHour, StraddleParameters};
 use serde::{Deserialize, Serialize};
 
 /// Statistiques de volatilité pour une tranche de 15 minutes spécifique
@@ -42,6 +42,11 @@ pub struct Stats15Min {
     // Minute optimale d'entrée (0-14) basée sur le profil
     #[serde(skip_serializing_if = "Option::is_none")]
     pub optimal_entry_minute: Option<u8>,
+    // Intervalles de confiance (95%) des métriques principales
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub atr_mean_ci: Option<ConfidenceInterval>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakout_percentage_ci: Option<ConfidenceInterval>,
 }
 
 impl Stats15Min {
@@ -29,6 +29,8 @@ mod tests {
             straddle_parameters: None,
             volatility_profile: None,
             optimal_entry_minute: None,
+            atr_mean_ci: None,
+            breakout_percentage_ci: None,
         };
         assert_eq!(stats.time_label(), "14:00-14:15");
 
@@ -66,6 +68,8 @@ mod tests {
             straddle_parameters: None,
             volatility_profile: None,
             optimal_entry_minute: None,
+            atr_mean_ci: None,
+            breakout_percentage_ci: None,
         };
 
         let score = stats.quality_score();
@@ -99,6 +103,8 @@ mod tests {
             straddle_parameters: None,
             volatility_profile: None,
             optimal_entry_minute: None,
+            atr_mean_ci: None,
+            breakout_percentage_ci: None,
         };
 
         assert_eq!(stats.quality_score(), 0.0);
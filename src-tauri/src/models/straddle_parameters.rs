@@ -9,4 +9,10 @@ pub struct StraddleParameters {
     pub sl_recovery_pips: f64,
     pub risk_reward_ratio: f64,
     pub spread_safety_margin_pips: f64,
+    /// Offset propre au Buy Stop quand le profil pré-annonce justifie une asymétrie
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset_long_pips: Option<f64>,
+    /// Offset propre au Sell Stop quand le profil pré-annonce justifie une asymétrie
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset_short_pips: Option<f64>,
 }
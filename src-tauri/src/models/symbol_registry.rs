@@ -0,0 +1,137 @@
+// models/symbol_registry.rs - Spécifications des symboles (registre courtier)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::asset_class::AssetType;
+use super::asset_detection::strip_broker_suffix;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Spécification d'un symbole telle que définie par le courtier
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SymbolSpec {
+    pub symbol: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub asset_type: AssetType,
+    pub digits: u32,
+    /// Plus petite variation de prix (le "point" MT5)
+    pub tick_size: f64,
+    /// Taille d'un pip (ou point d'indice) utilisée pour normaliser les mouvements
+    pub pip_size: f64,
+    pub contract_size: f64,
+    #[serde(default)]
+    pub base_currency: Option<String>,
+    #[serde(default)]
+    pub quote_currency: Option<String>,
+    /// Sessions de cotation telles qu'exportées (ex. "Mon-Fri 00:05-23:55")
+    #[serde(default)]
+    pub trading_hours: Option<String>,
+    /// Autres noms du même instrument (suffixes courtier, noms alternatifs)
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default = "default_source")]
+    pub source: String,
+}
+
+fn default_source() -> String {
+    "manual".to_string()
+}
+
+impl SymbolSpec {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.symbol.trim().is_empty() {
+            return Err("Symbole vide".to_string());
+        }
+        for (label, value) in [
+            ("tick_size", self.tick_size),
+            ("pip_size", self.pip_size),
+            ("contract_size", self.contract_size),
+        ] {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(format!(
+                    "{} invalide pour {}: {}",
+                    label, self.symbol, value
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Vrai si la devise fait partie de l'instrument (base ou cotation)
+    pub fn involves_currency(&self, currency: &str) -> bool {
+        [&self.base_currency, &self.quote_currency].iter().any(|c| {
+            c.as_deref()
+                .is_some_and(|c| c.eq_ignore_ascii_case(currency))
+        })
+    }
+}
+
+/// Index des spécifications par symbole et alias (en majuscules) ;
+/// l'instance partagée est tenue par `SymbolRegistryService`
+#[derive(Debug, Clone, Default)]
+pub struct SymbolRegistry {
+    specs: Vec<SymbolSpec>,
+    index: HashMap<String, usize>,
+}
+
+impl SymbolRegistry {
+    pub fn new(specs: Vec<SymbolSpec>) -> Self {
+        let mut index = HashMap::new();
+        for (idx, spec) in specs.iter().enumerate() {
+            index.insert(spec.symbol.to_uppercase(), idx);
+            for alias in &spec.aliases {
+                // Un symbole réel prime sur l'alias d'un autre symbole
+                index.entry(alias.to_uppercase()).or_insert(idx);
+            }
+        }
+        Self { specs, index }
+    }
+
+    /// Résout un symbole : nom exact, alias, puis nom sans suffixe courtier
+    pub fn resolve(&self, symbol: &str) -> Option<&SymbolSpec> {
+        let exact = symbol.trim().to_uppercase();
+        self.index
+            .get(&exact)
+            .or_else(|| self.index.get(&strip_broker_suffix(symbol)))
+            .and_then(|idx| self.specs.get(*idx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(symbol: &str, aliases: &[&str]) -> SymbolSpec {
+        SymbolSpec {
+            symbol: symbol.to_string(),
+            description: None,
+            asset_type: AssetType::Index,
+            digits: 1,
+            tick_size: 0.1,
+            pip_size: 1.0,
+            contract_size: 1.0,
+            base_currency: None,
+            quote_currency: Some("EUR".to_string()),
+            trading_hours: None,
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            source: "manual".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_alias_and_suffix() {
+        let registry = SymbolRegistry::new(vec![spec("DE40", &["GER40", "DAX"])]);
+        assert_eq!(
+            registry.resolve("de40").map(|s| s.symbol.as_str()),
+            Some("DE40")
+        );
+        assert_eq!(
+            registry.resolve("GER40.cash").map(|s| s.symbol.as_str()),
+            Some("DE40")
+        );
+        assert!(registry
+            .resolve("DAX")
+            .is_some_and(|s| s.involves_currency("eur")));
+        assert!(registry.resolve("NAS100").is_none());
+    }
+}
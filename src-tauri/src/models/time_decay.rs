@@ -0,0 +1,62 @@
+// models/time_decay.rs - Pondération exponentielle par ancienneté (demi-vie)
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Poids d'une observation : 0.5^(âge / demi-vie), âge mesuré en jours depuis la référence.
+/// Sans demi-vie, toutes les observations pèsent 1 (moyennes historiques classiques).
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct TimeDecay {
+    #[serde(default)]
+    pub half_life_days: Option<f64>,
+}
+
+impl TimeDecay {
+    pub fn new(half_life_days: Option<f64>) -> Self {
+        Self { half_life_days }
+    }
+
+    /// Demi-vie strictement positive configurée
+    pub fn is_enabled(&self) -> bool {
+        self.half_life_days.is_some_and(|h| h > 0.0)
+    }
+
+    /// Poids d'une observation datée ; les dates postérieures à la référence pèsent 1
+    pub fn weight(&self, date: NaiveDate, reference: NaiveDate) -> f64 {
+        match self.half_life_days {
+            Some(half_life) if half_life > 0.0 => {
+                let age_days = (reference - date).num_days().max(0) as f64;
+                0.5_f64.powf(age_days / half_life)
+            }
+            _ => 1.0,
+        }
+    }
+
+    /// Poids de plusieurs dates, référence = la plus récente
+    pub fn weights(&self, dates: &[NaiveDate]) -> Vec<f64> {
+        match dates.iter().max() {
+            Some(&reference) => dates.iter().map(|&d| self.weight(d, reference)).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight_halves_every_half_life() {
+        let decay = TimeDecay::new(Some(365.0));
+        let reference = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap_or_default();
+        let one_year = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap_or_default();
+        let two_years = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap_or_default();
+
+        assert!((decay.weight(reference, reference) - 1.0).abs() < 1e-12);
+        assert!((decay.weight(one_year, reference) - 0.5).abs() < 1e-12);
+        assert!((decay.weight(two_years, reference) - 0.25).abs() < 1e-3);
+        assert_eq!(TimeDecay::default().weight(two_years, reference), 1.0);
+        assert!(!TimeDecay::new(Some(0.0)).is_enabled());
+    }
+}
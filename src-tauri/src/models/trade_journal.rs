@@ -0,0 +1,96 @@
+// models/trade_journal.rs - Journal des trades réels (relevé MT5) et écarts avec la simulation
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use serde::{Deserialize, Serialize};
+
+/// Position aller-retour reconstituée à partir des transactions du relevé
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalTrade {
+    /// Symbole courtier
+    pub symbol: String,
+    /// "buy" ou "sell" (sens de l'ouverture)
+    pub side: String,
+    pub volume: f64,
+    /// RFC 3339, UTC
+    pub open_time_utc: String,
+    pub entry_price: f64,
+    /// None si la position est encore ouverte à la fin du relevé
+    pub close_time_utc: Option<String>,
+    pub exit_price: Option<f64>,
+    /// Profit + commissions + swap des deux transactions (devise du compte)
+    pub net_profit: f64,
+    pub comment: String,
+}
+
+/// Ce que `EventSimulator` prévoyait pour l'annonce, avec les paramètres du plan
+#[derive(Debug, Clone, Serialize)]
+pub struct PredictedExecution {
+    /// Issue simulée (TakeProfit, StopLoss, Timeout, NoEntry, ...)
+    pub outcome: String,
+    /// Prix de déclenchement simulé du côté effectivement ouvert
+    pub entry_price: Option<f64>,
+    /// Résultat net simulé (points MT5)
+    pub points: f64,
+}
+
+/// Annonce tradée : trades réels rapprochés de la simulation
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeDivergence {
+    pub event: String,
+    pub currency: String,
+    pub pair: String,
+    pub event_time_utc: String,
+    /// Identifiant de la ligne du plan (None pour un trade hors plan)
+    pub plan_entry_id: Option<String>,
+    /// "Directionnel" ou "Simultane" (déduit des ouvertures réelles)
+    pub mode: String,
+    pub trades: Vec<JournalTrade>,
+    /// None hors plan : paramètres d'ordre inconnus
+    pub predicted: Option<PredictedExecution>,
+    /// Résultat réel (points MT5, positions clôturées)
+    pub realised_points: f64,
+    pub net_profit: f64,
+    /// Prix d'exécution - prix simulé, dans le sens défavorable (points MT5)
+    pub slippage_points: Option<f64>,
+    /// Délai entre l'annonce et la première ouverture (secondes)
+    pub entry_delay_seconds: Option<i64>,
+    /// Même issue (gain / perte) que la simulation
+    pub outcome_match: Option<bool>,
+    pub notes: Vec<String>,
+}
+
+/// Glissement observé d'un instrument, à comparer au modèle de coûts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionCost {
+    /// Symbole canonique
+    pub symbol: String,
+    pub samples: usize,
+    pub observed_slippage_pips: f64,
+    /// Glissement du modèle de coûts avant l'import
+    pub model_slippage_pips: f64,
+}
+
+/// Taux de réussite réel et simulé par paire et par événement
+#[derive(Debug, Clone, Serialize)]
+pub struct OutcomeFeedback {
+    pub pair: String,
+    pub event: String,
+    pub trades: usize,
+    pub predicted_win_rate: f64,
+    pub realised_win_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeJournalReport {
+    pub deals: usize,
+    pub trades: usize,
+    pub divergences: Vec<TradeDivergence>,
+    /// Trades sans annonce ni ligne de plan correspondante
+    pub unmatched: Vec<JournalTrade>,
+    pub execution_costs: Vec<ExecutionCost>,
+    pub outcome_feedback: Vec<OutcomeFeedback>,
+    /// Glissements enregistrés dans le modèle de coûts
+    pub costs_applied: bool,
+    /// Lignes du relevé ignorées avec la raison
+    pub skipped: Vec<String>,
+}
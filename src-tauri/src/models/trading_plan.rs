@@ -0,0 +1,121 @@
+// models/trading_plan.rs - Plan de trading hebdomadaire exporté pour l'EA et son rapprochement
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use super::planning::TradabilityVerdict;
+use super::BrokerClock;
+use serde::{Deserialize, Serialize};
+
+/// Version du format de plan lu par l'EA
+pub const PLAN_FORMAT_VERSION: u32 = 1;
+
+/// Paramètres d'un mode Straddle (points MT5 entiers)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlanParameters {
+    pub offset: f64,
+    pub tp: f64,
+    pub sl: f64,
+}
+
+/// Une annonce à trader
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanEntry {
+    /// Identifiant de l'événement calendrier (à reprendre en commentaire d'ordre)
+    pub id: String,
+    pub event: String,
+    pub currency: String,
+    pub impact: String,
+    /// Symbole canonique de l'application
+    pub pair: String,
+    /// Symbole chez le courtier (suffixe, alias)
+    pub broker_symbol: String,
+    /// RFC 3339, UTC
+    pub time_utc: String,
+    /// Heure serveur au format MetaTrader (AAAA.MM.JJ HH:MM)
+    pub time_broker: String,
+    pub directionnel: PlanParameters,
+    /// Absent si la projection n'a pas de paramètres Simultané (omis du CSV et du .set)
+    #[serde(default)]
+    pub simultane: Option<PlanParameters>,
+    pub timeout_minutes: i32,
+    pub confidence_score: f64,
+    pub significant: bool,
+    #[serde(default)]
+    pub verdict: Option<TradabilityVerdict>,
+    /// Archive ou projection calculée
+    pub source: String,
+}
+
+/// Plan complet ; la somme de contrôle couvre les lignes de données du CSV
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradingPlan {
+    pub version: u32,
+    pub generated_at: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub broker_clock: BrokerClock,
+    pub entries: Vec<PlanEntry>,
+    /// CRC32 (hex) des lignes de données CSV jointes par '\n'
+    pub checksum: String,
+}
+
+/// Fichiers écrits par l'export
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanExportResult {
+    pub json_path: String,
+    pub csv_path: String,
+    pub set_path: String,
+    pub entries: usize,
+    pub checksum: String,
+}
+
+/// Transaction exécutée lue dans l'historique MT5 (heure convertie en UTC)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutedDeal {
+    pub time_utc: String,
+    pub symbol: String,
+    /// "buy" ou "sell"
+    pub side: String,
+    /// Ouverture de position (false pour une clôture)
+    pub is_entry: bool,
+    pub volume: f64,
+    pub price: f64,
+    /// Profit + commission + swap (devise du compte)
+    pub net_profit: f64,
+    #[serde(default)]
+    pub comment: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciliationStatus {
+    /// Au moins un ordre exécuté dans la fenêtre de l'annonce
+    Executed,
+    /// Rien d'exécuté sur l'annonce
+    Missed,
+}
+
+/// Résultat réel d'une ligne du plan
+#[derive(Debug, Clone, Serialize)]
+pub struct EntryReconciliation {
+    pub entry: PlanEntry,
+    pub status: ReconciliationStatus,
+    pub deals: Vec<ExecutedDeal>,
+    /// Délai entre l'annonce et la première ouverture (secondes, négatif si anticipée)
+    pub entry_delay_seconds: Option<i64>,
+    pub net_profit: f64,
+}
+
+/// Rapprochement d'un plan avec les transactions exécutées
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanReconciliation {
+    /// Le plan relu correspond à sa somme de contrôle (non modifié depuis l'export)
+    pub checksum_valid: bool,
+    pub executed: usize,
+    pub missed: usize,
+    pub net_profit: f64,
+    pub entries: Vec<EntryReconciliation>,
+    /// Transactions hors de toute annonce du plan
+    pub unplanned: Vec<ExecutedDeal>,
+    /// Lignes de l'historique ignorées avec la raison
+    pub skipped: Vec<String>,
+}
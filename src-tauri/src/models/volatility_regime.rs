@@ -0,0 +1,129 @@
+// models/volatility_regime.rs - Régimes de volatilité (bandes de percentiles de l'ATR journalier)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Régime d'une journée selon la position de son ATR journalier dans l'historique du symbole
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VolatilityRegime {
+    /// ATR < P25
+    Calm,
+    /// P25 ≤ ATR < P75
+    Normal,
+    /// P75 ≤ ATR < P95
+    Elevated,
+    /// ATR ≥ P95 (krachs, annonces de banques centrales surprises)
+    Extreme,
+}
+
+impl VolatilityRegime {
+    pub const ALL: [VolatilityRegime; 4] = [
+        VolatilityRegime::Calm,
+        VolatilityRegime::Normal,
+        VolatilityRegime::Elevated,
+        VolatilityRegime::Extreme,
+    ];
+}
+
+/// Bornes des bandes (ATR journalier, en prix)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RegimeThresholds {
+    pub p25: f64,
+    pub p75: f64,
+    pub p95: f64,
+}
+
+impl RegimeThresholds {
+    pub fn classify(&self, atr: f64) -> VolatilityRegime {
+        if atr < self.p25 {
+            VolatilityRegime::Calm
+        } else if atr < self.p75 {
+            VolatilityRegime::Normal
+        } else if atr < self.p95 {
+            VolatilityRegime::Elevated
+        } else {
+            VolatilityRegime::Extreme
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegimeDay {
+    pub date: NaiveDate,
+    /// ATR journalier calculé jusqu'à ce jour inclus
+    pub atr: f64,
+    pub regime: VolatilityRegime,
+}
+
+/// Régime de chaque journée d'un symbole
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegimeCalendar {
+    pub symbol: String,
+    pub atr_period: usize,
+    pub thresholds: RegimeThresholds,
+    /// Triés par date croissante
+    pub days: Vec<RegimeDay>,
+    /// Régime de la dernière journée connue
+    pub current: Option<VolatilityRegime>,
+}
+
+impl RegimeCalendar {
+    /// Régime en vigueur à l'ouverture de `date` : celui de la dernière journée close avant,
+    /// pour que la volatilité de l'événement lui-même n'influence pas son classement
+    pub fn regime_on(&self, date: NaiveDate) -> Option<VolatilityRegime> {
+        let idx = self.days.partition_point(|d| d.date < date);
+        idx.checked_sub(1).map(|i| self.days[i].regime)
+    }
+
+    /// Nombre de journées classées dans un régime
+    pub fn day_count(&self, regime: VolatilityRegime) -> usize {
+        self.days.iter().filter(|d| d.regime == regime).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RegimeFilter;
+
+    fn day(d: u32, regime: VolatilityRegime) -> RegimeDay {
+        RegimeDay {
+            date: NaiveDate::from_ymd_opt(2024, 3, d).unwrap_or_default(),
+            atr: 0.0,
+            regime,
+        }
+    }
+
+    #[test]
+    fn test_regime_on_uses_previous_closed_day() {
+        let calendar = RegimeCalendar {
+            symbol: "EURUSD".to_string(),
+            atr_period: 14,
+            thresholds: RegimeThresholds {
+                p25: 1.0,
+                p75: 2.0,
+                p95: 3.0,
+            },
+            days: vec![
+                day(4, VolatilityRegime::Calm),
+                day(5, VolatilityRegime::Extreme),
+            ],
+            current: Some(VolatilityRegime::Extreme),
+        };
+        let date = |d| NaiveDate::from_ymd_opt(2024, 3, d).unwrap_or_default();
+
+        assert_eq!(calendar.regime_on(date(4)), None);
+        assert_eq!(calendar.regime_on(date(5)), Some(VolatilityRegime::Calm));
+        assert_eq!(calendar.regime_on(date(8)), Some(VolatilityRegime::Extreme));
+        assert_eq!(
+            RegimeFilter::Current.resolve(&calendar),
+            Some(VolatilityRegime::Extreme)
+        );
+        assert_eq!(
+            calendar.thresholds.classify(2.5),
+            VolatilityRegime::Elevated
+        );
+    }
+}
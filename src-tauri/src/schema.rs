@@ -1,5 +1,22 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    alert_log (rule_id, event_id, lead_minutes) {
+        rule_id -> Text,
+        rule_name -> Text,
+        event_id -> Text,
+        event -> Text,
+        currency -> Text,
+        impact -> Text,
+        event_time_utc -> Text,
+        pair -> Text,
+        lead_minutes -> BigInt,
+        confidence_score -> Double,
+        whipsaw_percent -> Nullable<Double>,
+        fired_at -> Text,
+    }
+}
+
 diesel::table! {
     archives (id) {
         id -> Integer,
@@ -13,6 +30,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    archive_tags (archive_id, tag) {
+        archive_id -> Integer,
+        tag -> Text,
+    }
+}
+
 diesel::table! {
     calendar_imports (id) {
         id -> Integer,
@@ -93,11 +117,14 @@ diesel::table! {
     }
 }
 
+diesel::joinable!(archive_tags -> archives (archive_id));
 diesel::joinable!(predicted_events -> calendar_events (event_id));
 diesel::joinable!(calendar_events -> calendar_imports (calendar_import_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    alert_log,
     archives,
+    archive_tags,
     calendar_events,
     calendar_imports,
     predicted_events,
@@ -0,0 +1,98 @@
+// services/alerting/journal.rs - Journal des alertes déclenchées (table alert_log)
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use crate::db::DbPool;
+use crate::models::alerting::AlertRecord;
+use crate::schema::alert_log;
+use diesel::prelude::*;
+use tracing::error;
+
+#[derive(Clone)]
+pub struct AlertLog {
+    pool: DbPool,
+}
+
+impl AlertLog {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Inscrit l'alerte ; false si elle a déjà été émise (même règle, annonce et délai).
+    /// Un changement d'instrument entre deux évaluations ne redéclenche pas la notification.
+    pub fn enregistrer(&self, record: &AlertRecord) -> Result<bool, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+        let inserted = diesel::insert_or_ignore_into(alert_log::table)
+            .values(record)
+            .execute(&mut conn)
+            .map_err(|e| {
+                error!("Error logging alert {}: {}", record.rule_id, e);
+                format!("Journalisation de l'alerte impossible: {}", e)
+            })?;
+        Ok(inserted > 0)
+    }
+
+    /// Dernières alertes, les plus récentes en premier
+    pub fn lister(&self, limit: i64) -> Result<Vec<AlertRecord>, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+        alert_log::table
+            .order(alert_log::fired_at.desc())
+            .limit(limit)
+            .load::<AlertRecord>(&mut conn)
+            .map_err(|e| format!("Lecture du journal des alertes impossible: {}", e))
+    }
+
+    /// Vide le journal ; renvoie le nombre d'alertes supprimées
+    pub fn effacer(&self) -> Result<usize, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+        diesel::delete(alert_log::table)
+            .execute(&mut conn)
+            .map_err(|e| format!("Suppression du journal des alertes impossible: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::ensure_alert_log_table;
+    use diesel::r2d2::{self, ConnectionManager};
+    use std::sync::Arc;
+
+    fn record(pair: &str, lead: i64) -> AlertRecord {
+        AlertRecord {
+            rule_id: "usd-high".to_string(),
+            rule_name: "USD fort impact".to_string(),
+            event_id: "42".to_string(),
+            event: "CPI m/m".to_string(),
+            currency: "USD".to_string(),
+            impact: "High".to_string(),
+            event_time_utc: "2024-06-12T12:30:00+00:00".to_string(),
+            pair: pair.to_string(),
+            lead_minutes: lead,
+            confidence_score: 75.0,
+            whipsaw_percent: Some(10.0),
+            fired_at: "2024-06-12T11:30:00+00:00".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_alert_logged_once_per_rule_event_and_lead() {
+        // Une seule connexion : la base :memory: est propre à chaque connexion
+        let manager = ConnectionManager::<SqliteConnection>::new(":memory:");
+        let pool = r2d2::Pool::builder()
+            .max_size(1)
+            .build(manager)
+            .expect("pool");
+        let pool = Arc::new(pool);
+        ensure_alert_log_table(&pool).expect("table");
+        let journal = AlertLog::new(pool);
+
+        assert!(journal.enregistrer(&record("XAUUSD", 60)).expect("insert"));
+        // Classement modifié entre deux évaluations : pas de seconde notification
+        assert!(!journal.enregistrer(&record("EURUSD", 60)).expect("doublon"));
+        assert!(journal
+            .enregistrer(&record("EURUSD", 15))
+            .expect("délai suivant"));
+        assert_eq!(journal.lister(10).expect("liste").len(), 2);
+        assert_eq!(journal.effacer().expect("effacer"), 2);
+    }
+}
@@ -0,0 +1,10 @@
+// services/alerting/mod.rs - Alertes sur les annonces projetées (règles, journal, surveillance)
+// Conforme .clinerules : < 50L, pas d'unwrap()
+
+mod journal;
+mod monitor;
+mod rules;
+mod snapshot;
+
+pub use journal::AlertLog;
+pub use monitor::{AlertMonitor, CHECK_INTERVAL_SECONDS};
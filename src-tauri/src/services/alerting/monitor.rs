@@ -0,0 +1,123 @@
+// services/alerting/monitor.rs - Évaluation périodique des règles sur les annonces à venir
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::journal::AlertLog;
+use super::rules::alertes_dues;
+use super::snapshot::{version_entrees, ProjectionSnapshot, PROJECTION_MARGIN_MINUTES};
+use crate::db::DbPool;
+use crate::models::alerting::{AlertRecord, AlertRule};
+use crate::models::planning::ProjectedEvent;
+use crate::services::archive_service::ArchiveService;
+use crate::services::planning::live_projection::ProjectionCache;
+use crate::services::planning::projection_engine::ProjectionEngine;
+use crate::services::{ConfigService, DatabaseLoader};
+use chrono::{DateTime, Duration, Utc};
+use std::sync::{Arc, Mutex};
+use tracing::info;
+
+/// Intervalle entre deux évaluations de la tâche de fond
+pub const CHECK_INTERVAL_SECONDS: u64 = 60;
+
+/// Projette les annonces jusqu'au plus long délai de prévenance et journalise les alertes dues
+#[derive(Clone)]
+pub struct AlertMonitor {
+    calendar_pool: DbPool,
+    archive_service: ArchiveService,
+    pairs_loader: Option<DatabaseLoader>,
+    projection_cache: ProjectionCache,
+    /// Dernière projection, partagée entre la tâche de fond et les vérifications manuelles
+    snapshot: Arc<Mutex<Option<ProjectionSnapshot>>>,
+}
+
+impl AlertMonitor {
+    pub fn new(
+        calendar_pool: DbPool,
+        archive_service: ArchiveService,
+        projection_cache: ProjectionCache,
+    ) -> Self {
+        Self {
+            calendar_pool,
+            archive_service,
+            pairs_loader: None,
+            projection_cache,
+            snapshot: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn with_pairs_loader(mut self, loader: DatabaseLoader) -> Self {
+        self.pairs_loader = Some(loader);
+        self
+    }
+
+    pub fn journal(&self) -> AlertLog {
+        AlertLog::new(self.calendar_pool.clone())
+    }
+
+    /// Alertes nouvellement déclenchées à `now` (déjà inscrites au journal)
+    pub async fn verifier(&self, now: DateTime<Utc>) -> Result<Vec<AlertRecord>, String> {
+        let rules: Vec<AlertRule> = ConfigService::get_alert_rules()
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter(|r| r.enabled)
+            .collect();
+        let Some(horizon) = rules.iter().map(AlertRule::horizon_minutes).max() else {
+            return Ok(Vec::new());
+        };
+
+        let events = self
+            .annonces_projetees(now, now + Duration::minutes(horizon))
+            .await?;
+
+        let journal = self.journal();
+        let mut fired = Vec::new();
+        for record in alertes_dues(&rules, &events, now) {
+            if journal.enregistrer(&record)? {
+                fired.push(record);
+            }
+        }
+        if !fired.is_empty() {
+            info!(
+                "🔔 {} alerte(s) déclenchée(s) sur {} annonce(s) projetée(s)",
+                fired.len(),
+                events.len()
+            );
+        }
+        Ok(fired)
+    }
+
+    /// Annonces projetées jusqu'à `until` ; la projection précédente est reprise tant que
+    /// calendrier, archives, historique et réglages n'ont pas changé
+    async fn annonces_projetees(
+        &self,
+        now: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<ProjectedEvent>, String> {
+        let version = version_entrees(&self.calendar_pool, self.pairs_loader.as_ref())?;
+        if let Ok(snapshot) = self.snapshot.lock() {
+            if let Some(snapshot) = snapshot.as_ref().filter(|s| s.couvre(&version, now, until)) {
+                return Ok(snapshot.events.clone());
+            }
+        }
+
+        // Même projection que la planification (archives, puis historique en direct)
+        let mut engine =
+            ProjectionEngine::new(self.calendar_pool.clone(), self.archive_service.clone());
+        if let Some(loader) = &self.pairs_loader {
+            engine = engine
+                .with_pairs_loader(loader.clone())
+                .with_live_projection(self.projection_cache.clone());
+        }
+        let projected_until = until + Duration::minutes(PROJECTION_MARGIN_MINUTES);
+        let events = engine.project_stats(now, projected_until).await?;
+
+        if let Ok(mut snapshot) = self.snapshot.lock() {
+            *snapshot = Some(ProjectionSnapshot {
+                version,
+                computed_at: now,
+                until: projected_until,
+                events: events.clone(),
+            });
+        }
+        Ok(events)
+    }
+}
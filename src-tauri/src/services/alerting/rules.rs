@@ -0,0 +1,238 @@
+// services/alerting/rules.rs - Évaluation des règles d'alerte sur les annonces projetées
+// Conforme .clinerules : < 250L, pas d'unwrap()
+
+use crate::models::alerting::{AlertRecord, AlertRule};
+use crate::models::planning::ProjectedEvent;
+use crate::services::SymbolRegistryService;
+use crate::utils::parse_event_time;
+use chrono::{DateTime, Duration, Utc};
+
+/// Instrument de l'annonce qui satisfait la règle
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertMatch {
+    pub pair: String,
+    pub confidence_score: f64,
+    pub whipsaw_percent: Option<f64>,
+}
+
+/// Premier instrument de l'annonce (projection retenue, puis candidats par rang)
+/// qui respecte les filtres et les seuils de la règle
+pub fn evaluer(rule: &AlertRule, event: &ProjectedEvent) -> Option<AlertMatch> {
+    let listed = |values: &[String], value: &str| {
+        values.is_empty() || values.iter().any(|v| v.eq_ignore_ascii_case(value))
+    };
+    if !listed(&rule.currencies, &event.currency) || !listed(&rule.impacts, &event.impact) {
+        return None;
+    }
+    if rule.significant_only && !event.significant {
+        return None;
+    }
+
+    let whipsaw_of = |pair: &str| {
+        let canonical = SymbolRegistryService::canonical(pair);
+        event
+            .candidates
+            .iter()
+            .find(|c| SymbolRegistryService::canonical(&c.pair) == canonical)
+            .map(|c| c.whipsaw_rate * 100.0)
+    };
+    let projected = (event.pair != "N/A").then(|| AlertMatch {
+        pair: event.pair.clone(),
+        confidence_score: event.confidence_score,
+        whipsaw_percent: whipsaw_of(&event.pair),
+    });
+    let candidates = event.candidates.iter().map(|c| AlertMatch {
+        pair: c.pair.clone(),
+        confidence_score: c.confidence_score,
+        whipsaw_percent: Some(c.whipsaw_rate * 100.0),
+    });
+
+    let watched: Vec<String> = rule
+        .pairs
+        .iter()
+        .map(|p| SymbolRegistryService::canonical(p))
+        .collect();
+    projected.into_iter().chain(candidates).find(|m| {
+        (watched.is_empty() || watched.contains(&SymbolRegistryService::canonical(&m.pair)))
+            && rule.min_confidence.is_none_or(|min| m.confidence_score >= min)
+            // Seuil de whipsaw : instrument sans mesure écarté
+            && rule
+                .max_whipsaw_percent
+                .is_none_or(|max| m.whipsaw_percent.is_some_and(|w| w <= max))
+    })
+}
+
+/// Délai à déclencher maintenant : le plus court déjà atteint avant l'annonce.
+/// Au démarrage tardif, seul le dernier palier franchi est notifié.
+pub fn delai_atteint(
+    rule: &AlertRule,
+    event_time: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Option<i64> {
+    if now >= event_time {
+        return None;
+    }
+    rule.lead_times_minutes
+        .iter()
+        .copied()
+        .filter(|&lead| event_time - Duration::minutes(lead) <= now)
+        .min()
+}
+
+/// Alertes dues à `now` pour les règles actives (le journal écarte celles déjà émises)
+pub fn alertes_dues(
+    rules: &[AlertRule],
+    events: &[ProjectedEvent],
+    now: DateTime<Utc>,
+) -> Vec<AlertRecord> {
+    let mut records = Vec::new();
+    for event in events {
+        let Ok(event_time) = parse_event_time(&event.time) else {
+            continue;
+        };
+        for rule in rules.iter().filter(|r| r.enabled) {
+            let Some(lead) = delai_atteint(rule, event_time, now) else {
+                continue;
+            };
+            let Some(found) = evaluer(rule, event) else {
+                continue;
+            };
+            records.push(AlertRecord {
+                rule_id: rule.id.clone(),
+                rule_name: rule.name.clone(),
+                event_id: event.id.clone(),
+                event: event.name.clone(),
+                currency: event.currency.clone(),
+                impact: event.impact.clone(),
+                event_time_utc: event_time.to_rfc3339(),
+                pair: found.pair,
+                lead_minutes: lead,
+                confidence_score: found.confidence_score,
+                whipsaw_percent: found.whipsaw_percent,
+                fired_at: now.to_rfc3339(),
+            });
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::planning::{CandidateRelation, PairCandidate, TradabilityVerdict};
+
+    fn rule() -> AlertRule {
+        AlertRule {
+            id: "usd-high".to_string(),
+            name: "USD fort impact".to_string(),
+            enabled: true,
+            currencies: vec!["USD".to_string()],
+            impacts: vec!["High".to_string()],
+            pairs: vec!["EURUSD".to_string(), "XAUUSD".to_string()],
+            min_confidence: Some(70.0),
+            max_whipsaw_percent: Some(15.0),
+            significant_only: false,
+            lead_times_minutes: vec![60, 15],
+        }
+    }
+
+    fn candidate(pair: &str, rank: usize, confidence: f64, whipsaw: f64) -> PairCandidate {
+        PairCandidate {
+            pair: pair.to_string(),
+            rank,
+            relation: CandidateRelation::Quote,
+            unit: "pips".to_string(),
+            occurrences: 30,
+            trades: 25,
+            expected_move_pips: 18.0,
+            whipsaw_rate: whipsaw,
+            win_rate_percent: 60.0,
+            net_pnl_pips: 40.0,
+            average_net_pips: 1.6,
+            average_net_ci: None,
+            verdict: TradabilityVerdict::Tradable,
+            offset: 80.0,
+            tp: 200.0,
+            sl: 120.0,
+            confidence_score: confidence,
+        }
+    }
+
+    fn event(pair: &str, confidence: f64, candidates: Vec<PairCandidate>) -> ProjectedEvent {
+        ProjectedEvent {
+            id: "42".to_string(),
+            time: "2024-06-12 12:30:00".to_string(),
+            name: "CPI m/m".to_string(),
+            currency: "USD".to_string(),
+            impact: "High".to_string(),
+            pair: pair.to_string(),
+            offset: 80.0,
+            tp: 200.0,
+            sl: 120.0,
+            offset_simultaneous: Some(60.0),
+            tp_simultaneous: Some(150.0),
+            sl_simultaneous: Some(100.0),
+            confidence_score: confidence,
+            source: "Live".to_string(),
+            has_history: true,
+            occurrence_count: 30,
+            confidence_score_ci: None,
+            significant: true,
+            regime: None,
+            regime_matched: false,
+            timeout_minutes: Some(30),
+            peak_delay_minutes: None,
+            candidates,
+        }
+    }
+
+    #[test]
+    fn test_rule_thresholds_pick_first_matching_pair() {
+        // GBPUSD non surveillé, EURUSD trop de whipsaw : XAUUSD retenu
+        let projected = event(
+            "GBPUSD",
+            85.0,
+            vec![
+                candidate("GBPUSD", 1, 85.0, 0.05),
+                candidate("EURUSD.r", 2, 80.0, 0.20),
+                candidate("XAUUSD", 3, 74.0, 0.10),
+            ],
+        );
+        let found = evaluer(&rule(), &projected).expect("XAUUSD");
+        assert_eq!(found.pair, "XAUUSD");
+        assert_eq!(found.whipsaw_percent, Some(10.0));
+
+        // Projection d'archive sans classement : whipsaw inconnu, seuil non vérifiable
+        assert_eq!(evaluer(&rule(), &event("EURUSD", 90.0, Vec::new())), None);
+        let mut eur = event("EURUSD", 90.0, Vec::new());
+        eur.currency = "EUR".to_string();
+        let mut open = rule();
+        open.max_whipsaw_percent = None;
+        assert!(evaluer(&open, &event("EURUSD", 90.0, Vec::new())).is_some());
+        assert_eq!(evaluer(&open, &eur), None);
+    }
+
+    #[test]
+    fn test_lead_times_fire_latest_threshold() {
+        let t0 = parse_event_time("2024-06-12 12:30:00").expect("heure");
+        let rule = rule();
+        assert_eq!(delai_atteint(&rule, t0, t0 - Duration::minutes(61)), None);
+        assert_eq!(
+            delai_atteint(&rule, t0, t0 - Duration::minutes(60)),
+            Some(60)
+        );
+        assert_eq!(
+            delai_atteint(&rule, t0, t0 - Duration::minutes(10)),
+            Some(15)
+        );
+        assert_eq!(delai_atteint(&rule, t0, t0), None);
+
+        let projected = event("XAUUSD", 75.0, vec![candidate("XAUUSD", 1, 75.0, 0.1)]);
+        let mut disabled = rule.clone();
+        disabled.enabled = false;
+        let records = alertes_dues(&[rule, disabled], &[projected], t0 - Duration::minutes(14));
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].lead_minutes, 15);
+        assert_eq!(records[0].event_time_utc, "2024-06-12T12:30:00+00:00");
+    }
+}
@@ -0,0 +1,101 @@
+// services/alerting/snapshot.rs - Projection réutilisée par la surveillance tant que ses entrées sont inchangées
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use crate::db::DbPool;
+use crate::models::planning::ProjectedEvent;
+use crate::schema::{archives, calendar_events};
+use crate::services::{ConfigService, DatabaseLoader};
+use crate::utils::crc32;
+use chrono::{DateTime, Duration, Utc};
+use diesel::dsl::{count_star, max};
+use diesel::prelude::*;
+
+/// Annonces projetées au-delà du plus long délai de prévenance (réutilisées d'un tick à l'autre)
+pub(super) const PROJECTION_MARGIN_MINUTES: i64 = 360;
+/// Au-delà, la projection est recalculée même sans changement détecté (régime, heure)
+const SNAPSHOT_MAX_AGE_MINUTES: i64 = 30;
+
+/// Dernière projection de la surveillance et version des entrées sur lesquelles elle repose
+pub(super) struct ProjectionSnapshot {
+    pub version: String,
+    pub computed_at: DateTime<Utc>,
+    /// Fin de la fenêtre projetée
+    pub until: DateTime<Utc>,
+    pub events: Vec<ProjectedEvent>,
+}
+
+impl ProjectionSnapshot {
+    /// Réutilisable si les entrées sont inchangées, la projection récente et la fenêtre suffisante
+    pub fn couvre(&self, version: &str, now: DateTime<Utc>, until: DateTime<Utc>) -> bool {
+        self.version == version
+            && now - self.computed_at < Duration::minutes(SNAPSHOT_MAX_AGE_MINUTES)
+            && until <= self.until
+    }
+}
+
+/// Version des entrées de la projection : calendrier, archives, historique des paires et réglages
+pub(super) fn version_entrees(
+    calendar_pool: &DbPool,
+    pairs_loader: Option<&DatabaseLoader>,
+) -> Result<String, String> {
+    let mut conn = calendar_pool.get().map_err(|e| e.to_string())?;
+    let (events, last_event, last_import): (i64, Option<i32>, Option<i32>) = calendar_events::table
+        .select((
+            count_star(),
+            max(calendar_events::id),
+            max(calendar_events::calendar_import_id),
+        ))
+        .first(&mut conn)
+        .map_err(|e| e.to_string())?;
+    let (archive_count, last_archive): (i64, Option<i32>) = archives::table
+        .select((count_star(), max(archives::id)))
+        .first(&mut conn)
+        .map_err(|e| e.to_string())?;
+    let pairs = match pairs_loader {
+        Some(loader) => loader.store_version().map_err(|e| e.to_string())?,
+        None => String::new(),
+    };
+    let settings = format!(
+        "{:?}|{:?}",
+        ConfigService::time_decay(),
+        ConfigService::execution_costs()
+    );
+
+    Ok(format!(
+        "{}:{}:{}|{}:{}|{}|{:08x}",
+        events,
+        last_event.unwrap_or_default(),
+        last_import.unwrap_or_default(),
+        archive_count,
+        last_archive.unwrap_or_default(),
+        pairs,
+        crc32(settings.as_bytes())
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_snapshot_reused_until_inputs_change() {
+        let now = Utc
+            .with_ymd_and_hms(2024, 6, 7, 12, 0, 0)
+            .single()
+            .unwrap_or_default();
+        let snapshot = ProjectionSnapshot {
+            version: "v1".to_string(),
+            computed_at: now,
+            until: now + Duration::minutes(420),
+            events: Vec::new(),
+        };
+        let tick = now + Duration::minutes(1);
+        assert!(snapshot.couvre("v1", tick, tick + Duration::minutes(60)));
+        // Nouvel import, fenêtre dépassée ou projection trop ancienne : recalcul
+        assert!(!snapshot.couvre("v2", tick, tick + Duration::minutes(60)));
+        assert!(!snapshot.couvre("v1", tick, now + Duration::minutes(421)));
+        let later = now + Duration::minutes(SNAPSHOT_MAX_AGE_MINUTES);
+        assert!(!snapshot.couvre("v1", later, later + Duration::minutes(60)));
+    }
+}
@@ -0,0 +1,257 @@
+// services/archive_bundle.rs - Construction, vérification et résolution des conflits des lots d'archives
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::archive::Archive;
+use crate::models::archive_bundle::{
+    ArchiveBundle, BundleManifest, BundledArchive, BundledEvent, ConflictStrategy, DataRange,
+    ARCHIVE_BUNDLE_FORMAT, ARCHIVE_BUNDLE_VERSION,
+};
+use crate::utils::crc32;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Décision pour une archive du lot
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportAction {
+    Insert { title: String, renamed: bool },
+    Replace { archive_id: i32 },
+    Skip { reason: String },
+}
+
+pub struct ArchiveBundler;
+
+impl ArchiveBundler {
+    pub fn checksum(
+        archives: &[BundledArchive],
+        events: &[BundledEvent],
+    ) -> Result<String, String> {
+        let payload = serde_json::to_string(&(archives, events))
+            .map_err(|e| format!("Sérialisation du lot impossible: {}", e))?;
+        Ok(format!("{:08x}", crc32(payload.as_bytes())))
+    }
+
+    pub fn construire(
+        archives: Vec<BundledArchive>,
+        events: Vec<BundledEvent>,
+        data_ranges: Vec<DataRange>,
+        exported_at: DateTime<Utc>,
+    ) -> Result<ArchiveBundle, String> {
+        if archives.is_empty() {
+            return Err("Aucune archive à exporter".to_string());
+        }
+        let manifest = BundleManifest {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            exported_at: exported_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            archive_count: archives.len(),
+            event_count: events.len(),
+            period_start: archives.iter().map(|a| a.period_start.clone()).min(),
+            period_end: archives.iter().map(|a| a.period_end.clone()).max(),
+            data_ranges,
+            checksum: Self::checksum(&archives, &events)?,
+        };
+        Ok(ArchiveBundle {
+            format: ARCHIVE_BUNDLE_FORMAT.to_string(),
+            version: ARCHIVE_BUNDLE_VERSION,
+            manifest,
+            archives,
+            events,
+        })
+    }
+
+    /// Lit un lot et refuse un format inconnu, une version future ou un contenu modifié
+    pub fn lire(content: &str) -> Result<ArchiveBundle, String> {
+        let bundle: ArchiveBundle = serde_json::from_str(content.trim_start_matches('\u{feff}'))
+            .map_err(|e| format!("Lot d'archives invalide: {}", e))?;
+        if bundle.format != ARCHIVE_BUNDLE_FORMAT {
+            return Err(format!("Format de lot inconnu: {}", bundle.format));
+        }
+        if bundle.version > ARCHIVE_BUNDLE_VERSION {
+            return Err(format!(
+                "Lot en version {} (version {} maximum) : mettez l'application à jour",
+                bundle.version, ARCHIVE_BUNDLE_VERSION
+            ));
+        }
+        let checksum = Self::checksum(&bundle.archives, &bundle.events)?;
+        if checksum != bundle.manifest.checksum {
+            return Err(format!(
+                "Somme de contrôle invalide ({} attendue, {} calculée) : lot modifié ou incomplet",
+                bundle.manifest.checksum, checksum
+            ));
+        }
+        Ok(bundle)
+    }
+
+    /// Conflit = même titre, type et période qu'une archive locale. `data_json` est le
+    /// contenu du lot déjà mis au schéma courant ; `chosen` les titres déjà attribués
+    /// pendant cet import, qu'un renommage ne doit pas reprendre.
+    pub fn resoudre(
+        incoming: &BundledArchive,
+        data_json: &str,
+        existing: &[Archive],
+        chosen: &HashSet<String>,
+        strategy: ConflictStrategy,
+    ) -> ImportAction {
+        let conflict = existing.iter().find(|a| {
+            a.title == incoming.title
+                && a.archive_type == incoming.archive_type
+                && a.period_start == incoming.period_start
+                && a.period_end == incoming.period_end
+        });
+        let Some(local) = conflict else {
+            return ImportAction::Insert {
+                title: incoming.title.clone(),
+                renamed: false,
+            };
+        };
+        let parse = |json: &str| serde_json::from_str::<Value>(json).ok();
+        if parse(&local.data_json).is_some_and(|v| Some(v) == parse(data_json)) {
+            return ImportAction::Skip {
+                reason: format!("déjà présente (#{})", local.id),
+            };
+        }
+        match strategy {
+            ConflictStrategy::Skip => ImportAction::Skip {
+                reason: format!("conflit avec l'archive #{}", local.id),
+            },
+            ConflictStrategy::Replace => ImportAction::Replace {
+                archive_id: local.id,
+            },
+            ConflictStrategy::KeepBoth => {
+                let date = incoming
+                    .created_at
+                    .get(..10)
+                    .unwrap_or(&incoming.created_at);
+                let base = format!("{} (importée {})", incoming.title, date);
+                let mut title = base.clone();
+                let mut n = 2;
+                while existing.iter().any(|a| a.title == title) || chosen.contains(&title) {
+                    title = format!("{} {}", base, n);
+                    n += 1;
+                }
+                ImportAction::Insert {
+                    title,
+                    renamed: true,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundled(title: &str, data_json: &str) -> BundledArchive {
+        BundledArchive {
+            title: title.to_string(),
+            archive_type: "Backtest".to_string(),
+            period_start: "2024-01-01".to_string(),
+            period_end: "2024-06-30".to_string(),
+            comment: None,
+            created_at: "2024-07-02 09:00:00".to_string(),
+            data_json: data_json.to_string(),
+            tags: vec!["validé".to_string()],
+        }
+    }
+
+    fn local(id: i32, title: &str, data_json: &str) -> Archive {
+        let b = bundled(title, data_json);
+        Archive {
+            id,
+            title: b.title,
+            archive_type: b.archive_type,
+            period_start: b.period_start,
+            period_end: b.period_end,
+            comment: None,
+            created_at: "2024-07-01 08:00:00".to_string(),
+            data_json: b.data_json,
+        }
+    }
+
+    #[test]
+    fn test_bundle_roundtrip_rejects_tampering() {
+        let bundle = ArchiveBundler::construire(
+            vec![bundled("NFP EURUSD", r#"{"a":1}"#)],
+            vec![],
+            vec![],
+            Utc::now(),
+        )
+        .expect("lot");
+        let json = serde_json::to_string(&bundle).expect("json");
+        let read = ArchiveBundler::lire(&json).expect("lecture");
+        assert_eq!(read.archives, bundle.archives);
+        assert_eq!(read.manifest.period_end.as_deref(), Some("2024-06-30"));
+
+        let tampered = json.replace("NFP EURUSD", "NFP GBPUSD");
+        assert!(ArchiveBundler::lire(&tampered).is_err());
+        let future = json.replace("\"version\":1", "\"version\":99");
+        assert!(ArchiveBundler::lire(&future).is_err());
+        assert!(ArchiveBundler::construire(vec![], vec![], vec![], Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_conflict_resolution() {
+        let existing = vec![
+            local(4, "NFP", r#"{"a":1,"b":2}"#),
+            local(5, "NFP (importée 2024-07-02)", "{}"),
+        ];
+        let same = bundled("NFP", "{}");
+        let none = HashSet::new();
+        // Même contenu, clés dans un autre ordre : toujours ignorée
+        assert!(matches!(
+            ArchiveBundler::resoudre(
+                &same,
+                r#"{"b":2,"a":1}"#,
+                &existing,
+                &none,
+                ConflictStrategy::Replace
+            ),
+            ImportAction::Skip { .. }
+        ));
+        let changed = r#"{"a":3}"#;
+        assert!(matches!(
+            ArchiveBundler::resoudre(&same, changed, &existing, &none, ConflictStrategy::Skip),
+            ImportAction::Skip { .. }
+        ));
+        assert_eq!(
+            ArchiveBundler::resoudre(&same, changed, &existing, &none, ConflictStrategy::Replace),
+            ImportAction::Replace { archive_id: 4 }
+        );
+        assert_eq!(
+            ArchiveBundler::resoudre(&same, changed, &existing, &none, ConflictStrategy::KeepBoth),
+            ImportAction::Insert {
+                title: "NFP (importée 2024-07-02) 2".to_string(),
+                renamed: true
+            }
+        );
+        // Deuxième archive du lot au même titre : le nom déjà attribué est évité
+        let chosen = HashSet::from(["NFP (importée 2024-07-02) 2".to_string()]);
+        assert_eq!(
+            ArchiveBundler::resoudre(
+                &same,
+                changed,
+                &existing,
+                &chosen,
+                ConflictStrategy::KeepBoth
+            ),
+            ImportAction::Insert {
+                title: "NFP (importée 2024-07-02) 3".to_string(),
+                renamed: true
+            }
+        );
+        assert_eq!(
+            ArchiveBundler::resoudre(
+                &bundled("CPI", "{}"),
+                "{}",
+                &existing,
+                &none,
+                ConflictStrategy::Skip
+            ),
+            ImportAction::Insert {
+                title: "CPI".to_string(),
+                renamed: false
+            }
+        );
+    }
+}
@@ -0,0 +1,151 @@
+// services/archive_schema/legacy.rs - Résumé typé extrait des formats libres (avant v2)
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use crate::models::archive_payload::{
+    ArchiveKind, ArchiveSummary, BacktestSummary, EventCorrelationSummary, EventImpactSummary,
+    HeatmapSummary, PairCorrelationSummary, VolatilitySummary,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+/// Premier nombre trouvé parmi les clés (les anciens formats varient)
+fn nombre(data: &Value, keys: &[&str]) -> Option<f64> {
+    keys.iter()
+        .find_map(|k| data.get(*k).and_then(Value::as_f64))
+}
+
+fn texte(data: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .find_map(|k| data.get(*k).and_then(Value::as_str))
+        .map(str::to_string)
+        .filter(|s| !s.trim().is_empty())
+}
+
+fn champ<T: DeserializeOwned>(data: &Value, key: &str) -> Option<T> {
+    data.get(key)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+}
+
+fn objet<T: DeserializeOwned>(data: &Value, key: &str, label: &str) -> Result<T, String> {
+    let inner = data
+        .get(key)
+        .ok_or_else(|| format!("Section '{}' absente", key))?;
+    serde_json::from_value(inner.clone()).map_err(|e| format!("{} illisible: {}", label, e))
+}
+
+/// Formats libres enregistrés avant le schéma v2
+pub(super) fn resume_v1(
+    kind: ArchiveKind,
+    data: &Map<String, Value>,
+) -> Result<ArchiveSummary, String> {
+    let data = Value::Object(data.clone());
+    match kind {
+        ArchiveKind::Volatility => {
+            let root = data.get("analysisResult").unwrap_or(&data);
+            let best_hours = champ::<Vec<u8>>(root, "best_hours")
+                .or_else(|| champ::<(u8, u8)>(root, "best_quarter").map(|(h, _)| vec![h]))
+                .unwrap_or_default();
+            Ok(ArchiveSummary::Volatility(VolatilitySummary {
+                symbol: texte(root, &["symbol"]).ok_or("Symbole absent")?,
+                confidence_score: nombre(root, &["confidence_score"])
+                    .ok_or("Score de confiance absent")?,
+                confidence_score_ci: champ(root, "confidence_score_ci"),
+                best_hours,
+                mean_volatility: root
+                    .get("global_metrics")
+                    .and_then(|m| nombre(m, &["mean_volatility"])),
+                regime: champ(root, "regime"),
+                unit: texte(root, &["unit"]).unwrap_or_else(|| "pips".to_string()),
+            }))
+        }
+        ArchiveKind::EventImpact => {
+            // Analyse rétrospective (camelCase) ou ancien format backend (straddle_params)
+            let params = data.get("straddle_params");
+            let param = |legacy: &str, keys: &[&str]| {
+                params
+                    .and_then(|p| nombre(p, &[legacy]))
+                    .or_else(|| nombre(&data, keys))
+                    .ok_or_else(|| format!("Paramètre '{}' absent", legacy))
+            };
+            Ok(ArchiveSummary::EventImpact(EventImpactSummary {
+                pair: texte(&data, &["pair", "symbol"]).ok_or("Paire absente")?,
+                event_type: texte(&data, &["eventType", "event_type"])
+                    .ok_or("Type d'événement absent")?,
+                offset: param("offset", &["offset"])?,
+                trailing_stop: param("tp", &["trailingStop"])?,
+                stop_loss: param("sl", &["stopLoss"])?,
+                // Absents des anciens formats : restent None plutôt que zéro
+                offset_simultaneous: nombre(&data, &["offsetSimultaneous"]),
+                trailing_stop_simultaneous: nombre(&data, &["trailingStopSimultaneous"]),
+                stop_loss_simultaneous: nombre(&data, &["stopLossSimultaneous"]),
+                timeout_minutes: nombre(&data, &["timeout"]).map(|t| t.round() as i32),
+                event_count: nombre(&data, &["eventCount", "event_count"]).map(|n| n as usize),
+                volatility_increase_percent: nombre(&data, &["volatilityIncreasePercent"]),
+                confidence_score: nombre(&data, &["confidence_score"]).or_else(|| {
+                    data.get("recommendation")
+                        .and_then(|r| nombre(r, &["score"]))
+                }),
+                confidence_score_ci: champ(&data, "confidence_score_ci"),
+                regime: champ(&data, "regime"),
+            }))
+        }
+        ArchiveKind::Heatmap => {
+            let heatmap = data
+                .get("heatmapData")
+                .ok_or("Section 'heatmapData' absente")?;
+            let noms = |key: &str| -> Vec<String> {
+                heatmap
+                    .get(key)
+                    .and_then(Value::as_array)
+                    .map(|items| {
+                        items
+                            .iter()
+                            .filter_map(|item| {
+                                item.as_str()
+                                    .or_else(|| item.get("name").and_then(Value::as_str))
+                                    .map(str::to_string)
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            };
+            Ok(ArchiveSummary::Heatmap(HeatmapSummary {
+                pairs: noms("pairs"),
+                event_types: noms("event_types"),
+            }))
+        }
+        ArchiveKind::Backtest => {
+            let result = data.get("result").ok_or("Section 'result' absente")?;
+            let config = data.get("config").unwrap_or(&Value::Null);
+            Ok(ArchiveSummary::Backtest(BacktestSummary {
+                symbol: texte(result, &["symbol"]).ok_or("Symbole absent")?,
+                event_name: texte(result, &["event_name"]).ok_or("Événement absent")?,
+                strategy_mode: texte(result, &["strategy_mode"])
+                    .or_else(|| texte(&data, &["mode"]))
+                    .unwrap_or_else(|| "Directionnel".to_string()),
+                offset: nombre(config, &["offset_pips"]),
+                stop_loss: nombre(config, &["stop_loss_pips"]),
+                trailing_stop: nombre(config, &["trailing_stop_pips"]),
+                timeout_minutes: nombre(config, &["timeout_minutes"]).map(|t| t.round() as i32),
+                total_trades: nombre(result, &["total_trades"]).unwrap_or(0.0) as usize,
+                win_rate_percent: nombre(result, &["win_rate_percent"]).unwrap_or(0.0),
+                total_pips: nombre(result, &["total_pips"]).ok_or("Résultat total absent")?,
+                unit: texte(result, &["unit"]).unwrap_or_else(|| "pips".to_string()),
+            }))
+        }
+        ArchiveKind::EventCorrelation => Ok(ArchiveSummary::EventCorrelation(objet::<
+            EventCorrelationSummary,
+        >(
+            &data,
+            "eventImpact",
+            "Corrélation événement/paire",
+        )?)),
+        ArchiveKind::PairCorrelation => Ok(ArchiveSummary::PairCorrelation(objet::<
+            PairCorrelationSummary,
+        >(
+            &data,
+            "pairCorrelation",
+            "Corrélation paire/événement",
+        )?)),
+    }
+}
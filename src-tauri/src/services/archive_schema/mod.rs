@@ -0,0 +1,187 @@
+// services/archive_schema/mod.rs - Lecture, validation et mise à niveau du contenu des archives
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+mod legacy;
+
+use crate::models::archive::Archive;
+use crate::models::archive_payload::{ArchiveKind, ArchiveSummary, ARCHIVE_SCHEMA_VERSION};
+use legacy::resume_v1;
+use serde_json::{Map, Value};
+
+/// Le contenu d'origine (lu par les vues du frontend) est conservé à côté de
+/// `schema_version` et du résumé typé `summary` lu par le backend
+pub struct ArchiveSchema;
+
+impl ArchiveSchema {
+    /// Version du contenu (1 = ancien format libre, sans résumé)
+    pub fn version(data: &Value) -> u32 {
+        data.get("schema_version")
+            .and_then(Value::as_u64)
+            .map(|v| v as u32)
+            .unwrap_or(1)
+    }
+
+    /// Contenu mis à la version courante puis validé (enregistrement et migration)
+    pub fn upgrade(archive_type: &str, data_json: &str) -> Result<String, String> {
+        let kind = ArchiveKind::from_archive_type(archive_type)
+            .ok_or_else(|| format!("Type d'archive inconnu: {}", archive_type))?;
+        let value: Value =
+            serde_json::from_str(data_json).map_err(|e| format!("JSON invalide: {}", e))?;
+        let Value::Object(mut data) = value else {
+            return Err("Le contenu d'une archive doit être un objet JSON".to_string());
+        };
+
+        let version = Self::version(&Value::Object(data.clone()));
+        if version > ARCHIVE_SCHEMA_VERSION {
+            return Err(format!(
+                "Archive au schéma v{} (application : v{})",
+                version, ARCHIVE_SCHEMA_VERSION
+            ));
+        }
+        // v2 complétait les champs absents par des zéros : résumé recalculé depuis le contenu
+        if version < 3 {
+            let summary = resume_v1(kind, &data)?;
+            let summary =
+                serde_json::to_value(summary).map_err(|e| format!("Résumé invalide: {}", e))?;
+            data.insert("summary".to_string(), summary);
+        }
+        // Étapes suivantes (v3 → v4…) à ajouter ici
+
+        let summary = lire_resume(&data)?;
+        if summary.kind() != kind {
+            return Err(format!(
+                "Résumé {:?} incompatible avec le type d'archive '{}'",
+                summary.kind(),
+                archive_type
+            ));
+        }
+        summary.validate()?;
+
+        data.insert(
+            "schema_version".to_string(),
+            Value::from(ARCHIVE_SCHEMA_VERSION),
+        );
+        serde_json::to_string(&data).map_err(|e| format!("Sérialisation impossible: {}", e))
+    }
+
+    /// Résumé typé d'une archive (mise à niveau en mémoire si la ligne n'a pas été migrée)
+    pub fn summary(archive: &Archive) -> Result<ArchiveSummary, String> {
+        let value: Value = serde_json::from_str(&archive.data_json)
+            .map_err(|e| format!("JSON invalide: {}", e))?;
+        if Self::version(&value) == ARCHIVE_SCHEMA_VERSION {
+            if let Value::Object(data) = &value {
+                return lire_resume(data);
+            }
+        }
+        let upgraded = Self::upgrade(&archive.archive_type, &archive.data_json)?;
+        let value: Value =
+            serde_json::from_str(&upgraded).map_err(|e| format!("JSON invalide: {}", e))?;
+        match &value {
+            Value::Object(data) => lire_resume(data),
+            _ => Err("Contenu d'archive invalide".to_string()),
+        }
+    }
+}
+
+fn lire_resume(data: &Map<String, Value>) -> Result<ArchiveSummary, String> {
+    let summary = data.get("summary").ok_or("Résumé typé absent")?;
+    serde_json::from_value(summary.clone()).map_err(|e| format!("Résumé illisible: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retrospective_archive_upgrade() {
+        // Format enregistré par la vue rétrospective (camelCase, sans score)
+        let legacy = r#"{"pair":"EURUSD","eventType":"Non-Farm Payrolls","offset":120,
+            "trailingStop":240,"stopLoss":180,"offsetSimultaneous":90,
+            "trailingStopSimultaneous":200,"stopLossSimultaneous":150,"timeout":25,
+            "eventCount":36,"volatilityIncreasePercent":140.5,"atrTimelineBefore":[1,2]}"#;
+        let type_label = "Correlation de la volatilité Paire/Evenement";
+        let upgraded = ArchiveSchema::upgrade(type_label, legacy).expect("mise à niveau");
+        let value: Value = serde_json::from_str(&upgraded).expect("json");
+        assert_eq!(ArchiveSchema::version(&value), ARCHIVE_SCHEMA_VERSION);
+        // Le contenu lu par le frontend est conservé
+        assert!(value.get("atrTimelineBefore").is_some());
+
+        let Value::Object(data) = value else {
+            panic!("objet attendu");
+        };
+        let ArchiveSummary::EventImpact(summary) = lire_resume(&data).expect("résumé") else {
+            panic!("impact d'événement attendu");
+        };
+        assert_eq!(summary.event_type, "Non-Farm Payrolls");
+        assert_eq!(summary.trailing_stop, 240.0);
+        assert_eq!(summary.timeout_minutes, Some(25));
+        assert_eq!(summary.event_count, Some(36));
+        assert_eq!(summary.confidence_score, None);
+
+        // Idempotent sur une archive déjà à jour
+        assert_eq!(
+            ArchiveSchema::upgrade(type_label, &upgraded).expect("v3"),
+            upgraded
+        );
+    }
+
+    #[test]
+    fn test_missing_legacy_fields_stay_empty() {
+        // Ancien format sans paramètres Simultané ni statistiques d'événement
+        let legacy = r#"{"pair":"EURUSD","eventType":"CPI","offset":120,
+            "trailingStop":240,"stopLoss":180}"#;
+        let type_label = "Métriques Rétrospectives";
+        let upgraded = ArchiveSchema::upgrade(type_label, legacy).expect("mise à niveau");
+        let value: Value = serde_json::from_str(&upgraded).expect("json");
+        let Value::Object(data) = value else {
+            panic!("objet attendu");
+        };
+        let ArchiveSummary::EventImpact(summary) = lire_resume(&data).expect("résumé") else {
+            panic!("impact d'événement attendu");
+        };
+        assert_eq!(summary.offset_simultaneous, None);
+        assert_eq!(summary.stop_loss_simultaneous, None);
+        assert_eq!(summary.event_count, None);
+        assert_eq!(summary.volatility_increase_percent, None);
+
+        // Un résumé v2 aux zéros fabriqués est recalculé depuis le contenu d'origine
+        let mut v2 = data.clone();
+        v2.insert("schema_version".to_string(), Value::from(2));
+        if let Some(Value::Object(s)) = v2.get_mut("summary") {
+            s.insert("event_count".to_string(), Value::from(0));
+        }
+        let v2 = serde_json::to_string(&v2).expect("json");
+        assert_eq!(
+            ArchiveSchema::upgrade(type_label, &v2).expect("v2"),
+            upgraded
+        );
+
+        let backtest = r#"{"result":{"symbol":"EURUSD","event_name":"CPI","total_pips":12.5}}"#;
+        let upgraded = ArchiveSchema::upgrade("Backtest", backtest).expect("backtest");
+        let value: Value = serde_json::from_str(&upgraded).expect("json");
+        let summary = value.get("summary").expect("résumé");
+        assert!(summary.get("offset").is_some_and(Value::is_null));
+        assert!(summary.get("timeout_minutes").is_some_and(Value::is_null));
+    }
+
+    #[test]
+    fn test_validation_rejects_incomplete_payloads() {
+        // Paramètres absents : l'ancien code lisait des zéros
+        let err = ArchiveSchema::upgrade(
+            "Métriques Rétrospectives",
+            r#"{"pair":"EURUSD","eventType":"CPI"}"#,
+        );
+        assert!(err.is_err());
+        assert!(ArchiveSchema::upgrade("Inconnu", "{}").is_err());
+        assert!(ArchiveSchema::upgrade("Heatmap", "[1,2]").is_err());
+        assert!(ArchiveSchema::upgrade("Heatmap", r#"{"schema_version":9}"#).is_err());
+
+        let heatmap = r#"{"heatmapData":{"pairs":["EURUSD"],
+            "event_types":[{"name":"CPI"},"NFP"],"data":{}}}"#;
+        let upgraded = ArchiveSchema::upgrade("Heatmap", heatmap).expect("heatmap");
+        assert!(upgraded.contains(r#""event_types":["CPI","NFP"]"#));
+
+        let corr = r#"{"pairCorrelation":{"pair":"GBPUSD","events":[{"name":"BoE","count":4}]}}"#;
+        assert!(ArchiveSchema::upgrade("Corrélation paire/événement", corr).is_ok());
+    }
+}
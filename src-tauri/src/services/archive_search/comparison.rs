@@ -0,0 +1,201 @@
+// services/archive_search/comparison.rs - Comparaison d'archives de même nature (deltas par métrique)
+// Conforme .clinerules : < 250L, pas d'unwrap()
+
+use super::ArchiveSearch;
+use crate::models::archive::Archive;
+use crate::models::archive_comparison::{ArchiveComparison, ComparedArchive, MetricDelta};
+use crate::models::archive_payload::ArchiveSummary;
+use crate::services::SymbolRegistryService;
+
+/// (nom, paramètre ?, valeur)
+type MetricValue = (&'static str, bool, Option<f64>);
+
+impl ArchiveSearch {
+    /// Compare des archives de même nature portant sur la même paire et le même événement,
+    /// de la plus ancienne à la plus récente
+    pub fn comparer(
+        mut entries: Vec<(Archive, ArchiveSummary)>,
+    ) -> Result<ArchiveComparison, String> {
+        if entries.len() < 2 {
+            return Err("Au moins deux archives sont nécessaires pour comparer".to_string());
+        }
+        entries.sort_by(|(a, _), (b, _)| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        let (first, first_summary) = &entries[0];
+        let identity = |summary: &ArchiveSummary| {
+            (
+                summary.kind(),
+                summary.instrument().map(SymbolRegistryService::canonical),
+                summary.events().first().map(|e| e.trim().to_lowercase()),
+            )
+        };
+        let reference = identity(first_summary);
+        let pair = reference.1.clone().ok_or_else(|| {
+            format!(
+                "Archive #{} ({}) : comparaison non disponible pour ce type",
+                first.id, first.archive_type
+            )
+        })?;
+        if let Some((other, _)) = entries.iter().find(|(_, s)| identity(s) != reference) {
+            return Err(format!(
+                "Archive #{} ({}) : nature, paire ou événement différents de l'archive #{}",
+                other.id, other.title, first.id
+            ));
+        }
+
+        let rows: Vec<Vec<MetricValue>> = entries
+            .iter()
+            .map(|(_, summary)| valeurs(summary))
+            .collect::<Result<_, _>>()?;
+        let metrics = rows
+            .first()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(i, (name, parameter, _))| {
+                        let values: Vec<Option<f64>> = rows.iter().map(|r| r[i].2).collect();
+                        delta(name, *parameter, values)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(ArchiveComparison {
+            kind: reference.0,
+            pair,
+            event: first_summary.events().first().map(|e| e.to_string()),
+            archives: entries
+                .iter()
+                .map(|(a, _)| ComparedArchive {
+                    id: a.id,
+                    title: a.title.clone(),
+                    created_at: a.created_at.clone(),
+                    period_start: a.period_start.clone(),
+                    period_end: a.period_end.clone(),
+                })
+                .collect(),
+            metrics,
+        })
+    }
+}
+
+/// Valeurs dans un ordre fixe par nature d'archive
+fn valeurs(summary: &ArchiveSummary) -> Result<Vec<MetricValue>, String> {
+    match summary {
+        ArchiveSummary::EventImpact(e) => Ok(vec![
+            ("offset", true, Some(e.offset)),
+            ("trailing_stop", true, Some(e.trailing_stop)),
+            ("stop_loss", true, Some(e.stop_loss)),
+            ("offset_simultaneous", true, e.offset_simultaneous),
+            (
+                "trailing_stop_simultaneous",
+                true,
+                e.trailing_stop_simultaneous,
+            ),
+            ("stop_loss_simultaneous", true, e.stop_loss_simultaneous),
+            ("timeout_minutes", true, e.timeout_minutes.map(f64::from)),
+            ("event_count", false, e.event_count.map(|n| n as f64)),
+            (
+                "volatility_increase_percent",
+                false,
+                e.volatility_increase_percent,
+            ),
+            ("confidence_score", false, e.confidence_score),
+        ]),
+        ArchiveSummary::Backtest(b) => Ok(vec![
+            ("offset", true, b.offset),
+            ("stop_loss", true, b.stop_loss),
+            ("trailing_stop", true, b.trailing_stop),
+            ("timeout_minutes", true, b.timeout_minutes.map(f64::from)),
+            ("total_trades", false, Some(b.total_trades as f64)),
+            ("win_rate_percent", false, Some(b.win_rate_percent)),
+            ("total_pips", false, Some(b.total_pips)),
+        ]),
+        ArchiveSummary::Volatility(v) => Ok(vec![
+            ("confidence_score", false, Some(v.confidence_score)),
+            ("mean_volatility", false, v.mean_volatility),
+        ]),
+        other => Err(format!(
+            "Comparaison non disponible pour les archives {:?}",
+            other.kind()
+        )),
+    }
+}
+
+fn delta(name: &str, parameter: bool, values: Vec<Option<f64>>) -> MetricDelta {
+    let first = values.iter().flatten().next().copied();
+    let last = values.iter().rev().flatten().next().copied();
+    let change = first.zip(last).map(|(f, l)| l - f);
+    let change_percent = first
+        .zip(change)
+        .filter(|(f, _)| f.abs() > f64::EPSILON)
+        .map(|(f, c)| c / f.abs() * 100.0);
+    MetricDelta {
+        name: name.to_string(),
+        parameter,
+        values,
+        change,
+        change_percent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::archive_payload::{ArchiveKind, VolatilitySummary};
+    use crate::services::archive_search::tests::{archive, impact};
+
+    #[test]
+    fn test_compare_orders_by_creation_and_computes_deltas() {
+        let comparison = ArchiveSearch::comparer(vec![
+            (
+                archive(7, "Récent", "2024-09-01 08:00:00"),
+                impact("EURUSD", 120.0, 20),
+            ),
+            (
+                archive(3, "Ancien", "2024-03-01 08:00:00"),
+                impact("EURUSD.r", 100.0, 10),
+            ),
+        ])
+        .expect("comparaison");
+        assert_eq!(comparison.kind, ArchiveKind::EventImpact);
+        assert_eq!(comparison.pair, "EURUSD");
+        assert_eq!(comparison.archives[0].id, 3);
+        let offset = &comparison.metrics[0];
+        assert!(offset.parameter);
+        assert_eq!(offset.values, vec![Some(100.0), Some(120.0)]);
+        assert_eq!(offset.change, Some(20.0));
+        assert_eq!(offset.change_percent, Some(20.0));
+        let score = comparison
+            .metrics
+            .iter()
+            .find(|m| m.name == "confidence_score")
+            .expect("score");
+        assert_eq!(score.change, None);
+
+        let mixed = ArchiveSearch::comparer(vec![
+            (archive(1, "A", "2024-03-01"), impact("EURUSD", 100.0, 10)),
+            (archive(2, "B", "2024-04-01"), impact("GBPUSD", 100.0, 10)),
+        ]);
+        assert!(mixed.is_err());
+        let volatility = ArchiveSummary::Volatility(VolatilitySummary {
+            symbol: "EURUSD".to_string(),
+            confidence_score: 50.0,
+            confidence_score_ci: None,
+            best_hours: vec![],
+            mean_volatility: Some(10.0),
+            regime: None,
+            unit: "pips".to_string(),
+        });
+        let kinds = ArchiveSearch::comparer(vec![
+            (archive(1, "A", "2024-03-01"), impact("EURUSD", 100.0, 10)),
+            (archive(2, "B", "2024-04-01"), volatility),
+        ]);
+        assert!(kinds.is_err());
+        assert!(ArchiveSearch::comparer(vec![]).is_err());
+    }
+}
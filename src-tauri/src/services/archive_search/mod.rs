@@ -0,0 +1,181 @@
+// services/archive_search/mod.rs - Étiquettes et filtres de recherche d'archives
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::archive::{Archive, ArchiveFilter};
+use crate::models::archive_payload::ArchiveSummary;
+use crate::services::SymbolRegistryService;
+
+mod comparison;
+
+const MAX_TAG_LEN: usize = 40;
+
+pub struct ArchiveSearch;
+
+impl ArchiveSearch {
+    /// Étiquettes en minuscules, espaces réduits, sans doublon, triées
+    pub fn normaliser_tags(tags: &[String]) -> Result<Vec<String>, String> {
+        let mut normalized = Vec::new();
+        for tag in tags {
+            let tag = tag.split_whitespace().collect::<Vec<_>>().join(" ");
+            let tag = tag.to_lowercase();
+            if tag.is_empty() {
+                continue;
+            }
+            if tag.chars().count() > MAX_TAG_LEN {
+                return Err(format!(
+                    "Étiquette trop longue (max {} caractères): {}",
+                    MAX_TAG_LEN, tag
+                ));
+            }
+            normalized.push(tag);
+        }
+        normalized.sort();
+        normalized.dedup();
+        Ok(normalized)
+    }
+
+    /// Tous les critères renseignés doivent être satisfaits. Une archive sans résumé
+    /// exploitable ne répond qu'aux critères portant sur le titre, le type, les dates et les étiquettes.
+    pub fn correspond(
+        archive: &Archive,
+        summary: Option<&ArchiveSummary>,
+        tags: &[String],
+        filter: &ArchiveFilter,
+    ) -> bool {
+        let pairs = summary.map(ArchiveSummary::instruments).unwrap_or_default();
+        let events = summary.map(ArchiveSummary::events).unwrap_or_default();
+
+        if !filter.archive_types.is_empty()
+            && !filter
+                .archive_types
+                .iter()
+                .any(|t| t.trim() == archive.archive_type.trim())
+        {
+            return false;
+        }
+        if let Some(pair) = non_vide(&filter.pair) {
+            let wanted = SymbolRegistryService::canonical(pair);
+            if !pairs
+                .iter()
+                .any(|p| SymbolRegistryService::canonical(p) == wanted)
+            {
+                return false;
+            }
+        }
+        if let Some(event) = non_vide(&filter.event) {
+            let wanted = event.to_lowercase();
+            if !events.iter().any(|e| e.to_lowercase().contains(&wanted)) {
+                return false;
+            }
+        }
+        if let Ok(wanted) = Self::normaliser_tags(&filter.tags) {
+            if !wanted.iter().all(|t| tags.contains(t)) {
+                return false;
+            }
+        }
+        let created = archive.created_at.get(..10).unwrap_or(&archive.created_at);
+        if non_vide(&filter.created_from).is_some_and(|from| created < from)
+            || non_vide(&filter.created_to).is_some_and(|to| created > to)
+        {
+            return false;
+        }
+        if let Some(query) = non_vide(&filter.query) {
+            let mut haystack = vec![
+                archive.title.as_str(),
+                archive.comment.as_deref().unwrap_or_default(),
+            ];
+            haystack.extend(pairs.iter().copied());
+            haystack.extend(events.iter().copied());
+            haystack.extend(tags.iter().map(String::as_str));
+            let haystack = haystack.join(" ").to_lowercase();
+            if !query
+                .to_lowercase()
+                .split_whitespace()
+                .all(|word| haystack.contains(word))
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn non_vide(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::archive_payload::EventImpactSummary;
+
+    pub(super) fn archive(id: i32, title: &str, created_at: &str) -> Archive {
+        Archive {
+            id,
+            title: title.to_string(),
+            archive_type: "Correlation de la volatilité Paire/Evenement".to_string(),
+            period_start: "2024-01-01".to_string(),
+            period_end: "2024-06-30".to_string(),
+            comment: Some("Avant la BCE".to_string()),
+            created_at: created_at.to_string(),
+            data_json: "{}".to_string(),
+        }
+    }
+
+    pub(super) fn impact(pair: &str, offset: f64, event_count: usize) -> ArchiveSummary {
+        ArchiveSummary::EventImpact(EventImpactSummary {
+            pair: pair.to_string(),
+            event_type: "CPI m/m".to_string(),
+            offset,
+            trailing_stop: 150.0,
+            stop_loss: 200.0,
+            offset_simultaneous: Some(80.0),
+            trailing_stop_simultaneous: Some(120.0),
+            stop_loss_simultaneous: Some(180.0),
+            timeout_minutes: Some(30),
+            event_count: Some(event_count),
+            volatility_increase_percent: Some(40.0),
+            confidence_score: None,
+            confidence_score_ci: None,
+            regime: None,
+        })
+    }
+
+    #[test]
+    fn test_filter_by_text_pair_tag_and_dates() {
+        let a = archive(1, "Retro EURUSD", "2024-07-01 10:00:00");
+        let summary = impact("EURUSD", 100.0, 12);
+        let tags =
+            ArchiveSearch::normaliser_tags(&["  Validé ".into(), "validé".into()]).expect("tags");
+        assert_eq!(tags, vec!["validé".to_string()]);
+
+        let matches =
+            |filter: ArchiveFilter| ArchiveSearch::correspond(&a, Some(&summary), &tags, &filter);
+        assert!(matches(ArchiveFilter {
+            query: Some("cpi bce".into()),
+            pair: Some("eurusd.r".into()),
+            event: Some("cpi".into()),
+            tags: vec!["VALIDÉ".into()],
+            created_from: Some("2024-07-01".into()),
+            created_to: Some("2024-07-01".into()),
+            ..Default::default()
+        }));
+        assert!(!matches(ArchiveFilter {
+            pair: Some("GBPUSD".into()),
+            ..Default::default()
+        }));
+        assert!(!matches(ArchiveFilter {
+            created_to: Some("2024-06-30".into()),
+            ..Default::default()
+        }));
+        assert!(!matches(ArchiveFilter {
+            archive_types: vec!["Backtest".into()],
+            ..Default::default()
+        }));
+        assert!(!matches(ArchiveFilter {
+            query: Some("nfp".into()),
+            ..Default::default()
+        }));
+        assert!(ArchiveSearch::normaliser_tags(&["x".repeat(41)]).is_err());
+    }
+}
@@ -0,0 +1,236 @@
+// services/archive_service/bundle.rs - Export et import des lots d'archives portables
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::ArchiveService;
+use crate::db::DbPool;
+use crate::models::archive::NewArchive;
+use crate::models::archive_bundle::{
+    BundleExportResult, BundleImportReport, BundledArchive, BundledEvent, ConflictStrategy,
+    DataRange,
+};
+use crate::schema::archives;
+use crate::services::archive_bundle::{ArchiveBundler, ImportAction};
+use crate::services::archive_schema::ArchiveSchema;
+use crate::services::archive_search::ArchiveSearch;
+use crate::services::SymbolRegistryService;
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Nullable, Text};
+use std::collections::HashSet;
+use tracing::info;
+
+#[derive(QueryableByName)]
+struct RangeRow {
+    #[diesel(sql_type = Text)]
+    symbol: String,
+    #[diesel(sql_type = Text)]
+    timeframe: String,
+    #[diesel(sql_type = BigInt)]
+    candle_count: i64,
+    #[diesel(sql_type = Nullable<Text>)]
+    first: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    last: Option<String>,
+}
+
+impl ArchiveService {
+    /// Écrit un lot portable des archives choisies (étiquettes comprises), avec en option
+    /// les événements du calendrier qu'elles citent et les plages de bougies de pairs.db
+    pub fn export_bundle(
+        &self,
+        archive_ids: &[i32],
+        include_events: bool,
+        pairs_pool: Option<&DbPool>,
+        path: &str,
+    ) -> Result<BundleExportResult, String> {
+        let mut tags = self.load_tags()?;
+        let mut archives = Vec::new();
+        let mut symbols = Vec::new();
+        let mut event_names = HashSet::new();
+        for &id in archive_ids {
+            let archive = self.get_archive(id)?;
+            if let Ok(summary) = ArchiveSchema::summary(&archive) {
+                symbols.extend(summary.instruments().into_iter().map(str::to_string));
+                event_names.extend(
+                    summary
+                        .events()
+                        .into_iter()
+                        .map(|e| e.trim().to_lowercase()),
+                );
+            }
+            archives.push(BundledArchive {
+                tags: tags.remove(&archive.id).unwrap_or_default(),
+                title: archive.title,
+                archive_type: archive.archive_type,
+                period_start: archive.period_start,
+                period_end: archive.period_end,
+                comment: archive.comment,
+                created_at: archive.created_at,
+                data_json: archive.data_json,
+            });
+        }
+
+        let events = if include_events {
+            self.bundle_events(&archives, &event_names)?
+        } else {
+            Vec::new()
+        };
+        let data_ranges = match pairs_pool {
+            Some(pool) => Self::plages_donnees(pool, &symbols)?,
+            None => Vec::new(),
+        };
+        let bundle = ArchiveBundler::construire(archives, events, data_ranges, Utc::now())?;
+        let json = serde_json::to_string_pretty(&bundle)
+            .map_err(|e| format!("Sérialisation du lot impossible: {}", e))?;
+        std::fs::write(path, json)
+            .map_err(|e| format!("Écriture de {} impossible: {}", path, e))?;
+
+        info!(
+            "📦 Lot exporté : {} archive(s), {} événement(s) → {}",
+            bundle.archives.len(),
+            bundle.events.len(),
+            path
+        );
+        Ok(BundleExportResult {
+            path: path.to_string(),
+            archives: bundle.archives.len(),
+            events: bundle.events.len(),
+            checksum: bundle.manifest.checksum,
+        })
+    }
+
+    /// Importe un lot : les archives en conflit suivent `strategy`, les événements déjà
+    /// présents (même devise, heure et libellé) sont ignorés
+    pub fn import_bundle(
+        &self,
+        path: &str,
+        strategy: ConflictStrategy,
+        import_events: bool,
+    ) -> Result<BundleImportReport, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Lot illisible ({}): {}", path, e))?;
+        let bundle = ArchiveBundler::lire(&content)?;
+        // Conflits jugés contre les archives locales d'avant l'import ; titres attribués
+        // au fil de l'import pour que deux archives renommées ne prennent pas le même nom
+        let existing = self.list_archives()?;
+        let mut chosen = HashSet::new();
+        let mut report = BundleImportReport {
+            manifest: bundle.manifest.clone(),
+            imported: 0,
+            replaced: 0,
+            renamed: 0,
+            skipped: Vec::new(),
+            events_imported: 0,
+            events_skipped: 0,
+        };
+
+        for incoming in &bundle.archives {
+            let data_json =
+                match ArchiveSchema::upgrade(&incoming.archive_type, &incoming.data_json) {
+                    Ok(data_json) => data_json,
+                    Err(e) => {
+                        report.skipped.push(format!("{}: {}", incoming.title, e));
+                        continue;
+                    }
+                };
+            match ArchiveBundler::resoudre(incoming, &data_json, &existing, &chosen, strategy) {
+                ImportAction::Skip { reason } => {
+                    report
+                        .skipped
+                        .push(format!("{}: {}", incoming.title, reason));
+                }
+                ImportAction::Replace { archive_id } => {
+                    let tags = ArchiveSearch::normaliser_tags(&incoming.tags)?;
+                    let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+                    conn.transaction(|conn| {
+                        diesel::update(archives::table.find(archive_id))
+                            .set((
+                                archives::data_json.eq(&data_json),
+                                archives::comment.eq(&incoming.comment),
+                                archives::created_at.eq(&incoming.created_at),
+                            ))
+                            .execute(conn)?;
+                        Self::ecrire_tags(conn, archive_id, &tags)
+                    })
+                    .map_err(|e: diesel::result::Error| {
+                        format!("Remplacement de '{}' impossible: {}", incoming.title, e)
+                    })?;
+                    report.replaced += 1;
+                }
+                ImportAction::Insert { title, renamed } => {
+                    let tags = ArchiveSearch::normaliser_tags(&incoming.tags)?;
+                    // Contenu déjà mis au schéma ; l'empreinte reste celle du poste d'origine
+                    let new_archive = NewArchive {
+                        title,
+                        archive_type: incoming.archive_type.clone(),
+                        period_start: incoming.period_start.clone(),
+                        period_end: incoming.period_end.clone(),
+                        comment: incoming.comment.clone(),
+                        data_json,
+                    };
+                    // Archive, date d'origine (ordre des comparaisons) et étiquettes : tout ou rien
+                    let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+                    let archive = conn
+                        .transaction(|conn| {
+                            let archive = Self::inserer_avec(conn, &new_archive)?;
+                            diesel::update(archives::table.find(archive.id))
+                                .set(archives::created_at.eq(&incoming.created_at))
+                                .execute(conn)?;
+                            Self::ecrire_tags(conn, archive.id, &tags)?;
+                            Ok(archive)
+                        })
+                        .map_err(|e: diesel::result::Error| {
+                            format!("Import de '{}' impossible: {}", incoming.title, e)
+                        })?;
+                    report.imported += 1;
+                    report.renamed += usize::from(renamed);
+                    chosen.insert(archive.title);
+                }
+            }
+        }
+
+        if import_events && !bundle.events.is_empty() {
+            let (imported, skipped) =
+                self.import_events(&bundle.manifest.exported_at, &bundle.events)?;
+            report.events_imported = imported;
+            report.events_skipped = skipped;
+        }
+
+        info!(
+            "📥 Lot importé : {} archive(s) ajoutée(s), {} remplacée(s), {} ignorée(s), {} événement(s)",
+            report.imported,
+            report.replaced,
+            report.skipped.len(),
+            report.events_imported
+        );
+        Ok(report)
+    }
+
+    /// Historique de bougies disponible pour les paires citées (pairs.db)
+    fn plages_donnees(pairs_pool: &DbPool, symbols: &[String]) -> Result<Vec<DataRange>, String> {
+        let wanted: Vec<String> = symbols
+            .iter()
+            .map(|s| SymbolRegistryService::canonical(s))
+            .collect();
+        let mut conn = pairs_pool.get().map_err(|e| e.to_string())?;
+        let rows: Vec<RangeRow> = diesel::sql_query(
+            "SELECT symbol, timeframe, COUNT(*) AS candle_count, \
+             CAST(MIN(time) AS TEXT) AS first, CAST(MAX(time) AS TEXT) AS last \
+             FROM candle_data GROUP BY symbol, timeframe ORDER BY symbol, timeframe",
+        )
+        .load(&mut conn)
+        .map_err(|e| format!("Lecture des plages de données impossible: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .filter(|r| wanted.contains(&SymbolRegistryService::canonical(&r.symbol)))
+            .map(|r| DataRange {
+                symbol: r.symbol,
+                timeframe: r.timeframe,
+                candle_count: r.candle_count,
+                first: r.first,
+                last: r.last,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,144 @@
+// services/archive_service/bundle_events.rs - Événements du calendrier joints aux lots d'archives
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use super::ArchiveService;
+use crate::models::archive_bundle::{BundledArchive, BundledEvent};
+use crate::models::calendar_event::{CalendarEvent, NewCalendarEvent};
+use crate::schema::{calendar_events, calendar_imports};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use diesel::prelude::*;
+use std::collections::HashSet;
+
+impl ArchiveService {
+    /// Événements de la période couverte dont le libellé est cité par une archive
+    pub(super) fn bundle_events(
+        &self,
+        archives: &[BundledArchive],
+        event_names: &HashSet<String>,
+    ) -> Result<Vec<BundledEvent>, String> {
+        let day = |value: &str| NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok();
+        let start = archives.iter().filter_map(|a| day(&a.period_start)).min();
+        let end = archives.iter().filter_map(|a| day(&a.period_end)).max();
+        let (Some(start), Some(end)) = (start, end) else {
+            return Ok(Vec::new());
+        };
+        if event_names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+        let rows: Vec<CalendarEvent> = calendar_events::table
+            .filter(calendar_events::event_time.ge(start.and_time(Default::default())))
+            .filter(
+                calendar_events::event_time
+                    .lt((end + chrono::Duration::days(1)).and_time(Default::default())),
+            )
+            .order(calendar_events::event_time)
+            .select(CalendarEvent::as_select())
+            .load(&mut conn)
+            .map_err(|e| format!("Lecture du calendrier impossible: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .filter(|e| event_names.contains(&e.description.trim().to_lowercase()))
+            .map(|e| BundledEvent {
+                symbol: e.symbol,
+                event_time: e.event_time.format("%Y-%m-%d %H:%M:%S").to_string(),
+                impact: e.impact,
+                description: e.description,
+                actual: e.actual,
+                forecast: e.forecast,
+                previous: e.previous,
+            })
+            .collect())
+    }
+
+    /// Ajoute les événements absents sous un calendrier dédié au lot
+    pub(super) fn import_events(
+        &self,
+        exported_at: &str,
+        events: &[BundledEvent],
+    ) -> Result<(usize, usize), String> {
+        let mut parsed = Vec::new();
+        let mut invalid = 0;
+        for event in events {
+            match NaiveDateTime::parse_from_str(&event.event_time, "%Y-%m-%d %H:%M:%S") {
+                Ok(time) => parsed.push((time, event)),
+                Err(_) => invalid += 1,
+            }
+        }
+        let (Some(first), Some(last)) = (
+            parsed.iter().map(|(t, _)| *t).min(),
+            parsed.iter().map(|(t, _)| *t).max(),
+        ) else {
+            return Ok((0, invalid));
+        };
+
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+        conn.transaction(|conn| {
+            let known: HashSet<(String, NaiveDateTime, String)> = calendar_events::table
+                .filter(calendar_events::event_time.between(first, last))
+                .select((
+                    calendar_events::symbol,
+                    calendar_events::event_time,
+                    calendar_events::description,
+                ))
+                .load::<(String, NaiveDateTime, String)>(conn)?
+                .into_iter()
+                .collect();
+            let missing: Vec<_> = parsed
+                .iter()
+                .filter(|(time, e)| {
+                    !known.contains(&(e.symbol.clone(), *time, e.description.clone()))
+                })
+                .collect();
+            if missing.is_empty() {
+                return Ok((0, invalid + parsed.len()));
+            }
+
+            let name = format!("Lot d'archives {}", exported_at);
+            diesel::insert_or_ignore_into(calendar_imports::table)
+                .values((
+                    calendar_imports::name.eq(&name),
+                    calendar_imports::filename.eq("archive-bundle"),
+                    calendar_imports::event_count.eq(0),
+                    calendar_imports::oldest_event_date
+                        .eq(first.format("%Y-%m-%d %H:%M:%S").to_string()),
+                    calendar_imports::newest_event_date
+                        .eq(last.format("%Y-%m-%d %H:%M:%S").to_string()),
+                    calendar_imports::imported_at.eq(Utc::now().to_rfc3339()),
+                    calendar_imports::is_active.eq(true),
+                ))
+                .execute(conn)?;
+            let import_id: i32 = calendar_imports::table
+                .filter(calendar_imports::name.eq(&name))
+                .select(calendar_imports::id)
+                .first(conn)?;
+
+            let rows: Vec<NewCalendarEvent> = missing
+                .iter()
+                .map(|(time, e)| NewCalendarEvent {
+                    symbol: e.symbol.clone(),
+                    event_time: *time,
+                    impact: e.impact.clone(),
+                    description: e.description.clone(),
+                    actual: e.actual,
+                    forecast: e.forecast,
+                    previous: e.previous,
+                    calendar_import_id: import_id,
+                })
+                .collect();
+            let inserted = diesel::insert_into(calendar_events::table)
+                .values(&rows)
+                .execute(conn)?;
+            diesel::update(calendar_imports::table.find(import_id))
+                .set(
+                    calendar_imports::event_count
+                        .eq(calendar_imports::event_count + inserted as i32),
+                )
+                .execute(conn)?;
+            Ok((inserted, invalid + parsed.len() - inserted))
+        })
+        .map_err(|e: diesel::result::Error| format!("Import des événements impossible: {}", e))
+    }
+}
@@ -0,0 +1,83 @@
+// services/archive_service/fingerprint.rs - Empreinte des entrées jointe aux archives enregistrées
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::ArchiveService;
+use crate::models::archive_payload::ArchiveSummary;
+use crate::models::input_fingerprint::InputFingerprint;
+use crate::schema::calendar_events;
+use crate::services::input_fingerprint::Fingerprinter;
+use diesel::prelude::*;
+use serde_json::Value;
+use tracing::warn;
+
+impl ArchiveService {
+    /// Remonte à la racine l'empreinte fournie par l'analyse, sinon la relève sur les
+    /// données actuelles (sans historique des paires, l'archive reste sans empreinte)
+    pub(super) fn avec_empreinte(&self, data_json: String) -> Result<String, String> {
+        let mut data = match serde_json::from_str(&data_json) {
+            Ok(Value::Object(data)) => data,
+            Ok(_) => return Ok(data_json),
+            Err(e) => return Err(format!("Contenu d'archive illisible: {}", e)),
+        };
+        if data.contains_key("fingerprint") {
+            return Ok(data_json);
+        }
+        let value = Value::Object(data.clone());
+        let fingerprint = match Fingerprinter::extraire(&value) {
+            Some(fingerprint) => Some(fingerprint),
+            None => match self.relever_empreinte(&value) {
+                Ok(fingerprint) => fingerprint,
+                Err(e) => {
+                    warn!("⚠️ Empreinte des entrées non relevée: {}", e);
+                    None
+                }
+            },
+        };
+        let Some(fingerprint) = fingerprint else {
+            return Ok(data_json);
+        };
+        let fingerprint = serde_json::to_value(fingerprint)
+            .map_err(|e| format!("Empreinte non sérialisable: {}", e))?;
+        data.insert("fingerprint".to_string(), fingerprint);
+        serde_json::to_string(&data)
+            .map_err(|e| format!("Contenu d'archive non sérialisable: {}", e))
+    }
+
+    /// Empreinte relevée à l'enregistrement : historiques des paires et occurrences
+    /// de l'événement cités par le résumé (paramètres d'analyse inconnus)
+    fn relever_empreinte(&self, data: &Value) -> Result<Option<InputFingerprint>, String> {
+        let Some(loader) = &self.pairs_loader else {
+            return Ok(None);
+        };
+        let summary = data.get("summary").ok_or("Résumé absent")?;
+        let summary: ArchiveSummary = serde_json::from_value(summary.clone())
+            .map_err(|e| format!("Résumé illisible: {}", e))?;
+        let events = summary.events();
+        let event_filter = (events.len() == 1).then(|| events[0]);
+        let calendar_import_ids: Vec<i32> = match event_filter {
+            Some(filter) => {
+                let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+                calendar_events::table
+                    .filter(calendar_events::description.eq(filter))
+                    .select(calendar_events::calendar_import_id)
+                    .load(&mut conn)
+                    .map_err(|e| e.to_string())?
+            }
+            None => Vec::new(),
+        };
+        let candles = summary
+            .instruments()
+            .into_iter()
+            .map(|symbol| loader.candle_range(symbol, "M1"))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        Fingerprinter::construire(
+            candles,
+            calendar_import_ids.clone(),
+            event_filter,
+            calendar_import_ids.len(),
+            Default::default(),
+        )
+        .map(Some)
+    }
+}
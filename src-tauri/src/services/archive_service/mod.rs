@@ -0,0 +1,277 @@
+mod bundle;
+mod bundle_events;
+mod fingerprint;
+
+use crate::db::DbPool;
+use crate::models::archive::{
+    Archive, ArchiveFilter, ArchiveSearchResult, ArchiveTagCount, NewArchive, NewArchiveTag,
+};
+use crate::models::archive_comparison::ArchiveComparison;
+use crate::models::archive_payload::{ArchiveMigrationReport, ARCHIVE_SCHEMA_VERSION};
+use crate::schema::{archive_tags, archives};
+use crate::services::archive_schema::ArchiveSchema;
+use crate::services::archive_search::ArchiveSearch;
+use crate::services::DatabaseLoader;
+use diesel::prelude::*;
+use std::collections::HashMap;
+use tracing::{error, info, warn};
+
+#[derive(Clone)]
+pub struct ArchiveService {
+    pool: DbPool,
+    /// Historique des paires, pour l'empreinte des archives enregistrées sans empreinte
+    pairs_loader: Option<DatabaseLoader>,
+}
+
+impl ArchiveService {
+    pub fn new(pool: DbPool) -> Self {
+        ArchiveService {
+            pool,
+            pairs_loader: None,
+        }
+    }
+
+    pub fn with_pairs_loader(mut self, loader: DatabaseLoader) -> Self {
+        self.pairs_loader = Some(loader);
+        self
+    }
+
+    /// Enregistre une archive après mise au schéma courant et validation de son contenu.
+    /// L'empreinte des entrées fournie par l'analyse est remontée à la racine, sinon
+    /// elle est relevée sur les données actuelles.
+    pub fn create_archive(&self, mut new_archive: NewArchive) -> Result<Archive, String> {
+        let data_json =
+            ArchiveSchema::upgrade(&new_archive.archive_type, &new_archive.data_json).map_err(
+                |e| format!("Archive '{}' refusée: {}", new_archive.title, e),
+            )?;
+        new_archive.data_json = self.avec_empreinte(data_json)?;
+        self.inserer(&new_archive)
+    }
+
+    fn inserer(&self, new_archive: &NewArchive) -> Result<Archive, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+        Self::inserer_avec(&mut conn, new_archive).map_err(|e| {
+            error!("Error creating archive: {}", e);
+            e.to_string()
+        })
+    }
+
+    /// Insertion sur une connexion donnée (éventuellement dans une transaction)
+    fn inserer_avec(
+        conn: &mut SqliteConnection,
+        new_archive: &NewArchive,
+    ) -> QueryResult<Archive> {
+        diesel::insert_into(archives::table)
+            .values(new_archive)
+            .execute(conn)?;
+        archives::table.order(archives::id.desc()).first(conn)
+    }
+
+    pub fn list_archives(&self) -> Result<Vec<Archive>, String> {
+        tracing::debug!("🔍 ArchiveService.list_archives: getting connection");
+        let mut conn = self.pool.get().map_err(|e| {
+            tracing::error!("❌ Pool error: {}", e);
+            e.to_string()
+        })?;
+
+        tracing::debug!("🔍 ArchiveService.list_archives: executing query");
+        let result = archives::table
+            .order(archives::created_at.desc())
+            .load::<Archive>(&mut conn);
+
+        match &result {
+            Ok(archives) => tracing::info!(
+                "✅ ArchiveService.list_archives: {} archives loaded",
+                archives.len()
+            ),
+            Err(e) => tracing::error!("❌ ArchiveService.list_archives SQL error: {}", e),
+        }
+
+        result.map_err(|e| {
+            error!("Error listing archives: {}", e);
+            e.to_string()
+        })
+    }
+
+    pub fn get_archive(&self, archive_id: i32) -> Result<Archive, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+
+        archives::table
+            .find(archive_id)
+            .first::<Archive>(&mut conn)
+            .map_err(|e| {
+                error!("Error getting archive {}: {}", archive_id, e);
+                e.to_string()
+            })
+    }
+
+    pub fn delete_archive(&self, archive_id: i32) -> Result<usize, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+
+        conn.transaction(|conn| {
+            diesel::delete(archive_tags::table.filter(archive_tags::archive_id.eq(archive_id)))
+                .execute(conn)?;
+            diesel::delete(archives::table.find(archive_id)).execute(conn)
+        })
+        .map_err(|e: diesel::result::Error| {
+            error!("Error deleting archive {}: {}", archive_id, e);
+            e.to_string()
+        })
+    }
+
+    /// Remplace les étiquettes d'une archive ; renvoie les étiquettes normalisées
+    pub fn set_tags(&self, archive_id: i32, tags: &[String]) -> Result<Vec<String>, String> {
+        let tags = ArchiveSearch::normaliser_tags(tags)?;
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+        conn.transaction(|conn| Self::ecrire_tags(conn, archive_id, &tags))
+            .map_err(|e: diesel::result::Error| {
+                error!("Error tagging archive {}: {}", archive_id, e);
+                format!("Étiquetage de l'archive {} impossible: {}", archive_id, e)
+            })?;
+
+        info!("🏷️ Archive {} : {} étiquette(s)", archive_id, tags.len());
+        Ok(tags)
+    }
+
+    /// Remplace les étiquettes (déjà normalisées) ; à appeler dans une transaction
+    fn ecrire_tags(
+        conn: &mut SqliteConnection,
+        archive_id: i32,
+        tags: &[String],
+    ) -> QueryResult<usize> {
+        let rows: Vec<NewArchiveTag> = tags
+            .iter()
+            .map(|tag| NewArchiveTag {
+                archive_id,
+                tag: tag.clone(),
+            })
+            .collect();
+        archives::table.find(archive_id).select(archives::id).first::<i32>(conn)?;
+        diesel::delete(archive_tags::table.filter(archive_tags::archive_id.eq(archive_id)))
+            .execute(conn)?;
+        diesel::insert_into(archive_tags::table)
+            .values(&rows)
+            .execute(conn)
+    }
+
+    /// Étiquettes utilisées, de la plus fréquente à la plus rare
+    pub fn list_tags(&self) -> Result<Vec<ArchiveTagCount>, String> {
+        let mut counts: Vec<ArchiveTagCount> = self
+            .load_tags()?
+            .into_values()
+            .flatten()
+            .fold(HashMap::<String, usize>::new(), |mut acc, tag| {
+                *acc.entry(tag).or_default() += 1;
+                acc
+            })
+            .into_iter()
+            .map(|(tag, count)| ArchiveTagCount { tag, count })
+            .collect();
+        counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+        Ok(counts)
+    }
+
+    fn load_tags(&self) -> Result<HashMap<i32, Vec<String>>, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+        let rows: Vec<(i32, String)> = archive_tags::table
+            .order((archive_tags::archive_id, archive_tags::tag))
+            .load(&mut conn)
+            .map_err(|e| {
+                error!("Error loading archive tags: {}", e);
+                e.to_string()
+            })?;
+        let mut tags: HashMap<i32, Vec<String>> = HashMap::new();
+        for (archive_id, tag) in rows {
+            tags.entry(archive_id).or_default().push(tag);
+        }
+        Ok(tags)
+    }
+
+    /// Archives répondant au filtre, les plus récentes d'abord
+    pub fn search_archives(&self, filter: &ArchiveFilter) -> Result<Vec<ArchiveSearchResult>, String> {
+        let mut tags = self.load_tags()?;
+        let results: Vec<ArchiveSearchResult> = self
+            .list_archives()?
+            .into_iter()
+            .filter_map(|archive| {
+                let tags = tags.remove(&archive.id).unwrap_or_default();
+                let summary = ArchiveSchema::summary(&archive).ok();
+                if !ArchiveSearch::correspond(&archive, summary.as_ref(), &tags, filter) {
+                    return None;
+                }
+                let pairs = summary
+                    .as_ref()
+                    .map(|s| s.instruments().into_iter().map(str::to_string).collect())
+                    .unwrap_or_default();
+                let events = summary
+                    .as_ref()
+                    .map(|s| s.events().into_iter().map(str::to_string).collect())
+                    .unwrap_or_default();
+                Some(ArchiveSearchResult {
+                    archive,
+                    tags,
+                    pairs,
+                    events,
+                })
+            })
+            .collect();
+        info!("🔎 Recherche d'archives : {} résultat(s)", results.len());
+        Ok(results)
+    }
+
+    /// Compare plusieurs archives d'une même paire et d'un même événement
+    pub fn compare_archives(&self, archive_ids: &[i32]) -> Result<ArchiveComparison, String> {
+        let entries = archive_ids
+            .iter()
+            .map(|&id| {
+                let archive = self.get_archive(id)?;
+                let summary = ArchiveSchema::summary(&archive)
+                    .map_err(|e| format!("Archive #{} illisible: {}", id, e))?;
+                Ok((archive, summary))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        ArchiveSearch::comparer(entries)
+    }
+
+    /// Met au schéma courant les archives enregistrées avec une version antérieure.
+    /// Une archive non convertible est laissée telle quelle et signalée.
+    pub fn migrate_payloads(&self) -> Result<ArchiveMigrationReport, String> {
+        let mut conn = self.pool.get().map_err(|e| e.to_string())?;
+        let rows: Vec<Archive> = archives::table
+            .load(&mut conn)
+            .map_err(|e| e.to_string())?;
+
+        let mut report = ArchiveMigrationReport::default();
+        for archive in rows {
+            let up_to_date = serde_json::from_str::<serde_json::Value>(&archive.data_json)
+                .is_ok_and(|v| ArchiveSchema::version(&v) == ARCHIVE_SCHEMA_VERSION);
+            if up_to_date {
+                continue;
+            }
+            match ArchiveSchema::upgrade(&archive.archive_type, &archive.data_json) {
+                Ok(data_json) => {
+                    diesel::update(archives::table.find(archive.id))
+                        .set(archives::data_json.eq(data_json))
+                        .execute(&mut conn)
+                        .map_err(|e| e.to_string())?;
+                    report.migrated += 1;
+                }
+                Err(e) => {
+                    warn!("⚠️ Archive {} non migrée: {}", archive.id, e);
+                    report
+                        .failed
+                        .push(format!("#{} {}: {}", archive.id, archive.title, e));
+                }
+            }
+        }
+        if report.migrated > 0 || !report.failed.is_empty() {
+            info!(
+                "🗄️ Archives mises au schéma v{}: {} migrée(s), {} en échec",
+                ARCHIVE_SCHEMA_VERSION,
+                report.migrated,
+                report.failed.len()
+            );
+        }
+        Ok(report)
+    }
+}
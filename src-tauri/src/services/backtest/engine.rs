@@ -1,8 +1,9 @@
 use super::models::*;
 use super::simulator::EventSimulator;
-use crate::models::{CalendarEvent, AssetProperties};
+use crate::models::{CalendarEvent, AssetProperties, RegimeCalendar, VolatilityRegime};
 use crate::services::database_loader::DatabaseLoader;
-use chrono::Duration;
+use crate::services::statistics;
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Utc};
 
 pub struct BacktestEngine;
 
@@ -14,6 +15,23 @@ impl BacktestEngine {
         mode: StrategyMode,
         loader: &DatabaseLoader,
     ) -> Result<BacktestResult, String> {
+        let trades = Self::simulate_events(pair, events, &config, mode, loader);
+
+        // 3. Calculer les métriques globales
+        // On récupère le nom de l'événement depuis le premier événement ou on utilise une valeur par défaut
+        // Note: Le champ description contient le nom de l'événement (ex: "Non-Farm Employment Change")
+        let event_name = events.first().map(|e| e.description.clone()).unwrap_or_else(|| "Unknown".to_string());
+        Ok(Self::calculer_synthese(pair, &event_name, trades, mode))
+    }
+
+    /// Simule chaque événement sur ses bougies M1 (événements sans données ignorés)
+    pub fn simulate_events(
+        pair: &str,
+        events: &[CalendarEvent],
+        config: &BacktestConfig,
+        mode: StrategyMode,
+        loader: &DatabaseLoader,
+    ) -> Vec<TradeResult> {
         let mut trades = Vec::new();
 
         for event in events {
@@ -31,15 +49,42 @@ impl BacktestEngine {
             }
 
             // 2. Simuler l'événement
-            let trade = EventSimulator::simulate(event, &candles, &config, mode);
+            let trade = EventSimulator::simulate(event, &candles, config, mode);
             trades.push(trade);
         }
 
-        // 3. Calculer les métriques globales
-        // On récupère le nom de l'événement depuis le premier événement ou on utilise une valeur par défaut
-        // Note: Le champ description contient le nom de l'événement (ex: "Non-Farm Employment Change")
-        let event_name = events.first().map(|e| e.description.clone()).unwrap_or_else(|| "Unknown".to_string());
-        Ok(Self::calculer_synthese(pair, &event_name, trades, mode))
+        trades
+    }
+
+    /// Pseudo-événements à heure fixe (HH:MM UTC) sur chaque jour ouvré de la période
+    pub fn time_slot_events(time: NaiveTime, start: NaiveDate, end: NaiveDate) -> Vec<CalendarEvent> {
+        let mut events = Vec::new();
+        let mut current_date = start;
+
+        while current_date <= end {
+            // Skip weekends (Saturday=6, Sunday=7)
+            let weekday = current_date.weekday().number_from_monday();
+            if weekday <= 5 {
+                let event_time = current_date.and_time(time);
+
+                events.push(CalendarEvent {
+                    id: 0, // Dummy ID
+                    symbol: "USD".to_string(), // Dummy currency
+                    event_time,
+                    description: format!("Time Backtest {}", time),
+                    impact: "Medium".to_string(),
+                    actual: None,
+                    forecast: None,
+                    previous: None,
+                    created_at: Utc::now().naive_utc(),
+                    calendar_import_id: 0, // Dummy import ID
+                });
+            }
+
+            current_date += Duration::days(1);
+        }
+
+        events
     }
 
     fn calculer_synthese(pair: &str, event_name: &str, trades: Vec<TradeResult>, mode: StrategyMode) -> BacktestResult {
@@ -102,6 +147,16 @@ impl BacktestEngine {
             if gross_profit > 0.0 { 999.0 } else { 0.0 }
         };
 
+        // Intervalles : seuls les trades déclenchés comptent
+        let traded_pips: Vec<f64> = trades
+            .iter()
+            .filter(|t| t.outcome != TradeOutcome::NoEntry)
+            .map(|t| t.pips_net)
+            .collect();
+        let win_rate_ci =
+            statistics::wilson_interval(winning, winning + losing).map(|ci| ci.scaled(100.0));
+        let average_pips_ci = statistics::bootstrap_mean_interval(&traded_pips);
+
         BacktestResult {
             symbol: pair.to_string(),
             event_name: event_name.to_string(),
@@ -117,6 +172,42 @@ impl BacktestEngine {
             profit_factor,
             trades,
             strategy_mode: mode,
+            win_rate_ci,
+            average_pips_ci,
+            regime: None,
+            regime_breakdown: Vec::new(),
+            fingerprint: None,
         }
     }
+
+    /// Ventile les trades déclenchés selon le régime du jour de l'événement
+    pub fn ventiler_par_regime(trades: &[TradeResult], calendar: &RegimeCalendar) -> Vec<RegimeBreakdown> {
+        VolatilityRegime::ALL
+            .iter()
+            .filter_map(|&regime| {
+                let pips: Vec<f64> = trades
+                    .iter()
+                    .filter(|t| t.outcome != TradeOutcome::NoEntry)
+                    .filter(|t| {
+                        chrono::DateTime::parse_from_rfc3339(&t.event_date)
+                            .ok()
+                            .and_then(|dt| calendar.regime_on(dt.date_naive()))
+                            == Some(regime)
+                    })
+                    .map(|t| t.pips_net)
+                    .collect();
+                if pips.is_empty() {
+                    return None;
+                }
+                let wins = pips.iter().filter(|&&p| p > 0.0).count();
+                Some(RegimeBreakdown {
+                    regime,
+                    trades: pips.len(),
+                    win_rate_percent: wins as f64 / pips.len() as f64 * 100.0,
+                    average_pips: pips.iter().sum::<f64>() / pips.len() as f64,
+                    win_rate_ci: statistics::wilson_interval(wins, pips.len()).map(|ci| ci.scaled(100.0)),
+                })
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,159 @@
+// services/backtest/execution.rs - Suivi d'une simulation : positions, recovery et résultats de sortie
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use super::models::*;
+use crate::models::Candle;
+use chrono::{DateTime, Utc};
+
+impl Position {
+    pub(super) fn ouvrir(
+        direction: Direction,
+        entry_price: f64,
+        stop_loss: f64,
+        entry_time: DateTime<Utc>,
+    ) -> Self {
+        Position {
+            direction,
+            entry_price,
+            entry_time,
+            stop_loss,
+            highest_price: entry_price,
+            lowest_price: entry_price,
+            mfe: 0.0,
+            mae: 0.0,
+        }
+    }
+
+    /// Met à jour extrêmes, MFE et MAE avec une nouvelle bougie
+    pub(super) fn suivre(&mut self, candle: &Candle) {
+        self.highest_price = self.highest_price.max(candle.high);
+        self.lowest_price = self.lowest_price.min(candle.low);
+        let (up, down) = (
+            self.highest_price - self.entry_price,
+            self.entry_price - self.lowest_price,
+        );
+        (self.mfe, self.mae) = match self.direction {
+            Direction::Long => (up, down),
+            Direction::Short => (down, up),
+        };
+    }
+}
+
+/// État cumulé d'une simulation d'événement (journal, jambes clôturées, résultat)
+pub(super) struct Suivi {
+    pub(super) event_time: DateTime<Utc>,
+    pub(super) point_value: f64,
+    pub(super) logs: Vec<String>,
+    pub(super) trades_count: u32,
+    pub(super) total_pips: f64,
+    pub(super) opposite_leg_delay: Option<i32>,
+}
+
+impl Suivi {
+    pub(super) fn new(event_time: DateTime<Utc>, point_value: f64) -> Self {
+        Suivi {
+            event_time,
+            point_value,
+            logs: Vec::new(),
+            trades_count: 0,
+            total_pips: 0.0,
+            opposite_leg_delay: None,
+        }
+    }
+
+    /// Jambe opposée (mode Simultané) ouverte au prix du SL de la première
+    pub(super) fn ouvrir_recovery(
+        &mut self,
+        stopped: &Position,
+        candle: &Candle,
+        config: &BacktestConfig,
+        spread_val: f64,
+    ) -> Position {
+        self.logs.push("Activation Recovery (Trade #2)".to_string());
+        self.opposite_leg_delay = Some((candle.datetime - stopped.entry_time).num_minutes() as i32);
+
+        let recovery_sl_pips = config.sl_recovery_pips.unwrap_or(config.stop_loss_pips);
+        let recovery_sl_dist = recovery_sl_pips * config.point_value;
+        let entry = stopped.stop_loss;
+        let (direction, sl, label) = match stopped.direction {
+            Direction::Long => (
+                Direction::Short,
+                entry + recovery_sl_dist + spread_val,
+                "SHORT",
+            ),
+            Direction::Short => (Direction::Long, entry - recovery_sl_dist, "LONG"),
+        };
+        self.logs
+            .push(format!("Entrée RECOVERY {} à {:.5}", label, entry));
+        Position::ouvrir(direction, entry, sl, candle.datetime)
+    }
+
+    /// Issue d'une sortie au stop (trailing en profit, double perte ou perte simple)
+    pub(super) fn issue_stop(&self, pips: f64) -> TradeOutcome {
+        if pips >= 0.0 {
+            TradeOutcome::TakeProfit // Trailing Stop en profit
+        } else if self.trades_count > 1 {
+            TradeOutcome::DoubleLoss
+        } else {
+            TradeOutcome::StopLoss
+        }
+    }
+
+    pub(super) fn cloture(
+        self,
+        pos: &Position,
+        exit_time: DateTime<Utc>,
+        duration_minutes: i32,
+        outcome: TradeOutcome,
+    ) -> TradeResult {
+        TradeResult {
+            event_date: self.event_time.to_rfc3339(),
+            entry_time: pos.entry_time.to_rfc3339(),
+            exit_time: exit_time.to_rfc3339(),
+            duration_minutes,
+            pips_net: self.total_pips,
+            outcome,
+            max_favorable_excursion: pos.mfe / self.point_value,
+            max_adverse_excursion: pos.mae / self.point_value,
+            logs: self.logs,
+            opposite_leg_delay_minutes: self.opposite_leg_delay,
+        }
+    }
+
+    /// Clôture au cours de clôture de la bougie (timeout ou fin des données)
+    pub(super) fn cloture_forcee(
+        mut self,
+        pos: &Position,
+        candle: &Candle,
+        spread_val: f64,
+    ) -> TradeResult {
+        let pips = match pos.direction {
+            Direction::Long => candle.close - pos.entry_price,
+            // Spread en sortie short
+            Direction::Short => pos.entry_price - (candle.close + spread_val),
+        } / self.point_value;
+        self.total_pips += pips;
+        let outcome = if self.trades_count > 0 {
+            TradeOutcome::RecoveryWin
+        } else {
+            TradeOutcome::Timeout
+        };
+        let elapsed = (candle.datetime - self.event_time).num_minutes() as i32;
+        self.cloture(pos, candle.datetime, elapsed, outcome)
+    }
+
+    pub(super) fn sans_entree(self, duration_minutes: i32) -> TradeResult {
+        TradeResult {
+            event_date: self.event_time.to_rfc3339(),
+            entry_time: "".to_string(),
+            exit_time: "".to_string(),
+            duration_minutes,
+            pips_net: 0.0,
+            outcome: TradeOutcome::NoEntry,
+            max_favorable_excursion: 0.0,
+            max_adverse_excursion: 0.0,
+            logs: self.logs,
+            opposite_leg_delay_minutes: None,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod engine;
+mod execution;
 pub mod models;
 pub mod simulator;
 
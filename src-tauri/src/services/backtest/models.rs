@@ -1,3 +1,5 @@
+use crate::models::input_fingerprint::InputFingerprint;
+use crate::models::{ConfidenceInterval, VolatilityRegime};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -39,6 +41,9 @@ pub struct TradeResult {
     pub max_favorable_excursion: f64, // MFE (en pips)
     pub max_adverse_excursion: f64,   // MAE (en pips)
     pub logs: Vec<String>, // Journal d'exécution pour comprendre le trade
+    /// Délai entre la première entrée et le déclenchement de la jambe opposée (whipsaw)
+    #[serde(default)]
+    pub opposite_leg_delay_minutes: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +62,31 @@ pub struct BacktestResult {
     pub profit_factor: f64,
     pub trades: Vec<TradeResult>,
     pub strategy_mode: StrategyMode,
+    /// Intervalle de Wilson du taux de réussite (en %)
+    #[serde(default)]
+    pub win_rate_ci: Option<ConfidenceInterval>,
+    /// Intervalle bootstrap du gain moyen par trade
+    #[serde(default)]
+    pub average_pips_ci: Option<ConfidenceInterval>,
+    /// Régime auquel les événements ont été restreints (None = tout l'historique)
+    #[serde(default)]
+    pub regime: Option<VolatilityRegime>,
+    /// Résultats ventilés selon le régime de volatilité du jour de chaque trade
+    #[serde(default)]
+    pub regime_breakdown: Vec<RegimeBreakdown>,
+    /// Données et paramètres d'entrée (reproductibilité)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<InputFingerprint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegimeBreakdown {
+    pub regime: VolatilityRegime,
+    /// Trades déclenchés (NoEntry exclus)
+    pub trades: usize,
+    pub win_rate_percent: f64,
+    pub average_pips: f64,
+    pub win_rate_ci: Option<ConfidenceInterval>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
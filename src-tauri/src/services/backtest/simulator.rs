@@ -1,3 +1,4 @@
+use super::execution::Suivi;
 use super::models::*;
 use crate::models::{CalendarEvent, Candle};
 
@@ -10,259 +11,139 @@ impl EventSimulator {
         config: &BacktestConfig,
         mode: StrategyMode,
     ) -> TradeResult {
-        let mut logs = Vec::new();
         let event_time = event.event_time.and_utc();
-        
+        let mut suivi = Suivi::new(event_time, config.point_value);
+
         // Trouver la bougie de référence (T0 ou juste avant)
         // On suppose qu'on place les ordres à l'ouverture de la bougie de l'événement
         let t0_candle = candles.iter().find(|c| c.datetime >= event_time);
-        
-        let reference_price = match t0_candle {
-            Some(c) => c.open,
-            None => return TradeResult {
-                event_date: event_time.to_rfc3339(),
-                entry_time: "".to_string(),
-                exit_time: "".to_string(),
-                duration_minutes: 0,
-                pips_net: 0.0,
-                outcome: TradeOutcome::NoEntry,
-                max_favorable_excursion: 0.0,
-                max_adverse_excursion: 0.0,
-                logs: vec!["Pas de données pour T0".to_string()],
-            }
+        let Some(reference_price) = t0_candle.map(|c| c.open) else {
+            suivi.logs.push("Pas de données pour T0".to_string());
+            return suivi.sans_entree(0);
         };
 
-        logs.push(format!("Prix référence (Open T0): {:.5}", reference_price));
+        suivi
+            .logs
+            .push(format!("Prix référence (Open T0): {:.5}", reference_price));
 
         // Calcul des niveaux d'entrée
+        // Spread historique de la minute si disponible, sinon le spread simulé de la config
+        let default_spread = config.spread_pips * config.point_value;
+        let entry_spread = t0_candle.map_or(default_spread, |c| c.spread_or(default_spread));
         let offset_val = config.offset_pips * config.point_value;
-        let spread_val = config.spread_pips * config.point_value;
-        
-        let buy_trigger = reference_price + offset_val + spread_val;
+
+        let buy_trigger = reference_price + offset_val + entry_spread;
         let sell_trigger = reference_price - offset_val;
 
-        logs.push(format!("Buy Stop: {:.5}, Sell Stop: {:.5}", buy_trigger, sell_trigger));
+        suivi.logs.push(format!(
+            "Buy Stop: {:.5}, Sell Stop: {:.5}",
+            buy_trigger, sell_trigger
+        ));
+        suivi.logs.push(format!(
+            "Spread à l'entrée: {:.1} pips ({})",
+            entry_spread / config.point_value,
+            if t0_candle.and_then(|c| c.spread).is_some() {
+                "historique"
+            } else {
+                "simulé"
+            }
+        ));
 
         // Simulation boucle par boucle
         let mut position: Option<Position> = None;
-        let mut trades_count = 0;
-        let mut total_pips_event = 0.0;
+        let mut derniere: Option<(&Candle, f64)> = None;
+        let sl_dist = config.stop_loss_pips * config.point_value;
+        let ts_dist = config.trailing_stop_pips * config.point_value;
 
         // On commence à scanner à partir de T0
         for candle in candles.iter().filter(|c| c.datetime >= event_time) {
+            let spread_val = candle.spread_or(default_spread);
+
             // Vérifier le Timeout
             let elapsed = (candle.datetime - event_time).num_minutes();
             if elapsed > config.timeout_minutes as i64 {
-                if let Some(pos) = position.as_ref() {
-                    // Clôture au Timeout
-                    let exit_price = if pos.direction == Direction::Long { candle.close } else { candle.close + spread_val }; // Spread en sortie short
-                    let pips = (if pos.direction == Direction::Long { exit_price - pos.entry_price } else { pos.entry_price - exit_price }) / config.point_value;
-                    total_pips_event += pips;
-                    
-                    return TradeResult {
-                        event_date: event_time.to_rfc3339(),
-                        entry_time: pos.entry_time.to_rfc3339(),
-                        exit_time: candle.datetime.to_rfc3339(),
-                        duration_minutes: elapsed as i32,
-                        pips_net: total_pips_event,
-                        outcome: if trades_count > 0 { TradeOutcome::RecoveryWin } else { TradeOutcome::Timeout },
-                        max_favorable_excursion: pos.mfe / config.point_value,
-                        max_adverse_excursion: pos.mae / config.point_value,
-                        logs,
-                    };
-                } else {
+                return match position {
+                    Some(pos) => suivi.cloture_forcee(&pos, candle, spread_val),
                     // Timeout sans entrée
-                    return TradeResult {
-                        event_date: event_time.to_rfc3339(),
-                        entry_time: "".to_string(),
-                        exit_time: "".to_string(),
-                        duration_minutes: elapsed as i32,
-                        pips_net: 0.0,
-                        outcome: TradeOutcome::NoEntry,
-                        max_favorable_excursion: 0.0,
-                        max_adverse_excursion: 0.0,
-                        logs,
-                    };
-                }
+                    None => suivi.sans_entree(elapsed as i32),
+                };
             }
+            derniere = Some((candle, spread_val));
 
             // Si pas de position, vérifier les déclenchements
+            // (exécution au prix trigger, slippage ignoré pour l'instant)
             if position.is_none() {
-                // Check Buy
-                if candle.high >= buy_trigger {
-                    // On suppose qu'on est exécuté au prix trigger (slippage ignoré pour l'instant)
-                    let entry = buy_trigger;
-                    let sl_dist = config.stop_loss_pips * config.point_value;
-                    let sl = entry - sl_dist;
-                    
-                    position = Some(Position {
-                        direction: Direction::Long,
-                        entry_price: entry,
-                        entry_time: candle.datetime,
-                        stop_loss: sl,
-                        highest_price: entry,
-                        lowest_price: entry,
-                        mfe: 0.0,
-                        mae: 0.0,
-                    });
-                    logs.push(format!("Entrée LONG à {:.5} (High: {:.5})", entry, candle.high));
-                }
-                // Check Sell (si pas Buy sur la même bougie, ou gestion priorité)
                 // Pour simplifier M1: si High touche Buy, on prend Buy.
                 // TODO: Gérer le cas où High et Low touchent (bougie volatile) -> souvent Whipsaw
-                else if candle.low <= sell_trigger {
+                if candle.high >= buy_trigger {
+                    let entry = buy_trigger;
+                    position = Some(Position::ouvrir(
+                        Direction::Long,
+                        entry,
+                        entry - sl_dist,
+                        candle.datetime,
+                    ));
+                    suivi.logs.push(format!(
+                        "Entrée LONG à {:.5} (High: {:.5})",
+                        entry, candle.high
+                    ));
+                } else if candle.low <= sell_trigger {
                     let entry = sell_trigger;
-                    let sl_dist = config.stop_loss_pips * config.point_value;
-                    let sl = entry + sl_dist + spread_val; // SL d'un short paie le spread
-                    
-                    position = Some(Position {
-                        direction: Direction::Short,
-                        entry_price: entry,
-                        entry_time: candle.datetime,
-                        stop_loss: sl,
-                        highest_price: entry,
-                        lowest_price: entry,
-                        mfe: 0.0,
-                        mae: 0.0,
-                    });
-                    logs.push(format!("Entrée SHORT à {:.5} (Low: {:.5})", entry, candle.low));
+                    // SL d'un short paie le spread
+                    position = Some(Position::ouvrir(
+                        Direction::Short,
+                        entry,
+                        entry + sl_dist + spread_val,
+                        candle.datetime,
+                    ));
+                    suivi.logs.push(format!(
+                        "Entrée SHORT à {:.5} (Low: {:.5})",
+                        entry, candle.low
+                    ));
                 }
             }
 
             // Si position active, gérer SL et Trailing
-            if let Some(pos) = position.as_mut() {
-                // Update MAE/MFE
-                if pos.direction == Direction::Long {
-                    if candle.high > pos.highest_price { pos.highest_price = candle.high; }
-                    if candle.low < pos.lowest_price { pos.lowest_price = candle.low; }
-                    pos.mfe = pos.highest_price - pos.entry_price;
-                    pos.mae = pos.entry_price - pos.lowest_price;
-
-                    // Check SL
-                    if candle.low <= pos.stop_loss {
-                        logs.push(format!("SL touché à {:.5}", pos.stop_loss));
-                        let pips = (pos.stop_loss - pos.entry_price) / config.point_value;
-                        total_pips_event += pips;
-                        trades_count += 1;
-                        
-                        // GESTION RECOVERY (Mode Simultané) - Uniquement si perte
-                        if mode == StrategyMode::Simultane && trades_count == 1 && pips < 0.0 {
-                            logs.push(format!("Activation Recovery (Trade #2)"));
-                            
-                            // Reverse position
-                            let recovery_sl_pips = config.sl_recovery_pips.unwrap_or(config.stop_loss_pips);
-                            let recovery_sl_dist = recovery_sl_pips * config.point_value;
-                            
-                            // Short entry at SL price
-                            let entry = pos.stop_loss;
-                            let sl = entry + recovery_sl_dist + spread_val;
-                            
-                            position = Some(Position {
-                                direction: Direction::Short,
-                                entry_price: entry,
-                                entry_time: candle.datetime,
-                                stop_loss: sl,
-                                highest_price: entry,
-                                lowest_price: entry,
-                                mfe: 0.0,
-                                mae: 0.0,
-                            });
-                            logs.push(format!("Entrée RECOVERY SHORT à {:.5}", entry));
-                            continue;
-                        }
-
-                        let outcome = if pips >= 0.0 {
-                            TradeOutcome::TakeProfit // Trailing Stop en profit
-                        } else if trades_count > 1 {
-                            TradeOutcome::DoubleLoss
-                        } else {
-                            TradeOutcome::StopLoss
-                        };
+            let Some(pos) = position.as_mut() else {
+                continue;
+            };
+            pos.suivre(candle);
+
+            // SL d'un short touché au prix Ask (High + Spread)
+            let (sl_touche, pips) = match pos.direction {
+                Direction::Long => (candle.low <= pos.stop_loss, pos.stop_loss - pos.entry_price),
+                Direction::Short => (
+                    candle.high + spread_val >= pos.stop_loss,
+                    pos.entry_price - pos.stop_loss,
+                ),
+            };
+            if sl_touche {
+                suivi.logs.push(format!("SL touché à {:.5}", pos.stop_loss));
+                let pips = pips / config.point_value;
+                suivi.total_pips += pips;
+                suivi.trades_count += 1;
+
+                // GESTION RECOVERY (Mode Simultané) - Uniquement si perte
+                if mode == StrategyMode::Simultane && suivi.trades_count == 1 && pips < 0.0 {
+                    let recovery = suivi.ouvrir_recovery(pos, candle, config, spread_val);
+                    position = Some(recovery);
+                    continue;
+                }
 
-                        return TradeResult {
-                            event_date: event_time.to_rfc3339(),
-                            entry_time: pos.entry_time.to_rfc3339(),
-                            exit_time: candle.datetime.to_rfc3339(),
-                            duration_minutes: (candle.datetime - pos.entry_time).num_minutes() as i32,
-                            pips_net: total_pips_event,
-                            outcome,
-                            max_favorable_excursion: pos.mfe / config.point_value,
-                            max_adverse_excursion: pos.mae / config.point_value,
-                            logs,
-                        };
-                    }
+                let outcome = suivi.issue_stop(pips);
+                let duration = (candle.datetime - pos.entry_time).num_minutes() as i32;
+                return suivi.cloture(pos, candle.datetime, duration, outcome);
+            }
 
-                    // Trailing Stop
-                    let ts_dist = config.trailing_stop_pips * config.point_value;
+            // Trailing Stop
+            match pos.direction {
+                Direction::Long => {
                     let new_sl = candle.close - ts_dist;
                     if new_sl > pos.stop_loss {
                         pos.stop_loss = new_sl;
-                        // logs.push(format!("Trailing Stop monté à {:.5}", new_sl));
-                    }
-
-                } else { // Short
-                    if candle.high > pos.highest_price { pos.highest_price = candle.high; }
-                    if candle.low < pos.lowest_price { pos.lowest_price = candle.low; }
-                    pos.mfe = pos.entry_price - pos.lowest_price;
-                    pos.mae = pos.highest_price - pos.entry_price;
-
-                    // Check SL (Ask price = High + Spread)
-                    let ask_high = candle.high + spread_val;
-                    if ask_high >= pos.stop_loss {
-                        logs.push(format!("SL touché à {:.5}", pos.stop_loss));
-                        let pips = (pos.entry_price - pos.stop_loss) / config.point_value;
-                        total_pips_event += pips;
-                        trades_count += 1;
-
-                        // GESTION RECOVERY (Mode Simultané) - Uniquement si perte
-                        if mode == StrategyMode::Simultane && trades_count == 1 && pips < 0.0 {
-                            logs.push(format!("Activation Recovery (Trade #2)"));
-                            
-                            // Reverse position
-                            let recovery_sl_pips = config.sl_recovery_pips.unwrap_or(config.stop_loss_pips);
-                            let recovery_sl_dist = recovery_sl_pips * config.point_value;
-                            
-                            // Long entry at SL price
-                            let entry = pos.stop_loss;
-                            let sl = entry - recovery_sl_dist;
-                            
-                            position = Some(Position {
-                                direction: Direction::Long,
-                                entry_price: entry,
-                                entry_time: candle.datetime,
-                                stop_loss: sl,
-                                highest_price: entry,
-                                lowest_price: entry,
-                                mfe: 0.0,
-                                mae: 0.0,
-                            });
-                            logs.push(format!("Entrée RECOVERY LONG à {:.5}", entry));
-                            continue;
-                        }
-                        
-                        let outcome = if pips >= 0.0 {
-                            TradeOutcome::TakeProfit // Trailing Stop en profit
-                        } else if trades_count > 1 {
-                            TradeOutcome::DoubleLoss
-                        } else {
-                            TradeOutcome::StopLoss
-                        };
-
-                        return TradeResult {
-                            event_date: event_time.to_rfc3339(),
-                            entry_time: pos.entry_time.to_rfc3339(),
-                            exit_time: candle.datetime.to_rfc3339(),
-                            duration_minutes: (candle.datetime - pos.entry_time).num_minutes() as i32,
-                            pips_net: total_pips_event,
-                            outcome,
-                            max_favorable_excursion: pos.mfe / config.point_value,
-                            max_adverse_excursion: pos.mae / config.point_value,
-                            logs,
-                        };
                     }
-
-                    // Trailing Stop
-                    let ts_dist = config.trailing_stop_pips * config.point_value;
+                }
+                Direction::Short => {
                     let new_sl = candle.close + ts_dist + spread_val;
                     if new_sl < pos.stop_loss {
                         pos.stop_loss = new_sl;
@@ -271,17 +152,100 @@ impl EventSimulator {
             }
         }
 
-        // Fin de boucle sans sortie (ne devrait pas arriver avec le check Timeout, mais au cas où)
-        TradeResult {
-            event_date: event_time.to_rfc3339(),
-            entry_time: "".to_string(),
-            exit_time: "".to_string(),
-            duration_minutes: 0,
-            pips_net: 0.0,
-            outcome: TradeOutcome::NoEntry,
-            max_favorable_excursion: 0.0,
-            max_adverse_excursion: 0.0,
-            logs,
+        // Données épuisées avant le timeout : une position (ou une recovery) ouverte
+        // est clôturée au dernier cours connu plutôt que comptée sans entrée
+        match (position, derniere) {
+            (Some(pos), Some((candle, spread_val))) => {
+                suivi
+                    .logs
+                    .push("Fin des données : clôture au dernier cours".to_string());
+                suivi.cloture_forcee(&pos, candle, spread_val)
+            }
+            _ => suivi.sans_entree(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn candle(minute: i64, high: f64, low: f64, close: f64) -> Candle {
+        Candle {
+            id: None,
+            symbol: "EURUSD".to_string(),
+            datetime: Utc
+                .with_ymd_and_hms(2024, 1, 2, 14, 30, 0)
+                .single()
+                .expect("date")
+                + Duration::minutes(minute),
+            open: 1.1000,
+            high,
+            low,
+            close,
+            volume: 1000.0,
+            spread: Some(0.0),
+        }
+    }
+
+    fn event() -> CalendarEvent {
+        let time = Utc
+            .with_ymd_and_hms(2024, 1, 2, 14, 30, 0)
+            .single()
+            .expect("date")
+            .naive_utc();
+        CalendarEvent {
+            id: 1,
+            symbol: "USD".to_string(),
+            event_time: time,
+            impact: "H".to_string(),
+            description: "CPI".to_string(),
+            actual: None,
+            forecast: None,
+            previous: None,
+            created_at: time,
+            calendar_import_id: 1,
         }
     }
+
+    fn config() -> BacktestConfig {
+        BacktestConfig {
+            offset_pips: 5.0,
+            stop_loss_pips: 5.0,
+            trailing_stop_pips: 20.0,
+            timeout_minutes: 30,
+            sl_recovery_pips: Some(5.0),
+            spread_pips: 0.0,
+            point_value: 0.0001,
+        }
+    }
+
+    #[test]
+    fn test_open_recovery_closed_when_data_ends() {
+        // Cassure haussière, retour sous le SL à T+2 puis fin des données à T+4
+        let candles = vec![
+            candle(0, 1.1006, 1.1001, 1.1005),
+            candle(1, 1.1006, 1.1003, 1.1005),
+            candle(2, 1.1005, 1.0995, 1.0996),
+            candle(3, 1.0997, 1.0990, 1.0991),
+            candle(4, 1.0992, 1.0985, 1.0986),
+        ];
+        let result =
+            EventSimulator::simulate(&event(), &candles, &config(), StrategyMode::Simultane);
+
+        assert_eq!(result.outcome, TradeOutcome::RecoveryWin);
+        assert_eq!(result.opposite_leg_delay_minutes, Some(2));
+        assert_eq!(result.duration_minutes, 4);
+        // -5 pips sur la première jambe, +14 sur la recovery (1.1000 → 1.0986)
+        assert!((result.pips_net - 9.0).abs() < 1e-6);
+
+        let directional = EventSimulator::simulate(
+            &event(),
+            &candles[..2],
+            &config(),
+            StrategyMode::Directionnel,
+        );
+        assert_eq!(directional.outcome, TradeOutcome::Timeout);
+    }
 }
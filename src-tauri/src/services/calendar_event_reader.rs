@@ -0,0 +1,53 @@
+// services/calendar_event_reader.rs - Lecture des annonces du calendrier (triées par date)
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use crate::db::DbPool;
+use crate::models::CalendarEvent;
+use crate::schema::calendar_events;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Accès en lecture au calendrier pour les analyses (sans passer par les commandes)
+pub struct CalendarEventReader {
+    pool: DbPool,
+}
+
+impl CalendarEventReader {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Tout le calendrier
+    pub fn tous(&self) -> Result<Vec<CalendarEvent>, String> {
+        self.charger(None)
+    }
+
+    /// Annonces de [debut, fin] (bornes incluses)
+    pub fn entre(
+        &self,
+        debut: NaiveDateTime,
+        fin: NaiveDateTime,
+    ) -> Result<Vec<CalendarEvent>, String> {
+        self.charger(Some((debut, fin)))
+    }
+
+    fn charger(
+        &self,
+        periode: Option<(NaiveDateTime, NaiveDateTime)>,
+    ) -> Result<Vec<CalendarEvent>, String> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| format!("Connection failed: {}", e))?;
+        let mut query = calendar_events::table
+            .select(CalendarEvent::as_select())
+            .order(calendar_events::event_time.asc())
+            .into_boxed();
+        if let Some((debut, fin)) = periode {
+            query = query.filter(calendar_events::event_time.between(debut, fin));
+        }
+        query
+            .load(&mut conn)
+            .map_err(|e| format!("Lecture du calendrier impossible: {}", e))
+    }
+}
@@ -0,0 +1,99 @@
+// services/candle_index/loading.rs - Chargement des paires dans l'index (CSV ou pairs.db)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::CandleIndex;
+use crate::models::Candle;
+use crate::services::CsvLoader;
+use chrono::DateTime;
+use std::collections::BTreeMap;
+
+impl CandleIndex {
+    /// Crée un index vide SANS charger les CSV (lazy loading)
+    /// Les paires sont chargées à la demande avec load_pair_candles()
+    #[allow(dead_code)]
+    pub fn new_lazy() -> Result<Self, String> {
+        // Juste vérifie que les symboles existent
+        let loader = CsvLoader::new();
+        let _symbols = loader
+            .list_available_symbols()
+            .map_err(|e| format!("Failed to list symbols: {}", e))?;
+
+        Ok(CandleIndex::new())
+    }
+
+    /// Charge et indexe ALL CSV files au démarrage
+    /// Appelé UNE SEULE FOIS au startup de l'app
+    /// NOTE: Cette fonction est conservée pour usage futur
+    #[allow(dead_code)]
+    pub fn load_all_pairs() -> Result<Self, String> {
+        let mut index = CandleIndex::new();
+        let loader = CsvLoader::new();
+
+        // Lister toutes les paires disponibles
+        let symbols = loader
+            .list_available_symbols()
+            .map_err(|e| format!("Failed to list symbols: {}", e))?;
+
+        // Charger et indexer chaque paire
+        for symbol in symbols {
+            let candles = loader
+                .load_candles(&symbol)
+                .map_err(|e| format!("Failed to load candles for {}: {}", symbol, e))?;
+
+            if !candles.is_empty() {
+                index.add_candles(&symbol, candles);
+            }
+        }
+
+        Ok(index)
+    }
+
+    /// Charge une paire spécifique à la demande (lazy loading)
+    /// Retourne true si la paire a été chargée, false si elle l'était déjà
+    /// Utilise DatabaseLoader si disponible, sinon fallback sur CsvLoader
+    pub fn load_pair_candles(&mut self, symbol: &str) -> Result<bool, String> {
+        // Vérifier si déjà chargée
+        if self.data.contains_key(symbol) {
+            return Ok(false); // Déjà en cache
+        }
+
+        let candles = if let Some(ref loader) = self.db_loader {
+            // Charger depuis la BD via DatabaseLoader
+            // Charger TOUTES les candles disponibles pour ce symbole
+            let start_time = DateTime::from_timestamp(0, 0)
+                .ok_or_else(|| "Failed to create start datetime".to_string())?;
+            let end_time =
+                DateTime::from_timestamp(2_000_000_000, 0) // ~2033
+                    .ok_or_else(|| "Failed to create end datetime".to_string())?;
+
+            loader
+                .load_candles_by_pair(symbol, "M1", start_time, end_time)
+                .map_err(|e| format!("Failed to load candles for {} from DB: {}", symbol, e))?
+        } else {
+            // Fallback sur CsvLoader si pas de DatabaseLoader
+            let loader = CsvLoader::new();
+            loader
+                .load_candles(symbol)
+                .map_err(|e| format!("Failed to load candles for {}: {}", symbol, e))?
+        };
+
+        if !candles.is_empty() {
+            self.add_candles(symbol, candles);
+            Ok(true) // Nouvelle paire chargée
+        } else {
+            Err(format!("No candles found for symbol: {}", symbol))
+        }
+    }
+
+    /// Ajoute des candles indexées par pair et date
+    fn add_candles(&mut self, symbol: &str, candles: Vec<Candle>) {
+        let mut date_map = BTreeMap::new();
+
+        for candle in candles {
+            let date = candle.datetime.date_naive();
+            date_map.entry(date).or_insert_with(Vec::new).push(candle);
+        }
+
+        self.data.insert(symbol.to_string(), date_map);
+    }
+}
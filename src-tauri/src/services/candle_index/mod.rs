@@ -1,12 +1,14 @@
-// services/candle_index.rs
+// services/candle_index/mod.rs
 // Index en mémoire pour recherche rapide de candles par date
 // Utilise BTreeMap pour requêtes range O(log n) au lieu de O(n) linéaire
 
-use crate::models::Candle;
-use crate::services::{CsvLoader, DatabaseLoader};
+use crate::models::{Candle, DailyBar};
+use crate::services::DatabaseLoader;
 use chrono::{DateTime, NaiveDate, Timelike, Utc};
 use std::collections::{BTreeMap, HashMap};
 
+mod loading;
+
 /// Structure pour stocker les candles indexées par paire et par date
 /// Permet des recherches O(log n) au lieu de O(n)
 pub struct CandleIndex {
@@ -33,93 +35,9 @@ impl CandleIndex {
         }
     }
 
-    /// Crée un index vide SANS charger les CSV (lazy loading)
-    /// Les paires sont chargées à la demande avec load_pair_candles()
-    #[allow(dead_code)]
-    pub fn new_lazy() -> Result<Self, String> {
-        // Juste vérifie que les symboles existent
-        let loader = CsvLoader::new();
-        let _symbols = loader
-            .list_available_symbols()
-            .map_err(|e| format!("Failed to list symbols: {}", e))?;
-
-        Ok(CandleIndex::new())
-    }
-
-    /// Charge et indexe ALL CSV files au démarrage
-    /// Appelé UNE SEULE FOIS au startup de l'app
-    /// NOTE: Cette fonction est conservée pour usage futur
-    #[allow(dead_code)]
-    pub fn load_all_pairs() -> Result<Self, String> {
-        let mut index = CandleIndex::new();
-        let loader = CsvLoader::new();
-
-        // Lister toutes les paires disponibles
-        let symbols = loader
-            .list_available_symbols()
-            .map_err(|e| format!("Failed to list symbols: {}", e))?;
-
-        // Charger et indexer chaque paire
-        for symbol in symbols {
-            let candles = loader
-                .load_candles(&symbol)
-                .map_err(|e| format!("Failed to load candles for {}: {}", symbol, e))?;
-
-            if !candles.is_empty() {
-                index.add_candles(&symbol, candles);
-            }
-        }
-
-        Ok(index)
-    }
-
-    /// Charge une paire spécifique à la demande (lazy loading)
-    /// Retourne true si la paire a été chargée, false si elle l'était déjà
-    /// Utilise DatabaseLoader si disponible, sinon fallback sur CsvLoader
-    pub fn load_pair_candles(&mut self, symbol: &str) -> Result<bool, String> {
-        // Vérifier si déjà chargée
-        if self.data.contains_key(symbol) {
-            return Ok(false); // Déjà en cache
-        }
-
-        let candles = if let Some(ref loader) = self.db_loader {
-            // Charger depuis la BD via DatabaseLoader
-            // Charger TOUTES les candles disponibles pour ce symbole
-            let start_time = DateTime::from_timestamp(0, 0)
-                .ok_or_else(|| "Failed to create start datetime".to_string())?;
-            let end_time =
-                DateTime::from_timestamp(2_000_000_000, 0) // ~2033
-                    .ok_or_else(|| "Failed to create end datetime".to_string())?;
-
-            loader
-                .load_candles_by_pair(symbol, "M1", start_time, end_time)
-                .map_err(|e| format!("Failed to load candles for {} from DB: {}", symbol, e))?
-        } else {
-            // Fallback sur CsvLoader si pas de DatabaseLoader
-            let loader = CsvLoader::new();
-            loader
-                .load_candles(symbol)
-                .map_err(|e| format!("Failed to load candles for {}: {}", symbol, e))?
-        };
-
-        if !candles.is_empty() {
-            self.add_candles(symbol, candles);
-            Ok(true) // Nouvelle paire chargée
-        } else {
-            Err(format!("No candles found for symbol: {}", symbol))
-        }
-    }
-
-    /// Ajoute des candles indexées par pair et date
-    fn add_candles(&mut self, symbol: &str, candles: Vec<Candle>) {
-        let mut date_map = BTreeMap::new();
-
-        for candle in candles {
-            let date = candle.datetime.date_naive();
-            date_map.entry(date).or_insert_with(Vec::new).push(candle);
-        }
-
-        self.data.insert(symbol.to_string(), date_map);
+    /// Accès à pairs.db (None pour un index chargé depuis les CSV)
+    pub fn db_loader(&self) -> Option<&DatabaseLoader> {
+        self.db_loader.as_ref()
     }
 
     /// Retourne true si une paire est chargée, false sinon
@@ -136,6 +54,19 @@ impl CandleIndex {
             .map(|date_map| date_map.values().flat_map(|v| v.clone()).collect())
     }
 
+    /// Barres journalières d'une paire chargée (pour le classement en régimes)
+    pub fn daily_bars(&self, symbol: &str) -> Vec<DailyBar> {
+        self.data
+            .get(symbol)
+            .map(|date_map| {
+                date_map
+                    .values()
+                    .filter_map(|candles| DailyBar::from_candles(candles))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Récupère les candles DANS UNE PLAGE DE DATES (optimisé O(log n))
     /// Important: retourne les candles comme Vec<(DateTime, high, low)> pour compatibilité
     pub fn get_candles_in_range(
@@ -0,0 +1,40 @@
+// services/config_service/alert_rules.rs - Persistance des règles d'alerte dans la configuration
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::ConfigService;
+use crate::models::alerting::AlertRule;
+use anyhow::{anyhow, Result};
+
+impl ConfigService {
+    /// Obtient les règles d'alerte
+    pub fn get_alert_rules() -> Result<Vec<AlertRule>> {
+        let config = Self::load_config()?;
+        Ok(config.alert_rules)
+    }
+
+    /// Ajoute ou remplace (même identifiant) une règle d'alerte
+    pub fn save_alert_rule(rule: AlertRule) -> Result<()> {
+        rule.validate().map_err(|e| anyhow!(e))?;
+
+        let mut config = Self::load_config()?;
+        match config.alert_rules.iter_mut().find(|r| r.id == rule.id) {
+            Some(existing) => *existing = rule,
+            None => config.alert_rules.push(rule),
+        }
+        config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+        Self::save_config(&config)
+    }
+
+    /// Supprime une règle d'alerte ; renvoie false si elle n'existait pas
+    pub fn delete_alert_rule(id: &str) -> Result<bool> {
+        let mut config = Self::load_config()?;
+        let before = config.alert_rules.len();
+        config.alert_rules.retain(|r| r.id != id);
+        if config.alert_rules.len() == before {
+            return Ok(false);
+        }
+        config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+        Self::save_config(&config)?;
+        Ok(true)
+    }
+}
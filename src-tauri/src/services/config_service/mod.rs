@@ -0,0 +1,281 @@
+//! Service de gestion de la configuration de l'application
+//! Gère la persistance du fichier calendrier sélectionné, des profils d'import,
+//! des pondérations calibrées du score de confiance, de la demi-vie des agrégations,
+//! de l'horloge serveur du courtier, des glissements observés sur les trades réels
+//! et des règles d'alerte sur les annonces à venir
+
+use crate::models::alerting::AlertRule;
+use crate::models::asset_class::AssetType;
+use crate::models::trade_journal::ExecutionCost;
+use crate::models::{BrokerClock, ScoreWeightSet, ScoreWeights, TimeDecay};
+use crate::services::pair_data::ColumnMappingProfile;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+mod alert_rules;
+
+/// Configuration de l'application
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AppConfig {
+    /// Fichier calendrier actuellement sélectionné
+    pub selected_calendar_file: Option<String>,
+
+    /// Timestamp de dernière mise à jour
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<String>,
+
+    /// Profils de colonnes pour les exports de paires non reconnus
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub import_profiles: Vec<ColumnMappingProfile>,
+
+    /// Pondérations du score de confiance calibrées par classe d'actifs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub score_weights: Vec<ScoreWeightSet>,
+
+    /// Pondération par ancienneté appliquée à toutes les agrégations historiques
+    #[serde(default)]
+    pub time_decay: TimeDecay,
+
+    /// Heure serveur du courtier MT5 (plans exportés, historiques de transactions)
+    #[serde(default)]
+    pub broker_clock: BrokerClock,
+
+    /// Glissements mesurés sur le journal des trades (remplacent ceux du modèle de coûts)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub execution_costs: Vec<ExecutionCost>,
+
+    /// Règles d'alerte évaluées en tâche de fond sur les annonces projetées
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alert_rules: Vec<AlertRule>,
+}
+
+/// Service de configuration
+pub struct ConfigService;
+
+impl ConfigService {
+    /// Obtient le chemin du fichier de configuration
+    fn get_config_path() -> Result<PathBuf> {
+        let data_dir =
+            dirs::data_local_dir().context("Impossible de trouver le dossier de données local")?;
+
+        let app_dir = data_dir.join("volatility-analyzer");
+        fs::create_dir_all(&app_dir).context("Impossible de créer le dossier de l'application")?;
+
+        Ok(app_dir.join("config.json"))
+    }
+
+    /// Charge la configuration depuis le fichier
+    pub fn load_config() -> Result<AppConfig> {
+        let config_path = Self::get_config_path()?;
+
+        if !config_path.exists() {
+            // Créer une config par défaut si le fichier n'existe pas
+            let default_config = AppConfig::default();
+            Self::save_config(&default_config)?;
+            return Ok(default_config);
+        }
+
+        let content = fs::read_to_string(&config_path)
+            .context("Impossible de lire le fichier de configuration")?;
+
+        let config: AppConfig = serde_json::from_str(&content)
+            .context("Impossible de parser le fichier de configuration")?;
+
+        Ok(config)
+    }
+
+    /// Sauvegarde la configuration dans le fichier
+    pub fn save_config(config: &AppConfig) -> Result<()> {
+        let config_path = Self::get_config_path()?;
+
+        let content = serde_json::to_string_pretty(config)
+            .context("Impossible de sérialiser la configuration")?;
+
+        fs::write(&config_path, content)
+            .context("Impossible d'écrire le fichier de configuration")?;
+
+        Ok(())
+    }
+
+    /// Définit le fichier calendrier sélectionné
+    pub fn set_selected_calendar_file(filename: String) -> Result<()> {
+        let mut config = Self::load_config()?;
+        config.selected_calendar_file = Some(filename);
+        config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+        Self::save_config(&config)?;
+        Ok(())
+    }
+
+    /// Obtient le fichier calendrier sélectionné
+    pub fn get_selected_calendar_file() -> Result<Option<String>> {
+        let config = Self::load_config()?;
+        Ok(config.selected_calendar_file)
+    }
+
+    /// Obtient les profils de colonnes d'import
+    pub fn get_import_profiles() -> Result<Vec<ColumnMappingProfile>> {
+        let config = Self::load_config()?;
+        Ok(config.import_profiles)
+    }
+
+    /// Ajoute ou remplace (même nom) un profil de colonnes d'import
+    pub fn save_import_profile(profile: ColumnMappingProfile) -> Result<()> {
+        profile.validate().map_err(|e| anyhow!(e))?;
+
+        let mut config = Self::load_config()?;
+        config.import_profiles.retain(|p| p.name != profile.name);
+        config.import_profiles.push(profile);
+        config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+        Self::save_config(&config)
+    }
+
+    /// Supprime un profil de colonnes d'import ; renvoie false s'il n'existait pas
+    pub fn delete_import_profile(name: &str) -> Result<bool> {
+        let mut config = Self::load_config()?;
+        let before = config.import_profiles.len();
+        config.import_profiles.retain(|p| p.name != name);
+        if config.import_profiles.len() == before {
+            return Ok(false);
+        }
+        config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+        Self::save_config(&config)?;
+        Ok(true)
+    }
+
+    /// Obtient les pondérations calibrées enregistrées
+    pub fn get_score_weight_sets() -> Result<Vec<ScoreWeightSet>> {
+        let config = Self::load_config()?;
+        Ok(config.score_weights)
+    }
+
+    /// Enregistre des pondérations calibrées (remplace celles des mêmes classes d'actifs)
+    pub fn save_score_weight_sets(sets: &[ScoreWeightSet]) -> Result<()> {
+        let mut config = Self::load_config()?;
+        config
+            .score_weights
+            .retain(|w| !sets.iter().any(|s| s.asset_type == w.asset_type));
+        config.score_weights.extend(sets.iter().cloned());
+        config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+        Self::save_config(&config)
+    }
+
+    /// Revient au barème par défaut (une classe ou toutes) ; renvoie le nombre supprimé
+    pub fn reset_score_weights(asset_type: Option<AssetType>) -> Result<usize> {
+        let mut config = Self::load_config()?;
+        let before = config.score_weights.len();
+        config
+            .score_weights
+            .retain(|w| asset_type.is_some_and(|t| t != w.asset_type));
+        let removed = before - config.score_weights.len();
+        if removed > 0 {
+            config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+            Self::save_config(&config)?;
+        }
+        Ok(removed)
+    }
+
+    /// Pondération à appliquer pour une classe d'actifs (barème par défaut sinon)
+    pub fn score_weights_for(asset_type: AssetType) -> ScoreWeights {
+        match Self::get_score_weight_sets() {
+            Ok(sets) => sets
+                .into_iter()
+                .find(|s| s.asset_type == asset_type && s.informative)
+                .map(|s| s.weights)
+                .unwrap_or_default(),
+            Err(e) => {
+                tracing::warn!("⚠️ Pondérations du score illisibles, barème par défaut: {}", e);
+                ScoreWeights::default()
+            }
+        }
+    }
+
+    /// Enregistre la demi-vie (None = toutes les observations pèsent pareil)
+    pub fn set_time_decay(decay: TimeDecay) -> Result<()> {
+        if decay.half_life_days.is_some() && !decay.is_enabled() {
+            return Err(anyhow!("La demi-vie doit être strictement positive"));
+        }
+        let mut config = Self::load_config()?;
+        config.time_decay = decay;
+        config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+        Self::save_config(&config)
+    }
+
+    /// Pondération temporelle configurée (aucune si la config est illisible)
+    pub fn time_decay() -> TimeDecay {
+        match Self::load_config() {
+            Ok(config) => config.time_decay,
+            Err(e) => {
+                tracing::warn!("⚠️ Configuration illisible, pas de pondération temporelle: {}", e);
+                TimeDecay::default()
+            }
+        }
+    }
+
+    /// Enregistre l'horloge serveur du courtier
+    pub fn set_broker_clock(clock: BrokerClock) -> Result<()> {
+        clock.validate().map_err(|e| anyhow!(e))?;
+        let mut config = Self::load_config()?;
+        config.broker_clock = clock;
+        config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+        Self::save_config(&config)
+    }
+
+    /// Horloge serveur configurée (UTC+2/+3 par défaut si la config est illisible)
+    pub fn broker_clock() -> BrokerClock {
+        match Self::load_config() {
+            Ok(config) => config.broker_clock,
+            Err(e) => {
+                tracing::warn!("⚠️ Configuration illisible, horloge courtier par défaut: {}", e);
+                BrokerClock::default()
+            }
+        }
+    }
+
+    /// Enregistre des glissements observés (remplace ceux des mêmes instruments)
+    pub fn save_execution_costs(costs: &[ExecutionCost]) -> Result<()> {
+        let mut config = Self::load_config()?;
+        config
+            .execution_costs
+            .retain(|c| !costs.iter().any(|n| n.symbol == c.symbol));
+        config.execution_costs.extend(costs.iter().cloned());
+        config.last_updated = Some(chrono::Utc::now().to_rfc3339());
+        Self::save_config(&config)
+    }
+
+    /// Glissements observés (vide : modèle de coûts par défaut), à lire une fois par analyse
+    pub fn execution_costs() -> Vec<ExecutionCost> {
+        match Self::load_config() {
+            Ok(config) => config.execution_costs,
+            Err(e) => {
+                tracing::warn!("⚠️ Configuration illisible, glissements du modèle: {}", e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = AppConfig::default();
+        assert!(config.selected_calendar_file.is_none());
+        assert!(config.last_updated.is_none());
+        assert!(config.import_profiles.is_empty());
+        assert!(config.score_weights.is_empty());
+        assert!(!config.time_decay.is_enabled());
+        assert!(config.execution_costs.is_empty());
+        assert!(config.alert_rules.is_empty());
+    }
+
+    #[test]
+    fn test_config_without_profiles_still_parses() {
+        let config: AppConfig =
+            serde_json::from_str(r#"{"selected_calendar_file":"cal.csv"}"#).expect("config");
+        assert!(config.import_profiles.is_empty());
+    }
+}
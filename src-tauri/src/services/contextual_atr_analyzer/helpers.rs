@@ -1,4 +1,5 @@
 use crate::models::{Candle, Result, VolatilityError};
+use crate::services::indicators::{rolling_atr, true_ranges, AtrSmoothing, M1_STEP_MINUTES};
 use crate::services::metrics::MetricsCalculator;
 use chrono::Duration;
 
@@ -108,20 +109,18 @@ pub fn trouver_pic_atr_max(
         ));
     }
 
+    // ATR glissant calculé en une passe : la valeur j correspond à la bougie j + période - 1
+    let atr_values = rolling_atr(
+        &true_ranges(&post_candles, M1_STEP_MINUTES),
+        atr_period,
+        AtrSmoothing::Wilder,
+    );
     let mut max_atr = 0.0;
     let mut max_index = 0;
-
-    for i in atr_period..post_candles.len() {
-        let window = &post_candles[i - atr_period..i];
-        let calculator = MetricsCalculator::new(window);
-
-        if let Ok(atr_values) = calculator.calculer_atr(atr_period) {
-            if let Some(&atr) = atr_values.last() {
-                if atr > max_atr {
-                    max_atr = atr;
-                    max_index = i;
-                }
-            }
+    for (j, &atr) in atr_values.iter().enumerate() {
+        if atr > max_atr {
+            max_atr = atr;
+            max_index = j + atr_period.saturating_sub(1);
         }
     }
 
@@ -0,0 +1,279 @@
+// services/cross_asset_reaction.rs - Réaction multi-actifs à une même publication :
+// amplitude, sens, avance/retard
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::{
+    AssetProperties, Candle, CrossAssetOccurrence, InstrumentReaction, InstrumentReactionSummary,
+};
+use crate::services::SymbolRegistryService;
+use chrono::{DateTime, Duration, Utc};
+
+/// Fenêtre de réaction mesurée après la publication (minutes)
+pub const REACTION_WINDOW_MINUTES: i64 = 30;
+/// Part de l'excursion maximale marquant le moment de la réaction
+const REACTION_THRESHOLD: f64 = 0.5;
+
+pub struct CrossAssetAnalyzer;
+
+impl CrossAssetAnalyzer {
+    /// +1 si l'instrument monte quand la devise s'apprécie, -1 si elle est en cotation
+    pub fn currency_orientation(symbol: &str, currency: &str) -> i8 {
+        let (_, base, quote) = SymbolRegistryService::classify(symbol);
+        let is = |c: &Option<String>| {
+            c.as_deref()
+                .is_some_and(|c| c.eq_ignore_ascii_case(currency))
+        };
+        if is(&quote) && !is(&base) {
+            -1
+        } else {
+            1
+        }
+    }
+
+    /// Mesure la réaction d'un instrument (bougies de la fenêtre T0 → T+30)
+    pub fn mesurer_reaction(
+        symbol: &str,
+        currency: &str,
+        event_dt: DateTime<Utc>,
+        candles: &[Candle],
+        multiplier: f64,
+    ) -> InstrumentReaction {
+        let end = event_dt + Duration::minutes(REACTION_WINDOW_MINUTES);
+        let window: Vec<&Candle> = candles
+            .iter()
+            .filter(|c| c.datetime >= event_dt && c.datetime < end)
+            .collect();
+        let (Some(first), Some(last)) = (window.first(), window.last()) else {
+            return InstrumentReaction {
+                symbol: symbol.to_string(),
+                has_data: false,
+                peak_pips: 0.0,
+                multiplier: 0.0,
+                direction: 0,
+                currency_direction: 0,
+                reaction_minute: None,
+                lag_minutes: None,
+            };
+        };
+
+        let open = first.open;
+        let excursions: Vec<(DateTime<Utc>, f64)> = window
+            .iter()
+            .map(|c| (c.datetime, (c.high - open).abs().max((open - c.low).abs())))
+            .collect();
+        let peak = excursions.iter().map(|(_, e)| *e).fold(0.0_f64, f64::max);
+        let reaction_minute = (peak > 0.0)
+            .then(|| {
+                excursions
+                    .iter()
+                    .find(|(_, e)| *e >= peak * REACTION_THRESHOLD)
+                    .map(|(t, _)| (*t - event_dt).num_minutes())
+            })
+            .flatten();
+
+        let move_end = last.close - open;
+        let direction = if move_end > 0.0 {
+            1
+        } else if move_end < 0.0 {
+            -1
+        } else {
+            0
+        };
+
+        InstrumentReaction {
+            symbol: symbol.to_string(),
+            has_data: true,
+            peak_pips: AssetProperties::from_symbol(symbol).normalize(peak),
+            multiplier,
+            direction,
+            currency_direction: direction * Self::currency_orientation(symbol, currency),
+            reaction_minute,
+            lag_minutes: None,
+        }
+    }
+
+    /// Complète une occurrence : retards relatifs, accord de sens, meilleur instrument
+    pub fn assembler_occurrence(
+        event_id: i32,
+        datetime: String,
+        currency: String,
+        mut reactions: Vec<InstrumentReaction>,
+    ) -> CrossAssetOccurrence {
+        let fastest = reactions.iter().filter_map(|r| r.reaction_minute).min();
+        for reaction in &mut reactions {
+            reaction.lag_minutes = reaction.reaction_minute.zip(fastest).map(|(m, f)| m - f);
+        }
+
+        let majority: i32 = reactions.iter().map(|r| r.currency_direction as i32).sum();
+        let moving: Vec<&InstrumentReaction> = reactions
+            .iter()
+            .filter(|r| r.currency_direction != 0)
+            .collect();
+        let direction_agreement = if moving.is_empty() || majority == 0 {
+            0.0
+        } else {
+            let agree = moving
+                .iter()
+                .filter(|r| r.currency_direction as i32 == majority.signum())
+                .count();
+            agree as f64 / moving.len() as f64
+        };
+
+        let best_symbol = reactions
+            .iter()
+            .filter(|r| r.has_data && r.multiplier > 0.0)
+            .max_by(|a, b| {
+                a.multiplier
+                    .partial_cmp(&b.multiplier)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|r| r.symbol.clone());
+
+        CrossAssetOccurrence {
+            event_id,
+            datetime,
+            currency,
+            reactions,
+            direction_agreement,
+            best_symbol,
+        }
+    }
+
+    /// Synthèse par instrument
+    pub fn resumer(
+        symbols: &[String],
+        occurrences: &[CrossAssetOccurrence],
+    ) -> Vec<InstrumentReactionSummary> {
+        symbols
+            .iter()
+            .map(|symbol| {
+                let mut multipliers = Vec::new();
+                let mut peaks = Vec::new();
+                let mut lags = Vec::new();
+                let (mut agree, mut oriented) = (0usize, 0usize);
+                for occurrence in occurrences {
+                    let Some(r) = occurrence
+                        .reactions
+                        .iter()
+                        .find(|r| &r.symbol == symbol && r.has_data)
+                    else {
+                        continue;
+                    };
+                    multipliers.push(r.multiplier);
+                    peaks.push(r.peak_pips);
+                    lags.extend(r.lag_minutes.map(|l| l as f64));
+                    let majority: i32 = occurrence
+                        .reactions
+                        .iter()
+                        .map(|o| o.currency_direction as i32)
+                        .sum();
+                    if r.currency_direction != 0 && majority != 0 {
+                        oriented += 1;
+                        if r.currency_direction as i32 == majority.signum() {
+                            agree += 1;
+                        }
+                    }
+                }
+                let moyenne = |v: &[f64]| {
+                    if v.is_empty() {
+                        0.0
+                    } else {
+                        v.iter().sum::<f64>() / v.len() as f64
+                    }
+                };
+                InstrumentReactionSummary {
+                    symbol: symbol.clone(),
+                    occurrences_with_data: multipliers.len(),
+                    mean_multiplier: moyenne(&multipliers),
+                    mean_peak_pips: moyenne(&peaks),
+                    mean_lag_minutes: (!lags.is_empty()).then(|| moyenne(&lags)),
+                    best_count: occurrences
+                        .iter()
+                        .filter(|o| o.best_symbol.as_deref() == Some(symbol.as_str()))
+                        .count(),
+                    agreement_rate: if oriented == 0 {
+                        0.0
+                    } else {
+                        agree as f64 / oriented as f64
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_currency_orientation() {
+        assert_eq!(
+            CrossAssetAnalyzer::currency_orientation("EURUSD", "usd"),
+            -1
+        );
+        assert_eq!(
+            CrossAssetAnalyzer::currency_orientation("USDJPY.r", "USD"),
+            1
+        );
+        assert_eq!(CrossAssetAnalyzer::currency_orientation("US30", "USD"), 1);
+    }
+
+    fn chemin(symbol: &str, t0: DateTime<Utc>, closes: &[f64]) -> Vec<Candle> {
+        let mut open = closes.first().copied().unwrap_or(1.0);
+        closes
+            .iter()
+            .enumerate()
+            .map(|(m, &close)| {
+                let candle = Candle {
+                    id: None,
+                    symbol: symbol.to_string(),
+                    datetime: t0 + Duration::minutes(m as i64),
+                    open,
+                    high: open.max(close),
+                    low: open.min(close),
+                    close,
+                    volume: 1.0,
+                    spread: None,
+                };
+                open = close;
+                candle
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_usd_strength_agrees_across_quote_and_base_pairs() {
+        let t0 = Utc
+            .with_ymd_and_hms(2024, 4, 10, 12, 30, 0)
+            .single()
+            .unwrap_or_default();
+        // Dollar fort : EURUSD baisse vite, USDJPY monte avec 2 minutes de retard
+        let eurusd = chemin("EURUSD", t0, &[1.0800, 1.0790, 1.0780, 1.0780, 1.0780]);
+        let usdjpy = chemin("USDJPY", t0, &[150.00, 150.00, 150.00, 150.25, 150.40]);
+
+        let reactions = vec![
+            CrossAssetAnalyzer::mesurer_reaction("EURUSD", "USD", t0, &eurusd, 3.0),
+            CrossAssetAnalyzer::mesurer_reaction("USDJPY", "USD", t0, &usdjpy, 2.0),
+        ];
+        assert_eq!(reactions[0].currency_direction, 1);
+        assert_eq!(reactions[1].currency_direction, 1);
+
+        let occurrence = CrossAssetAnalyzer::assembler_occurrence(
+            1,
+            "2024-04-10 12:30:00".into(),
+            "USD".into(),
+            reactions,
+        );
+        assert_eq!(occurrence.direction_agreement, 1.0);
+        assert_eq!(occurrence.best_symbol.as_deref(), Some("EURUSD"));
+        assert_eq!(occurrence.reactions[0].lag_minutes, Some(0));
+        assert_eq!(occurrence.reactions[1].lag_minutes, Some(2));
+
+        let summary =
+            CrossAssetAnalyzer::resumer(&["EURUSD".into(), "USDJPY".into()], &[occurrence]);
+        assert_eq!(summary[0].best_count, 1);
+        assert_eq!(summary[1].mean_lag_minutes, Some(2.0));
+    }
+}
@@ -55,7 +55,8 @@ pub fn clean_european_csv(input_path: &str, output_dir: &Path) -> Result<Cleanin
         report.lines_processed += 1;
 
         if line_num == 0 {
-            writeln!(output_file, "timestamp,open,high,low,close,volume")
+            // Colonne spread toujours présente (vide si la source ne l'exporte pas)
+            writeln!(output_file, "timestamp,open,high,low,close,volume,spread")
                 .map_err(|e| format!("Header: {}", e))?;
             continue;
         }
@@ -99,7 +100,7 @@ pub fn clean_european_csv(input_path: &str, output_dir: &Path) -> Result<Cleanin
 }
 
 /// Nettoie une ligne individuelle
-fn clean_line(line: &str) -> Result<String, String> {
+pub(crate) fn clean_line(line: &str) -> Result<String, String> {
     // Détecter le délimiteur : point-virgule (nouveau format) ou virgule (ancien)
     let delimiter = if line.contains(';') { ';' } else { ',' };
 
@@ -108,23 +109,24 @@ fn clean_line(line: &str) -> Result<String, String> {
         return Err("Format invalide".to_string());
     }
 
-    // Format point-virgule (nouveau) : 6 colonnes directes
+    // Format point-virgule (nouveau) : 6 colonnes directes, 7 avec le spread (points MT5)
     if delimiter == ';' {
-        if parts.len() != 6 {
-            return Err(format!("Attendu 6 colonnes, trouvé {}", parts.len()));
+        if parts.len() != 6 && parts.len() != 7 {
+            return Err(format!("Attendu 6 ou 7 colonnes, trouvé {}", parts.len()));
         }
 
-        // Time (UTC);Open;High;Low;Close;Volume
+        // Time (UTC);Open;High;Low;Close;Volume[;Spread]
         let timestamp = parts[0];
         let open = parts[1].replace(',', ".");
         let high = parts[2].replace(',', ".");
         let low = parts[3].replace(',', ".");
         let close = parts[4].replace(',', ".");
         let volume = parts[5].replace(',', ".");
+        let spread = parts.get(6).map(|s| s.replace(',', ".")).unwrap_or_default();
 
         return Ok(format!(
-            "{},{},{},{},{},{}",
-            timestamp, open, high, low, close, volume
+            "{},{},{},{},{},{},{}",
+            timestamp, open, high, low, close, volume, spread
         ));
     }
 
@@ -160,7 +162,7 @@ fn clean_line(line: &str) -> Result<String, String> {
     let ohlcv = reconstruct_ohlcv(&values)?;
 
     Ok(format!(
-        "{},{},{},{},{},{}",
+        "{},{},{},{},{},{},",
         timestamp, ohlcv[0], ohlcv[1], ohlcv[2], ohlcv[3], ohlcv[4]
     ))
 }
@@ -0,0 +1,134 @@
+// services/database_loader/history.rs - Barres journalières et étendue de l'historique d'une paire
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::{DatabaseLoader, LoaderError};
+use crate::models::input_fingerprint::DataRange;
+use crate::models::DailyBar;
+use tracing::{info, instrument};
+
+impl DatabaseLoader {
+    /// Agrège l'historique M1 d'un symbole en barres journalières (UTC)
+    ///
+    /// Le regroupement se fait en SQL sur la date (10 premiers caractères de `time`)
+    /// pour éviter de charger des millions de bougies en mémoire.
+    #[instrument(skip(self))]
+    pub fn load_daily_bars(&self, symbol: &str) -> Result<Vec<DailyBar>, LoaderError> {
+        let conn = self.connexion()?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT day, MAX(high), MIN(low), MAX(last_close) FROM (
+                     SELECT substr(time, 1, 10) AS day, high, low,
+                            LAST_VALUE(close) OVER (
+                                PARTITION BY substr(time, 1, 10) ORDER BY time
+                                ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+                            ) AS last_close
+                     FROM candle_data
+                     WHERE symbol = ? AND timeframe = 'M1'
+                 )
+                 GROUP BY day
+                 ORDER BY day ASC",
+            )
+            .map_err(|e| LoaderError::Query(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(rusqlite::params![symbol], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, f64>(1)?,
+                    row.get::<_, f64>(2)?,
+                    row.get::<_, f64>(3)?,
+                ))
+            })
+            .map_err(|e| LoaderError::Query(e.to_string()))?;
+
+        let mut bars = Vec::new();
+        for row in rows {
+            let (day, high, low, close) = row.map_err(|e| LoaderError::Query(e.to_string()))?;
+            let date = chrono::NaiveDate::parse_from_str(&day, "%Y-%m-%d")
+                .map_err(|e| LoaderError::Parsing(format!("Invalid date {}: {}", day, e)))?;
+            bars.push(DailyBar {
+                date,
+                high,
+                low,
+                close,
+            });
+        }
+
+        info!("Loaded {} daily bars for {}", bars.len(), symbol);
+        Ok(bars)
+    }
+
+    /// Empreinte de l'historique d'une paire/timeframe : nombre de candles et bornes, dernier
+    /// import (une fusion ou un import Ask réécrit des candles sans changer les bornes) et
+    /// candles alternatives (restaurées à la suppression d'une source)
+    #[instrument(skip(self))]
+    pub fn data_version(&self, symbol: &str, timeframe: &str) -> Result<String, LoaderError> {
+        let range = self.candle_range(symbol, timeframe)?;
+        let conn = self.connexion()?;
+        let (last_import, alternates): (Option<i64>, i64) = conn
+            .query_row(
+                "SELECT (SELECT MAX(id) FROM import_log WHERE symbol = ?1 AND timeframe = ?2),
+                        (SELECT COUNT(*) FROM candle_alternates WHERE symbol = ?1 AND timeframe = ?2)",
+                rusqlite::params![symbol, timeframe],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map_err(|e| LoaderError::Query(e.to_string()))?;
+
+        Ok(format!(
+            "{}:{}:{}:{}:{}",
+            range.candle_count,
+            range.first.unwrap_or_default(),
+            range.last.unwrap_or_default(),
+            last_import.unwrap_or_default(),
+            alternates
+        ))
+    }
+
+    /// Version de toute la base (dernier import, lignes par paire, candles alternatives), lue
+    /// sur de petites tables pour pouvoir être vérifiée à chaque tick de surveillance
+    #[instrument(skip(self))]
+    pub fn store_version(&self) -> Result<String, LoaderError> {
+        let conn = self.connexion()?;
+        let (last_import, pairs, rows, alternates): (Option<i64>, i64, Option<i64>, i64) = conn
+            .query_row(
+                "SELECT (SELECT MAX(id) FROM import_log),
+                        (SELECT COUNT(*) FROM pair_metadata),
+                        (SELECT SUM(row_count) FROM pair_metadata),
+                        (SELECT COUNT(*) FROM candle_alternates)",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .map_err(|e| LoaderError::Query(e.to_string()))?;
+
+        Ok(format!(
+            "{}:{}:{}:{}",
+            last_import.unwrap_or_default(),
+            pairs,
+            rows.unwrap_or_default(),
+            alternates
+        ))
+    }
+
+    /// Nombre de candles et bornes de l'historique d'une paire/timeframe
+    #[instrument(skip(self))]
+    pub fn candle_range(&self, symbol: &str, timeframe: &str) -> Result<DataRange, LoaderError> {
+        let conn = self.connexion()?;
+
+        let (candle_count, first, last): (i64, Option<String>, Option<String>) = conn
+            .query_row(
+                "SELECT COUNT(*), MIN(time), MAX(time) FROM candle_data WHERE symbol = ? AND timeframe = ?",
+                rusqlite::params![symbol, timeframe],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .map_err(|e| LoaderError::Query(e.to_string()))?;
+
+        Ok(DataRange {
+            symbol: symbol.to_string(),
+            timeframe: timeframe.to_string(),
+            candle_count,
+            first,
+            last,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-// services/database_loader.rs - Charge les candles depuis la BD paires
+// services/database_loader/mod.rs - Charge les candles depuis la BD paires
 // Alternative à CsvLoader: lit depuis candle_data table à la place des fichiers CSV
 
 use crate::db::DbPool;
@@ -7,6 +7,8 @@ use chrono::{DateTime, Utc};
 use std::path::PathBuf;
 use tracing::{error, info, instrument};
 
+mod history;
+
 /// Erreur spécifique au DatabaseLoader
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -78,6 +80,23 @@ impl DatabaseLoader {
         DatabaseLoader { db_pool: pool }
     }
 
+    /// Connexion à pairs.db (chemin standard), attente de 5 s si la base est verrouillée
+    fn connexion(&self) -> Result<rusqlite::Connection, LoaderError> {
+        let db_path = dirs::data_local_dir()
+            .map(|d| d.join("volatility-analyzer").join("pairs.db"))
+            .unwrap_or_else(|| PathBuf::from("pairs.db"));
+
+        let conn = rusqlite::Connection::open(&db_path).map_err(|e| {
+            error!("Failed to open DB at {:?}: {}", db_path, e);
+            LoaderError::Connection(e.to_string())
+        })?;
+
+        // Set busy timeout to 5000ms to avoid "database is locked" errors
+        conn.busy_timeout(std::time::Duration::from_millis(5000))
+            .map_err(|e| LoaderError::Connection(e.to_string()))?;
+        Ok(conn)
+    }
+
     /// Charge les candles pour une paire donnée dans une plage temporelle
     ///
     /// # Arguments
@@ -102,24 +121,7 @@ impl DatabaseLoader {
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
     ) -> Result<Vec<Candle>, LoaderError> {
-        // Vérifier que le pool est actif (nouveau pattern: pool passé au constructor)
-        let _pool_ref = &self.db_pool;
-        tracing::debug!("Using pool-managed candle loader (pool active)");
-
-        let db_path = dirs::data_local_dir()
-            .map(|d| d.join("volatility-analyzer").join("pairs.db"))
-            .unwrap_or_else(|| PathBuf::from("pairs.db"));
-
-        let conn = rusqlite::Connection::open(&db_path).map_err(|e| {
-            error!("Failed to open DB at {:?}: {}", db_path, e);
-            LoaderError::Connection(e.to_string())
-        })?;
-
-        // Set busy timeout to 5000ms to avoid "database is locked" errors
-        conn.busy_timeout(std::time::Duration::from_millis(5000)).map_err(|e| {
-            error!("Failed to set busy_timeout: {}", e);
-            LoaderError::Connection(e.to_string())
-        })?;
+        let conn = self.connexion()?;
 
         // Enable WAL mode for concurrency
         conn.pragma_update(None, "journal_mode", "WAL").map_err(|e| {
@@ -136,7 +138,7 @@ impl DatabaseLoader {
 
         let mut stmt = conn
             .prepare(
-                "SELECT symbol, time, open, high, low, close, volume
+                "SELECT symbol, time, open, high, low, close, volume, spread
                  FROM candle_data
                  WHERE symbol = ? AND timeframe = ? AND time >= ? AND time <= ?
                  ORDER BY time ASC",
@@ -158,6 +160,7 @@ impl DatabaseLoader {
                         row.get::<_, f64>(4)?,    // low
                         row.get::<_, f64>(5)?,    // close
                         row.get::<_, f64>(6)?,    // volume
+                        row.get::<_, Option<f64>>(7)?, // spread (prix, optionnel)
                     ))
                 },
             )
@@ -168,7 +171,7 @@ impl DatabaseLoader {
 
         let candles: Result<Vec<_>, LoaderError> = rows
             .map(|row_result| {
-                let (sym, time_str, open, high, low, close, volume) =
+                let (sym, time_str, open, high, low, close, volume, spread) =
                     row_result.map_err(|e| LoaderError::Query(e.to_string()))?;
 
                 let datetime = DateTime::parse_from_rfc3339(&time_str)
@@ -179,6 +182,7 @@ impl DatabaseLoader {
                     })?;
 
                 Candle::new(sym, datetime, open, high, low, close, volume)
+                    .map(|candle| candle.with_spread(spread))
                     .map_err(|e| LoaderError::Validation(e.to_string()))
             })
             .collect();
@@ -203,17 +207,7 @@ impl DatabaseLoader {
     #[allow(dead_code)]
     #[instrument(skip(self))]
     pub fn get_all_symbols(&self) -> Result<Vec<String>, LoaderError> {
-        let _pool_ref = &self.db_pool;
-        let db_path = dirs::data_local_dir()
-            .map(|d| d.join("volatility-analyzer").join("pairs.db"))
-            .unwrap_or_else(|| PathBuf::from("pairs.db"));
-
-        let conn = rusqlite::Connection::open(&db_path)
-            .map_err(|e| LoaderError::Connection(e.to_string()))?;
-
-        // Set busy timeout to 5000ms
-        conn.busy_timeout(std::time::Duration::from_millis(5000))
-            .map_err(|e| LoaderError::Connection(e.to_string()))?;
+        let conn = self.connexion()?;
 
         let mut stmt = conn
             .prepare("SELECT DISTINCT symbol FROM candle_data ORDER BY symbol")
@@ -232,17 +226,7 @@ impl DatabaseLoader {
     #[allow(dead_code)]
     #[instrument(skip(self))]
     pub fn get_timeframes_for_symbol(&self, symbol: &str) -> Result<Vec<String>, LoaderError> {
-        let _pool_ref = &self.db_pool;
-        let db_path = dirs::data_local_dir()
-            .map(|d| d.join("volatility-analyzer").join("pairs.db"))
-            .unwrap_or_else(|| PathBuf::from("pairs.db"));
-
-        let conn = rusqlite::Connection::open(&db_path)
-            .map_err(|e| LoaderError::Connection(e.to_string()))?;
-
-        // Set busy timeout to 5000ms
-        conn.busy_timeout(std::time::Duration::from_millis(5000))
-            .map_err(|e| LoaderError::Connection(e.to_string()))?;
+        let conn = self.connexion()?;
 
         let mut stmt = conn
             .prepare(
@@ -263,17 +247,7 @@ impl DatabaseLoader {
     #[allow(dead_code)]
     #[instrument(skip(self))]
     pub fn count_candles(&self, symbol: &str, timeframe: &str) -> Result<i64, LoaderError> {
-        let _pool_ref = &self.db_pool;
-        let db_path = dirs::data_local_dir()
-            .map(|d| d.join("volatility-analyzer").join("pairs.db"))
-            .unwrap_or_else(|| PathBuf::from("pairs.db"));
-
-        let conn = rusqlite::Connection::open(&db_path)
-            .map_err(|e| LoaderError::Connection(e.to_string()))?;
-
-        // Set busy timeout to 5000ms
-        conn.busy_timeout(std::time::Duration::from_millis(5000))
-            .map_err(|e| LoaderError::Connection(e.to_string()))?;
+        let conn = self.connexion()?;
 
         let count: i64 = conn
             .query_row(
@@ -2,9 +2,10 @@
 use super::global_analyzer_helpers::calculer_poids_temporel;
 use super::global_analyzer_metrics::*;
 use super::global_analyzer_types::*;
-use crate::models::{AnalysisFilters, GlobalAnalysisResult};
-use crate::services::ArchiveService;
-use tracing::info;
+use crate::models::archive_payload::ArchiveSummary;
+use crate::models::{AnalysisFilters, GlobalAnalysisResult, TimeDecay};
+use crate::services::{ArchiveSchema, ArchiveService, ConfigService};
+use tracing::{info, warn};
 
 pub struct GlobalAnalyzer {
     archive_service: ArchiveService,
@@ -27,8 +28,10 @@ impl GlobalAnalyzer {
         let total_archives = archives.len();
         info!("Début analyse globale sur {} archives", total_archives);
 
+        // Pondération lue une fois pour toutes les archives
+        let decay = ConfigService::time_decay();
         let (weighted_data, filtered_archives) =
-            self.filter_and_weight_archives(&archives, filters.as_ref())?;
+            self.filter_and_weight_archives(&archives, filters.as_ref(), &decay)?;
 
         info!(
             "Archives valides pour analyse : {}/{}",
@@ -63,6 +66,7 @@ impl GlobalAnalyzer {
         &self,
         archives: &[crate::models::Archive],
         filters: Option<&AnalysisFilters>,
+        decay: &TimeDecay,
     ) -> Result<(Vec<WeightedArchiveData>, Vec<crate::models::Archive>), String> {
         let mut weighted_data: Vec<WeightedArchiveData> = Vec::new();
         let mut filtered_archives: Vec<crate::models::Archive> = Vec::new();
@@ -82,25 +86,15 @@ impl GlobalAnalyzer {
                 continue;
             }
 
-            let mut current_analyzable_data: Option<AnalyzableArchiveData> = None;
-            match serde_json::from_str::<ArchiveWrapper>(&archive.data_json) {
-                Ok(wrapper) => {
-                    if !wrapper.analysis_result.symbol.is_empty() {
-                        current_analyzable_data = Some(wrapper.analysis_result);
-                    }
-                }
-                Err(_) => {
-                    if let Ok(result) =
-                        serde_json::from_str::<AnalyzableArchiveData>(&archive.data_json)
-                    {
-                        if !result.symbol.is_empty() {
-                            current_analyzable_data = Some(result);
-                        }
-                    }
-                }
-            }
+            let summary = ArchiveSchema::summary(archive)
+                .map_err(|e| warn!("⚠️ Archive {} ignorée: {}", archive.id, e))
+                .ok();
+            let current_analyzable_data = match &summary {
+                Some(ArchiveSummary::Volatility(v)) => Some(AnalyzableArchiveData::from(v)),
+                _ => None,
+            };
 
-            if !self.passes_pair_filter(&current_analyzable_data, archive, target_pairs) {
+            if !self.passes_pair_filter(summary.as_ref(), target_pairs) {
                 continue;
             }
 
@@ -116,7 +110,7 @@ impl GlobalAnalyzer {
                                     .expect("epoch time is valid"),
                             )
                         });
-                let weight = calculer_poids_temporel(created_at_dt);
+                let weight = calculer_poids_temporel(created_at_dt, decay);
                 info!(
                     "Archive {} lue avec succès: {} (poids: {:.2})",
                     archive.id, data.symbol, weight
@@ -169,34 +163,14 @@ impl GlobalAnalyzer {
 
     fn passes_pair_filter(
         &self,
-        data: &Option<AnalyzableArchiveData>,
-        archive: &crate::models::Archive,
+        summary: Option<&ArchiveSummary>,
         target_pairs: Option<&Vec<String>>,
     ) -> bool {
-        if let Some(pairs) = target_pairs {
-            if !pairs.is_empty() {
-                let mut found = false;
-                if let Some(d) = data {
-                    if pairs.contains(&d.symbol) {
-                        found = true;
-                    }
-                } else {
-                    for pair in pairs {
-                        if archive
-                            .data_json
-                            .contains(&format!("\"pair\":\"{}\"", pair))
-                            || archive
-                                .data_json
-                                .contains(&format!("\"symbol\":\"{}\"", pair))
-                        {
-                            found = true;
-                            break;
-                        }
-                    }
-                }
-                return found;
-            }
+        match target_pairs {
+            Some(pairs) if !pairs.is_empty() => summary
+                .and_then(ArchiveSummary::instrument)
+                .is_some_and(|instrument| pairs.iter().any(|p| p == instrument)),
+            _ => true,
         }
-        true
     }
 }
@@ -1,8 +1,9 @@
 // services/global_analyzer_event_analysis.rs
 // Fonctions d'analyse des événements (extracted from global_analyzer_metrics)
 
-use super::global_analyzer_types::*;
+use crate::models::archive_payload::{ArchiveKind, ArchiveSummary};
 use crate::models::{OptimalTimeWindow, TradableEventType};
+use crate::services::ArchiveSchema;
 use std::collections::HashMap;
 use tracing::warn;
 
@@ -10,14 +11,16 @@ pub fn compute_tradable_events(archives: &[crate::models::Archive]) -> Vec<Trada
     let mut event_stats: HashMap<String, (f64, f64, Vec<String>)> = HashMap::new();
 
     for archive in archives {
-        if !archive.archive_type.contains("Corrélation événement/paire") {
+        if ArchiveKind::from_archive_type(&archive.archive_type)
+            != Some(ArchiveKind::EventCorrelation)
+        {
             continue;
         }
 
-        match serde_json::from_str::<EventImpactArchive>(&archive.data_json) {
-            Ok(event_data) => {
-                let event_name = event_data.event_impact.event_name.clone();
-                let pair_impacts = &event_data.event_impact.pair_impacts;
+        match ArchiveSchema::summary(archive) {
+            Ok(ArchiveSummary::EventCorrelation(event_data)) => {
+                let event_name = event_data.event_name.clone();
+                let pair_impacts = &event_data.pair_impacts;
 
                 if pair_impacts.is_empty() {
                     continue;
@@ -45,6 +48,7 @@ pub fn compute_tradable_events(archives: &[crate::models::Archive]) -> Vec<Trada
                 entry.1 += 1.0;
                 entry.2.extend(affected_pairs);
             }
+            Ok(_) => {}
             Err(e) => {
                 warn!("Erreur lecture archive corrélation {}: {}", archive.id, e);
             }
@@ -81,15 +85,17 @@ pub fn compute_optimal_time_windows(archives: &[crate::models::Archive]) -> Vec<
     let mut event_timing_stats: HashMap<String, (usize, f64, f64, Vec<String>)> = HashMap::new();
 
     for archive in archives {
-        if !archive.archive_type.contains("Corrélation paire/événement") {
+        if ArchiveKind::from_archive_type(&archive.archive_type)
+            != Some(ArchiveKind::PairCorrelation)
+        {
             continue;
         }
 
-        match serde_json::from_str::<PairCorrelationArchive>(&archive.data_json) {
-            Ok(pair_data) => {
-                let pair = pair_data.pair_correlation.pair.clone();
+        match ArchiveSchema::summary(archive) {
+            Ok(ArchiveSummary::PairCorrelation(pair_data)) => {
+                let pair = pair_data.pair.clone();
 
-                for event in &pair_data.pair_correlation.events {
+                for event in &pair_data.events {
                     let entry = event_timing_stats.entry(event.name.clone()).or_insert((
                         0,
                         0.0,
@@ -106,6 +112,7 @@ pub fn compute_optimal_time_windows(archives: &[crate::models::Archive]) -> Vec<
                     }
                 }
             }
+            Ok(_) => {}
             Err(e) => {
                 warn!(
                     "Erreur lecture archive corrélation paire pour timing {}: {}",
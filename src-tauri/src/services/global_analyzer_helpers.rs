@@ -1,7 +1,14 @@
 // services/global_analyzer_helpers.rs - Fonctions helper pour l'analyseur global
 
-pub fn calculer_poids_temporel(archive_date: chrono::NaiveDateTime) -> f64 {
+use crate::models::TimeDecay;
+
+/// Poids d'une archive selon son ancienneté : demi-vie configurée si définie,
+/// sinon paliers historiques (1.0 < 3 mois, 0.7 < 6 mois, 0.4 au-delà)
+pub fn calculer_poids_temporel(archive_date: chrono::NaiveDateTime, decay: &TimeDecay) -> f64 {
     let now = chrono::Local::now().naive_local();
+    if decay.is_enabled() {
+        return decay.weight(archive_date.date(), now.date());
+    }
     let age_days = (now - archive_date).num_days() as f64;
     let age_months = age_days / 30.0;
 
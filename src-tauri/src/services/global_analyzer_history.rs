@@ -0,0 +1,265 @@
+// services/global_analyzer_history.rs - Analyse globale calculée sur tout l'historique (bougies + calendrier)
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::global_analyzer_history_metrics::*;
+use super::global_analyzer_metrics::{
+    compute_best_pairs, compute_global_stats, compute_golden_hours,
+};
+use super::global_analyzer_occurrence::{mesurer_occurrence, EventOccurrence};
+use super::global_analyzer_types::*;
+use crate::models::{
+    AnalysisFilters, AssetProperties, CalendarEvent, GlobalAnalysisProgress, GlobalAnalysisResult,
+};
+use crate::services::planning::candidate_ranking::CandidateRanker;
+use crate::services::SymbolRegistryService;
+use crate::services::{DatabaseLoader, VolatilityAnalyzer};
+use chrono::{DateTime, NaiveDate, Utc};
+use rayon::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tracing::{info, warn};
+
+/// Heures les plus volatiles retenues par paire pour les "golden hours"
+const BEST_HOURS_PER_PAIR: usize = 3;
+/// Paires balayées en même temps : chacune charge tout son historique M1 en mémoire
+const PAIRES_EN_PARALLELE: usize = 4;
+
+/// Résultat du balayage d'une paire
+struct PairScan {
+    data: AnalyzableArchiveData,
+    recommendation: &'static str,
+    hourly_volatility: Vec<(u8, f64)>,
+    days: BTreeSet<NaiveDate>,
+    occurrences: Vec<EventOccurrence>,
+}
+
+pub struct GlobalHistoryAnalyzer {
+    loader: DatabaseLoader,
+    events: Vec<CalendarEvent>,
+}
+
+impl GlobalHistoryAnalyzer {
+    pub fn new(loader: DatabaseLoader, events: Vec<CalendarEvent>) -> Self {
+        GlobalHistoryAnalyzer { loader, events }
+    }
+
+    /// Balaye toutes les paires (par lots parallèles) et tous les événements du calendrier.
+    /// `progress` est appelé après chaque paire, puis une dernière fois avec `done`.
+    pub fn analyze_history(
+        &self,
+        filters: Option<AnalysisFilters>,
+        progress: &(dyn Fn(&GlobalAnalysisProgress) + Sync),
+    ) -> Result<GlobalAnalysisResult, String> {
+        let (start, end) = bornes(filters.as_ref())?;
+        let wanted: Option<Vec<String>> = filters
+            .as_ref()
+            .and_then(|f| f.pairs.as_ref())
+            .filter(|pairs| !pairs.is_empty())
+            .map(|pairs| {
+                pairs
+                    .iter()
+                    .map(|p| SymbolRegistryService::canonical(p))
+                    .collect()
+            });
+        let symbols: Vec<String> = self
+            .loader
+            .get_all_symbols()
+            .map_err(|e| format!("Lecture des paires impossible: {}", e))?
+            .into_iter()
+            .filter(|s| {
+                wanted
+                    .as_ref()
+                    .is_none_or(|w| w.contains(&SymbolRegistryService::canonical(s)))
+            })
+            .collect();
+        if symbols.is_empty() {
+            return Err("Aucune paire en base correspondant aux filtres.".to_string());
+        }
+        let events: Vec<&CalendarEvent> = self
+            .events
+            .iter()
+            .filter(|e| {
+                let t = e.event_time.and_utc();
+                t >= start && t <= end
+            })
+            .collect();
+        info!(
+            "🌍 Analyse globale sur l'historique : {} paires, {} événements",
+            symbols.len(),
+            events.len()
+        );
+
+        let total = symbols.len();
+        let processed = AtomicUsize::new(0);
+        // Par lots bornés : l'historique d'un lot est libéré avant de charger le suivant
+        let scans: Vec<PairScan> = symbols
+            .chunks(PAIRES_EN_PARALLELE)
+            .flat_map(|lot| {
+                lot.par_iter()
+                    .filter_map(|symbol| {
+                        let scan = self
+                            .scanner(symbol, &events, start, end)
+                            .map_err(|e| warn!("⚠️ Paire {} ignorée: {}", symbol, e))
+                            .ok();
+                        let done = processed.fetch_add(1, Ordering::SeqCst) + 1;
+                        progress(&GlobalAnalysisProgress {
+                            symbol: symbol.clone(),
+                            processed: done,
+                            total,
+                            percent: done as f64 * 100.0 / total as f64,
+                            done: false,
+                        });
+                        scan
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        if scans.is_empty() {
+            return Err("Aucune paire analysable sur la période demandée.".to_string());
+        }
+
+        let result = assembler(scans, filters);
+        info!(
+            "✅ Analyse globale terminée : {} paires, {} jours, {} types d'événements",
+            result.total_analyses,
+            result.total_days_analyzed,
+            result.event_impacts.len()
+        );
+        progress(&GlobalAnalysisProgress {
+            symbol: String::new(),
+            processed: total,
+            total,
+            percent: 100.0,
+            done: true,
+        });
+        Ok(result)
+    }
+
+    fn scanner(
+        &self,
+        symbol: &str,
+        events: &[&CalendarEvent],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<PairScan, String> {
+        let candles = self
+            .loader
+            .load_candles_by_pair(symbol, "M1", start, end)
+            .map_err(|e| e.to_string())?;
+        let days = candles.iter().map(|c| c.datetime.date_naive()).collect();
+        let asset = AssetProperties::from_symbol(symbol);
+        let occurrences = events
+            .iter()
+            .filter(|e| CandidateRanker::relation(symbol, &e.symbol).is_some())
+            .filter_map(|e| mesurer_occurrence(symbol, e, &candles, &asset))
+            .collect();
+
+        let analysis = VolatilityAnalyzer::new(candles)
+            .analyze(symbol, None)
+            .map_err(|e| e.to_string())?;
+        let hourly_volatility: Vec<(u8, f64)> = analysis
+            .hourly_stats
+            .iter()
+            .filter(|h| h.candle_count > 0)
+            .map(|h| (h.hour, h.volatility_mean))
+            .collect();
+        let mut ranked = hourly_volatility.clone();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        Ok(PairScan {
+            data: AnalyzableArchiveData {
+                symbol: symbol.to_string(),
+                best_hours: ranked
+                    .iter()
+                    .take(BEST_HOURS_PER_PAIR)
+                    .map(|(hour, _)| *hour)
+                    .collect(),
+                confidence_score: analysis.confidence_score,
+                global_metrics: Some(AnalyzableGlobalMetrics {
+                    mean_volatility: analysis.global_metrics.mean_volatility,
+                }),
+            },
+            recommendation: analysis.recommendation.to_string(),
+            hourly_volatility,
+            days,
+            occurrences,
+        })
+    }
+}
+
+/// Période demandée (bornes incluses), tout l'historique par défaut
+fn bornes(filters: Option<&AnalysisFilters>) -> Result<(DateTime<Utc>, DateTime<Utc>), String> {
+    let parse = |date: Option<&String>, h: u32, m: u32, s: u32| -> Result<_, String> {
+        date.map(|d| {
+            NaiveDate::parse_from_str(d, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(h, m, s))
+                .map(|dt| dt.and_utc())
+                .ok_or_else(|| format!("Date invalide (AAAA-MM-JJ attendu): {}", d))
+        })
+        .transpose()
+    };
+    let start = parse(filters.and_then(|f| f.start_date.as_ref()), 0, 0, 0)?
+        .or_else(|| DateTime::from_timestamp(0, 0))
+        .ok_or("Date de début invalide")?;
+    let end =
+        parse(filters.and_then(|f| f.end_date.as_ref()), 23, 59, 59)?.unwrap_or_else(Utc::now);
+    if start > end {
+        return Err("La date de début est postérieure à la date de fin.".to_string());
+    }
+    Ok((start, end))
+}
+
+fn assembler(scans: Vec<PairScan>, filters: Option<AnalysisFilters>) -> GlobalAnalysisResult {
+    let now = Utc::now().naive_utc();
+    let days: BTreeSet<&NaiveDate> = scans.iter().flat_map(|s| &s.days).collect();
+    let total_days_analyzed = days.len();
+    let mut recommendations: HashMap<&str, usize> = HashMap::new();
+    let mut hourly: HashMap<u8, Vec<f64>> = HashMap::new();
+    for scan in &scans {
+        *recommendations.entry(scan.recommendation).or_insert(0) += 1;
+        for (hour, volatility) in &scan.hourly_volatility {
+            hourly.entry(*hour).or_default().push(*volatility);
+        }
+    }
+    let most_frequent_recommendation = recommendations
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(r, _)| r.to_string())
+        .unwrap_or_else(|| "-".to_string());
+
+    let mut occurrences = Vec::new();
+    let mut weighted_data = Vec::new();
+    for scan in scans {
+        occurrences.extend(scan.occurrences);
+        // Historique brut : chaque paire pèse autant
+        weighted_data.push(WeightedArchiveData {
+            data: scan.data,
+            weight: 1.0,
+            created_at: now,
+        });
+    }
+
+    let mut global_stats = compute_global_stats(&weighted_data);
+    global_stats.most_frequent_recommendation = most_frequent_recommendation;
+    let mut golden_hours = compute_golden_hours(&weighted_data);
+    for golden in &mut golden_hours {
+        if let Some(values) = hourly.get(&golden.hour) {
+            golden.avg_volatility = values.iter().sum::<f64>() / values.len() as f64;
+        }
+    }
+
+    GlobalAnalysisResult {
+        total_analyses: weighted_data.len(),
+        total_days_analyzed,
+        filters_applied: filters,
+        global_stats,
+        best_pairs: compute_best_pairs(&weighted_data),
+        golden_hours,
+        event_impacts: compute_event_impacts(&occurrences),
+        tradable_events: compute_tradable_events(&occurrences),
+        pair_straddle_rates: compute_pair_straddle_rates(&occurrences),
+        optimal_time_windows: compute_optimal_time_windows(&occurrences),
+        generated_at: chrono::Local::now().to_string(),
+    }
+}
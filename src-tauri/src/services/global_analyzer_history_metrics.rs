@@ -0,0 +1,235 @@
+// services/global_analyzer_history_metrics.rs - Sections de l'analyse globale agrégées depuis les occurrences
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::global_analyzer_occurrence::{moyenne, EventOccurrence, AGITATION_FACTOR};
+use crate::models::{EventImpact, OptimalTimeWindow, StraddleSuccessRate, TradableEventType};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Mouvement net / amplitude : au-delà directionnel, en deçà aller-retour
+const DIRECTIONAL_RATIO: f64 = 0.5;
+const WHIPSAW_RATIO: f64 = 0.2;
+/// Occurrences nécessaires pour une consistance pleine
+const CONSISTENCY_SAMPLE: f64 = 10.0;
+
+fn grouper<'a, K: Ord>(
+    occurrences: &'a [EventOccurrence],
+    key: impl Fn(&EventOccurrence) -> K,
+) -> BTreeMap<K, Vec<&'a EventOccurrence>> {
+    let mut groups: BTreeMap<K, Vec<&EventOccurrence>> = BTreeMap::new();
+    for occurrence in occurrences {
+        groups.entry(key(occurrence)).or_default().push(occurrence);
+    }
+    groups
+}
+
+/// Annonces distinctes (une annonce mesurée sur plusieurs paires compte une fois)
+fn annonces(group: &[&EventOccurrence]) -> usize {
+    group
+        .iter()
+        .map(|o| o.event_time)
+        .collect::<BTreeSet<_>>()
+        .len()
+}
+
+fn paires(group: &[&EventOccurrence]) -> Vec<String> {
+    group
+        .iter()
+        .map(|o| o.symbol.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn par_score_decroissant<T>(items: &mut [T], score: impl Fn(&T) -> f64) {
+    items.sort_by(|a, b| {
+        score(b)
+            .partial_cmp(&score(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+pub fn compute_event_impacts(occurrences: &[EventOccurrence]) -> Vec<EventImpact> {
+    let mut impacts: Vec<EventImpact> =
+        grouper(occurrences, |o| (o.event_name.clone(), o.currency.clone()))
+            .into_iter()
+            .map(|((event_name, currency), group)| {
+                let ratio = moyenne(group.iter().map(|o| o.volatility_ratio));
+                EventImpact {
+                    event_name,
+                    currency,
+                    avg_impact_pips: moyenne(group.iter().map(|o| o.impact_pips)),
+                    occurrence_count: annonces(&group),
+                    impact_level: if ratio >= 3.0 {
+                        "High"
+                    } else if ratio >= AGITATION_FACTOR {
+                        "Medium"
+                    } else {
+                        "Low"
+                    }
+                    .to_string(),
+                }
+            })
+            .collect();
+    par_score_decroissant(&mut impacts, |i| i.avg_impact_pips);
+    impacts
+}
+
+pub fn compute_tradable_events(occurrences: &[EventOccurrence]) -> Vec<TradableEventType> {
+    let mut events: Vec<TradableEventType> = grouper(occurrences, |o| o.event_name.clone())
+        .into_iter()
+        .map(|(event_name, group)| {
+            let avg_volatility_increase = moyenne(group.iter().map(|o| o.volatility_ratio));
+            // Paires dont la volatilité augmente en moyenne à l'annonce
+            let affected_pairs = grouper(occurrences, |o| o.symbol.clone())
+                .into_iter()
+                .filter(|(_, by_pair)| {
+                    let own: Vec<f64> = by_pair
+                        .iter()
+                        .filter(|o| o.event_name == event_name)
+                        .map(|o| o.volatility_ratio)
+                        .collect();
+                    !own.is_empty() && moyenne(own.into_iter()) > 1.0
+                })
+                .map(|(symbol, _)| symbol)
+                .collect();
+            TradableEventType {
+                occurrence_count: annonces(&group),
+                tradability_score: ((avg_volatility_increase - 1.0) * 100.0).clamp(0.0, 100.0),
+                avg_volatility_increase,
+                affected_pairs,
+                event_name,
+            }
+        })
+        .collect();
+    par_score_decroissant(&mut events, |e| e.tradability_score);
+    events
+}
+
+pub fn compute_pair_straddle_rates(occurrences: &[EventOccurrence]) -> Vec<StraddleSuccessRate> {
+    let mut rates: Vec<StraddleSuccessRate> = grouper(occurrences, |o| o.symbol.clone())
+        .into_iter()
+        .map(|(pair, group)| {
+            let total_events = group.len();
+            let rate = |pred: &dyn Fn(&EventOccurrence) -> bool| {
+                group.iter().filter(|o| pred(o)).count() as f64 * 100.0 / total_events as f64
+            };
+            let directional_move_rate = rate(&|o| o.directional_ratio >= DIRECTIONAL_RATIO);
+            let whipsaw_rate = rate(&|o| o.directional_ratio < WHIPSAW_RATIO);
+
+            let mut by_event: Vec<(String, f64)> = grouper(occurrences, |o| o.event_name.clone())
+                .into_iter()
+                .filter_map(|(name, all)| {
+                    let own: Vec<f64> = all
+                        .iter()
+                        .filter(|o| o.symbol == pair)
+                        .map(|o| o.impact_pips)
+                        .collect();
+                    (!own.is_empty()).then(|| (name, moyenne(own.into_iter())))
+                })
+                .collect();
+            par_score_decroissant(&mut by_event, |e| e.1);
+            StraddleSuccessRate {
+                total_events,
+                directional_move_rate,
+                whipsaw_rate,
+                avg_volatility: moyenne(group.iter().map(|o| o.impact_pips)),
+                straddle_score: (directional_move_rate - whipsaw_rate).max(0.0),
+                top_events: by_event.into_iter().take(3).map(|(name, _)| name).collect(),
+                pair,
+            }
+        })
+        .collect();
+    par_score_decroissant(&mut rates, |r| r.straddle_score);
+    rates
+}
+
+pub fn compute_optimal_time_windows(occurrences: &[EventOccurrence]) -> Vec<OptimalTimeWindow> {
+    let mut windows: Vec<OptimalTimeWindow> = grouper(occurrences, |o| o.event_name.clone())
+        .into_iter()
+        .map(|(event_type, group)| {
+            let peak = moyenne(group.iter().map(|o| o.peak_minutes));
+            let spread = moyenne(group.iter().map(|o| (o.peak_minutes - peak).powi(2))).sqrt();
+            // Pic régulier (faible dispersion relative) et échantillon suffisant
+            let regularity = if peak > 0.0 {
+                (1.0 - spread / peak).clamp(0.0, 1.0)
+            } else {
+                1.0
+            };
+            let occurrence_count = annonces(&group);
+            OptimalTimeWindow {
+                occurrence_count,
+                avg_peak_time_minutes: peak,
+                // Entrer au plus tard une minute avant l'annonce, avant l'agitation sinon
+                avg_entry_window_minutes: moyenne(group.iter().map(|o| o.agitation_minutes))
+                    .max(1.0),
+                avg_return_to_normal_minutes: moyenne(
+                    group.iter().map(|o| o.return_to_normal_minutes),
+                ),
+                consistency_score: regularity
+                    * (occurrence_count as f64 / CONSISTENCY_SAMPLE).min(1.0)
+                    * 100.0,
+                affected_pairs: paires(&group),
+                event_type,
+            }
+        })
+        .collect();
+    par_score_decroissant(&mut windows, |w| w.consistency_score);
+    windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    fn event_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 8, 13, 30, 0)
+            .single()
+            .expect("date")
+    }
+
+    fn occurrence(symbol: &str, day: i64, ratio: f64, directional: f64) -> EventOccurrence {
+        EventOccurrence {
+            symbol: symbol.to_string(),
+            event_name: "Non-Farm Payrolls".to_string(),
+            currency: "USD".to_string(),
+            event_time: event_time() + Duration::days(day),
+            impact_pips: 10.0 * ratio,
+            volatility_ratio: ratio,
+            directional_ratio: directional,
+            peak_minutes: 2.0,
+            agitation_minutes: 0.0,
+            return_to_normal_minutes: 10.0,
+        }
+    }
+
+    #[test]
+    fn test_sections_aggregated_from_occurrences() {
+        let occurrences = vec![
+            occurrence("EURUSD", 0, 4.0, 0.8),
+            occurrence("GBPUSD", 0, 2.0, 0.1),
+            occurrence("EURUSD", 28, 3.0, 0.7),
+        ];
+
+        let impacts = compute_event_impacts(&occurrences);
+        assert_eq!(impacts.len(), 1);
+        assert_eq!(impacts[0].occurrence_count, 2);
+        assert_eq!(impacts[0].impact_level, "High");
+        assert!((impacts[0].avg_impact_pips - 30.0).abs() < 1e-9);
+
+        let tradable = compute_tradable_events(&occurrences);
+        assert_eq!(tradable[0].affected_pairs, vec!["EURUSD", "GBPUSD"]);
+        assert_eq!(tradable[0].tradability_score, 100.0);
+
+        let rates = compute_pair_straddle_rates(&occurrences);
+        assert_eq!(rates[0].pair, "EURUSD");
+        assert_eq!(rates[0].directional_move_rate, 100.0);
+        assert_eq!(rates[1].whipsaw_rate, 100.0);
+        assert_eq!(rates[1].straddle_score, 0.0);
+
+        let windows = compute_optimal_time_windows(&occurrences);
+        assert_eq!(windows[0].avg_entry_window_minutes, 1.0);
+        // Pic parfaitement régulier, 2 annonces sur 10 requises
+        assert!((windows[0].consistency_score - 20.0).abs() < 1e-9);
+    }
+}
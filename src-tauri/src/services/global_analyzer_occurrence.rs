@@ -0,0 +1,188 @@
+// services/global_analyzer_occurrence.rs - Mesure d'une occurrence d'événement sur les bougies M1
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::{AssetProperties, CalendarEvent, Candle};
+use chrono::{DateTime, Duration, Utc};
+
+/// Référence de calme : T-60 → T-30 (minutes)
+const BASELINE_WINDOW: (i64, i64) = (-60, -30);
+/// Fenêtre d'impact après l'annonce
+const IMPACT_WINDOW: (i64, i64) = (0, 30);
+/// Fenêtre de suivi du pic et du retour au calme
+const RECOVERY_WINDOW: (i64, i64) = (0, 90);
+/// Fenêtre de détection d'une agitation avant l'annonce
+const PRE_EVENT_WINDOW: (i64, i64) = (-30, 0);
+/// Une bougie M1 est "agitée" au-delà de ce multiple du range de référence
+pub(super) const AGITATION_FACTOR: f64 = 1.5;
+/// Réaction d'une paire à une occurrence d'événement (amplitudes en pips/points)
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventOccurrence {
+    pub symbol: String,
+    pub event_name: String,
+    pub currency: String,
+    pub event_time: DateTime<Utc>,
+    /// Amplitude haut-bas sur T → T+30
+    pub impact_pips: f64,
+    /// Range M1 moyen T → T+30 / range M1 moyen de référence
+    pub volatility_ratio: f64,
+    /// Mouvement net / amplitude sur T → T+30
+    pub directional_ratio: f64,
+    pub peak_minutes: f64,
+    /// Minutes avant T où l'agitation commence (0 si calme jusqu'à l'annonce)
+    pub agitation_minutes: f64,
+    pub return_to_normal_minutes: f64,
+}
+
+/// Bougies de [T+from, T+to[ (bougies triées par date)
+fn fenetre(candles: &[Candle], event_time: DateTime<Utc>, window: (i64, i64)) -> &[Candle] {
+    let start = event_time + Duration::minutes(window.0);
+    let end = event_time + Duration::minutes(window.1);
+    let lo = candles.partition_point(|c| c.datetime < start);
+    let hi = candles.partition_point(|c| c.datetime < end);
+    candles.get(lo..hi).unwrap_or(&[])
+}
+
+pub(super) fn moyenne(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0usize), |(s, n), v| (s + v, n + 1));
+    if count > 0 {
+        sum / count as f64
+    } else {
+        0.0
+    }
+}
+
+fn minutes(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    (to - from).num_minutes() as f64
+}
+
+/// Mesure une occurrence ; None sans bougies autour de l'annonce ou sans référence de calme
+pub fn mesurer_occurrence(
+    symbol: &str,
+    event: &CalendarEvent,
+    candles: &[Candle],
+    asset: &AssetProperties,
+) -> Option<EventOccurrence> {
+    let event_time = event.event_time.and_utc();
+    let range = |c: &Candle| asset.normalize(c.high - c.low);
+    let baseline = moyenne(
+        fenetre(candles, event_time, BASELINE_WINDOW)
+            .iter()
+            .map(range),
+    );
+    let impact = fenetre(candles, event_time, IMPACT_WINDOW);
+    let (first, last) = (impact.first()?, impact.last()?);
+    if baseline <= 0.0 {
+        return None;
+    }
+
+    let high = impact.iter().map(|c| c.high).fold(f64::MIN, f64::max);
+    let low = impact.iter().map(|c| c.low).fold(f64::MAX, f64::min);
+    let amplitude = high - low;
+    let directional_ratio = if amplitude > 0.0 {
+        (last.close - first.open).abs() / amplitude
+    } else {
+        0.0
+    };
+
+    let agitated = |c: &Candle| range(c) > baseline * AGITATION_FACTOR;
+    let recovery = fenetre(candles, event_time, RECOVERY_WINDOW);
+    let peak = recovery
+        .iter()
+        .enumerate()
+        .max_by(|a, b| range(a.1).total_cmp(&range(b.1)))
+        .map(|(idx, c)| (idx, c.datetime))
+        .unwrap_or((0, first.datetime));
+    let return_to_normal_minutes = recovery
+        .iter()
+        .skip(peak.0 + 1)
+        .find(|c| !agitated(c))
+        .map(|c| minutes(event_time, c.datetime))
+        .unwrap_or(RECOVERY_WINDOW.1 as f64);
+    let agitation_minutes = fenetre(candles, event_time, PRE_EVENT_WINDOW)
+        .iter()
+        .find(|c| agitated(c))
+        .map(|c| minutes(c.datetime, event_time))
+        .unwrap_or(0.0);
+
+    Some(EventOccurrence {
+        symbol: symbol.to_string(),
+        event_name: event.description.clone(),
+        currency: event.symbol.clone(),
+        event_time,
+        impact_pips: asset.normalize(amplitude),
+        volatility_ratio: moyenne(impact.iter().map(range)) / baseline,
+        directional_ratio,
+        peak_minutes: minutes(event_time, peak.1),
+        agitation_minutes,
+        return_to_normal_minutes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn event_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 8, 13, 30, 0)
+            .single()
+            .expect("date")
+    }
+
+    fn candle(minute: i64, open: f64, range: f64, close: f64) -> Candle {
+        Candle {
+            id: None,
+            symbol: "EURUSD".to_string(),
+            datetime: event_time() + Duration::minutes(minute),
+            open,
+            high: open + range,
+            low: open,
+            close,
+            volume: 100.0,
+            spread: None,
+        }
+    }
+
+    fn nfp() -> CalendarEvent {
+        CalendarEvent {
+            id: 1,
+            symbol: "USD".to_string(),
+            event_time: event_time().naive_utc(),
+            impact: "H".to_string(),
+            description: "Non-Farm Payrolls".to_string(),
+            actual: None,
+            forecast: None,
+            previous: None,
+            created_at: event_time().naive_utc(),
+            calendar_import_id: 1,
+        }
+    }
+
+    #[test]
+    fn test_occurrence_measured_from_candles() {
+        // Calme à 2 pips, agitation à T-3, impulsion haussière T → T+4 (pic à T+2)
+        let candles: Vec<Candle> = (-60..90)
+            .map(|m| match m {
+                -3 => candle(m, 1.1000, 0.0005, 1.1000),
+                0..=4 => {
+                    let open = 1.1000 + 0.0008 * m as f64;
+                    let range = if m == 2 { 0.0015 } else { 0.0010 };
+                    candle(m, open, range, open + 0.0008)
+                }
+                5.. => candle(m, 1.1040, 0.0002, 1.1040),
+                _ => candle(m, 1.1000, 0.0002, 1.1000),
+            })
+            .collect();
+        let asset = AssetProperties::from_symbol("EURUSD");
+        let o = mesurer_occurrence("EURUSD", &nfp(), &candles, &asset).expect("occurrence");
+        assert!((o.impact_pips - 42.0).abs() < 1e-6);
+        assert!((o.volatility_ratio - 1.75).abs() < 1e-6);
+        assert!(o.directional_ratio > 0.9);
+        assert_eq!(o.peak_minutes, 2.0);
+        assert_eq!(o.return_to_normal_minutes, 5.0);
+        assert_eq!(o.agitation_minutes, 3.0);
+
+        // Pas de bougies de référence : occurrence non mesurable
+        assert!(mesurer_occurrence("EURUSD", &nfp(), &candles[40..], &asset).is_none());
+    }
+}
@@ -1,8 +1,9 @@
 // services/global_analyzer_straddle_calc.rs
 // Calcul du succès Straddle par paire
 
-use super::global_analyzer_types::PairCorrelationArchive;
+use crate::models::archive_payload::{ArchiveKind, ArchiveSummary};
 use crate::models::StraddleSuccessRate;
+use crate::services::ArchiveSchema;
 use std::collections::HashMap;
 use tracing::warn;
 
@@ -12,14 +13,16 @@ pub fn compute_pair_straddle_rates(
     let mut pair_stats: HashMap<String, (Vec<f64>, Vec<String>)> = HashMap::new();
 
     for archive in archives {
-        if !archive.archive_type.contains("Corrélation paire/événement") {
+        if ArchiveKind::from_archive_type(&archive.archive_type)
+            != Some(ArchiveKind::PairCorrelation)
+        {
             continue;
         }
 
-        match serde_json::from_str::<PairCorrelationArchive>(&archive.data_json) {
-            Ok(pair_data) => {
-                let pair = pair_data.pair_correlation.pair.clone();
-                let events = &pair_data.pair_correlation.events;
+        match ArchiveSchema::summary(archive) {
+            Ok(ArchiveSummary::PairCorrelation(pair_data)) => {
+                let pair = pair_data.pair.clone();
+                let events = &pair_data.events;
 
                 if events.is_empty() {
                     continue;
@@ -33,6 +36,7 @@ pub fn compute_pair_straddle_rates(
                 entry.0.extend(volatilities);
                 entry.1.extend(event_names);
             }
+            Ok(_) => {}
             Err(e) => {
                 warn!(
                     "Erreur lecture archive corrélation paire {}: {}",
@@ -0,0 +1,153 @@
+// services/heatmap_cells.rs - Agrégation des cellules de la heatmap de corrélation
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::{ConfidenceInterval, RegimeCalendar, TimeDecay, VolatilityRegime};
+use crate::services::statistics;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+/// Score moyen d'un type d'événement sur une paire
+#[derive(Debug, Clone)]
+pub struct HeatmapCell {
+    pub value: f64,
+    pub has_data: bool,
+    /// Intervalle bootstrap du score moyen (un échantillon = un événement)
+    pub interval: Option<ConfidenceInterval>,
+}
+
+/// Agrège les scores des occurrences d'un événement sur une paire.
+/// Les occurrences hors du régime demandé sont ignorées, celles sans bougies aussi
+/// (`score` renvoie None) ; la moyenne est pondérée par ancienneté.
+pub fn agreger_cellule(
+    occurrences: &[NaiveDateTime],
+    regime: Option<(&RegimeCalendar, VolatilityRegime)>,
+    decay: &TimeDecay,
+    mut score: impl FnMut(NaiveDateTime) -> Option<f64>,
+) -> HeatmapCell {
+    let mut scores = Vec::new();
+    let mut dates = Vec::new();
+    let mut has_data = false;
+
+    for &occurrence in occurrences {
+        if let Some((calendar, target)) = regime {
+            if calendar.regime_on(occurrence.date()) != Some(target) {
+                continue;
+            }
+        }
+        let Some(value) = score(occurrence) else {
+            continue;
+        };
+        has_data = true;
+        if value > 0.0 {
+            scores.push(value);
+            dates.push(occurrence.date());
+        }
+    }
+
+    HeatmapCell {
+        value: statistics::weighted_mean(&scores, &decay.weights(&dates)),
+        has_data,
+        interval: statistics::bootstrap_mean_interval(&scores),
+    }
+}
+
+/// Grille événement × paire : score arrondi au dixième (-1 sans données) et intervalles
+#[derive(Debug, Default)]
+pub struct HeatmapGrid {
+    pub data: HashMap<String, HashMap<String, f64>>,
+    /// Cellules sans données absentes
+    pub intervals: HashMap<String, HashMap<String, ConfidenceInterval>>,
+}
+
+impl HeatmapGrid {
+    pub fn inserer(&mut self, event_type: &str, pair: &str, cell: HeatmapCell) {
+        let value = if cell.has_data {
+            (cell.value * 10.0).round() / 10.0
+        } else {
+            -1.0
+        };
+        self.data
+            .entry(event_type.to_string())
+            .or_default()
+            .insert(pair.to_string(), value);
+        if let Some(interval) = cell.interval {
+            self.intervals
+                .entry(event_type.to_string())
+                .or_default()
+                .insert(pair.to_string(), interval);
+        }
+    }
+
+    /// Cellule sans données comparables (ex. régimes non classables pour la paire)
+    pub fn sans_donnees(&mut self, event_type: &str, pair: &str) {
+        self.data
+            .entry(event_type.to_string())
+            .or_default()
+            .insert(pair.to_string(), -1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RegimeDay, RegimeThresholds};
+    use chrono::{Datelike, NaiveDate};
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, day)
+            .and_then(|d| d.and_hms_opt(14, 30, 0))
+            .unwrap_or_default()
+    }
+
+    fn calendar() -> RegimeCalendar {
+        let day = |d: u32, regime| RegimeDay {
+            date: at(d).date(),
+            atr: 0.01,
+            regime,
+        };
+        RegimeCalendar {
+            symbol: "EURUSD".to_string(),
+            atr_period: 14,
+            thresholds: RegimeThresholds {
+                p25: 0.005,
+                p75: 0.01,
+                p95: 0.02,
+            },
+            days: vec![
+                day(1, VolatilityRegime::Calm),
+                day(2, VolatilityRegime::Extreme),
+            ],
+            current: Some(VolatilityRegime::Extreme),
+        }
+    }
+
+    #[test]
+    fn test_cell_skips_other_regimes_and_missing_candles() {
+        // 2 mars : régime de la veille (calme) ; 3 mars : extrême ; 4 mars : sans bougies
+        let occurrences = [at(2), at(3), at(4)];
+        let calendar = calendar();
+        let cell = agreger_cellule(
+            &occurrences,
+            Some((&calendar, VolatilityRegime::Extreme)),
+            &TimeDecay::default(),
+            |dt| (dt != at(4)).then_some(dt.day() as f64),
+        );
+        assert!(cell.has_data);
+        assert_eq!(cell.value, 3.0);
+    }
+
+    #[test]
+    fn test_grid_marks_cells_without_data() {
+        let mut grid = HeatmapGrid::default();
+        let vide = HeatmapCell {
+            value: 0.0,
+            has_data: false,
+            interval: None,
+        };
+        grid.inserer("NFP", "EURUSD", vide);
+        grid.sans_donnees("CPI", "EURUSD");
+        assert_eq!(grid.data["NFP"]["EURUSD"], -1.0);
+        assert_eq!(grid.data["CPI"]["EURUSD"], -1.0);
+        assert!(grid.intervals.is_empty());
+    }
+}
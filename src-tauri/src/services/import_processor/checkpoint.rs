@@ -0,0 +1,96 @@
+// services/import_processor/checkpoint.rs - Point de reprise d'un import en flux (import_log)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::merge::{merge_batch, MergeContext, MergeStats};
+use super::writer::apply_ask_quotes;
+use crate::services::pair_data::{NormalizedCandle, StreamPosition};
+use rusqlite::{params, Connection, OptionalExtension};
+
+/// Point de reprise enregistré dans import_log
+pub(super) struct Checkpoint {
+    pub(super) log_id: i64,
+    pub(super) symbol: String,
+    pub(super) timeframe: String,
+    pub(super) rows_committed: usize,
+    pub(super) position: StreamPosition,
+}
+
+/// Empreinte du fichier source (taille + date de modification) pour valider une reprise
+pub(super) fn file_fingerprint(path: &str) -> Result<String, String> {
+    let meta = std::fs::metadata(path).map_err(|e| format!("Erreur métadonnées: {}", e))?;
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Ok(format!("{}:{}", meta.len(), modified))
+}
+
+/// Dernier point de reprise valide pour ce fichier (même empreinte), s'il existe
+pub(super) fn find_checkpoint(
+    conn: &Connection,
+    filename: &str,
+    fingerprint: &str,
+) -> Result<Option<Checkpoint>, String> {
+    conn.query_row(
+        "SELECT id, symbol, timeframe, row_count, resume_offset, resume_line FROM import_log
+         WHERE filename = ? AND checksum = ? AND status IN ('in_progress', 'interrupted')
+           AND resume_offset IS NOT NULL
+         ORDER BY id DESC LIMIT 1",
+        params![filename, fingerprint],
+        |row| {
+            Ok(Checkpoint {
+                log_id: row.get(0)?,
+                symbol: row.get(1)?,
+                timeframe: row.get(2)?,
+                rows_committed: row.get::<_, i64>(3)? as usize,
+                position: StreamPosition {
+                    byte: row.get::<_, i64>(4)? as u64,
+                    line: row.get::<_, i64>(5)? as u64,
+                },
+            })
+        },
+    )
+    .optional()
+    .map_err(|e| format!("SELECT import_log error: {}", e))
+}
+
+/// Écrit un lot et avance le point de reprise dans la même transaction
+pub(super) fn commit_batch(
+    conn: &mut Connection,
+    ctx: &MergeContext,
+    candles: &[NormalizedCandle],
+    position: StreamPosition,
+    ask_side: bool,
+) -> Result<MergeStats, String> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Transaction begin error: {}", e))?;
+
+    // Un export Ask complète les bougies existantes sans passer par la fusion
+    let stats = if ask_side {
+        MergeStats {
+            inserted: apply_ask_quotes(&tx, candles, ctx.symbol, ctx.timeframe)?,
+            ..MergeStats::default()
+        }
+    } else {
+        merge_batch(&tx, candles, ctx)?
+    };
+
+    tx.execute(
+        "UPDATE import_log SET row_count = row_count + ?, resume_offset = ?, resume_line = ?
+         WHERE id = ?",
+        params![
+            stats.written() as i64,
+            position.byte as i64,
+            position.line as i64,
+            ctx.log_id
+        ],
+    )
+    .map_err(|e| format!("UPDATE import_log error: {}", e))?;
+
+    tx.commit()
+        .map_err(|e| format!("Transaction commit error: {}", e))?;
+    Ok(stats)
+}
@@ -0,0 +1,271 @@
+// services/import_processor/merge.rs - Fusion des bougies de sources qui se chevauchent
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::services::pair_data::NormalizedCandle;
+use chrono::Utc;
+use rusqlite::{params, OptionalExtension, Transaction};
+use serde::{Deserialize, Serialize};
+
+/// Politique appliquée quand une bougie existe déjà pour (symbole, timeframe, heure)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum MergePolicy {
+    /// La première source importée reste en place (comportement historique)
+    #[default]
+    KeepExisting,
+    /// Le fichier importé en dernier remplace les bougies existantes
+    PreferNewer,
+    /// La source dont le nom de fichier contient `source` l'emporte
+    PreferSource { source: String },
+    /// Conserve l'existant et signale les bougies dont les prix divergent
+    Reconcile { tolerance_pips: f64 },
+}
+
+impl MergePolicy {
+    /// Vrai si la bougie entrante doit remplacer celle d'une autre source
+    fn incoming_wins(&self, incoming_file: &str, existing_file: &str) -> bool {
+        match self {
+            MergePolicy::KeepExisting | MergePolicy::Reconcile { .. } => false,
+            MergePolicy::PreferNewer => true,
+            MergePolicy::PreferSource { source } => {
+                let source = source.to_lowercase();
+                incoming_file.to_lowercase().contains(&source)
+                    && !existing_file.to_lowercase().contains(&source)
+            }
+        }
+    }
+}
+
+/// Compteurs de fusion d'un lot (ou d'un import complet)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct MergeStats {
+    pub inserted: usize,
+    pub replaced: usize,
+    /// Bougies d'une autre source conservées (l'entrée part dans candle_alternates)
+    pub kept_existing: usize,
+    /// Relectures de lignes déjà écrites par ce même fichier (reprise)
+    pub already_present: usize,
+    pub divergent: usize,
+}
+
+impl MergeStats {
+    /// Bougies écrites dans candle_data par ce fichier
+    pub fn written(&self) -> usize {
+        self.inserted + self.replaced
+    }
+
+    pub fn add(&mut self, other: &MergeStats) {
+        self.inserted += other.inserted;
+        self.replaced += other.replaced;
+        self.kept_existing += other.kept_existing;
+        self.already_present += other.already_present;
+        self.divergent += other.divergent;
+    }
+}
+
+/// Contexte commun à tous les lots d'un import
+pub(super) struct MergeContext<'a> {
+    pub log_id: i64,
+    pub symbol: &'a str,
+    pub timeframe: &'a str,
+    pub filename: &'a str,
+    pub imported_at: &'a str,
+    pub point_value: f64,
+    pub pip_size: f64,
+    pub policy: &'a MergePolicy,
+}
+
+struct ExistingBar {
+    id: i64,
+    source_file: String,
+    prices: [f64; 4],
+}
+
+/// Fusionne un lot dans candle_data et enregistre la plage couverte par la source
+pub(super) fn merge_batch(
+    tx: &Transaction,
+    candles: &[NormalizedCandle],
+    ctx: &MergeContext,
+) -> Result<MergeStats, String> {
+    let mut stats = MergeStats::default();
+    for candle in candles {
+        let dt = chrono::DateTime::<Utc>::from_timestamp(candle.timestamp, 0)
+            .ok_or(format!("Invalid timestamp: {}", candle.timestamp))?;
+        let time = dt.to_rfc3339();
+
+        // Les deux formats de date coexistent en BD selon le chemin d'import
+        let existing = tx
+            .prepare_cached(
+                "SELECT id, source_file, open, high, low, close FROM candle_data
+                 WHERE symbol = ?1 AND timeframe = ?2 AND (time = ?3 OR time = ?4) LIMIT 1",
+            )
+            .and_then(|mut stmt| {
+                stmt.query_row(
+                    params![
+                        ctx.symbol,
+                        ctx.timeframe,
+                        &time,
+                        dt.format("%Y-%m-%d %H:%M:%S").to_string()
+                    ],
+                    |row| {
+                        Ok(ExistingBar {
+                            id: row.get(0)?,
+                            source_file: row.get(1)?,
+                            prices: [row.get(2)?, row.get(3)?, row.get(4)?, row.get(5)?],
+                        })
+                    },
+                )
+                .optional()
+            })
+            .map_err(|e| format!("SELECT candle_data error: {}", e))?;
+
+        let Some(existing) = existing else {
+            write_candle(tx, "INSERT INTO candle_data", candle, &time, ctx)?;
+            stats.inserted += 1;
+            continue;
+        };
+        if existing.source_file == ctx.filename {
+            stats.already_present += 1;
+            continue;
+        }
+
+        if let MergePolicy::Reconcile { tolerance_pips } = ctx.policy {
+            let diff_pips = max_price_diff(&existing.prices, candle) / ctx.pip_size;
+            if diff_pips > *tolerance_pips {
+                tx.execute(
+                    "INSERT INTO candle_divergence (symbol, timeframe, time, source_file, other_source_file,
+                                                    max_diff_pips, detected_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?)",
+                    params![
+                        ctx.symbol,
+                        ctx.timeframe,
+                        &time,
+                        ctx.filename,
+                        &existing.source_file,
+                        diff_pips,
+                        ctx.imported_at
+                    ],
+                )
+                .map_err(|e| format!("INSERT candle_divergence error: {}", e))?;
+                stats.divergent += 1;
+            }
+        }
+
+        if ctx
+            .policy
+            .incoming_wins(ctx.filename, &existing.source_file)
+        {
+            // L'ancienne bougie reste disponible si ce fichier est supprimé plus tard
+            tx.execute(
+                "INSERT OR REPLACE INTO candle_alternates
+                    (symbol, timeframe, time, open, high, low, close, volume, spread,
+                     ask_open, ask_high, ask_low, ask_close, source_file, imported_at)
+                 SELECT symbol, timeframe, ?2, open, high, low, close, volume, spread,
+                        ask_open, ask_high, ask_low, ask_close, source_file, imported_at
+                 FROM candle_data WHERE id = ?1",
+                params![existing.id, &time],
+            )
+            .map_err(|e| format!("INSERT candle_alternates error: {}", e))?;
+            tx.execute("DELETE FROM candle_data WHERE id = ?", params![existing.id])
+                .map_err(|e| format!("DELETE candle_data error: {}", e))?;
+            write_candle(tx, "INSERT INTO candle_data", candle, &time, ctx)?;
+            stats.replaced += 1;
+        } else {
+            write_candle(
+                tx,
+                "INSERT OR REPLACE INTO candle_alternates",
+                candle,
+                &time,
+                ctx,
+            )?;
+            stats.kept_existing += 1;
+        }
+    }
+
+    record_range(tx, candles, ctx, &stats)?;
+    Ok(stats)
+}
+
+fn write_candle(
+    tx: &Transaction,
+    insert: &str,
+    candle: &NormalizedCandle,
+    time: &str,
+    ctx: &MergeContext,
+) -> Result<(), String> {
+    let sql = format!(
+        "{} (symbol, timeframe, time, open, high, low, close, volume, imported_at, source_file,
+             spread, ask_open, ask_high, ask_low, ask_close)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        insert
+    );
+    tx.prepare_cached(&sql)
+        .and_then(|mut stmt| {
+            stmt.execute(params![
+                ctx.symbol,
+                ctx.timeframe,
+                time,
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                candle.volume,
+                ctx.imported_at,
+                ctx.filename,
+                candle.spread_price(ctx.point_value),
+                candle.ask.map(|a| a.open),
+                candle.ask.map(|a| a.high),
+                candle.ask.map(|a| a.low),
+                candle.ask.map(|a| a.close),
+            ])
+        })
+        .map(|_| ())
+        .map_err(|e| format!("{} error: {}", insert, e))
+}
+
+fn max_price_diff(existing: &[f64; 4], candle: &NormalizedCandle) -> f64 {
+    existing
+        .iter()
+        .zip([candle.open, candle.high, candle.low, candle.close])
+        .map(|(a, b)| (a - b).abs())
+        .fold(0.0, f64::max)
+}
+
+/// Provenance : plage [début, fin] fournie par la source, qu'elle ait gagné ou non
+fn record_range(
+    tx: &Transaction,
+    candles: &[NormalizedCandle],
+    ctx: &MergeContext,
+    stats: &MergeStats,
+) -> Result<(), String> {
+    let (Some(start), Some(end)) = (
+        candles.iter().map(|c| c.timestamp).min(),
+        candles.iter().map(|c| c.timestamp).max(),
+    ) else {
+        return Ok(());
+    };
+    let to_time = |ts: i64| {
+        chrono::DateTime::<Utc>::from_timestamp(ts, 0)
+            .map(|dt| dt.to_rfc3339())
+            .ok_or(format!("Invalid timestamp: {}", ts))
+    };
+
+    tx.execute(
+        "INSERT INTO candle_provenance (import_log_id, source_file, symbol, timeframe, start_time,
+                                        end_time, row_count, rows_kept, merge_policy)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        params![
+            ctx.log_id,
+            ctx.filename,
+            ctx.symbol,
+            ctx.timeframe,
+            to_time(start)?,
+            to_time(end)?,
+            candles.len() as i64,
+            stats.written() as i64,
+            serde_json::to_string(ctx.policy).unwrap_or_default()
+        ],
+    )
+    .map_err(|e| format!("INSERT candle_provenance error: {}", e))?;
+    Ok(())
+}
@@ -0,0 +1,227 @@
+// services/import_processor/mod.rs - Import en flux des CSV de paires (parse + nettoyage + insertion)
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+mod checkpoint;
+mod merge;
+mod promotion;
+mod sources;
+mod writer;
+
+pub use merge::{MergePolicy, MergeStats};
+pub use sources::{delete_source_file, list_sources, SourceCoverage, SourceRemoval};
+
+use crate::models::AssetProperties;
+use crate::services::pair_data::{
+    get_point_value, CandleStream, PairDataConverter, StreamPosition,
+};
+use checkpoint::{commit_batch, file_fingerprint, find_checkpoint, Checkpoint};
+use chrono::Utc;
+use merge::MergeContext;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use writer::{finalize, open_pairs_db};
+
+/// Bougies insérées par transaction (= granularité du point de reprise)
+const BATCH_SIZE: usize = 20_000;
+
+/// Progression d'un import, émise vers le frontend après chaque lot validé
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProgress {
+    pub filename: String,
+    pub symbol: String,
+    pub timeframe: String,
+    pub rows_committed: usize,
+    pub bytes_read: u64,
+    pub total_bytes: u64,
+    pub percent: f64,
+    pub resumed: bool,
+    pub done: bool,
+}
+
+/// Résultat de l'import en flux d'un fichier
+#[derive(Debug, Clone)]
+pub struct StreamImportOutcome {
+    pub symbol: String,
+    pub timeframe: String,
+    /// Format retenu par la détection (intégré ou profil utilisateur)
+    pub format: String,
+    /// Lignes écrites en BD, sessions précédentes incluses en cas de reprise
+    pub rows_committed: usize,
+    pub duplicates_skipped: usize,
+    /// Détail de la fusion avec les bougies déjà présentes (session courante)
+    pub merge: MergeStats,
+    pub lines_processed: usize,
+    pub errors: usize,
+    pub warnings: Vec<String>,
+    pub resumed_from_line: Option<u64>,
+}
+
+/// Importe un fichier en un seul passage : parse, nettoyage et insertion par lots.
+/// Chaque lot est validé avec son point de reprise dans import_log ; un import
+/// interrompu reprend à la dernière ligne validée au lieu de tout recommencer.
+/// Les bougies déjà fournies par une autre source sont arbitrées par `policy`.
+pub fn import_file_streaming(
+    source_path: &str,
+    policy: &MergePolicy,
+    progress: &dyn Fn(&ImportProgress),
+) -> Result<StreamImportOutcome, String> {
+    let filename = Path::new(source_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or("Nom de fichier invalide")?
+        .to_string();
+    let fingerprint = file_fingerprint(source_path)?;
+    let ask_side = PairDataConverter::is_ask_side_file(&filename);
+
+    let mut conn = open_pairs_db()?;
+    let mut stream = CandleStream::open(source_path)?;
+    let imported_at = Utc::now().to_rfc3339();
+
+    let (checkpoint, mut pending) = match find_checkpoint(&conn, &filename, &fingerprint)? {
+        Some(checkpoint) => {
+            tracing::info!(
+                "⏯️ Reprise de {} à la ligne {} ({} lignes déjà validées)",
+                filename,
+                checkpoint.position.line,
+                checkpoint.rows_committed
+            );
+            stream.resume_at(checkpoint.position)?;
+            (checkpoint, Vec::new())
+        }
+        None => {
+            // Le premier lot sert à détecter paire et timeframe
+            let first = stream.next_batch(BATCH_SIZE);
+            let metadata = PairDataConverter::extract_metadata(&first, &filename)?;
+            conn.execute(
+                "INSERT INTO import_log (filename, symbol, timeframe, imported_at, row_count, status, checksum,
+                                         source_path, resume_offset, resume_line)
+                 VALUES (?, ?, ?, ?, 0, 'in_progress', ?, ?, 0, 0)",
+                params![
+                    &filename,
+                    &metadata.pair,
+                    &metadata.timeframe,
+                    &imported_at,
+                    &fingerprint,
+                    source_path
+                ],
+            )
+            .map_err(|e| format!("INSERT import_log error: {}", e))?;
+            let checkpoint = Checkpoint {
+                log_id: conn.last_insert_rowid(),
+                symbol: metadata.pair,
+                timeframe: metadata.timeframe,
+                rows_committed: 0,
+                position: StreamPosition::default(),
+            };
+            (checkpoint, first)
+        }
+    };
+
+    let resumed_from_line = (checkpoint.position.line > 0).then_some(checkpoint.position.line);
+    let point_value = get_point_value(&checkpoint.symbol);
+    let mut rows_committed = checkpoint.rows_committed;
+    let mut merge_stats = MergeStats::default();
+    let merge_ctx = MergeContext {
+        log_id: checkpoint.log_id,
+        symbol: &checkpoint.symbol,
+        timeframe: &checkpoint.timeframe,
+        filename: &filename,
+        imported_at: &imported_at,
+        point_value,
+        pip_size: AssetProperties::from_symbol(&checkpoint.symbol).pip_value,
+        policy,
+    };
+
+    loop {
+        if pending.is_empty() {
+            pending = stream.next_batch(BATCH_SIZE);
+        }
+        if pending.is_empty() {
+            break;
+        }
+
+        let position = stream.position();
+        let result = commit_batch(&mut conn, &merge_ctx, &pending, position, ask_side);
+        let batch = match result {
+            Ok(batch) => batch,
+            Err(e) => {
+                let _ = conn.execute(
+                    "UPDATE import_log SET status = 'interrupted', error_message = ? WHERE id = ?",
+                    params![&e, checkpoint.log_id],
+                );
+                return Err(e);
+            }
+        };
+
+        rows_committed += batch.written();
+        merge_stats.add(&batch);
+        pending.clear();
+
+        progress(&ImportProgress {
+            filename: filename.clone(),
+            symbol: checkpoint.symbol.clone(),
+            timeframe: checkpoint.timeframe.clone(),
+            rows_committed,
+            bytes_read: position.byte,
+            total_bytes: stream.total_bytes(),
+            percent: percent(position.byte, stream.total_bytes()),
+            resumed: resumed_from_line.is_some(),
+            done: false,
+        });
+    }
+
+    finalize(
+        &mut conn,
+        &checkpoint,
+        rows_committed,
+        ask_side,
+        &imported_at,
+        &filename,
+        &stream,
+    )?;
+
+    progress(&ImportProgress {
+        filename: filename.clone(),
+        symbol: checkpoint.symbol.clone(),
+        timeframe: checkpoint.timeframe.clone(),
+        rows_committed,
+        bytes_read: stream.total_bytes(),
+        total_bytes: stream.total_bytes(),
+        percent: 100.0,
+        resumed: resumed_from_line.is_some(),
+        done: true,
+    });
+
+    let duplicates_skipped = merge_stats.kept_existing + merge_stats.already_present;
+    tracing::info!(
+        "🎉 Import en flux terminé: {}/{} ({} lignes, {} remplacées, {} doublons, {} divergentes, {} erreurs)",
+        checkpoint.symbol,
+        checkpoint.timeframe,
+        rows_committed,
+        merge_stats.replaced,
+        duplicates_skipped,
+        merge_stats.divergent,
+        stream.errors()
+    );
+
+    Ok(StreamImportOutcome {
+        symbol: checkpoint.symbol,
+        timeframe: checkpoint.timeframe,
+        format: stream.detection().format.clone(),
+        rows_committed,
+        duplicates_skipped,
+        merge: merge_stats,
+        lines_processed: stream.lines_read(),
+        errors: stream.errors(),
+        warnings: stream.warnings().to_vec(),
+        resumed_from_line,
+    })
+}
+
+fn percent(done: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (done as f64 / total as f64 * 100.0).min(100.0)
+}
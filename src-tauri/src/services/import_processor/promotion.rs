@@ -0,0 +1,83 @@
+// services/import_processor/promotion.rs - Bougies d'autres sources reprenant la place d'un fichier supprimé
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use rusqlite::{params, Transaction};
+
+/// Bougie alternative d'une autre source à promouvoir dans candle_data
+struct Promotion {
+    candle_id: i64,
+    alternate_id: i64,
+}
+
+/// Remplace chaque bougie du fichier par l'alternative d'une autre source ; renvoie le nombre restauré
+pub(super) fn promouvoir_alternatives(
+    tx: &Transaction,
+    source_file: &str,
+) -> Result<usize, String> {
+    let promotions = find_promotions(tx, source_file)?;
+
+    for promotion in &promotions {
+        tx.execute(
+            "UPDATE candle_data SET (time, open, high, low, close, volume, spread, ask_open, ask_high,
+                                     ask_low, ask_close, source_file, imported_at) =
+                (SELECT time, open, high, low, close, volume, spread, ask_open, ask_high,
+                        ask_low, ask_close, source_file, imported_at
+                 FROM candle_alternates WHERE id = ?2)
+             WHERE id = ?1",
+            params![promotion.candle_id, promotion.alternate_id],
+        )
+        .map_err(|e| format!("UPDATE candle_data (restauration) error: {}", e))?;
+        tx.execute(
+            "DELETE FROM candle_alternates WHERE id = ?",
+            params![promotion.alternate_id],
+        )
+        .map_err(|e| format!("DELETE candle_alternates error: {}", e))?;
+    }
+    Ok(promotions.len())
+}
+
+pub(super) fn affected_pairs(
+    tx: &Transaction,
+    source_file: &str,
+) -> Result<Vec<(String, String)>, String> {
+    let mut stmt = tx
+        .prepare(
+            "SELECT DISTINCT symbol, timeframe FROM candle_data WHERE source_file = ?1
+             UNION SELECT DISTINCT symbol, timeframe FROM candle_provenance WHERE source_file = ?1",
+        )
+        .map_err(|e| format!("Prepare error: {}", e))?;
+    let rows = stmt
+        .query_map(params![source_file], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(|e| format!("SELECT paires error: {}", e))?;
+    rows.collect::<Result<_, _>>()
+        .map_err(|e| format!("Lecture paires error: {}", e))
+}
+
+/// Associe chaque bougie du fichier à l'alternative la plus récente d'une autre source
+fn find_promotions(tx: &Transaction, source_file: &str) -> Result<Vec<Promotion>, String> {
+    // Les alternatives sont horodatées en RFC 3339, candle_data peut contenir l'ancien format
+    let mut stmt = tx
+        .prepare(
+            "SELECT candle_id, alternate_id FROM (
+                SELECT c.id AS candle_id,
+                       (SELECT a.id FROM candle_alternates a
+                        WHERE a.symbol = c.symbol AND a.timeframe = c.timeframe
+                          AND a.time IN (c.time, replace(c.time, ' ', 'T') || '+00:00')
+                          AND a.source_file <> ?1
+                        ORDER BY a.imported_at DESC LIMIT 1) AS alternate_id
+                FROM candle_data c
+                WHERE c.source_file = ?1
+             ) WHERE alternate_id IS NOT NULL",
+        )
+        .map_err(|e| format!("Prepare error: {}", e))?;
+    let rows = stmt
+        .query_map(params![source_file], |row| {
+            Ok(Promotion {
+                candle_id: row.get(0)?,
+                alternate_id: row.get(1)?,
+            })
+        })
+        .map_err(|e| format!("SELECT alternatives error: {}", e))?;
+    rows.collect::<Result<_, _>>()
+        .map_err(|e| format!("Lecture alternatives error: {}", e))
+}
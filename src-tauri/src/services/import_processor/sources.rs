@@ -0,0 +1,274 @@
+// services/import_processor/sources.rs - Suppression d'une source sans trou dans l'historique
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::promotion::{affected_pairs, promouvoir_alternatives};
+use super::writer::open_pairs_db;
+use rusqlite::{params, Connection};
+use serde::Serialize;
+
+/// Bilan de la suppression d'un fichier source
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceRemoval {
+    pub source_file: String,
+    /// Bougies du fichier remplacées par celles d'une autre source
+    pub rows_restored: usize,
+    /// Bougies supprimées faute d'autre source sur la même heure
+    pub rows_deleted: usize,
+}
+
+/// Couverture d'une source pour une paire (agrégat de ses plages de provenance)
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceCoverage {
+    pub source_file: String,
+    pub start_time: String,
+    pub end_time: String,
+    /// Bougies fournies par le fichier
+    pub rows_supplied: i64,
+    /// Bougies du fichier retenues dans candle_data
+    pub rows_kept: i64,
+    pub divergent_bars: i64,
+}
+
+/// Supprime les bougies d'un fichier source ; là où une autre source avait
+/// fourni la même heure, sa bougie (conservée dans candle_alternates) reprend la place
+pub fn delete_source_file(source_file: &str) -> Result<SourceRemoval, String> {
+    let mut conn = open_pairs_db()?;
+    remove_source(&mut conn, source_file)
+}
+
+/// Sources ayant fourni des bougies pour une paire, de la plus ancienne à la plus récente
+pub fn list_sources(symbol: &str, timeframe: &str) -> Result<Vec<SourceCoverage>, String> {
+    let conn = open_pairs_db()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT p.source_file, MIN(p.start_time), MAX(p.end_time), SUM(p.row_count), SUM(p.rows_kept),
+                    (SELECT COUNT(*) FROM candle_divergence d
+                     WHERE d.symbol = ?1 AND d.timeframe = ?2 AND d.source_file = p.source_file)
+             FROM candle_provenance p
+             WHERE p.symbol = ?1 AND p.timeframe = ?2
+             GROUP BY p.source_file
+             ORDER BY MIN(p.start_time)",
+        )
+        .map_err(|e| format!("Prepare error: {}", e))?;
+    let rows = stmt
+        .query_map(params![symbol, timeframe], |row| {
+            Ok(SourceCoverage {
+                source_file: row.get(0)?,
+                start_time: row.get(1)?,
+                end_time: row.get(2)?,
+                rows_supplied: row.get(3)?,
+                rows_kept: row.get(4)?,
+                divergent_bars: row.get(5)?,
+            })
+        })
+        .map_err(|e| format!("SELECT candle_provenance error: {}", e))?;
+    rows.collect::<Result<_, _>>()
+        .map_err(|e| format!("Lecture provenance error: {}", e))
+}
+
+pub(super) fn remove_source(
+    conn: &mut Connection,
+    source_file: &str,
+) -> Result<SourceRemoval, String> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Transaction begin error: {}", e))?;
+
+    let pairs = affected_pairs(&tx, source_file)?;
+    let rows_restored = promouvoir_alternatives(&tx, source_file)?;
+
+    let rows_deleted = tx
+        .execute(
+            "DELETE FROM candle_data WHERE source_file = ?",
+            params![source_file],
+        )
+        .map_err(|e| format!("DELETE candle_data error: {}", e))?;
+
+    for sql in [
+        "DELETE FROM candle_alternates WHERE source_file = ?1",
+        "DELETE FROM candle_provenance WHERE source_file = ?1",
+        "DELETE FROM candle_divergence WHERE source_file = ?1 OR other_source_file = ?1",
+    ] {
+        tx.execute(sql, params![source_file])
+            .map_err(|e| format!("Nettoyage provenance error: {}", e))?;
+    }
+
+    for (symbol, timeframe) in &pairs {
+        tx.execute(
+            "UPDATE pair_metadata SET row_count =
+                (SELECT COUNT(*) FROM candle_data WHERE symbol = ?1 AND timeframe = ?2)
+             WHERE symbol = ?1 AND timeframe = ?2",
+            params![symbol, timeframe],
+        )
+        .map_err(|e| format!("UPDATE pair_metadata error: {}", e))?;
+        tx.execute(
+            "DELETE FROM pair_metadata WHERE symbol = ? AND timeframe = ? AND row_count = 0",
+            params![symbol, timeframe],
+        )
+        .map_err(|e| format!("DELETE pair_metadata error: {}", e))?;
+    }
+
+    tx.commit()
+        .map_err(|e| format!("Transaction commit error: {}", e))?;
+
+    tracing::info!(
+        "🗑️ Source {} supprimée: {} bougies restaurées depuis d'autres sources, {} supprimées",
+        source_file,
+        rows_restored,
+        rows_deleted
+    );
+    Ok(SourceRemoval {
+        source_file: source_file.to_string(),
+        rows_restored,
+        rows_deleted,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::merge::{merge_batch, MergeContext, MergePolicy};
+    use super::*;
+    use crate::services::pair_data::NormalizedCandle;
+
+    fn setup() -> Connection {
+        let conn = Connection::open_in_memory().expect("BD mémoire");
+        conn.execute_batch(
+            "CREATE TABLE candle_data (id INTEGER PRIMARY KEY, symbol TEXT, timeframe TEXT, time TEXT,
+                open REAL, high REAL, low REAL, close REAL, volume REAL, imported_at TEXT,
+                source_file TEXT, spread REAL, ask_open REAL, ask_high REAL, ask_low REAL, ask_close REAL,
+                UNIQUE(symbol, timeframe, time));
+             CREATE TABLE candle_alternates (id INTEGER PRIMARY KEY, symbol TEXT, timeframe TEXT, time TEXT,
+                open REAL, high REAL, low REAL, close REAL, volume REAL, spread REAL, ask_open REAL,
+                ask_high REAL, ask_low REAL, ask_close REAL, source_file TEXT, imported_at TEXT,
+                UNIQUE(symbol, timeframe, time, source_file));
+             CREATE TABLE candle_provenance (id INTEGER PRIMARY KEY, import_log_id INTEGER, source_file TEXT,
+                symbol TEXT, timeframe TEXT, start_time TEXT, end_time TEXT, row_count INTEGER,
+                rows_kept INTEGER, merge_policy TEXT);
+             CREATE TABLE candle_divergence (id INTEGER PRIMARY KEY, symbol TEXT, timeframe TEXT, time TEXT,
+                source_file TEXT, other_source_file TEXT, max_diff_pips REAL, detected_at TEXT);
+             CREATE TABLE pair_metadata (symbol TEXT, timeframe TEXT, row_count INTEGER,
+                UNIQUE(symbol, timeframe));",
+        )
+        .expect("schéma");
+        conn
+    }
+
+    fn candles(start: i64, count: i64, close: f64) -> Vec<NormalizedCandle> {
+        (0..count)
+            .map(|i| NormalizedCandle {
+                timestamp: start + i * 60,
+                open: close,
+                high: close + 0.0002,
+                low: close - 0.0002,
+                close,
+                volume: 1.0,
+                spread_points: None,
+                ask: None,
+            })
+            .collect()
+    }
+
+    fn import(conn: &mut Connection, file: &str, bars: &[NormalizedCandle], policy: &MergePolicy) {
+        let ctx = MergeContext {
+            log_id: 1,
+            symbol: "EURUSD",
+            timeframe: "M1",
+            filename: file,
+            imported_at: file,
+            point_value: 0.00001,
+            pip_size: 0.0001,
+            policy,
+        };
+        let tx = conn.transaction().expect("tx");
+        merge_batch(&tx, bars, &ctx).expect("fusion");
+        tx.commit().expect("commit");
+    }
+
+    fn source_of(conn: &Connection, ts: i64) -> Option<String> {
+        let time = chrono::DateTime::<chrono::Utc>::from_timestamp(ts, 0)
+            .map(|dt| dt.to_rfc3339())
+            .unwrap_or_default();
+        conn.query_row(
+            "SELECT source_file FROM candle_data WHERE time = ?",
+            params![time],
+            |row| row.get(0),
+        )
+        .ok()
+    }
+
+    #[test]
+    fn test_prefer_newer_then_delete_restores_older_source() {
+        let mut conn = setup();
+        let t0 = 1_704_186_000;
+        import(
+            &mut conn,
+            "broker_a.csv",
+            &candles(t0, 10, 1.1000),
+            &MergePolicy::KeepExisting,
+        );
+        // La source B chevauche les 5 dernières minutes de A et en ajoute 5
+        import(
+            &mut conn,
+            "broker_b.csv",
+            &candles(t0 + 300, 10, 1.1001),
+            &MergePolicy::PreferNewer,
+        );
+
+        assert_eq!(source_of(&conn, t0).as_deref(), Some("broker_a.csv"));
+        assert_eq!(source_of(&conn, t0 + 300).as_deref(), Some("broker_b.csv"));
+
+        let removal = remove_source(&mut conn, "broker_b.csv").expect("suppression");
+        assert_eq!(removal.rows_restored, 5);
+        assert_eq!(removal.rows_deleted, 5);
+        // Pas de trou là où A avait des données
+        assert_eq!(source_of(&conn, t0 + 300).as_deref(), Some("broker_a.csv"));
+        assert_eq!(source_of(&conn, t0 + 600), None);
+
+        // Et inversement : supprimer A garde les bougies de B conservées en alternatives
+        import(
+            &mut conn,
+            "broker_b.csv",
+            &candles(t0 + 300, 10, 1.1001),
+            &MergePolicy::KeepExisting,
+        );
+        remove_source(&mut conn, "broker_a.csv").expect("suppression A");
+        assert_eq!(source_of(&conn, t0 + 300).as_deref(), Some("broker_b.csv"));
+        assert_eq!(source_of(&conn, t0), None);
+    }
+
+    #[test]
+    fn test_reconcile_flags_divergent_bars() {
+        let mut conn = setup();
+        let t0 = 1_704_186_000;
+        import(
+            &mut conn,
+            "a.csv",
+            &candles(t0, 4, 1.1000),
+            &MergePolicy::KeepExisting,
+        );
+        let mut other = candles(t0, 4, 1.1000);
+        other[2].close += 0.0005;
+        other[2].high += 0.0005;
+        let policy = MergePolicy::Reconcile {
+            tolerance_pips: 2.0,
+        };
+        import(&mut conn, "b.csv", &other, &policy);
+
+        let divergent: i64 = conn
+            .query_row("SELECT COUNT(*) FROM candle_divergence", [], |row| {
+                row.get(0)
+            })
+            .expect("count");
+        assert_eq!(divergent, 1);
+        assert_eq!(source_of(&conn, t0 + 120).as_deref(), Some("a.csv"));
+
+        let coverage: i64 = conn
+            .query_row(
+                "SELECT SUM(rows_kept) FROM candle_provenance WHERE source_file = 'b.csv'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("provenance");
+        assert_eq!(coverage, 0);
+    }
+}
@@ -0,0 +1,111 @@
+// services/import_processor/writer.rs - Écritures SQL de l'import en flux
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use super::Checkpoint;
+use crate::services::pair_data::{CandleStream, NormalizedCandle};
+use chrono::Utc;
+use rusqlite::{params, Connection, Transaction};
+
+/// Ouvre pairs.db en direct (rusqlite) avec les mêmes PRAGMA que le pool
+pub(super) fn open_pairs_db() -> Result<Connection, String> {
+    let db_path = dirs::data_local_dir()
+        .ok_or("Failed to get data directory")?
+        .join("volatility-analyzer")
+        .join("pairs.db");
+
+    let conn = Connection::open(&db_path).map_err(|e| format!("Failed to open pairs.db: {}", e))?;
+    conn.busy_timeout(std::time::Duration::from_millis(5000))
+        .map_err(|e| format!("Failed to set busy_timeout: {}", e))?;
+    conn.pragma_update(None, "journal_mode", "WAL")
+        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
+    conn.pragma_update(None, "synchronous", "NORMAL")
+        .map_err(|e| format!("Failed to set synchronous mode: {}", e))?;
+    Ok(conn)
+}
+
+/// Complète les bougies Bid existantes avec les prix Ask d'un export séparé
+/// et en déduit le spread historique (Ask Close - Bid Close)
+pub(super) fn apply_ask_quotes(
+    tx: &Transaction,
+    candles: &[NormalizedCandle],
+    symbol: &str,
+    timeframe: &str,
+) -> Result<usize, String> {
+    let mut stmt = tx
+        .prepare_cached(
+            "UPDATE candle_data
+             SET ask_open = ?1, ask_high = ?2, ask_low = ?3, ask_close = ?4,
+                 spread = MAX(?4 - close, 0)
+             WHERE symbol = ?5 AND timeframe = ?6 AND (time = ?7 OR time = ?8)",
+        )
+        .map_err(|e| format!("Prepare error: {}", e))?;
+
+    let mut updated = 0;
+    for candle in candles {
+        let dt = chrono::DateTime::<Utc>::from_timestamp(candle.timestamp, 0)
+            .ok_or(format!("Invalid timestamp: {}", candle.timestamp))?;
+
+        // Les deux formats de date coexistent en BD selon le chemin d'import
+        updated += stmt
+            .execute(params![
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                symbol,
+                timeframe,
+                dt.to_rfc3339(),
+                dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+            ])
+            .map_err(|e| format!("UPDATE candle_data (ask) error: {}", e))?;
+    }
+    Ok(updated)
+}
+
+/// Clôt l'import : métadonnées de la paire et statut final dans import_log
+pub(super) fn finalize(
+    conn: &mut Connection,
+    checkpoint: &Checkpoint,
+    rows_committed: usize,
+    ask_side: bool,
+    imported_at: &str,
+    filename: &str,
+    stream: &CandleStream,
+) -> Result<(), String> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Transaction begin error: {}", e))?;
+
+    // Un export Ask ne crée pas de bougies : le compteur de la paire ne bouge pas.
+    // Le compteur est recalculé : une fusion peut remplacer des bougies sans en ajouter
+    if !ask_side {
+        tx.execute(
+            "INSERT INTO pair_metadata (symbol, timeframe, row_count, last_updated, last_imported_file)
+             VALUES (?1, ?2, (SELECT COUNT(*) FROM candle_data WHERE symbol = ?1 AND timeframe = ?2), ?3, ?4)
+             ON CONFLICT(symbol, timeframe) DO UPDATE SET
+                row_count = excluded.row_count,
+                last_updated = excluded.last_updated,
+                last_imported_file = excluded.last_imported_file",
+            params![&checkpoint.symbol, &checkpoint.timeframe, imported_at, filename],
+        )
+        .map_err(|e| format!("UPDATE pair_metadata error: {}", e))?;
+    }
+
+    let error_message = stream
+        .warnings()
+        .first()
+        .map(|w| format!("{} ligne(s) ignorée(s), première: {}", stream.errors(), w));
+    tx.execute(
+        "UPDATE import_log SET status = 'success', expected_row_count = ?, error_message = ?
+         WHERE id = ?",
+        params![
+            (rows_committed + stream.errors()) as i64,
+            error_message,
+            checkpoint.log_id
+        ],
+    )
+    .map_err(|e| format!("UPDATE import_log error: {}", e))?;
+
+    tx.commit()
+        .map_err(|e| format!("Transaction commit error: {}", e))
+}
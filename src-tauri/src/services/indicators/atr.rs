@@ -0,0 +1,283 @@
+// services/indicators/atr.rs - True Range et ATR (SMA, EMA, Wilder) : série, instantané, incrémental
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::Candle;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Période ATR de référence de l'application
+pub const DEFAULT_ATR_PERIOD: usize = 14;
+/// Historique lu pour amorcer un ATR instantané (multiple de la période)
+pub const ATR_WARMUP_FACTOR: usize = 5;
+/// Pas d'une bougie M1 (minutes) ; au-delà du pas, la clôture précédente est ignorée
+pub const M1_STEP_MINUTES: i64 = 1;
+
+/// Méthode de lissage des True Ranges
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AtrSmoothing {
+    /// Moyenne simple des `period` derniers True Ranges
+    Sma,
+    /// Exponentielle classique, alpha = 2 / (period + 1)
+    Ema,
+    /// Lissage de Wilder, alpha = 1 / period (référence de l'application)
+    #[default]
+    Wilder,
+}
+
+impl AtrSmoothing {
+    fn alpha(self, period: usize) -> f64 {
+        match self {
+            AtrSmoothing::Ema => 2.0 / (period as f64 + 1.0),
+            AtrSmoothing::Sma | AtrSmoothing::Wilder => 1.0 / period as f64,
+        }
+    }
+}
+
+/// True Range : max(H-L, |H-Cp|, |L-Cp|), ou H-L sans clôture précédente
+pub fn true_range(high: f64, low: f64, prev_close: Option<f64>) -> f64 {
+    let range = high - low;
+    match prev_close {
+        Some(pc) => range.max((high - pc).abs()).max((low - pc).abs()),
+        None => range,
+    }
+}
+
+fn contigues(prev: DateTime<Utc>, next: DateTime<Utc>, step_minutes: i64) -> bool {
+    (next - prev).num_minutes() == step_minutes
+}
+
+/// True Range de chaque bougie ; la clôture précédente n'est utilisée que si les bougies
+/// se suivent au pas du timeframe (`step_minutes`, ex. `M1_STEP_MINUTES`)
+pub fn true_ranges<'a>(
+    candles: impl IntoIterator<Item = &'a Candle>,
+    step_minutes: i64,
+) -> Vec<f64> {
+    let mut prev: Option<&Candle> = None;
+    candles
+        .into_iter()
+        .map(|candle| {
+            let prev_close = prev
+                .filter(|p| contigues(p.datetime, candle.datetime, step_minutes))
+                .map(|p| p.close);
+            prev = Some(candle);
+            true_range(candle.high, candle.low, prev_close)
+        })
+        .collect()
+}
+
+/// ATR calculé bougie par bougie : amorcé par la SMA des `period` premiers TR, puis lissé
+#[derive(Debug, Clone)]
+pub struct AtrState {
+    period: usize,
+    smoothing: AtrSmoothing,
+    window: VecDeque<f64>,
+    sum: f64,
+    value: Option<f64>,
+    last: Option<(DateTime<Utc>, f64)>,
+}
+
+impl AtrState {
+    pub fn new(period: usize, smoothing: AtrSmoothing) -> Self {
+        let period = period.max(1);
+        Self {
+            period,
+            smoothing,
+            window: VecDeque::with_capacity(period + 1),
+            sum: 0.0,
+            value: None,
+            last: None,
+        }
+    }
+
+    /// Ajoute un True Range ; None tant que la période n'est pas remplie
+    pub fn push_true_range(&mut self, tr: f64) -> Option<f64> {
+        match (self.smoothing, self.value) {
+            (AtrSmoothing::Sma, _) | (_, None) => {
+                self.window.push_back(tr);
+                self.sum += tr;
+                if self.window.len() > self.period {
+                    if let Some(oldest) = self.window.pop_front() {
+                        self.sum -= oldest;
+                    }
+                }
+                if self.window.len() == self.period {
+                    self.value = Some(self.sum / self.period as f64);
+                }
+            }
+            (smoothing, Some(prev)) => {
+                self.value = Some(prev + smoothing.alpha(self.period) * (tr - prev));
+            }
+        }
+        self.value
+    }
+
+    /// Ajoute une bougie (True Range calculé avec la bougie précédente si contiguë)
+    pub fn push_candle(&mut self, candle: &Candle, step_minutes: i64) -> Option<f64> {
+        let prev_close = self
+            .last
+            .filter(|(time, _)| contigues(*time, candle.datetime, step_minutes))
+            .map(|(_, close)| close);
+        self.last = Some((candle.datetime, candle.close));
+        self.push_true_range(true_range(candle.high, candle.low, prev_close))
+    }
+
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+}
+
+/// ATR glissant : une valeur par True Range à partir de l'index `period - 1`
+pub fn rolling_atr(true_ranges: &[f64], period: usize, smoothing: AtrSmoothing) -> Vec<f64> {
+    let mut state = AtrState::new(period, smoothing);
+    true_ranges
+        .iter()
+        .filter_map(|&tr| state.push_true_range(tr))
+        .collect()
+}
+
+/// ATR à la dernière bougie d'une fenêtre ; la période est ramenée à la taille d'une fenêtre courte
+pub fn atr_of(
+    candles: &[Candle],
+    step_minutes: i64,
+    period: usize,
+    smoothing: AtrSmoothing,
+) -> Option<f64> {
+    let trs = true_ranges(candles, step_minutes);
+    if trs.is_empty() {
+        return None;
+    }
+    rolling_atr(&trs, period.min(trs.len()), smoothing)
+        .last()
+        .copied()
+}
+
+/// ATR instantané à `index`, amorcé sur au plus `period × ATR_WARMUP_FACTOR` bougies précédentes
+pub fn atr_at(
+    candles: &[Candle],
+    index: usize,
+    step_minutes: i64,
+    period: usize,
+    smoothing: AtrSmoothing,
+) -> Option<f64> {
+    if index >= candles.len() || index + 1 < period {
+        return None;
+    }
+    let start = (index + 1).saturating_sub(period.max(1) * ATR_WARMUP_FACTOR);
+    let mut state = AtrState::new(period, smoothing);
+    candles[start..=index]
+        .iter()
+        .fold(None, |_, candle| state.push_candle(candle, step_minutes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn bougies(ranges: &[(f64, f64, f64)], gap_at: Option<usize>) -> Vec<Candle> {
+        let t0 = Utc
+            .with_ymd_and_hms(2024, 3, 4, 9, 0, 0)
+            .single()
+            .unwrap_or_default();
+        ranges
+            .iter()
+            .enumerate()
+            .map(|(i, &(high, low, close))| {
+                let shift = if gap_at.is_some_and(|g| i >= g) {
+                    60
+                } else {
+                    0
+                };
+                Candle {
+                    id: None,
+                    symbol: "EURUSD".to_string(),
+                    datetime: t0 + Duration::minutes(i as i64 + shift),
+                    open: close,
+                    high,
+                    low,
+                    close,
+                    volume: 1.0,
+                    spread: None,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_true_range_ignores_gaps() {
+        // La 3e bougie ouvre en gap : TR avec la clôture précédente, sauf après un trou horaire
+        let data = [(1.2, 1.0, 1.1), (1.15, 1.05, 1.1), (1.6, 1.5, 1.55)];
+        assert_eq!(
+            true_ranges(&bougies(&data, None), M1_STEP_MINUTES)[2],
+            1.6 - 1.1
+        );
+        let with_gap = true_ranges(&bougies(&data, Some(2)), M1_STEP_MINUTES);
+        assert!((with_gap[2] - 0.1).abs() < 1e-12);
+        assert!((with_gap[0] - 0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_smoothing_methods() {
+        let trs = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(rolling_atr(&trs, 3, AtrSmoothing::Sma), vec![2.0, 3.0, 4.0]);
+        // Wilder : 2, puis (2×2 + 4)/3, puis (8/3×2 + 5)/3
+        let wilder = rolling_atr(&trs, 3, AtrSmoothing::Wilder);
+        assert!((wilder[1] - 8.0 / 3.0).abs() < 1e-12);
+        assert!((wilder[2] - (16.0 / 3.0 + 5.0) / 3.0).abs() < 1e-12);
+        // EMA : alpha = 0.5
+        let ema = rolling_atr(&trs, 3, AtrSmoothing::Ema);
+        assert_eq!(ema, vec![2.0, 3.0, 4.0]);
+        assert!(rolling_atr(&trs, 6, AtrSmoothing::Wilder).is_empty());
+    }
+
+    #[test]
+    fn test_incremental_matches_series() {
+        let data: Vec<(f64, f64, f64)> = (0..60)
+            .map(|i| {
+                let mid = 1.1 + (i % 7) as f64 * 0.0003;
+                (mid + 0.0004 + (i % 3) as f64 * 0.0001, mid - 0.0004, mid)
+            })
+            .collect();
+        let candles = bougies(&data, None);
+        let series = rolling_atr(
+            &true_ranges(&candles, M1_STEP_MINUTES),
+            14,
+            AtrSmoothing::Wilder,
+        );
+
+        let mut state = AtrState::new(14, AtrSmoothing::Wilder);
+        let incremental: Vec<f64> = candles
+            .iter()
+            .filter_map(|c| state.push_candle(c, M1_STEP_MINUTES))
+            .collect();
+        assert_eq!(incremental, series);
+        assert_eq!(state.value(), series.last().copied());
+
+        // Instantané amorcé sur 70 bougies : identique à la série complète ici
+        assert_eq!(
+            atr_at(&candles, 59, M1_STEP_MINUTES, 14, AtrSmoothing::Wilder),
+            series.last().copied()
+        );
+        assert_eq!(
+            atr_at(&candles, 12, M1_STEP_MINUTES, 14, AtrSmoothing::Wilder),
+            None
+        );
+        assert!(atr_of(&candles[..5], M1_STEP_MINUTES, 14, AtrSmoothing::Wilder).is_some());
+
+        // Bougies M5 : contiguës au pas de 5 minutes seulement
+        let m5: Vec<Candle> = bougies(&data[..3], None)
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| Candle {
+                datetime: c.datetime + Duration::minutes(4 * i as i64),
+                ..c
+            })
+            .collect();
+        assert_eq!(
+            true_ranges(&m5, 5),
+            true_ranges(&candles[..3], M1_STEP_MINUTES)
+        );
+    }
+}
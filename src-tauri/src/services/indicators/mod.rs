@@ -0,0 +1,9 @@
+// services/indicators/mod.rs - Indicateurs techniques partagés par tous les analyseurs
+// Conforme .clinerules
+
+mod atr;
+
+pub use atr::{
+    atr_at, atr_of, rolling_atr, true_range, true_ranges, AtrSmoothing, DEFAULT_ATR_PERIOD,
+    M1_STEP_MINUTES,
+};
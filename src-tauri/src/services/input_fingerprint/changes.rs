@@ -0,0 +1,206 @@
+// services/input_fingerprint/changes.rs - Explication des écarts entre deux empreintes et rapport de recalcul
+// Conforme .clinerules : < 250L, pas d'unwrap()
+
+use super::Fingerprinter;
+use crate::models::archive::Archive;
+use crate::models::archive_payload::ArchiveSummary;
+use crate::models::input_fingerprint::{ArchiveRerunReport, DataRange, InputFingerprint};
+use crate::services::archive_search::ArchiveSearch;
+
+impl Fingerprinter {
+    /// Changements d'entrées entre deux empreintes, en clair
+    pub fn differences(previous: &InputFingerprint, current: &InputFingerprint) -> Vec<String> {
+        let mut changes = Vec::new();
+        let key = |r: &DataRange| format!("{} {}", r.symbol, r.timeframe);
+        let borne = |b: &Option<String>| b.clone().unwrap_or_else(|| "∅".to_string());
+        for now in &current.candles {
+            match previous.candles.iter().find(|p| key(p) == key(now)) {
+                None => changes.push(format!(
+                    "{} : historique nouvellement pris en compte ({} bougies)",
+                    key(now),
+                    now.candle_count
+                )),
+                Some(before) if before != now => changes.push(format!(
+                    "{} : {} → {} bougies, du {} au {} (avant : du {} au {})",
+                    key(now),
+                    before.candle_count,
+                    now.candle_count,
+                    borne(&now.first),
+                    borne(&now.last),
+                    borne(&before.first),
+                    borne(&before.last)
+                )),
+                Some(_) => {}
+            }
+        }
+        for before in &previous.candles {
+            if !current.candles.iter().any(|c| key(c) == key(before)) {
+                changes.push(format!("{} : historique absent", key(before)));
+            }
+        }
+
+        let added: Vec<String> = current
+            .calendar_import_ids
+            .iter()
+            .filter(|id| !previous.calendar_import_ids.contains(id))
+            .map(|id| format!("#{}", id))
+            .collect();
+        let removed: Vec<String> = previous
+            .calendar_import_ids
+            .iter()
+            .filter(|id| !current.calendar_import_ids.contains(id))
+            .map(|id| format!("#{}", id))
+            .collect();
+        if !added.is_empty() {
+            changes.push(format!("Calendrier(s) ajouté(s) : {}", added.join(", ")));
+        }
+        if !removed.is_empty() {
+            changes.push(format!("Calendrier(s) retiré(s) : {}", removed.join(", ")));
+        }
+        if previous.event_filter != current.event_filter {
+            changes.push(format!(
+                "Filtre d'événement : {:?} → {:?}",
+                previous.event_filter, current.event_filter
+            ));
+        }
+        if previous.event_count != current.event_count {
+            changes.push(format!(
+                "Occurrences retenues : {} → {}",
+                previous.event_count, current.event_count
+            ));
+        }
+
+        let names: std::collections::BTreeSet<&String> = previous
+            .parameters
+            .keys()
+            .chain(current.parameters.keys())
+            .collect();
+        for name in names {
+            let before = previous.parameters.get(name);
+            let now = current.parameters.get(name);
+            if before != now {
+                changes.push(format!(
+                    "Paramètre {} : {} → {}",
+                    name,
+                    before.map(String::as_str).unwrap_or("∅"),
+                    now.map(String::as_str).unwrap_or("∅")
+                ));
+            }
+        }
+        if previous.app_version != current.app_version {
+            changes.push(format!(
+                "Version de l'application : {} → {}",
+                previous.app_version, current.app_version
+            ));
+        }
+        changes
+    }
+
+    /// Compare le résumé archivé et le résumé recalculé, et explique les écarts
+    pub fn rapport(
+        archive: &Archive,
+        archived: ArchiveSummary,
+        recomputed: ArchiveSummary,
+        previous: Option<InputFingerprint>,
+        current: InputFingerprint,
+    ) -> Result<ArchiveRerunReport, String> {
+        let mut rerun = archive.clone();
+        rerun.title = format!("{} (recalcul)", archive.title);
+        // Toujours postérieur à l'archive d'origine dans la comparaison
+        rerun.created_at = "9999-12-31 23:59:59".to_string();
+        let metrics =
+            ArchiveSearch::comparer(vec![(archive.clone(), archived), (rerun, recomputed)])?
+                .metrics;
+        let results_changed = metrics
+            .iter()
+            .any(|m| m.change.is_some_and(|c| c.abs() > 1e-9));
+
+        let mut input_changes = match &previous {
+            Some(previous) => Self::differences(previous, &current),
+            None => vec![
+                "Archive enregistrée sans empreinte des entrées : origine des écarts inconnue"
+                    .to_string(),
+            ],
+        };
+        let inputs_changed = previous.as_ref().is_none_or(|p| p.hash != current.hash);
+        if !inputs_changed && results_changed {
+            input_changes
+                .push("Entrées identiques : écarts dus à une évolution du calcul".to_string());
+        }
+
+        Ok(ArchiveRerunReport {
+            archive_id: archive.id,
+            title: archive.title.clone(),
+            previous,
+            current,
+            inputs_changed,
+            input_changes,
+            metrics,
+            results_changed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::archive_payload::VolatilitySummary;
+    use crate::services::input_fingerprint::tests::fingerprint;
+
+    fn volatility(score: f64) -> ArchiveSummary {
+        ArchiveSummary::Volatility(VolatilitySummary {
+            symbol: "EURUSD".to_string(),
+            confidence_score: score,
+            confidence_score_ci: None,
+            best_hours: vec![],
+            mean_volatility: Some(10.0),
+            regime: None,
+            unit: "pips".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_differences_explained() {
+        let a = fingerprint(1000, vec![3, 1, 3], "90");
+        let b = fingerprint(1500, vec![1, 4], "180");
+        let changes = Fingerprinter::differences(&a, &b);
+        assert!(changes.iter().any(|c| c.contains("1000 → 1500 bougies")));
+        assert!(changes.iter().any(|c| c.contains("ajouté(s) : #4")));
+        assert!(changes.iter().any(|c| c.contains("retiré(s) : #3")));
+        assert!(changes.iter().any(|c| c.contains("time_decay : 90 → 180")));
+        assert!(Fingerprinter::differences(&a, &a).is_empty());
+    }
+
+    #[test]
+    fn test_rerun_report_flags_code_changes() {
+        let archive = Archive {
+            id: 9,
+            title: "EURUSD".to_string(),
+            archive_type: "Volatilité brute Paire/Période".to_string(),
+            period_start: "2024-01-01".to_string(),
+            period_end: "2024-06-30".to_string(),
+            comment: None,
+            created_at: "2024-07-01 10:00:00".to_string(),
+            data_json: "{}".to_string(),
+        };
+        let fp = fingerprint(1000, vec![1], "90");
+        let report = Fingerprinter::rapport(
+            &archive,
+            volatility(60.0),
+            volatility(65.0),
+            Some(fp.clone()),
+            fp.clone(),
+        )
+        .expect("rapport");
+        assert!(!report.inputs_changed);
+        assert!(report.results_changed);
+        assert_eq!(report.metrics[0].change, Some(5.0));
+        assert_eq!(report.input_changes.len(), 1);
+
+        let unknown =
+            Fingerprinter::rapport(&archive, volatility(60.0), volatility(60.0), None, fp)
+                .expect("rapport");
+        assert!(unknown.inputs_changed);
+        assert!(!unknown.results_changed);
+    }
+}
@@ -0,0 +1,126 @@
+// services/input_fingerprint/mod.rs - Empreinte des entrées d'une analyse
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use crate::models::input_fingerprint::{DataRange, InputFingerprint};
+use crate::models::CalendarEvent;
+use crate::services::DatabaseLoader;
+use crate::utils::crc32;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+mod changes;
+
+/// Timeframe des historiques lus par les analyses
+const FINGERPRINT_TIMEFRAME: &str = "M1";
+/// Sections des anciens formats où l'empreinte du résultat d'analyse peut se trouver
+const NESTED_RESULT_KEYS: [&str; 2] = ["analysisResult", "result"];
+
+pub struct Fingerprinter;
+
+impl Fingerprinter {
+    pub fn construire(
+        mut candles: Vec<DataRange>,
+        mut calendar_import_ids: Vec<i32>,
+        event_filter: Option<&str>,
+        event_count: usize,
+        parameters: BTreeMap<String, String>,
+    ) -> Result<InputFingerprint, String> {
+        candles.sort_by(|a, b| (&a.symbol, &a.timeframe).cmp(&(&b.symbol, &b.timeframe)));
+        calendar_import_ids.sort_unstable();
+        calendar_import_ids.dedup();
+        let event_filter = event_filter.map(str::to_string);
+        let inputs = serde_json::to_string(&(
+            &candles,
+            &calendar_import_ids,
+            &event_filter,
+            event_count,
+            &parameters,
+        ))
+        .map_err(|e| format!("Sérialisation des entrées impossible: {}", e))?;
+        Ok(InputFingerprint {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            candles,
+            calendar_import_ids,
+            event_filter,
+            event_count,
+            parameters,
+            hash: format!("{:08x}", crc32(inputs.as_bytes())),
+        })
+    }
+
+    /// Empreinte d'une analyse menée sur `symbols` à partir des occurrences `events`
+    pub fn capturer(
+        loader: &DatabaseLoader,
+        symbols: &[&str],
+        events: &[CalendarEvent],
+        event_filter: Option<&str>,
+        parameters: BTreeMap<String, String>,
+    ) -> Result<InputFingerprint, String> {
+        let candles = symbols
+            .iter()
+            .map(|s| loader.candle_range(s, FINGERPRINT_TIMEFRAME))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Empreinte des données impossible: {}", e))?;
+        Self::construire(
+            candles,
+            events.iter().map(|e| e.calendar_import_id).collect(),
+            event_filter,
+            events.len(),
+            parameters,
+        )
+    }
+
+    /// Valeur de paramètre sérialisée (clé stable dans l'empreinte)
+    pub fn parametre<T: Serialize>(value: &T) -> Result<String, String> {
+        serde_json::to_string(value).map_err(|e| format!("Paramètre non sérialisable: {}", e))
+    }
+
+    /// Empreinte enregistrée dans un contenu d'archive (racine ou résultat d'analyse imbriqué)
+    pub fn extraire(data: &Value) -> Option<InputFingerprint> {
+        std::iter::once(data)
+            .chain(NESTED_RESULT_KEYS.iter().filter_map(|k| data.get(*k)))
+            .filter_map(|section| section.get("fingerprint"))
+            .find_map(|fp| serde_json::from_value(fp.clone()).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(count: i64, last: &str) -> DataRange {
+        DataRange {
+            symbol: "EURUSD".to_string(),
+            timeframe: "M1".to_string(),
+            candle_count: count,
+            first: Some("2024-01-01 00:00:00".to_string()),
+            last: Some(last.to_string()),
+        }
+    }
+
+    pub(super) fn fingerprint(count: i64, ids: Vec<i32>, decay: &str) -> InputFingerprint {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("time_decay".to_string(), decay.to_string());
+        Fingerprinter::construire(
+            vec![range(count, "2024-06-30 23:59:00")],
+            ids,
+            Some("CPI m/m"),
+            12,
+            parameters,
+        )
+        .expect("empreinte")
+    }
+
+    #[test]
+    fn test_hash_is_stable_and_extracted() {
+        let a = fingerprint(1000, vec![3, 1, 3], "90");
+        assert_eq!(a.calendar_import_ids, vec![1, 3]);
+        assert_eq!(a.hash, fingerprint(1000, vec![1, 3], "90").hash);
+        assert_ne!(a.hash, fingerprint(1500, vec![1, 4], "180").hash);
+
+        let nested = serde_json::json!({ "analysisResult": { "fingerprint": a } });
+        assert_eq!(Fingerprinter::extraire(&nested), Some(a));
+        assert_eq!(Fingerprinter::extraire(&serde_json::json!({})), None);
+    }
+}
@@ -3,6 +3,7 @@
 
 use super::distribution::TrueRangeDistribution;
 use crate::models::{Candle, Result, VolatilityError};
+use crate::services::indicators::{rolling_atr, true_ranges, AtrSmoothing, M1_STEP_MINUTES};
 use tracing::{debug, info};
 
 /// Calculateur de métriques de volatilité
@@ -28,42 +29,9 @@ impl<'a> MetricsCalculator<'a> {
 
         info!("Calculating ATR with period {}", period);
 
-        let mut true_ranges = Vec::new();
-
-        // Calcule le True Range pour chaque bougie
-        for i in 0..self.candles.len() {
-            let is_contiguous = if i > 0 {
-                let diff = self
-                    .candles[i]
-                    .datetime
-                    .signed_duration_since(self.candles[i - 1].datetime);
-                diff.num_minutes() == 1
-            } else {
-                false
-            };
-
-            let prev_close = if is_contiguous {
-                Some(self.candles[i - 1].close)
-            } else {
-                None
-            };
-            true_ranges.push(self.candles[i].true_range(prev_close));
-        }
-
-        // Calcule l'ATR avec Wilder's smoothing (EMA)
-        let mut atr_values = Vec::new();
-
-        // Première valeur: moyenne simple des premiers TR
-        let first_atr = true_ranges[0..period].iter().sum::<f64>() / period as f64;
-        atr_values.push(first_atr);
-
-        // Smoothing Wilder: ATR[i] = (ATR[i-1] * (period - 1) + TR[i]) / period
-        let multiplier = (period - 1) as f64;
-        for tr in true_ranges.iter().skip(period) {
-            let prev_atr = atr_values[atr_values.len() - 1]; // Dernière valeur ATR calculée
-            let atr = (prev_atr * multiplier + tr) / period as f64;
-            atr_values.push(atr);
-        }
+        // True Range (clôture précédente si bougies M1 contiguës) + lissage de Wilder
+        let trs = true_ranges(self.candles, M1_STEP_MINUTES);
+        let atr_values = rolling_atr(&trs, period, AtrSmoothing::Wilder);
 
         debug!(
             "ATR calculated with Wilder's smoothing: {} values",
@@ -117,6 +117,7 @@ mod tests {
                 low: 1.0,
                 close: 1.5,
                 volume: 100.0,
+                spread: None,
             });
         }
         // Ajouter quelques outliers (5 candles avec TR = 10.0)
@@ -1,13 +1,19 @@
 // services/mod.rs - Exports publics des services
+pub mod alerting;
+pub mod archive_bundle;
+pub mod archive_schema;
+pub mod archive_search;
 pub mod archive_service;
 pub mod backtest;
 pub mod breakout_detector;
 pub mod calendar_converter;
+pub mod calendar_event_reader;
 pub mod calendar_scraper;
 pub mod candle_index;
 pub mod cleanup_service;
 pub mod config_service;
 pub mod contextual_atr_analyzer;
+pub mod cross_asset_reaction;
 pub mod csv_cleaner;
 pub mod csv_loader;
 pub mod database_loader;
@@ -21,31 +27,49 @@ pub mod event_metrics_aggregator;
 pub mod global_analyzer;
 pub mod global_analyzer_event_analysis;
 pub mod global_analyzer_helpers;
+pub mod global_analyzer_history;
+pub mod global_analyzer_history_metrics;
 pub mod global_analyzer_metrics;
+pub mod global_analyzer_occurrence;
 pub mod global_analyzer_straddle_calc;
 pub mod global_analyzer_types;
+pub mod heatmap_cells;
 pub mod import_processor;
+pub mod indicators;
+pub mod input_fingerprint;
 pub mod metrics;
 pub mod movement_analyzer;
 pub mod movement_quality_analyzer;
 pub mod pair_data;
 pub mod pair_data_stats;
 pub mod planning;
+pub mod post_event_analyzer;
+pub mod pre_event_analyzer;
+pub mod retrospective;
+pub mod score_calibration;
+pub mod seasonality_analyzer;
 pub mod session;
 pub mod session_analyzer;
+pub mod significance;
 pub mod slice_metrics_analyzer;
+pub mod statistics;
 pub mod straddle_adjustments;
 pub mod straddle_multipliers;
 pub mod straddle_parameter_service;
 pub mod straddle_scoring;
 pub mod straddle_simulator;
 pub mod straddle_simulator_helpers;
+pub mod symbol_registry;
+pub mod trade_journal;
 pub mod volatility;
 pub mod volatility_duration_calculator;
+pub mod volatility_regime;
 pub mod win_rate_calculator;
 
+pub use archive_schema::ArchiveSchema;
 pub use archive_service::*;
 pub use calendar_converter::*;
+pub use calendar_event_reader::CalendarEventReader;
 pub use calendar_scraper::*;
 pub use config_service::*;
 pub use csv_cleaner::*;
@@ -54,10 +78,12 @@ pub use database_loader::*;
 pub use economic_event_loader::*;
 pub use event_correlation::*;
 pub use global_analyzer::*;
+pub use global_analyzer_history::GlobalHistoryAnalyzer;
 pub use import_processor::*;
 pub use metrics::*;
 pub use movement_analyzer::{MovementAnalysisConfig, MovementAnalyzer};
 pub use pair_data::*;
 pub use session::*;
 pub use straddle_parameter_service::*;
+pub use symbol_registry::*;
 pub use volatility::*;
@@ -8,6 +8,7 @@ use crate::models::{Candle, EventMovementQuality, VolatilityError};
 // ============================================================================
 mod calculations {
     use crate::models::{Candle, VolatilityError, AssetProperties};
+    use crate::services::indicators::{atr_of, AtrSmoothing, DEFAULT_ATR_PERIOD, M1_STEP_MINUTES};
 
     /// Calcule l'ATR (Average True Range) sur une série de candles
     pub fn calculer_atr(candles: &[Candle]) -> Result<f64, VolatilityError> {
@@ -16,23 +17,9 @@ mod calculations {
                 "Besoin d'au moins 2 candles pour ATR".to_string(),
             ));
         }
-
-        let mut true_ranges = Vec::new();
-
-        for i in 1..candles.len() {
-            let high = candles[i].high;
-            let low = candles[i].low;
-            let prev_close = candles[i - 1].close;
-
-            let tr = (high - low)
-                .max((high - prev_close).abs())
-                .max((low - prev_close).abs());
-
-            true_ranges.push(tr);
-        }
-
-        let atr = true_ranges.iter().sum::<f64>() / true_ranges.len() as f64;
-        Ok(atr)
+        atr_of(candles, M1_STEP_MINUTES, DEFAULT_ATR_PERIOD, AtrSmoothing::Wilder).ok_or_else(|| {
+            VolatilityError::InsufficientData("ATR non calculable".to_string())
+        })
     }
 
     /// Analyse les mouvements post-événement
@@ -237,6 +224,7 @@ mod tests {
             low: price - low_delta,
             close: price,
             volume: 100.0,
+            spread: None,
         }
     }
 
@@ -0,0 +1,57 @@
+// services/pair_data/broker_formats.rs - Parseurs des exports Histdata et ticks Dukascopy
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use super::datetime_parser::DateTimeParser;
+use super::formats::FormatParsers;
+use super::types::{AskQuote, NormalizedCandle};
+
+/// Histdata.com horodate en EST sans heure d'été (UTC-5 fixe)
+const HISTDATA_UTC_OFFSET_SECS: i64 = 5 * 3600;
+
+impl FormatParsers {
+    /// Parse format Histdata ASCII: 20240102 170000;Open;High;Low;Close;Volume
+    pub(super) fn parse_histdata(record: &csv::StringRecord) -> Result<NormalizedCandle, String> {
+        let mut candle = Self::parse_dukascopy(record)?;
+        candle.timestamp += HISTDATA_UTC_OFFSET_SECS;
+        Ok(candle)
+    }
+
+    /// Parse un tick Dukascopy: Gmt time,Ask,Bid,AskVolume,BidVolume
+    /// Le tick devient une bougie plate Bid (+ Ask) que le flux agrège en M1
+    pub(super) fn parse_dukascopy_tick(
+        record: &csv::StringRecord,
+        headers: &[String],
+    ) -> Result<NormalizedCandle, String> {
+        let headers_norm: Vec<String> = headers.iter().map(|h| Self::normalize_header(h)).collect();
+        let column = |name: &str| headers_norm.iter().position(|h| h == name);
+        let value = |idx: Option<usize>, label: &str| -> Result<f64, String> {
+            let raw = idx
+                .and_then(|i| record.get(i))
+                .ok_or_else(|| format!("Colonne {} manquante", label))?;
+            Self::parse_decimal(raw)
+        };
+
+        let time_str = record.get(0).ok_or("Gmt time manquante")?;
+        let timestamp = DateTimeParser::parse(time_str)?;
+        let bid = value(column("bid"), "Bid")?;
+        let ask = value(column("ask"), "Ask")?;
+        let volume = value(column("askvolume"), "AskVolume").unwrap_or(0.0)
+            + value(column("bidvolume"), "BidVolume").unwrap_or(0.0);
+
+        Ok(NormalizedCandle {
+            timestamp,
+            open: bid,
+            high: bid,
+            low: bid,
+            close: bid,
+            volume,
+            spread_points: None,
+            ask: Some(AskQuote {
+                open: ask,
+                high: ask,
+                low: ask,
+                close: ask,
+            }),
+        })
+    }
+}
@@ -1,10 +1,11 @@
 // services/pair_data/converter.rs - Convertisseur principal
 // Conforme .clinerules : < 150L, pas d'unwrap()
 
+use super::detection::FormatDetection;
 use super::formats::FormatParsers;
 use super::metadata::MetadataExtractor;
+use super::stream::CandleStream;
 use super::types::{CsvFormat, NormalizedCandle, PairMetadata};
-use std::path::Path;
 
 /// Convertisseur de données de paires
 pub struct PairDataConverter;
@@ -12,7 +13,11 @@ pub struct PairDataConverter;
 impl PairDataConverter {
     /// Détecte le format du CSV en analysant les headers
     pub fn detect_format(headers: &[String]) -> CsvFormat {
-        let headers_lower: Vec<String> = headers.iter().map(|h| h.to_lowercase()).collect();
+        // Les exports natifs MT5 utilisent des headers entre chevrons (<DATE>, <SPREAD>)
+        let headers_lower: Vec<String> = headers
+            .iter()
+            .map(|h| FormatParsers::normalize_header(h))
+            .collect();
 
         // MetaTrader: Date,Time,Open,High,Low,Close,Volume
         if headers_lower.contains(&"date".to_string())
@@ -27,6 +32,14 @@ impl PairDataConverter {
             return CsvFormat::TradingView;
         }
 
+        // Ticks Dukascopy: Gmt time,Ask,Bid,AskVolume,BidVolume
+        if headers_lower.contains(&"ask".to_string())
+            && headers_lower.contains(&"bid".to_string())
+            && !headers_lower.iter().any(|h| h.contains("open"))
+        {
+            return CsvFormat::DukascopyTick;
+        }
+
         // Dukascopy: Gmt time,Open,High,Low,Close,Volume
         if headers_lower.iter().any(|h| h.contains("gmt")) {
             return CsvFormat::Dukascopy;
@@ -35,76 +48,33 @@ impl PairDataConverter {
         CsvFormat::Generic
     }
 
-    /// Lit et normalise un fichier CSV  
-    pub fn read_and_normalize(path: &str) -> Result<Vec<NormalizedCandle>, String> {
-        use std::fs::File;
-        use std::io::{BufRead, BufReader};
-
-        let file = File::open(path).map_err(|e| format!("Erreur ouverture: {}", e))?;
-        let buf_reader = BufReader::new(file);
-        let lines: Vec<String> = buf_reader
-            .lines()
-            .collect::<Result<_, _>>()
-            .map_err(|e| e.to_string())?;
-
-        if lines.is_empty() {
-            return Err("Fichier vide".to_string());
-        }
-
-        // Détecter le délimiteur à partir du header
-        let header_line = &lines[0];
-        let delimiter = if header_line.contains(';') {
-            b';' // Format européen: point-virgule
-        } else {
-            b',' // Format standard: virgule
-        };
-
-        tracing::info!(
-            "🔍 Délimiteur détecté: {}",
-            if delimiter == b';' { ";" } else { "," }
-        );
-
-        // Créer le contenu avec les lignes
-        let content = lines.join("\n");
-
-        let mut reader = csv::ReaderBuilder::new()
-            .has_headers(true)
-            .flexible(true)
-            .trim(csv::Trim::All)
-            .delimiter(delimiter)
-            .from_reader(content.as_bytes());
-
-        // Lire les headers
-        let headers: Vec<String> = reader
-            .headers()
-            .map_err(|e| format!("Erreur headers: {}", e))?
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+    /// Détecte un export côté Ask (Dukascopy exporte Bid et Ask dans des fichiers séparés)
+    pub fn is_ask_side_file(filename: &str) -> bool {
+        let upper = filename.to_uppercase();
+        upper.contains("_ASK_") || upper.contains("_ASK.")
+    }
 
-        let format = Self::detect_format(&headers);
-        tracing::debug!("🔍 Format détecté: {:?}", format);
-        tracing::debug!("📋 Headers: {:?}", headers);
+    /// Détecte le format d'un fichier (formats intégrés + profils utilisateur) sans l'importer
+    pub fn detect_file_format(path: &str) -> Result<FormatDetection, String> {
+        CandleStream::open(path).map(|stream| stream.detection().clone())
+    }
 
+    /// Lit et normalise un fichier CSV complet en mémoire
+    /// (les gros fichiers passent par `CandleStream` lot par lot)
+    pub fn read_and_normalize(path: &str) -> Result<Vec<NormalizedCandle>, String> {
+        let mut stream = CandleStream::open(path)?;
         let mut candles = Vec::new();
-        let mut line_number = 1; // Commence à 1 (après header)
-
-        for result in reader.records() {
-            line_number += 1;
-
-            // Ignorer les lignes mal formatées au lieu de faire échouer tout le fichier
-            let record = match result {
-                Ok(r) => r,
-                Err(e) => {
-                    tracing::warn!("⚠️ Ligne {} ignorée (erreur CSV): {}", line_number, e);
-                    continue;
-                }
-            };
-
-            match FormatParsers::parse_record(&record, &format, &headers) {
-                Ok(candle) => candles.push(candle),
-                Err(e) => tracing::warn!("⚠️ Ligne {} ignorée (parsing): {}", line_number, e),
+
+        loop {
+            let batch = stream.next_batch(50_000);
+            if batch.is_empty() {
+                break;
             }
+            candles.extend(batch);
+        }
+
+        for warning in stream.warnings() {
+            tracing::warn!("⚠️ Ligne ignorée: {}", warning);
         }
 
         if candles.is_empty() {
@@ -124,17 +94,4 @@ impl PairDataConverter {
     ) -> Result<PairMetadata, String> {
         MetadataExtractor::extract(candles, original_filename)
     }
-
-    /// Génère le nom du fichier normalisé
-    pub fn generate_filename(metadata: &PairMetadata) -> String {
-        MetadataExtractor::generate_filename(metadata)
-    }
-
-    /// Sauvegarde les candles normalisées dans un fichier CSV
-    pub fn save_normalized_csv(
-        candles: &[NormalizedCandle],
-        output_path: &Path,
-    ) -> Result<(), String> {
-        MetadataExtractor::save_to_csv(candles, output_path)
-    }
 }
@@ -9,8 +9,19 @@ pub(super) struct DateTimeParser;
 impl DateTimeParser {
     /// Parse une datetime string en timestamp Unix
     pub(super) fn parse(datetime_str: &str) -> Result<i64, String> {
+        let trimmed = datetime_str.trim();
+
+        // ISO 8601 (exports cTrader): 2024-01-02T17:00:00.000Z
+        if let Ok(dt) = DateTime::parse_from_rfc3339(trimmed) {
+            return Ok(dt.timestamp());
+        }
+
+        // Dukascopy "Local time" : suffixe GMT+0100, ticks : millisecondes
+        let (body, offset_secs) = Self::split_gmt_offset(trimmed);
+        let body = Self::strip_fraction(body);
+
         // Format: 2024.01.01 12:00 ou 2024-01-01 12:00:00
-        let cleaned = datetime_str.replace(".", "-").replace("/", "-");
+        let cleaned = body.replace(".", "-").replace("/", "-");
 
         // Essayer plusieurs formats
         let formats = vec![
@@ -20,25 +31,58 @@ impl DateTimeParser {
             "%d-%m-%Y %H:%M:%S",
             "%d-%m-%Y %H:%M",
             "%d-%m-%Y",
+            "%Y%m%d %H%M%S", // Histdata / NinjaTrader
+            "%Y%m%d",
         ];
 
         for format in formats {
             if let Ok(dt) = NaiveDateTime::parse_from_str(&cleaned, format) {
                 let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc);
-                return Ok(datetime_utc.timestamp());
+                return Ok(datetime_utc.timestamp() - offset_secs);
             }
-            
+
             // Essayer comme NaiveDate si NaiveDateTime échoue
             if let Ok(d) = chrono::NaiveDate::parse_from_str(&cleaned, format) {
                 if let Some(dt) = d.and_hms_opt(0, 0, 0) {
                     let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc);
-                    return Ok(datetime_utc.timestamp());
+                    return Ok(datetime_utc.timestamp() - offset_secs);
                 }
             }
         }
 
         Err(format!("Format datetime non reconnu: {}", datetime_str))
     }
+
+    /// Sépare un suffixe " GMT+0100" et renvoie le décalage en secondes
+    fn split_gmt_offset(s: &str) -> (&str, i64) {
+        let Some(idx) = s.find(" GMT") else {
+            return (s, 0);
+        };
+        let suffix = &s[idx + 4..];
+        let sign = if suffix.starts_with('-') { -1 } else { 1 };
+        let digits: String = suffix.chars().filter(|c| c.is_ascii_digit()).collect();
+        let offset = match digits.len() {
+            4 => {
+                let hours = digits[..2].parse::<i64>().unwrap_or(0);
+                let minutes = digits[2..].parse::<i64>().unwrap_or(0);
+                hours * 3600 + minutes * 60
+            }
+            1 | 2 => digits.parse::<i64>().unwrap_or(0) * 3600,
+            _ => 0,
+        };
+        (&s[..idx], sign * offset)
+    }
+
+    /// Retire les fractions de seconde : "17:00:00.123" → "17:00:00"
+    fn strip_fraction(s: &str) -> &str {
+        match s.rfind(':') {
+            Some(colon) => match s[colon..].find('.') {
+                Some(dot) => &s[..colon + dot],
+                None => s,
+            },
+            None => s,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -52,6 +96,24 @@ mod tests {
         assert!(DateTimeParser::parse("2024-01-01").is_ok());
     }
 
+    #[test]
+    fn test_parse_broker_formats() {
+        let expected = DateTimeParser::parse("2024-01-02 17:00:00").expect("référence");
+        assert_eq!(DateTimeParser::parse("20240102 170000"), Ok(expected));
+        assert_eq!(
+            DateTimeParser::parse("2024-01-02T17:00:00.000Z"),
+            Ok(expected)
+        );
+        assert_eq!(
+            DateTimeParser::parse("02.01.2024 17:00:00.123"),
+            Ok(expected)
+        );
+        assert_eq!(
+            DateTimeParser::parse("02.01.2024 18:00:00.000 GMT+0100"),
+            Ok(expected)
+        );
+    }
+
     #[test]
     fn test_parse_datetime_invalid() {
         assert!(DateTimeParser::parse("invalid").is_err());
@@ -0,0 +1,112 @@
+// services/pair_data/detection/builtin.rs - Formats intégrés candidats d'après l'échantillon
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::Layout;
+use crate::services::pair_data::converter::PairDataConverter;
+use crate::services::pair_data::types::CsvFormat;
+
+/// Headers du format nettoyé produit par `clean_line`
+const CLEANED_HEADERS: [&str; 7] = [
+    "timestamp",
+    "open",
+    "high",
+    "low",
+    "close",
+    "volume",
+    "spread",
+];
+
+/// Colonne volume des exports sans header (date;O;H;L;C;V)
+const HEADERLESS_VOLUME_COLUMN: usize = 5;
+
+/// Formats intégrés candidats, le plus probable en premier (il l'emporte à score égal)
+pub(super) fn builtin_layouts(sample: &[String], filename: &str) -> Vec<Layout> {
+    let first = sample.first().map(String::as_str).unwrap_or_default();
+    let delimiter = if first.contains(';') {
+        b';'
+    } else if first.contains('\t') {
+        b'\t'
+    } else {
+        b','
+    };
+
+    let fields = split_line(first, delimiter);
+    let headerless = fields
+        .first()
+        .and_then(|f| f.chars().next())
+        .is_some_and(|c| c.is_ascii_digit());
+
+    if headerless {
+        let layout = |format| Layout {
+            format,
+            delimiter,
+            headers: Vec::new(),
+            european_comma: false,
+        };
+        let dat_file = filename.to_uppercase().starts_with("DAT_");
+        if delimiter != b';' {
+            let format = if dat_file {
+                CsvFormat::Histdata
+            } else {
+                CsvFormat::CTrader
+            };
+            return vec![layout(format)];
+        }
+        // Histdata et NinjaTrader partagent la même disposition et le même score
+        return if dat_file || volumes_toujours_nuls(sample, delimiter) {
+            vec![layout(CsvFormat::Histdata), layout(CsvFormat::NinjaTrader)]
+        } else {
+            vec![layout(CsvFormat::NinjaTrader), layout(CsvFormat::Histdata)]
+        };
+    }
+
+    // Plus de colonnes que de headers = décimales à virgule
+    let european_comma = delimiter == b','
+        && sample
+            .get(1)
+            .is_some_and(|line| split_line(line, delimiter).len() > fields.len());
+
+    if european_comma {
+        return vec![Layout {
+            format: CsvFormat::Generic,
+            delimiter,
+            headers: CLEANED_HEADERS.iter().map(|h| h.to_string()).collect(),
+            european_comma,
+        }];
+    }
+
+    vec![Layout {
+        format: PairDataConverter::detect_format(&fields),
+        delimiter,
+        headers: fields,
+        european_comma,
+    }]
+}
+
+/// Histdata n'exporte pas de volume (colonne toujours à 0), NinjaTrader si
+fn volumes_toujours_nuls(sample: &[String], delimiter: u8) -> bool {
+    let volumes: Vec<f64> = sample
+        .iter()
+        .filter_map(|line| {
+            split_line(line, delimiter)
+                .get(HEADERLESS_VOLUME_COLUMN)?
+                .parse()
+                .ok()
+        })
+        .collect();
+    !volumes.is_empty() && volumes.iter().all(|v| *v == 0.0)
+}
+
+fn split_line(line: &str, delimiter: u8) -> Vec<String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .delimiter(delimiter)
+        .from_reader(line.as_bytes());
+    let mut record = csv::StringRecord::new();
+    match reader.read_record(&mut record) {
+        Ok(true) => record.iter().map(|s| s.to_string()).collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,293 @@
+// services/pair_data/detection/mod.rs - Détection du format par score sur un échantillon
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::formats::FormatParsers;
+use super::profile::ColumnMappingProfile;
+use super::types::{CsvFormat, NormalizedCandle};
+use crate::services::csv_cleaner::clean_line;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+mod builtin;
+
+/// Lignes de données lues pour scorer chaque format candidat
+const SAMPLE_LINES: usize = 25;
+
+/// Disposition retenue pour lire un fichier : format, délimiteur et headers
+#[derive(Debug, Clone)]
+pub(super) struct Layout {
+    pub format: CsvFormat,
+    pub delimiter: u8,
+    pub headers: Vec<String>,
+    /// Export européen "virgule" : les décimales créent des colonnes en trop
+    pub european_comma: bool,
+}
+
+impl Layout {
+    pub fn has_header(&self) -> bool {
+        !self.format.is_headerless()
+    }
+
+    /// Parse une ligne (les lignes européennes passent d'abord par `clean_line`)
+    pub fn parse(&self, record: &csv::StringRecord) -> Result<NormalizedCandle, String> {
+        if !self.european_comma {
+            return FormatParsers::parse_record(record, &self.format, &self.headers);
+        }
+
+        let line = record.iter().collect::<Vec<_>>().join(",");
+        let cleaned = clean_line(&line)?;
+        let cleaned_record = csv::StringRecord::from(cleaned.split(',').collect::<Vec<_>>());
+        FormatParsers::parse_record(&cleaned_record, &self.format, &self.headers)
+    }
+}
+
+/// Score d'un format candidat (part des lignes d'échantillon valides)
+#[derive(Debug, Clone, Serialize)]
+pub struct FormatCandidateScore {
+    pub label: String,
+    pub score: f64,
+}
+
+/// Rapport de détection exposé au frontend
+#[derive(Debug, Clone, Serialize)]
+pub struct FormatDetection {
+    pub format: String,
+    pub profile: Option<String>,
+    pub score: f64,
+    pub has_header: bool,
+    pub candidates: Vec<FormatCandidateScore>,
+}
+
+/// Choisit la meilleure disposition entre le format intégré et les profils utilisateur
+pub(super) fn detect_layout(
+    path: &str,
+    profiles: &[ColumnMappingProfile],
+) -> Result<(Layout, FormatDetection), String> {
+    let sample = read_sample(path)?;
+    let filename = Path::new(path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+
+    let mut candidates = builtin::builtin_layouts(&sample, filename);
+    candidates.extend(profiles.iter().filter_map(|profile| {
+        Some(Layout {
+            delimiter: profile.delimiter_byte()?,
+            format: CsvFormat::Custom(profile.clone()),
+            headers: Vec::new(),
+            european_comma: false,
+        })
+    }));
+
+    let scored: Vec<(Layout, f64)> = candidates
+        .into_iter()
+        .map(|layout| {
+            let score = score_layout(&layout, &sample);
+            (layout, score)
+        })
+        .collect();
+
+    // À score égal, l'ordre des candidats décide : formats intégrés (le plus probable
+    // d'abord), puis profils utilisateur
+    let best = scored
+        .iter()
+        .enumerate()
+        .fold(None::<(usize, f64)>, |best, (idx, (_, score))| match best {
+            Some((_, best_score)) if *score <= best_score => best,
+            _ => Some((idx, *score)),
+        })
+        .map(|(idx, _)| idx)
+        .unwrap_or(0);
+
+    let report = FormatDetection {
+        format: scored[best].0.format.label(),
+        profile: match &scored[best].0.format {
+            CsvFormat::Custom(profile) => Some(profile.name.clone()),
+            _ => None,
+        },
+        score: scored[best].1,
+        has_header: scored[best].0.has_header(),
+        candidates: scored
+            .iter()
+            .map(|(layout, score)| FormatCandidateScore {
+                label: layout.format.label(),
+                score: *score,
+            })
+            .collect(),
+    };
+
+    if report.score <= 0.0 {
+        return Err(format!(
+            "Format non reconnu: aucune ligne valide avec {} candidat(s)",
+            report.candidates.len()
+        ));
+    }
+
+    let (layout, _) = scored
+        .into_iter()
+        .nth(best)
+        .ok_or("Aucun format candidat")?;
+    Ok((layout, report))
+}
+
+fn read_sample(path: &str) -> Result<Vec<String>, String> {
+    let file = File::open(path).map_err(|e| format!("Erreur ouverture: {}", e))?;
+    let lines: Vec<String> = BufReader::new(file)
+        .lines()
+        .take(SAMPLE_LINES + 1)
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Erreur lecture échantillon: {}", e))?;
+
+    match lines.first() {
+        Some(first) if !first.trim().is_empty() => Ok(lines),
+        _ => Err("Fichier vide".to_string()),
+    }
+}
+
+/// Part des lignes de l'échantillon qui donnent une bougie cohérente
+fn score_layout(layout: &Layout, sample: &[String]) -> f64 {
+    let content = sample.join("\n");
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(layout.has_header())
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .delimiter(layout.delimiter)
+        .from_reader(content.as_bytes());
+
+    let mut total = 0usize;
+    let mut valid = 0usize;
+    for record in reader.records() {
+        total += 1;
+        let Ok(record) = record else { continue };
+        if let Ok(candle) = layout.parse(&record) {
+            if is_consistent(&candle) {
+                valid += 1;
+            }
+        }
+    }
+
+    if total == 0 {
+        0.0
+    } else {
+        valid as f64 / total as f64
+    }
+}
+
+fn is_consistent(candle: &NormalizedCandle) -> bool {
+    let prices = [candle.open, candle.high, candle.low, candle.close];
+    prices.iter().all(|p| p.is_finite() && *p > 0.0)
+        && candle.high >= candle.low
+        && candle.high >= candle.open.max(candle.close)
+        && candle.low <= candle.open.min(candle.close)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::pair_data::profile::DecimalStyle;
+    use std::io::Write;
+
+    fn write_named(dir: &tempfile::TempDir, name: &str, content: &str) -> String {
+        let path = dir.path().join(name);
+        let mut file = File::create(&path).expect("Failed to create file");
+        file.write_all(content.as_bytes()).expect("Failed to write");
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_histdata_headerless_is_shifted_to_utc() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_named(
+            &dir,
+            "DAT_ASCII_EURUSD_M1_2024.csv",
+            "20240102 120000;1.104270;1.104290;1.104250;1.104290;0\n\
+             20240102 120100;1.104290;1.104310;1.104280;1.104300;0\n",
+        );
+
+        let (layout, report) = detect_layout(&path, &[]).expect("détection");
+        assert!(matches!(layout.format, CsvFormat::Histdata));
+        assert_eq!(report.score, 1.0);
+
+        let record = csv::StringRecord::from(vec![
+            "20240102 120000",
+            "1.104270",
+            "1.104290",
+            "1.104250",
+            "1.104290",
+            "0",
+        ]);
+        let candle = layout.parse(&record).expect("ligne Histdata");
+        // 12:00 EST = 17:00 UTC
+        assert_eq!(candle.timestamp, 1704214800);
+    }
+
+    #[test]
+    fn test_custom_profile_wins_on_score() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_named(
+            &dir,
+            "export_broker.txt",
+            "Bar|Jour|Heure|O|H|B|C\n\
+             1|02/01/2024|17:00|1,10427|1,10429|1,10425|1,10429\n\
+             2|02/01/2024|17:01|1,10429|1,10431|1,10428|1,10430\n",
+        );
+        let profile = ColumnMappingProfile {
+            name: "Broker pipe".to_string(),
+            delimiter: '|',
+            has_header: true,
+            date_pattern: "%d/%m/%Y %H:%M".to_string(),
+            date_column: 1,
+            time_column: Some(2),
+            open_column: 3,
+            high_column: 4,
+            low_column: 5,
+            close_column: 6,
+            volume_column: None,
+            decimal_style: DecimalStyle::Comma,
+            utc_offset_minutes: 0,
+        };
+
+        let (layout, report) = detect_layout(&path, &[profile]).expect("détection");
+        assert_eq!(report.profile.as_deref(), Some("Broker pipe"));
+        assert_eq!(report.candidates.len(), 2);
+        assert_eq!(report.candidates[0].score, 0.0);
+
+        let record = csv::StringRecord::from(vec![
+            "1",
+            "02/01/2024",
+            "17:00",
+            "1,10427",
+            "1,10429",
+            "1,10425",
+            "1,10429",
+        ]);
+        let candle = layout.parse(&record).expect("ligne profil");
+        assert_eq!(candle.timestamp, 1704214800);
+        assert_eq!(candle.low, 1.10425);
+    }
+
+    #[test]
+    fn test_histdata_or_ninjatrader_decided_by_volume() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let rows = |volume: &str| {
+            format!(
+                "20240102 120000;1.104270;1.104290;1.104250;1.104290;{v}\n\
+                 20240102 120100;1.104290;1.104310;1.104280;1.104300;{v}\n",
+                v = volume
+            )
+        };
+
+        // Même disposition, même score : le volume toujours nul désigne Histdata
+        let histdata = write_named(&dir, "eurusd_m1.csv", &rows("0"));
+        let (layout, report) = detect_layout(&histdata, &[]).expect("détection");
+        assert!(matches!(layout.format, CsvFormat::Histdata));
+        assert_eq!(report.candidates.len(), 2);
+        assert_eq!(report.candidates[0].score, report.candidates[1].score);
+
+        let ninja = write_named(&dir, "eurusd_ninja.csv", &rows("57"));
+        let (layout, _) = detect_layout(&ninja, &[]).expect("détection");
+        assert!(matches!(layout.format, CsvFormat::NinjaTrader));
+    }
+}
@@ -2,14 +2,14 @@
 // Conforme .clinerules : < 200L, pas d'unwrap()
 
 use super::datetime_parser::DateTimeParser;
-use super::types::{CsvFormat, NormalizedCandle};
+use super::types::{AskQuote, CsvFormat, NormalizedCandle};
 
 /// Parseurs pour différents formats CSV
 pub(super) struct FormatParsers;
 
 impl FormatParsers {
     /// Parse un nombre avec virgule (EU) ou point (US) comme séparateur décimal
-    fn parse_decimal(s: &str) -> Result<f64, String> {
+    pub(super) fn parse_decimal(s: &str) -> Result<f64, String> {
         s.replace(',', ".")
             .parse::<f64>()
             .map_err(|_| format!("Nombre invalide: {}", s))
@@ -21,12 +21,67 @@ impl FormatParsers {
         format: &CsvFormat,
         headers: &[String],
     ) -> Result<NormalizedCandle, String> {
-        match format {
+        let mut candle = match format {
             CsvFormat::MetaTrader => Self::parse_metatrader(record),
             CsvFormat::TradingView => Self::parse_tradingview(record),
             CsvFormat::Dukascopy => Self::parse_dukascopy(record),
             CsvFormat::Generic => Self::parse_generic(record, headers),
-        }
+            CsvFormat::DukascopyTick => return Self::parse_dukascopy_tick(record, headers),
+            CsvFormat::Histdata => Self::parse_histdata(record),
+            // Positionnel (date,O,H,L,C,V), horodatage déjà en UTC
+            CsvFormat::NinjaTrader | CsvFormat::CTrader => Self::parse_dukascopy(record),
+            CsvFormat::Custom(profile) => profile.parse_record(record),
+        }?;
+
+        let (spread_points, ask) = Self::parse_spread_columns(record, headers);
+        candle.spread_points = spread_points;
+        candle.ask = ask;
+
+        Ok(candle)
+    }
+
+    /// Normalise un header : minuscules, sans chevrons MT5 (<SPREAD>) ni espaces
+    pub(super) fn normalize_header(header: &str) -> String {
+        header
+            .to_lowercase()
+            .chars()
+            .filter(|c| !matches!(c, '<' | '>' | ' ' | '_'))
+            .collect()
+    }
+
+    /// Extrait les colonnes optionnelles : spread (points MT5) et Ask OHLC
+    fn parse_spread_columns(
+        record: &csv::StringRecord,
+        headers: &[String],
+    ) -> (Option<f64>, Option<AskQuote>) {
+        let headers_norm: Vec<String> = headers.iter().map(|h| Self::normalize_header(h)).collect();
+        let value_of = |predicate: &dyn Fn(&str) -> bool| -> Option<f64> {
+            headers_norm
+                .iter()
+                .position(|h| predicate(h))
+                .and_then(|idx| record.get(idx))
+                .filter(|v| !v.is_empty())
+                .and_then(|v| Self::parse_decimal(v).ok())
+        };
+
+        let spread_points = value_of(&|h| h.contains("spread"));
+
+        let ask_open = value_of(&|h| h.contains("ask") && h.contains("open"));
+        let ask_high = value_of(&|h| h.contains("ask") && h.contains("high"));
+        let ask_low = value_of(&|h| h.contains("ask") && h.contains("low"));
+        let ask_close = value_of(&|h| h.contains("ask") && h.contains("close"));
+
+        let ask = match (ask_open, ask_high, ask_low, ask_close) {
+            (Some(open), Some(high), Some(low), Some(close)) => Some(AskQuote {
+                open,
+                high,
+                low,
+                close,
+            }),
+            _ => None,
+        };
+
+        (spread_points, ask)
     }
 
     /// Parse format MetaTrader: Date,Time,Open,High,Low,Close,Volume
@@ -50,6 +105,8 @@ impl FormatParsers {
             close: Self::parse_decimal(record.get(5).ok_or("Close column missing")?)?,
             volume: Self::parse_decimal(record.get(6).ok_or("Volume column missing")?)
                 .unwrap_or(0.0),
+            spread_points: None,
+            ask: None,
         })
     }
 
@@ -76,11 +133,13 @@ impl FormatParsers {
             close: Self::parse_decimal(record.get(4).ok_or("Close column missing")?)?,
             volume: Self::parse_decimal(record.get(5).ok_or("Volume column missing")?)
                 .unwrap_or(0.0),
+            spread_points: None,
+            ask: None,
         })
     }
 
     /// Parse format Dukascopy: Gmt time,Open,High,Low,Close,Volume
-    fn parse_dukascopy(record: &csv::StringRecord) -> Result<NormalizedCandle, String> {
+    pub(super) fn parse_dukascopy(record: &csv::StringRecord) -> Result<NormalizedCandle, String> {
         if record.len() < 6 {
             return Err("Ligne incomplète".to_string());
         }
@@ -96,6 +155,8 @@ impl FormatParsers {
             close: Self::parse_decimal(record.get(4).ok_or("Close column missing")?)?,
             volume: Self::parse_decimal(record.get(5).ok_or("Volume column missing")?)
                 .unwrap_or(0.0),
+            spread_points: None,
+            ask: None,
         })
     }
 
@@ -104,7 +165,12 @@ impl FormatParsers {
         record: &csv::StringRecord,
         headers: &[String],
     ) -> Result<NormalizedCandle, String> {
-        let headers_lower: Vec<String> = headers.iter().map(|h| h.to_lowercase()).collect();
+        // Les colonnes Ask sont lues à part (parse_spread_columns)
+        let headers_lower: Vec<String> = headers
+            .iter()
+            .map(|h| Self::normalize_header(h))
+            .map(|h| if h.contains("ask") { String::new() } else { h })
+            .collect();
 
         // Trouver les indices des colonnes
         let time_idx = headers_lower
@@ -141,6 +207,73 @@ impl FormatParsers {
             volume: volume_idx
                 .and_then(|idx| record.get(idx).and_then(|v| Self::parse_decimal(v).ok()))
                 .unwrap_or(0.0),
+            spread_points: None,
+            ask: None,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(names: &[&str]) -> Vec<String> {
+        names.iter().map(|h| h.to_string()).collect()
+    }
+
+    #[test]
+    fn test_metatrader_spread_column() {
+        let headers = headers(&[
+            "<DATE>",
+            "<TIME>",
+            "<OPEN>",
+            "<HIGH>",
+            "<LOW>",
+            "<CLOSE>",
+            "<TICKVOL>",
+            "<VOL>",
+            "<SPREAD>",
+        ]);
+        let record = csv::StringRecord::from(vec![
+            "2024.01.02",
+            "14:30:00",
+            "1.0950",
+            "1.0960",
+            "1.0940",
+            "1.0955",
+            "120",
+            "0",
+            "7",
+        ]);
+        let candle = FormatParsers::parse_record(&record, &CsvFormat::MetaTrader, &headers)
+            .expect("ligne MT5 valide");
+        assert_eq!(candle.spread_points, Some(7.0));
+        assert!(candle.ask.is_none());
+        assert!((candle.spread_price(0.00001).unwrap_or(0.0) - 0.00007).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_generic_bid_ask_columns() {
+        let headers = headers(&[
+            "Time", "BidOpen", "BidHigh", "BidLow", "BidClose", "AskOpen", "AskHigh", "AskLow",
+            "AskClose", "Volume",
+        ]);
+        let record = csv::StringRecord::from(vec![
+            "2024-01-02 14:30:00",
+            "1.0950",
+            "1.0960",
+            "1.0940",
+            "1.0955",
+            "1.0951",
+            "1.0961",
+            "1.0941",
+            "1.0956",
+            "10",
+        ]);
+        let candle = FormatParsers::parse_record(&record, &CsvFormat::Generic, &headers)
+            .expect("ligne Bid/Ask valide");
+        assert_eq!(candle.close, 1.0955);
+        assert_eq!(candle.ask.map(|a| a.close), Some(1.0956));
+        assert!((candle.spread_price(0.00001).unwrap_or(0.0) - 0.0001).abs() < 1e-9);
+    }
+}
@@ -51,8 +51,20 @@ impl MetadataExtractor {
 
     /// Extrait le nom de la paire depuis le nom de fichier
     fn extract_pair_from_filename(filename: &str) -> String {
-        // Enlever l'extension
-        let name = filename.replace(".csv", "").replace(".CSV", "");
+        // Enlever l'extension (.csv, .txt NinjaTrader...)
+        let name = Path::new(filename)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(filename)
+            .to_string();
+
+        // Segment de 6 lettres majuscules (DAT_ASCII_EURUSD_M1_2024, EURUSD.Last)
+        if let Some(token) = name
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .find(|t| t.len() == 6 && t.chars().all(|c| c.is_ascii_uppercase()))
+        {
+            return token.to_string();
+        }
 
         // Extraire les majuscules (paires forex typiquement en majuscules)
         let uppercase: String = name.chars().filter(|c| c.is_uppercase()).collect();
@@ -73,9 +85,10 @@ impl MetadataExtractor {
         }
 
         // Calculer l'écart moyen entre les 10 premières bougies
+        // (en valeur absolue : en import en flux, l'ordre est celui du fichier)
         let mut diffs = Vec::new();
         for i in 0..10.min(candles.len() - 1) {
-            diffs.push(candles[i + 1].timestamp - candles[i].timestamp);
+            diffs.push((candles[i + 1].timestamp - candles[i].timestamp).abs());
         }
 
         let avg_diff = diffs.iter().sum::<i64>() / diffs.len() as i64;
@@ -94,48 +107,4 @@ impl MetadataExtractor {
 
         Ok(timeframe.to_string())
     }
-
-    /// Génère le nom du fichier normalisé
-    #[allow(clippy::format_in_format_args)]
-    pub(super) fn generate_filename(metadata: &PairMetadata) -> String {
-        format!(
-            "{}_{}_{}_{}.csv",
-            metadata.pair,
-            metadata.timeframe,
-            format!("{}-{}", metadata.start_date, metadata.end_date),
-            metadata.import_date
-        )
-    }
-
-    /// Sauvegarde les candles normalisées dans un fichier CSV
-    pub(super) fn save_to_csv(
-        candles: &[NormalizedCandle],
-        output_path: &Path,
-    ) -> Result<(), String> {
-        let mut writer = csv::Writer::from_path(output_path)
-            .map_err(|e| format!("Erreur création CSV: {}", e))?;
-
-        // Écrire les headers
-        writer
-            .write_record(["timestamp", "open", "high", "low", "close", "volume"])
-            .map_err(|e| format!("Erreur écriture headers: {}", e))?;
-
-        // Écrire les données
-        for candle in candles {
-            writer
-                .write_record(&[
-                    candle.timestamp.to_string(),
-                    candle.open.to_string(),
-                    candle.high.to_string(),
-                    candle.low.to_string(),
-                    candle.close.to_string(),
-                    candle.volume.to_string(),
-                ])
-                .map_err(|e| format!("Erreur écriture ligne: {}", e))?;
-        }
-
-        writer.flush().map_err(|e| format!("Erreur flush: {}", e))?;
-
-        Ok(())
-    }
 }
@@ -1,13 +1,22 @@
 // services/pair_data/mod.rs - Module de conversion de données de paires
 // Conforme .clinerules : structure modulaire < 300L
 
+mod broker_formats;
 mod converter;
 mod datetime_parser;
+mod detection;
 mod formats;
 mod metadata;
+mod profile;
+mod stream;
 pub mod symbol_properties;
+mod tick_bar;
 mod types;
 
 // Ré-exports publics
 pub use converter::PairDataConverter;
+pub use detection::FormatDetection;
+pub use profile::ColumnMappingProfile;
+pub use stream::{CandleStream, StreamPosition};
 pub use symbol_properties::*;
+pub use types::NormalizedCandle;
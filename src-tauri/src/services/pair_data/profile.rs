@@ -0,0 +1,136 @@
+// services/pair_data/profile.rs - Profils de colonnes définis par l'utilisateur
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::types::NormalizedCandle;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// Séparateur décimal des prix dans le fichier
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DecimalStyle {
+    #[default]
+    Point,
+    Comma,
+}
+
+/// Mapping de colonnes pour un export non reconnu (indices à partir de 0)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnMappingProfile {
+    pub name: String,
+    pub delimiter: char,
+    #[serde(default)]
+    pub has_header: bool,
+    /// Motif chrono appliqué à la date (+ heure si colonne séparée), ex. "%Y%m%d %H%M%S"
+    pub date_pattern: String,
+    pub date_column: usize,
+    /// Colonne heure séparée, concaténée à la date avec une espace
+    #[serde(default)]
+    pub time_column: Option<usize>,
+    pub open_column: usize,
+    pub high_column: usize,
+    pub low_column: usize,
+    pub close_column: usize,
+    #[serde(default)]
+    pub volume_column: Option<usize>,
+    #[serde(default)]
+    pub decimal_style: DecimalStyle,
+    /// Décalage des dates du fichier par rapport à UTC, en minutes (EST fixe = -300)
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+}
+
+impl ColumnMappingProfile {
+    /// Vérifie la cohérence du profil avant sauvegarde
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Le profil doit avoir un nom".to_string());
+        }
+        if self.date_pattern.trim().is_empty() {
+            return Err("Le motif de date est obligatoire".to_string());
+        }
+        if self.delimiter_byte().is_none() {
+            return Err(format!("Délimiteur non supporté: {:?}", self.delimiter));
+        }
+        if self.delimiter == ',' && self.decimal_style == DecimalStyle::Comma {
+            return Err(
+                "Délimiteur et séparateur décimal ne peuvent pas être tous deux ','".to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Délimiteur au format attendu par le lecteur CSV (ASCII uniquement)
+    pub(super) fn delimiter_byte(&self) -> Option<u8> {
+        (self.delimiter.is_ascii() && self.delimiter != '.').then_some(self.delimiter as u8)
+    }
+
+    /// Nombre minimal de colonnes qu'une ligne doit contenir
+    fn min_columns(&self) -> usize {
+        [
+            Some(self.date_column),
+            self.time_column,
+            Some(self.open_column),
+            Some(self.high_column),
+            Some(self.low_column),
+            Some(self.close_column),
+            self.volume_column,
+        ]
+        .iter()
+        .flatten()
+        .max()
+        .map_or(0, |max| max + 1)
+    }
+
+    pub(super) fn parse_record(
+        &self,
+        record: &csv::StringRecord,
+    ) -> Result<NormalizedCandle, String> {
+        if record.len() < self.min_columns() {
+            return Err("Ligne incomplète".to_string());
+        }
+
+        let field = |idx: usize| {
+            record
+                .get(idx)
+                .ok_or_else(|| format!("Colonne {} manquante", idx))
+        };
+
+        let date = match self.time_column {
+            Some(time_idx) => format!("{} {}", field(self.date_column)?, field(time_idx)?),
+            None => field(self.date_column)?.to_string(),
+        };
+        let naive = NaiveDateTime::parse_from_str(&date, &self.date_pattern)
+            .or_else(|_| {
+                NaiveDate::parse_from_str(&date, &self.date_pattern)
+                    .map(|d| d.and_time(NaiveTime::MIN))
+            })
+            .map_err(|_| format!("Date '{}' hors motif {}", date, self.date_pattern))?;
+        let timestamp = naive.and_utc().timestamp() - i64::from(self.utc_offset_minutes) * 60;
+
+        Ok(NormalizedCandle {
+            timestamp,
+            open: self.number(field(self.open_column)?)?,
+            high: self.number(field(self.high_column)?)?,
+            low: self.number(field(self.low_column)?)?,
+            close: self.number(field(self.close_column)?)?,
+            volume: self
+                .volume_column
+                .and_then(|idx| record.get(idx))
+                .and_then(|v| self.number(v).ok())
+                .unwrap_or(0.0),
+            spread_points: None,
+            ask: None,
+        })
+    }
+
+    fn number(&self, raw: &str) -> Result<f64, String> {
+        let value = match self.decimal_style {
+            DecimalStyle::Point => raw.trim().to_string(),
+            DecimalStyle::Comma => raw.trim().replace(',', "."),
+        };
+        value
+            .parse::<f64>()
+            .map_err(|_| format!("Nombre invalide: {}", raw))
+    }
+}
@@ -0,0 +1,275 @@
+// services/pair_data/stream.rs - Lecture en flux d'un CSV de paire (mémoire bornée)
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::detection::{detect_layout, FormatDetection, Layout};
+use super::profile::ColumnMappingProfile;
+use super::tick_bar::TickBar;
+use super::types::NormalizedCandle;
+use crate::services::ConfigService;
+use std::fs::File;
+use std::io::BufReader;
+
+/// Position de reprise dans le fichier (octet + numéro de ligne du prochain enregistrement)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StreamPosition {
+    pub byte: u64,
+    pub line: u64,
+}
+
+/// Lecteur CSV en flux : parse et nettoie ligne par ligne, sans charger le fichier
+pub struct CandleStream {
+    reader: csv::Reader<BufReader<File>>,
+    layout: Layout,
+    detection: FormatDetection,
+    /// Bougie M1 en cours d'agrégation (fichiers de ticks)
+    tick_bar: TickBar,
+    total_bytes: u64,
+    lines_read: usize,
+    errors: usize,
+    warnings: Vec<String>,
+}
+
+impl CandleStream {
+    /// Nombre maximal d'avertissements conservés (le compteur d'erreurs reste exact)
+    const MAX_WARNINGS: usize = 50;
+
+    /// Ouvre le fichier avec les profils de colonnes enregistrés en configuration
+    pub fn open(path: &str) -> Result<Self, String> {
+        let profiles = ConfigService::get_import_profiles().unwrap_or_else(|e| {
+            tracing::warn!(
+                "⚠️ Profils d'import illisibles, formats intégrés seuls: {}",
+                e
+            );
+            Vec::new()
+        });
+        Self::open_with_profiles(path, &profiles)
+    }
+
+    /// Ouvre le fichier et retient le format au meilleur score de détection
+    pub fn open_with_profiles(
+        path: &str,
+        profiles: &[ColumnMappingProfile],
+    ) -> Result<Self, String> {
+        let (layout, detection) = detect_layout(path, profiles)?;
+        tracing::info!(
+            "🔍 Format retenu: {} (score {:.0}%, délimiteur {:?})",
+            detection.format,
+            detection.score * 100.0,
+            layout.delimiter as char
+        );
+
+        let file = File::open(path).map_err(|e| format!("Erreur ouverture: {}", e))?;
+        let total_bytes = file.metadata().map(|m| m.len()).unwrap_or(0);
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(layout.has_header())
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .delimiter(layout.delimiter)
+            .from_reader(BufReader::new(file));
+
+        if layout.has_header() {
+            reader
+                .headers()
+                .map_err(|e| format!("Erreur headers: {}", e))?;
+        }
+
+        Ok(Self {
+            reader,
+            layout,
+            detection,
+            tick_bar: TickBar::default(),
+            total_bytes,
+            lines_read: 0,
+            errors: 0,
+            warnings: Vec::new(),
+        })
+    }
+
+    /// Reprend la lecture à une position enregistrée lors d'un import interrompu
+    pub fn resume_at(&mut self, position: StreamPosition) -> Result<(), String> {
+        let mut pos = csv::Position::new();
+        pos.set_byte(position.byte).set_line(position.line);
+        self.reader
+            .seek(pos)
+            .map_err(|e| format!("Erreur reprise à l'octet {}: {}", position.byte, e))
+    }
+
+    /// Position du prochain enregistrement (à enregistrer comme point de reprise)
+    /// (pour un fichier de ticks : début de la bougie M1 encore incomplète)
+    pub fn position(&self) -> StreamPosition {
+        let pos = self
+            .tick_bar
+            .start()
+            .unwrap_or_else(|| self.reader.position());
+        StreamPosition {
+            byte: pos.byte(),
+            line: pos.line(),
+        }
+    }
+
+    /// Lit jusqu'à `max` bougies valides ; un lot vide signifie fin de fichier
+    pub fn next_batch(&mut self, max: usize) -> Vec<NormalizedCandle> {
+        let mut batch = Vec::with_capacity(max);
+        let mut record = csv::StringRecord::new();
+
+        while batch.len() < max {
+            let start = self.reader.position().clone();
+            match self.reader.read_record(&mut record) {
+                Ok(true) => {}
+                Ok(false) => {
+                    batch.extend(self.tick_bar.finish());
+                    break;
+                }
+                Err(e) => {
+                    // Ligne consommée : comptée pour que erreurs <= lignes lues
+                    self.lines_read += 1;
+                    self.record_error(format!("erreur CSV: {}", e));
+                    continue;
+                }
+            }
+            self.lines_read += 1;
+
+            match self.layout.parse(&record) {
+                Ok(tick) if self.layout.format.is_tick() => {
+                    batch.extend(self.tick_bar.absorb(tick, start));
+                }
+                Ok(candle) => batch.push(candle),
+                Err(e) => self.record_error(e),
+            }
+        }
+
+        batch
+    }
+
+    fn record_error(&mut self, message: String) {
+        self.errors += 1;
+        if self.warnings.len() < Self::MAX_WARNINGS {
+            let line = self.reader.position().line();
+            self.warnings.push(format!("L{}: {}", line, message));
+        }
+    }
+
+    pub fn detection(&self) -> &FormatDetection {
+        &self.detection
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    pub fn lines_read(&self) -> usize {
+        self.lines_read
+    }
+
+    pub fn errors(&self) -> usize {
+        self.errors
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn write_csv(content: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().expect("Failed to create temp file");
+        file.write_all(content.as_bytes()).expect("Failed to write");
+        file
+    }
+
+    #[test]
+    fn test_resume_from_position() {
+        let file = write_csv(
+            "time,open,high,low,close,volume\n\
+             1704067200,1.1000,1.1010,1.0990,1.1005,100\n\
+             1704067260,1.1005,1.1015,1.0995,1.1010,120\n\
+             1704067320,1.1010,1.1020,1.1000,1.1015,90\n",
+        );
+        let path = file.path().to_str().expect("path");
+
+        let mut stream = CandleStream::open_with_profiles(path, &[]).expect("open");
+        assert_eq!(stream.next_batch(2).len(), 2);
+        let checkpoint = stream.position();
+
+        let mut resumed = CandleStream::open_with_profiles(path, &[]).expect("open");
+        resumed.resume_at(checkpoint).expect("resume");
+        let rest = resumed.next_batch(10);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].timestamp, 1704067320);
+        assert!(resumed.next_batch(10).is_empty());
+    }
+
+    #[test]
+    fn test_european_comma_export_is_cleaned() {
+        let file = write_csv(
+            "Time (UTC),Open,High,Low,Close,Volume\n\
+             2024,10,10 13:33:00,108,114,108,131,108,102,108,113,278,52\n\
+             ligne invalide\n",
+        );
+        let path = file.path().to_str().expect("path");
+
+        let mut stream = CandleStream::open_with_profiles(path, &[]).expect("open");
+        let batch = stream.next_batch(10);
+        assert_eq!(batch.len(), 1);
+        assert!((batch[0].open - 108.114).abs() < 1e-9);
+        assert!((batch[0].close - 108.113).abs() < 1e-9);
+        assert_eq!(stream.errors(), 1);
+        assert_eq!(stream.lines_read(), 2);
+    }
+
+    #[test]
+    fn test_unreadable_line_counted_as_read() {
+        // Ligne non UTF-8 après l'échantillon de détection
+        let mut content = b"time,open,high,low,close,volume\n".to_vec();
+        for i in 0..40 {
+            let line = format!("{},1.1000,1.1010,1.0990,1.1005,100\n", 1704067200 + i * 60);
+            content.extend_from_slice(line.as_bytes());
+        }
+        content.extend_from_slice(b"17040\xff69600,1.1010,1.1020,1.1000,1.1015,90\n");
+        let mut file = NamedTempFile::new().expect("Failed to create temp file");
+        file.write_all(&content).expect("Failed to write");
+        let path = file.path().to_str().expect("path");
+
+        let mut stream = CandleStream::open_with_profiles(path, &[]).expect("open");
+        assert_eq!(stream.next_batch(100).len(), 40);
+        assert_eq!(stream.errors(), 1);
+        assert_eq!(stream.lines_read(), 41);
+    }
+
+    #[test]
+    fn test_dukascopy_ticks_aggregate_to_m1() {
+        let file = write_csv(
+            "Gmt time,Ask,Bid,AskVolume,BidVolume\n\
+             02.01.2024 17:00:01.120,1.10431,1.10429,1.5,2.0\n\
+             02.01.2024 17:00:30.500,1.10441,1.10438,1.0,1.0\n\
+             02.01.2024 17:00:59.900,1.10426,1.10424,0.5,0.5\n\
+             02.01.2024 17:01:00.050,1.10430,1.10428,1.0,1.0\n",
+        );
+        let path = file.path().to_str().expect("path");
+
+        let mut stream = CandleStream::open_with_profiles(path, &[]).expect("open");
+        let first = stream.next_batch(1);
+        assert_eq!(first.len(), 1);
+        let bar = &first[0];
+        assert_eq!(bar.timestamp, 1704214800);
+        assert_eq!(
+            (bar.open, bar.high, bar.low, bar.close),
+            (1.10429, 1.10438, 1.10424, 1.10424)
+        );
+        assert!((bar.volume - 6.5).abs() < 1e-9);
+        assert!((bar.spread_price(0.00001).unwrap_or(0.0) - 0.00002).abs() < 1e-9);
+
+        // Le point de reprise pointe sur le premier tick de la minute incomplète
+        let checkpoint = stream.position();
+        let mut resumed = CandleStream::open_with_profiles(path, &[]).expect("open");
+        resumed.resume_at(checkpoint).expect("resume");
+        let rest = resumed.next_batch(10);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].timestamp, 1704214860);
+    }
+}
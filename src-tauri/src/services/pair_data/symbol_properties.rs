@@ -2,10 +2,16 @@
 // Gestion des propriétés des symboles (valeur du point, pip, etc.)
 
 use crate::models::AssetProperties;
+use crate::services::SymbolRegistryService;
 
 /// Retourne la valeur d'un point (Tick Size) pour un symbole donné
 /// C'est la plus petite variation de prix possible (ou l'unité de base pour les calculs)
 pub fn get_point_value(symbol: &str) -> f64 {
+    // Symbole connu du registre : tick size du courtier
+    if let Some(spec) = SymbolRegistryService::lookup(symbol) {
+        return spec.tick_size;
+    }
+
     let props = AssetProperties::from_symbol(symbol);
     
     // Pour le Forex, le "point" MT5 est 1/10 de pip
@@ -0,0 +1,56 @@
+// services/pair_data/tick_bar.rs - Agrégation en flux des ticks en bougies M1
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::types::NormalizedCandle;
+
+/// Bougie M1 en cours d'agrégation (fichiers de ticks) et position de son premier tick
+#[derive(Default)]
+pub(super) struct TickBar {
+    current: Option<(NormalizedCandle, csv::Position)>,
+}
+
+impl TickBar {
+    /// Agrège un tick dans la bougie M1 courante ; renvoie la bougie close au changement de minute
+    pub(super) fn absorb(
+        &mut self,
+        tick: NormalizedCandle,
+        start: csv::Position,
+    ) -> Option<NormalizedCandle> {
+        let minute = tick.timestamp - tick.timestamp.rem_euclid(60);
+
+        if let Some((bar, _)) = self
+            .current
+            .as_mut()
+            .filter(|(bar, _)| bar.timestamp == minute)
+        {
+            bar.high = bar.high.max(tick.close);
+            bar.low = bar.low.min(tick.close);
+            bar.close = tick.close;
+            bar.volume += tick.volume;
+            if let (Some(bar_ask), Some(tick_ask)) = (bar.ask.as_mut(), tick.ask) {
+                bar_ask.high = bar_ask.high.max(tick_ask.close);
+                bar_ask.low = bar_ask.low.min(tick_ask.close);
+                bar_ask.close = tick_ask.close;
+            }
+            return None;
+        }
+
+        let opened = NormalizedCandle {
+            timestamp: minute,
+            ..tick
+        };
+        self.current
+            .replace((opened, start))
+            .map(|(closed, _)| closed)
+    }
+
+    /// Position du premier tick de la bougie encore incomplète
+    pub(super) fn start(&self) -> Option<&csv::Position> {
+        self.current.as_ref().map(|(_, start)| start)
+    }
+
+    /// Bougie incomplète restante (fin de fichier)
+    pub(super) fn finish(&mut self) -> Option<NormalizedCandle> {
+        self.current.take().map(|(bar, _)| bar)
+    }
+}
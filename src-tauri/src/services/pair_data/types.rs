@@ -1,15 +1,45 @@
 // services/pair_data/types.rs - Types de données pour conversion CSV
-// Conforme .clinerules : < 50L
+// Conforme .clinerules : < 120L
 
+use super::profile::ColumnMappingProfile;
 use serde::{Deserialize, Serialize};
 
 /// Format CSV détecté
 #[derive(Debug, Clone)]
 pub enum CsvFormat {
-    MetaTrader,  // Date,Time,Open,High,Low,Close,Volume
-    TradingView, // time,open,high,low,close,volume
-    Dukascopy,   // Gmt time,Open,High,Low,Close,Volume
-    Generic,     // Generic CSV with detected columns
+    MetaTrader,                   // Date,Time,Open,High,Low,Close,Volume
+    TradingView,                  // time,open,high,low,close,volume
+    Dukascopy,                    // Gmt time,Open,High,Low,Close,Volume
+    DukascopyTick,                // Gmt time,Ask,Bid,AskVolume,BidVolume (agrégé en M1)
+    Histdata,                     // 20240102 170000;O;H;L;C;V (sans header, EST fixe)
+    NinjaTrader,                  // 20240102 170000;O;H;L;C;V (sans header)
+    CTrader,                      // 2024-01-02T17:00:00.000Z,O,H,L,C,V (sans header)
+    Custom(ColumnMappingProfile), // Profil de colonnes défini par l'utilisateur
+    Generic,                      // Generic CSV with detected columns
+}
+
+impl CsvFormat {
+    /// Exports sans ligne d'en-tête
+    pub fn is_headerless(&self) -> bool {
+        match self {
+            CsvFormat::Histdata | CsvFormat::NinjaTrader | CsvFormat::CTrader => true,
+            CsvFormat::Custom(profile) => !profile.has_header,
+            _ => false,
+        }
+    }
+
+    /// Fichier de ticks à agréger en bougies M1
+    pub fn is_tick(&self) -> bool {
+        matches!(self, CsvFormat::DukascopyTick)
+    }
+
+    /// Libellé affiché dans le rapport de détection
+    pub fn label(&self) -> String {
+        match self {
+            CsvFormat::Custom(profile) => format!("Profil: {}", profile.name),
+            other => format!("{:?}", other),
+        }
+    }
 }
 
 /// Métadonnées d'une paire importée
@@ -22,6 +52,15 @@ pub struct PairMetadata {
     pub import_date: String,
 }
 
+/// Prix Ask OHLC (exports Dukascopy Bid/Ask ou colonnes Ask dédiées)
+#[derive(Debug, Clone, Copy)]
+pub struct AskQuote {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
 /// Bougie normalisée (format interne unifié)
 #[derive(Debug, Clone)]
 pub struct NormalizedCandle {
@@ -31,4 +70,19 @@ pub struct NormalizedCandle {
     pub low: f64,
     pub close: f64,
     pub volume: f64,
+    /// Colonne spread exportée (en points, convention MT5)
+    pub spread_points: Option<f64>,
+    /// Prix Ask si la source les exporte
+    pub ask: Option<AskQuote>,
+}
+
+impl NormalizedCandle {
+    /// Spread en prix : Ask - Bid si l'Ask est connu, sinon spread_points × point
+    pub fn spread_price(&self, point_value: f64) -> Option<f64> {
+        match (self.ask, self.spread_points) {
+            (Some(ask), _) => Some((ask.close - self.close).max(0.0)),
+            (None, Some(points)) => Some(points * point_value),
+            (None, None) => None,
+        }
+    }
 }
@@ -0,0 +1,283 @@
+// services/planning/candidate_ranking.rs - Classement des instruments candidats pour une annonce
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::asset_class::AssetType;
+use crate::models::planning::{CandidateRelation, PairCandidate, TradabilityVerdict};
+use crate::models::{AssetProperties, CalendarEvent, Candle, TimeDecay};
+use crate::services::backtest::models::TradeOutcome;
+use crate::services::backtest::simulator::EventSimulator;
+use crate::services::backtest::{BacktestConfig, StrategyMode};
+use crate::services::pair_data::get_point_value;
+use crate::services::statistics::{bootstrap_mean_interval, weighted_quantile};
+use crate::services::straddle_simulator_helpers::AssetCost;
+use crate::services::DatabaseLoader;
+use crate::services::SymbolRegistryService;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::cmp::Ordering;
+
+/// Occurrences les plus récentes simulées par instrument
+pub const MAX_RANKING_OCCURRENCES: usize = 60;
+/// Fenêtre de mesure du mouvement attendu (minutes après T0)
+const MOVE_WINDOW_MINUTES: i64 = 30;
+/// En dessous, le verdict reste "données insuffisantes"
+const MIN_CANDIDATE_TRADES: usize = 10;
+/// Au-delà, un gain moyen positif reste "marginal"
+const MAX_WHIPSAW_RATE: f64 = 0.35;
+
+/// Paramètres Straddle à simuler (points MT5)
+#[derive(Debug, Clone, Copy)]
+pub struct CandidateParams {
+    pub offset: f64,
+    pub tp: f64,
+    pub sl: f64,
+    pub timeout_minutes: i32,
+}
+
+pub struct CandidateRanker;
+
+impl CandidateRanker {
+    /// Lien d'un instrument avec la devise ; None pour une paire Forex qui ne la contient pas
+    pub fn relation(symbol: &str, currency: &str) -> Option<CandidateRelation> {
+        let is = |c: Option<&str>| c.is_some_and(|c| c.eq_ignore_ascii_case(currency));
+        let (asset_type, base, quote) = SymbolRegistryService::classify(symbol);
+
+        if is(base.as_deref()) {
+            Some(CandidateRelation::Base)
+        } else if is(quote.as_deref()) {
+            Some(CandidateRelation::Quote)
+        } else if matches!(asset_type, AssetType::ForexMajor | AssetType::ForexJpy) {
+            None
+        } else {
+            Some(CandidateRelation::Linked)
+        }
+    }
+
+    /// Simule les paramètres sur les dernières occurrences et mesure le mouvement attendu
+    pub fn evaluer(
+        pair: &str,
+        history: &[CalendarEvent],
+        params: CandidateParams,
+        loader: &DatabaseLoader,
+        decay: &TimeDecay,
+        cost: &AssetCost,
+    ) -> Result<PairCandidate, String> {
+        let asset = AssetProperties::from_symbol(pair);
+        let point = get_point_value(pair);
+        let config = BacktestConfig {
+            offset_pips: params.offset,
+            stop_loss_pips: params.sl,
+            trailing_stop_pips: params.tp,
+            timeout_minutes: params.timeout_minutes,
+            sl_recovery_pips: None,
+            // Coûts exprimés en pips, simulation en points
+            spread_pips: cost.spread_pips * asset.pip_value / point,
+            point_value: point,
+        };
+
+        let mut moves = Vec::new();
+        let mut trades = Vec::new();
+        let horizon = (params.timeout_minutes as i64).max(MOVE_WINDOW_MINUTES) + 10;
+        for event in history.iter().rev().take(MAX_RANKING_OCCURRENCES) {
+            let event_time = event.event_time.and_utc();
+            let candles = loader
+                .load_candles_by_pair(
+                    pair,
+                    "M1",
+                    event_time - Duration::minutes(5),
+                    event_time + Duration::minutes(horizon),
+                )
+                .map_err(|e| e.to_string())?;
+            let Some(peak) = excursion_max(event_time, &candles) else {
+                continue;
+            };
+            let date = event.event_time.date();
+            moves.push((date, asset.normalize(peak)));
+
+            let trade =
+                EventSimulator::simulate(event, &candles, &config, StrategyMode::Directionnel);
+            if trade.outcome != TradeOutcome::NoEntry {
+                let net = asset.normalize(trade.pips_net * point) - cost.slippage_pips;
+                trades.push((date, net, trade.outcome == TradeOutcome::Whipsaw));
+            }
+        }
+
+        Ok(Self::synthese(pair, &asset, params, &moves, &trades, decay))
+    }
+
+    /// Candidat sans occurrence exploitable (verdict "données insuffisantes")
+    pub fn sans_resultat(pair: &str, params: CandidateParams) -> PairCandidate {
+        let asset = AssetProperties::from_symbol(pair);
+        Self::synthese(pair, &asset, params, &[], &[], &TimeDecay::default())
+    }
+
+    fn synthese(
+        pair: &str,
+        asset: &AssetProperties,
+        params: CandidateParams,
+        moves: &[(NaiveDate, f64)],
+        trades: &[(NaiveDate, f64, bool)],
+        decay: &TimeDecay,
+    ) -> PairCandidate {
+        let move_dates: Vec<_> = moves.iter().map(|(d, _)| *d).collect();
+        let move_values: Vec<f64> = moves.iter().map(|(_, m)| *m).collect();
+        let expected_move_pips = weighted_quantile(&move_values, &decay.weights(&move_dates), 0.5);
+
+        let nets: Vec<f64> = trades.iter().map(|(_, net, _)| *net).collect();
+        let net_pnl_pips: f64 = nets.iter().sum();
+        let average_net_pips = if nets.is_empty() {
+            0.0
+        } else {
+            net_pnl_pips / nets.len() as f64
+        };
+        let ratio = |count: usize| {
+            if trades.is_empty() {
+                0.0
+            } else {
+                count as f64 / trades.len() as f64
+            }
+        };
+        let whipsaw_rate = ratio(trades.iter().filter(|(_, _, w)| *w).count());
+        let win_rate_percent = ratio(nets.iter().filter(|n| **n > 0.0).count()) * 100.0;
+        let average_net_ci = bootstrap_mean_interval(&nets);
+
+        PairCandidate {
+            pair: pair.to_string(),
+            rank: 0,
+            relation: CandidateRelation::Linked,
+            unit: asset.unit.clone(),
+            occurrences: moves.len(),
+            trades: trades.len(),
+            expected_move_pips,
+            whipsaw_rate,
+            win_rate_percent,
+            net_pnl_pips,
+            average_net_pips,
+            verdict: Self::verdict(
+                trades.len(),
+                average_net_pips,
+                average_net_ci.as_ref().map(|ci| ci.lower),
+                whipsaw_rate,
+            ),
+            average_net_ci,
+            offset: params.offset,
+            tp: params.tp,
+            sl: params.sl,
+            confidence_score: 0.0,
+        }
+    }
+
+    /// Rentable de façon significative et peu de whipsaws → tradable
+    pub fn verdict(
+        trades: usize,
+        average_net: f64,
+        ci_lower: Option<f64>,
+        whipsaw_rate: f64,
+    ) -> TradabilityVerdict {
+        if trades < MIN_CANDIDATE_TRADES {
+            TradabilityVerdict::InsufficientData
+        } else if average_net <= 0.0 {
+            TradabilityVerdict::Avoid
+        } else if ci_lower.is_some_and(|l| l > 0.0) && whipsaw_rate <= MAX_WHIPSAW_RATE {
+            TradabilityVerdict::Tradable
+        } else {
+            TradabilityVerdict::Marginal
+        }
+    }
+
+    /// Trie par verdict puis gain moyen net, puis mouvement attendu ; numérote les rangs
+    pub fn classer(mut candidates: Vec<PairCandidate>) -> Vec<PairCandidate> {
+        candidates.sort_by(|a, b| {
+            b.verdict
+                .cmp(&a.verdict)
+                .then(
+                    b.average_net_pips
+                        .partial_cmp(&a.average_net_pips)
+                        .unwrap_or(Ordering::Equal),
+                )
+                .then(
+                    b.expected_move_pips
+                        .partial_cmp(&a.expected_move_pips)
+                        .unwrap_or(Ordering::Equal),
+                )
+        });
+        for (i, candidate) in candidates.iter_mut().enumerate() {
+            candidate.rank = i + 1;
+        }
+        candidates
+    }
+}
+
+/// Excursion maximale depuis l'ouverture T0 sur la fenêtre de mesure
+fn excursion_max(event_time: DateTime<Utc>, candles: &[Candle]) -> Option<f64> {
+    let end = event_time + Duration::minutes(MOVE_WINDOW_MINUTES);
+    let mut window = candles
+        .iter()
+        .filter(|c| c.datetime >= event_time && c.datetime < end)
+        .peekable();
+    let open = window.peek()?.open;
+    Some(window.fold(0.0_f64, |peak, c| peak.max(c.high - open).max(open - c.low)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidat(pair: &str, verdict: TradabilityVerdict, average: f64) -> PairCandidate {
+        let params = CandidateParams {
+            offset: 0.0,
+            tp: 0.0,
+            sl: 0.0,
+            timeout_minutes: 30,
+        };
+        PairCandidate {
+            verdict,
+            average_net_pips: average,
+            ..CandidateRanker::sans_resultat(pair, params)
+        }
+    }
+
+    #[test]
+    fn test_relation_to_currency() {
+        assert_eq!(
+            CandidateRanker::relation("EURJPY", "EUR"),
+            Some(CandidateRelation::Base)
+        );
+        assert_eq!(
+            CandidateRanker::relation("XAUUSD", "USD"),
+            Some(CandidateRelation::Quote)
+        );
+        assert_eq!(
+            CandidateRanker::relation("US30", "USD"),
+            Some(CandidateRelation::Linked)
+        );
+        assert_eq!(CandidateRanker::relation("EURGBP", "USD"), None);
+    }
+
+    #[test]
+    fn test_verdict_and_ranking() {
+        assert_eq!(
+            CandidateRanker::verdict(5, 10.0, Some(2.0), 0.0),
+            TradabilityVerdict::InsufficientData
+        );
+        assert_eq!(
+            CandidateRanker::verdict(30, -1.0, None, 0.0),
+            TradabilityVerdict::Avoid
+        );
+        assert_eq!(
+            CandidateRanker::verdict(30, 4.0, Some(1.0), 0.5),
+            TradabilityVerdict::Marginal
+        );
+        assert_eq!(
+            CandidateRanker::verdict(30, 4.0, Some(1.0), 0.2),
+            TradabilityVerdict::Tradable
+        );
+
+        let ranked = CandidateRanker::classer(vec![
+            candidat("EURUSD", TradabilityVerdict::Marginal, 8.0),
+            candidat("XAUUSD", TradabilityVerdict::Tradable, 3.0),
+            candidat("USDJPY", TradabilityVerdict::Tradable, 5.0),
+        ]);
+        let order: Vec<(&str, usize)> = ranked.iter().map(|c| (c.pair.as_str(), c.rank)).collect();
+        assert_eq!(order, vec![("USDJPY", 1), ("XAUUSD", 2), ("EURUSD", 3)]);
+    }
+}
@@ -0,0 +1,146 @@
+// services/planning/live_candidates.rs - Projections calculées par instrument candidat d'une annonce
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::calendar_event::CalendarEvent;
+use crate::models::planning::{CandidateRelation, ProjectedEvent};
+use crate::models::trade_journal::ExecutionCost;
+use crate::models::TimeDecay;
+use crate::schema::calendar_events;
+use crate::services::planning::candidate_ranking::CandidateRanker;
+use crate::services::planning::live_projection::{LiveProjection, LiveProjector};
+use crate::services::planning::projection_engine::ProjectionEngine;
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel::SelectableHelper;
+use std::collections::HashMap;
+
+/// Données lues une seule fois par appel de projection
+#[derive(Default)]
+pub(super) struct LiveContext {
+    pub(super) symbols: Option<Vec<String>>,
+    /// Occurrences passées par (description, devise) : un même intitulé existe pour chaque devise
+    pub(super) histories: HashMap<(String, String), Vec<CalendarEvent>>,
+    pub(super) decay: TimeDecay,
+    pub(super) costs: Vec<ExecutionCost>,
+}
+
+impl ProjectionEngine {
+    /// Projections de chaque instrument lié à la devise, classées (vide sans projection live ni
+    /// historique) ; une base illisible est une erreur, pas une absence de candidat
+    pub(super) async fn rank_candidates(
+        &self,
+        event: &CalendarEvent,
+        context: &mut LiveContext,
+    ) -> Result<Vec<LiveProjection>, String> {
+        let (Some(loader), Some(cache)) = (self.pairs_loader.as_ref(), self.live_cache.as_ref())
+        else {
+            return Ok(Vec::new());
+        };
+
+        if context.symbols.is_none() {
+            let symbols = loader
+                .get_all_symbols()
+                .map_err(|e| format!("Paires disponibles illisibles: {}", e))?;
+            context.symbols = Some(symbols);
+        }
+        let universe: Vec<(String, CandidateRelation)> = context
+            .symbols
+            .iter()
+            .flatten()
+            .filter_map(|pair| {
+                CandidateRanker::relation(pair, &event.symbol).map(|r| (pair.clone(), r))
+            })
+            .collect();
+        if universe.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let history_key = (event.description.clone(), event.symbol.clone());
+        if !context.histories.contains_key(&history_key) {
+            let history = self
+                .fetch_event_history(&event.description, &event.symbol)
+                .map_err(|e| format!("Historique {} illisible: {}", event.description, e))?;
+            context.histories.insert(history_key.clone(), history);
+        }
+        let Some(history) = context.histories.get(&history_key) else {
+            return Ok(Vec::new());
+        };
+
+        let projector = LiveProjector::new(loader, cache, context.decay, &context.costs);
+        let mut projections = Vec::new();
+        for (pair, relation) in &universe {
+            if let Some(mut live) = projector
+                .projeter(&event.description, &event.symbol, pair, history)
+                .await?
+            {
+                live.candidate.relation = *relation;
+                projections.push(live);
+            }
+        }
+
+        // Classement sur les candidats, puis projections remises dans le même ordre
+        let ranked =
+            CandidateRanker::classer(projections.iter().map(|l| l.candidate.clone()).collect());
+        Ok(ranked
+            .into_iter()
+            .filter_map(|candidate| {
+                let index = projections.iter().position(|l| l.pair == candidate.pair)?;
+                let mut live = projections.swap_remove(index);
+                live.candidate = candidate;
+                Some(live)
+            })
+            .collect())
+    }
+
+    /// Projection calculée retenue faute d'archive (meilleur candidat du classement)
+    pub(super) fn projected_from_live(
+        &self,
+        event: &CalendarEvent,
+        best: &LiveProjection,
+    ) -> ProjectedEvent {
+        ProjectedEvent {
+            id: event.id.to_string(),
+            time: event.event_time.to_string(),
+            name: event.description.clone(),
+            currency: event.symbol.clone(),
+            impact: event.impact.clone(),
+            pair: best.pair.clone(),
+            offset: best.offset,
+            tp: best.tp,
+            sl: best.sl,
+            offset_simultaneous: Some(best.offset_simultaneous),
+            tp_simultaneous: Some(best.tp_simultaneous),
+            sl_simultaneous: Some(best.sl_simultaneous),
+            confidence_score: best.confidence_score,
+            source: "Live".to_string(),
+            has_history: false,  // Will be updated in caller
+            occurrence_count: 0, // Will be updated in caller
+            confidence_score_ci: best.confidence_score_ci.clone(),
+            significant: false, // Will be updated in caller
+            regime: self.current_regime(&best.pair),
+            regime_matched: false,
+            timeout_minutes: Some(best.timeout_minutes),
+            peak_delay_minutes: best.peak_delay_minutes,
+            candidates: Vec::new(), // Will be updated in caller
+        }
+    }
+
+    /// Occurrences passées d'un type d'événement pour une devise (ordre chronologique)
+    fn fetch_event_history(
+        &self,
+        description: &str,
+        currency: &str,
+    ) -> Result<Vec<CalendarEvent>, String> {
+        let mut conn = self.calendar_pool.get().map_err(|e| e.to_string())?;
+        let now = Utc::now().naive_utc();
+
+        calendar_events::table
+            .filter(calendar_events::description.eq(description))
+            .filter(calendar_events::symbol.eq(currency))
+            .filter(calendar_events::event_time.lt(now))
+            .order(calendar_events::event_time.asc())
+            .select(CalendarEvent::as_select())
+            .load(&mut conn)
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,311 @@
+// services/planning/live_projection.rs - Paramètres Straddle calculés à la volée pour la planification
+// Conforme .clinerules : < 250L, pas d'unwrap()
+
+use crate::models::planning::PairCandidate;
+use crate::models::trade_journal::ExecutionCost;
+use crate::models::{CalendarEvent, ConfidenceInterval, TimeDecay};
+use crate::services::planning::candidate_ranking::{CandidateParams, CandidateRanker};
+use crate::services::planning::projection_score::{intervalle_score_projection, score_projection};
+use crate::services::retrospective::simple_analyzers::{
+    agreger_ponderees, DatedValues, DecayProfileAnalyzer,
+};
+use crate::services::retrospective::RetroAnalysisService;
+use crate::services::straddle_simulator_helpers::cout_execution;
+use crate::services::DatabaseLoader;
+use crate::utils::crc32;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, warn};
+
+/// Paramètres projetés d'un type d'événement sur une paire (pipeline rétrospectif)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveProjection {
+    pub pair: String,
+    pub offset: f64,
+    pub tp: f64,
+    pub sl: f64,
+    pub offset_simultaneous: f64,
+    pub tp_simultaneous: f64,
+    pub sl_simultaneous: f64,
+    pub confidence_score: f64,
+    /// Intervalle bootstrap du score sur les occurrences passées
+    pub confidence_score_ci: Option<ConfidenceInterval>,
+    /// Timeout issu du profil de décroissance (repli : calculateur Bidi)
+    pub timeout_minutes: i32,
+    /// Médiane pondérée du délai du pic de volatilité
+    pub peak_delay_minutes: Option<f64>,
+    /// Occurrences passées du type d'événement
+    pub occurrences: usize,
+    /// Résultats simulés des paramètres sur les dernières occurrences
+    pub candidate: PairCandidate,
+}
+
+/// Un résultat n'est réutilisé que pour le même événement (intitulé et devise), la même
+/// paire et les mêmes données
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectionKey {
+    pub event_type: String,
+    pub currency: String,
+    pub pair: String,
+    pub data_version: String,
+}
+
+/// Cache partagé entre les appels de planification ; None mémorise un calcul impossible
+#[derive(Clone, Default)]
+pub struct ProjectionCache {
+    entries: Arc<Mutex<HashMap<ProjectionKey, Option<LiveProjection>>>>,
+}
+
+impl ProjectionCache {
+    pub fn get(&self, key: &ProjectionKey) -> Option<Option<LiveProjection>> {
+        self.entries.lock().ok()?.get(key).cloned()
+    }
+
+    /// Remplace le résultat de la même paire/événement calculé sur d'anciennes données
+    pub fn insert(&self, key: ProjectionKey, value: Option<LiveProjection>) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|k, _| {
+                k.event_type != key.event_type || k.currency != key.currency || k.pair != key.pair
+            });
+            entries.insert(key, value);
+        }
+    }
+}
+
+/// Calcule (ou relit du cache) la projection d'un type d'événement sur une paire
+pub struct LiveProjector<'a> {
+    loader: &'a DatabaseLoader,
+    cache: &'a ProjectionCache,
+    decay: TimeDecay,
+    /// Glissements observés, lus une fois par appel de projection
+    costs: &'a [ExecutionCost],
+}
+
+impl<'a> LiveProjector<'a> {
+    pub fn new(
+        loader: &'a DatabaseLoader,
+        cache: &'a ProjectionCache,
+        decay: TimeDecay,
+        costs: &'a [ExecutionCost],
+    ) -> Self {
+        Self {
+            loader,
+            cache,
+            decay,
+            costs,
+        }
+    }
+
+    /// Version des données : historique M1 de la paire, occurrences passées et import
+    /// calendrier le plus récent, empreinte de la pondération et des coûts de la paire
+    pub fn data_version(&self, pair: &str, history: &[CalendarEvent]) -> Result<String, String> {
+        let candles = self
+            .loader
+            .data_version(pair, "M1")
+            .map_err(|e| e.to_string())?;
+        let last_event = history
+            .last()
+            .map(|e| e.event_time.to_string())
+            .unwrap_or_default();
+        let calendar_import = history
+            .iter()
+            .map(|e| e.calendar_import_id)
+            .max()
+            .unwrap_or_default();
+        let settings = format!("{:?}|{:?}", self.decay, cout_execution(pair, self.costs));
+        Ok(format!(
+            "{}|{}:{}:{}|{:08x}",
+            candles,
+            history.len(),
+            last_event,
+            calendar_import,
+            crc32(settings.as_bytes())
+        ))
+    }
+
+    /// None si la paire n'a pas de quoi établir une projection ; Err si pairs.db est illisible
+    pub async fn projeter(
+        &self,
+        event_type: &str,
+        currency: &str,
+        pair: &str,
+        history: &[CalendarEvent],
+    ) -> Result<Option<LiveProjection>, String> {
+        let data_version = self.data_version(pair, history)?;
+        let key = ProjectionKey {
+            event_type: event_type.to_string(),
+            currency: currency.to_string(),
+            pair: pair.to_string(),
+            data_version,
+        };
+        if let Some(cached) = self.cache.get(&key) {
+            return Ok(cached);
+        }
+
+        let projection = Self::calculer(
+            pair,
+            event_type,
+            history,
+            self.loader,
+            &self.decay,
+            self.costs,
+        )
+        .await?;
+        self.cache.insert(key, projection.clone());
+        Ok(projection)
+    }
+
+    /// Délai du pic, décroissance et calculateur Bidi sur l'historique stocké
+    pub async fn calculer(
+        pair: &str,
+        event_type: &str,
+        history: &[CalendarEvent],
+        loader: &DatabaseLoader,
+        decay: &TimeDecay,
+        costs: &[ExecutionCost],
+    ) -> Result<Option<LiveProjection>, String> {
+        if history.is_empty() {
+            debug!("📭 Aucune occurrence passée de {}", event_type);
+            return Ok(None);
+        }
+        let impact = match RetroAnalysisService::calculer_impact_evenement(
+            pair, event_type, history, loader, decay,
+        )
+        .await
+        {
+            Ok(impact) => impact,
+            Err(e) => {
+                debug!("📭 Projection {} / {} impossible: {}", event_type, pair, e);
+                return Ok(None);
+            }
+        };
+
+        // Sans délai mesuré, la projection est gardée sans délai du pic (jamais 0 minute)
+        let peak =
+            RetroAnalysisService::calculer_delai_pic(pair, event_type, history, loader).await;
+        let peak_delays = match peak {
+            Ok((delays, _)) => delays,
+            Err(e) => {
+                warn!(
+                    "⚠️ Délai du pic {} / {} indisponible: {}",
+                    event_type, pair, e
+                );
+                Vec::new()
+            }
+        };
+        let measured = peak_delays.len();
+        let delays: DatedValues<f64> = peak_delays.iter().map(|(d, v)| (*d, *v as f64)).collect();
+        let peak_delay_minutes = (!delays.is_empty()).then(|| agreger_ponderees(&delays, decay).1);
+
+        let timeout_minutes =
+            match RetroAnalysisService::calculer_profil_decroissance(pair, history, loader).await {
+                Ok((rates, _)) => i32::from(
+                    DecayProfileAnalyzer::timeout_recommande(agreger_ponderees(&rates, decay).0).1,
+                ),
+                Err(_) => impact.timeout,
+            };
+
+        let params = CandidateParams {
+            offset: impact.offset,
+            tp: impact.trailing_stop,
+            sl: impact.stop_loss,
+            timeout_minutes,
+        };
+        let confidence_score =
+            score_projection(history.len(), measured, impact.volatility_increase_percent);
+        let confidence_score_ci = intervalle_score_projection(
+            history.len(),
+            measured,
+            impact.volatility_increase_percent,
+        );
+        let candidate = PairCandidate {
+            confidence_score,
+            ..CandidateRanker::evaluer(
+                pair,
+                history,
+                params,
+                loader,
+                decay,
+                &cout_execution(pair, costs),
+            )?
+        };
+
+        Ok(Some(LiveProjection {
+            pair: pair.to_string(),
+            offset: impact.offset,
+            tp: impact.trailing_stop,
+            sl: impact.stop_loss,
+            offset_simultaneous: impact.offset_simultaneous,
+            tp_simultaneous: impact.trailing_stop_simultaneous,
+            sl_simultaneous: impact.stop_loss_simultaneous,
+            confidence_score,
+            confidence_score_ci,
+            timeout_minutes,
+            peak_delay_minutes,
+            occurrences: history.len(),
+            candidate,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projection(pair: &str, offset: f64) -> LiveProjection {
+        LiveProjection {
+            pair: pair.to_string(),
+            offset,
+            tp: 0.0,
+            sl: 0.0,
+            offset_simultaneous: 0.0,
+            tp_simultaneous: 0.0,
+            sl_simultaneous: 0.0,
+            confidence_score: 50.0,
+            confidence_score_ci: None,
+            timeout_minutes: 25,
+            peak_delay_minutes: None,
+            occurrences: 30,
+            candidate: CandidateRanker::sans_resultat(
+                pair,
+                CandidateParams {
+                    offset,
+                    tp: 0.0,
+                    sl: 0.0,
+                    timeout_minutes: 25,
+                },
+            ),
+        }
+    }
+
+    #[test]
+    fn test_cache_invalidated_by_new_data() {
+        let cache = ProjectionCache::default();
+        let key = |version: &str| ProjectionKey {
+            event_type: "Non-Farm Payrolls".to_string(),
+            currency: "USD".to_string(),
+            pair: "EURUSD".to_string(),
+            data_version: version.to_string(),
+        };
+        cache.insert(key("v1"), Some(projection("EURUSD", 12.0)));
+        assert_eq!(
+            cache.get(&key("v1")).flatten().map(|p| p.offset),
+            Some(12.0)
+        );
+        assert!(cache.get(&key("v2")).is_none());
+
+        // Nouvel import : l'ancienne version est remplacée, un échec est aussi mémorisé
+        cache.insert(key("v2"), None);
+        assert!(cache.get(&key("v1")).is_none());
+        assert_eq!(cache.get(&key("v2")), Some(None));
+
+        // Même intitulé publié pour une autre devise : entrée distincte
+        let eur = ProjectionKey {
+            currency: "EUR".to_string(),
+            ..key("v2")
+        };
+        assert!(cache.get(&eur).is_none());
+        cache.insert(eur.clone(), Some(projection("EURUSD", 8.0)));
+        assert_eq!(cache.get(&key("v2")), Some(None));
+    }
+}
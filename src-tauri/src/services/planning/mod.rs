@@ -1 +1,7 @@
+pub mod candidate_ranking;
+mod live_candidates;
+pub mod live_projection;
+pub mod plan_export;
+pub mod plan_reconciliation;
 pub mod projection_engine;
+pub mod projection_score;
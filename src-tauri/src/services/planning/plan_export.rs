@@ -0,0 +1,330 @@
+// services/planning/plan_export.rs - Plan hebdomadaire lisible par l'EA : JSON, CSV et .set MT5
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::planning::ProjectedEvent;
+use crate::models::trading_plan::{
+    PlanEntry, PlanExportResult, PlanParameters, TradingPlan, PLAN_FORMAT_VERSION,
+};
+use crate::models::{BrokerClock, MT5_TIME_FORMAT};
+use crate::services::SymbolRegistryService;
+use crate::utils::{crc32, parse_event_time};
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tracing::info;
+
+/// Timeout écrit quand la projection (archive) n'en fournit pas
+const DEFAULT_PLAN_TIMEOUT_MINUTES: i32 = 30;
+const CSV_HEADER: &str =
+    "id;symbol;pair;time_utc;time_broker;mode;offset;tp;sl;timeout;confidence;event";
+
+pub struct PlanExporter;
+
+impl PlanExporter {
+    /// Plan des annonces projetées sur une paire (les annonces sans projection sont omises)
+    pub fn construire(
+        events: &[ProjectedEvent],
+        period: (DateTime<Utc>, DateTime<Utc>),
+        clock: BrokerClock,
+        symbol_map: &HashMap<String, String>,
+        generated_at: DateTime<Utc>,
+    ) -> Result<TradingPlan, String> {
+        clock.validate()?;
+        let mut entries = Vec::new();
+        for event in events.iter().filter(|e| e.pair != "N/A") {
+            let time = parse_event_time(&event.time)?;
+            entries.push(PlanEntry {
+                id: event.id.clone(),
+                event: event.name.clone(),
+                currency: event.currency.clone(),
+                impact: event.impact.clone(),
+                pair: event.pair.clone(),
+                broker_symbol: broker_symbol(&event.pair, symbol_map),
+                time_utc: time.to_rfc3339_opts(SecondsFormat::Secs, true),
+                time_broker: clock.to_broker(time).format(MT5_TIME_FORMAT).to_string(),
+                directionnel: points(event.offset, event.tp, event.sl),
+                simultane: match (
+                    event.offset_simultaneous,
+                    event.tp_simultaneous,
+                    event.sl_simultaneous,
+                ) {
+                    (Some(offset), Some(tp), Some(sl)) => Some(points(offset, tp, sl)),
+                    _ => None,
+                },
+                timeout_minutes: event
+                    .timeout_minutes
+                    .unwrap_or(DEFAULT_PLAN_TIMEOUT_MINUTES),
+                confidence_score: event.confidence_score,
+                significant: event.significant,
+                verdict: event
+                    .candidates
+                    .iter()
+                    .find(|c| c.pair == event.pair)
+                    .map(|c| c.verdict),
+                source: event.source.clone(),
+            });
+        }
+        entries.sort_by(|a, b| a.time_utc.cmp(&b.time_utc).then(a.id.cmp(&b.id)));
+
+        let checksum = Self::checksum(&entries);
+        Ok(TradingPlan {
+            version: PLAN_FORMAT_VERSION,
+            generated_at: generated_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            period_start: period.0.to_rfc3339_opts(SecondsFormat::Secs, true),
+            period_end: period.1.to_rfc3339_opts(SecondsFormat::Secs, true),
+            broker_clock: clock,
+            entries,
+            checksum,
+        })
+    }
+
+    /// Une ligne par mode : Directionnel puis Simultané (si la projection en a un)
+    pub fn lignes_csv(entries: &[PlanEntry]) -> Vec<String> {
+        let mut lines = Vec::with_capacity(entries.len() * 2);
+        for entry in entries {
+            let modes = [
+                ("directionnel", Some(entry.directionnel)),
+                ("simultane", entry.simultane),
+            ];
+            for (mode, params) in modes
+                .into_iter()
+                .filter_map(|(mode, params)| Some((mode, params?)))
+            {
+                lines.push(format!(
+                    "{};{};{};{};{};{};{:.0};{:.0};{:.0};{};{:.0};{}",
+                    entry.id,
+                    entry.broker_symbol,
+                    entry.pair,
+                    entry.time_utc,
+                    entry.time_broker,
+                    mode,
+                    params.offset,
+                    params.tp,
+                    params.sl,
+                    entry.timeout_minutes,
+                    entry.confidence_score,
+                    entry.event.replace([';', '\n', '\r'], " "),
+                ));
+            }
+        }
+        lines
+    }
+
+    pub fn checksum(entries: &[PlanEntry]) -> String {
+        format!(
+            "{:08x}",
+            crc32(Self::lignes_csv(entries).join("\n").as_bytes())
+        )
+    }
+
+    /// Vrai si les lignes du plan n'ont pas été modifiées depuis l'export
+    pub fn verifier(plan: &TradingPlan) -> bool {
+        plan.checksum
+            .eq_ignore_ascii_case(&Self::checksum(&plan.entries))
+    }
+
+    pub fn rendre_csv(plan: &TradingPlan) -> String {
+        let mut out = format!("{}\n", CSV_HEADER);
+        for line in Self::lignes_csv(&plan.entries) {
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Fichier de paramètres d'entrée MT5 : E<n>_* par annonce, PlanChecksum pour contrôle
+    pub fn rendre_set(plan: &TradingPlan) -> String {
+        let mut out = format!(
+            "; Plan de trading {} → {} (généré le {})\nPlanVersion={}\nPlanChecksum={}\nPlanEvents={}\n",
+            plan.period_start,
+            plan.period_end,
+            plan.generated_at,
+            plan.version,
+            plan.checksum,
+            plan.entries.len()
+        );
+        for (i, entry) in plan.entries.iter().enumerate() {
+            let n = i + 1;
+            let mut fields = vec![
+                ("Id", entry.id.clone()),
+                ("Symbol", entry.broker_symbol.clone()),
+                ("TimeBroker", entry.time_broker.clone()),
+                ("TimeUtc", entry.time_utc.clone()),
+                ("Timeout", entry.timeout_minutes.to_string()),
+                ("Offset", format!("{:.0}", entry.directionnel.offset)),
+                ("TP", format!("{:.0}", entry.directionnel.tp)),
+                ("SL", format!("{:.0}", entry.directionnel.sl)),
+            ];
+            // Sans paramètres Simultané, l'EA ne reçoit que le mode Directionnel
+            if let Some(simultane) = entry.simultane {
+                fields.extend([
+                    ("OffsetSim", format!("{:.0}", simultane.offset)),
+                    ("TPSim", format!("{:.0}", simultane.tp)),
+                    ("SLSim", format!("{:.0}", simultane.sl)),
+                ]);
+            }
+            for (key, value) in fields {
+                out.push_str(&format!("E{}_{}={}\n", n, key, value));
+            }
+        }
+        out
+    }
+
+    /// Écrit plan_<début>.json/.csv/.set dans le dossier choisi
+    pub fn ecrire(plan: &TradingPlan, output_dir: &str) -> Result<PlanExportResult, String> {
+        let dir = Path::new(output_dir);
+        fs::create_dir_all(dir).map_err(|e| format!("Dossier d'export inaccessible: {}", e))?;
+        let stem = format!("plan_{}", plan.period_start.get(..10).unwrap_or("semaine"));
+        let json = serde_json::to_string_pretty(plan)
+            .map_err(|e| format!("Sérialisation du plan impossible: {}", e))?;
+
+        let mut paths = Vec::new();
+        for (extension, content) in [
+            ("json", json),
+            ("csv", Self::rendre_csv(plan)),
+            ("set", Self::rendre_set(plan)),
+        ] {
+            let path = dir.join(format!("{}.{}", stem, extension));
+            fs::write(&path, content)
+                .map_err(|e| format!("Écriture de {} impossible: {}", path.display(), e))?;
+            paths.push(path.to_string_lossy().to_string());
+        }
+        info!(
+            "📤 Plan exporté : {} annonce(s), somme de contrôle {}",
+            plan.entries.len(),
+            plan.checksum
+        );
+
+        let [json_path, csv_path, set_path]: [String; 3] = paths
+            .try_into()
+            .map_err(|_| "Export incomplet".to_string())?;
+        Ok(PlanExportResult {
+            json_path,
+            csv_path,
+            set_path,
+            entries: plan.entries.len(),
+            checksum: plan.checksum.clone(),
+        })
+    }
+}
+
+/// Symbole courtier : correspondance explicite, puis registre, puis nom canonique
+fn broker_symbol(pair: &str, symbol_map: &HashMap<String, String>) -> String {
+    symbol_map
+        .get(pair)
+        .cloned()
+        .or_else(|| SymbolRegistryService::lookup(pair).map(|spec| spec.symbol))
+        .unwrap_or_else(|| pair.to_string())
+}
+
+fn points(offset: f64, tp: f64, sl: f64) -> PlanParameters {
+    PlanParameters {
+        offset: offset.round(),
+        tp: tp.round(),
+        sl: sl.round(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn projected(id: &str, time: &str, pair: &str) -> ProjectedEvent {
+        ProjectedEvent {
+            id: id.to_string(),
+            time: time.to_string(),
+            name: "Non-Farm Payrolls".to_string(),
+            currency: "USD".to_string(),
+            impact: "HIGH".to_string(),
+            pair: pair.to_string(),
+            offset: 120.4,
+            tp: 250.0,
+            sl: 180.0,
+            offset_simultaneous: Some(90.0),
+            tp_simultaneous: Some(200.0),
+            sl_simultaneous: Some(150.0),
+            confidence_score: 72.0,
+            source: "Live".to_string(),
+            has_history: true,
+            occurrence_count: 40,
+            confidence_score_ci: None,
+            significant: true,
+            regime: None,
+            regime_matched: false,
+            timeout_minutes: Some(25),
+            peak_delay_minutes: None,
+            candidates: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_plan_layout_and_checksum() {
+        let start = Utc
+            .with_ymd_and_hms(2024, 6, 3, 0, 0, 0)
+            .single()
+            .unwrap_or_default();
+        let events = vec![
+            projected("12", "2024-06-07 12:30:00", "EURUSD"),
+            projected("7", "2024-06-05 14:00:00", "N/A"),
+        ];
+        let map = HashMap::from([("EURUSD".to_string(), "EURUSD.r".to_string())]);
+        let plan =
+            PlanExporter::construire(&events, (start, start), BrokerClock::default(), &map, start)
+                .expect("plan");
+
+        assert_eq!(plan.entries.len(), 1);
+        let entry = &plan.entries[0];
+        assert_eq!(entry.broker_symbol, "EURUSD.r");
+        assert_eq!(entry.time_utc, "2024-06-07T12:30:00Z");
+        assert_eq!(entry.time_broker, "2024.06.07 15:30");
+        assert_eq!(entry.directionnel.offset, 120.0);
+
+        let csv = PlanExporter::rendre_csv(&plan);
+        assert_eq!(csv.lines().count(), 3);
+        assert!(csv.contains(
+            "12;EURUSD.r;EURUSD;2024-06-07T12:30:00Z;2024.06.07 15:30;simultane;90;200;150;25;72;"
+        ));
+        let set = PlanExporter::rendre_set(&plan);
+        assert!(set.contains("PlanEvents=1\n"));
+        assert!(set.contains("E1_SLSim=150\n"));
+        assert!(set.contains(&format!("PlanChecksum={}\n", plan.checksum)));
+
+        assert!(PlanExporter::verifier(&plan));
+        let mut edited = plan.clone();
+        edited.entries[0].directionnel.sl = 500.0;
+        assert!(!PlanExporter::verifier(&edited));
+    }
+
+    #[test]
+    fn test_missing_simultaneous_set_left_out() {
+        let start = Utc
+            .with_ymd_and_hms(2024, 6, 3, 0, 0, 0)
+            .single()
+            .unwrap_or_default();
+        // Archive sans paramètres Simultané : jamais exportée avec des zéros
+        let events = vec![ProjectedEvent {
+            offset_simultaneous: None,
+            tp_simultaneous: None,
+            sl_simultaneous: None,
+            ..projected("12", "2024-06-07 12:30:00", "EURUSD")
+        }];
+        let plan = PlanExporter::construire(
+            &events,
+            (start, start),
+            BrokerClock::default(),
+            &HashMap::new(),
+            start,
+        )
+        .expect("plan");
+        assert_eq!(plan.entries[0].simultane, None);
+
+        let csv = PlanExporter::rendre_csv(&plan);
+        assert_eq!(csv.lines().count(), 2);
+        assert!(!csv.contains("simultane"));
+        let set = PlanExporter::rendre_set(&plan);
+        assert!(set.contains("E1_SL=180\n"));
+        assert!(!set.contains("Sim="));
+    }
+}
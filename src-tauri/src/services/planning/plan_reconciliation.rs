@@ -0,0 +1,293 @@
+// services/planning/plan_reconciliation.rs - Rapprochement des transactions MT5 avec le plan exporté
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::trading_plan::{
+    EntryReconciliation, ExecutedDeal, PlanEntry, PlanReconciliation, ReconciliationStatus,
+    TradingPlan,
+};
+use crate::models::BrokerClock;
+use crate::services::planning::plan_export::PlanExporter;
+use crate::services::symbol_registry::{parse_csv_rows, Row};
+use crate::services::SymbolRegistryService;
+use chrono::{DateTime, Duration, NaiveDateTime, SecondsFormat, Utc};
+use tracing::info;
+
+/// Ordres acceptés avant l'heure annoncée (horloge EA en avance)
+pub(crate) const EARLY_TOLERANCE_MINUTES: i64 = 5;
+/// Clôtures acceptées après le timeout de l'annonce
+pub(crate) const LATE_CLOSE_MINUTES: i64 = 60;
+const DEAL_TIME_FORMATS: [&str; 4] = [
+    "%Y.%m.%d %H:%M:%S",
+    "%Y.%m.%d %H:%M",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+];
+
+/// Lit l'historique des transactions (export CSV MT5), heures serveur converties en UTC.
+/// Les lignes hors achat/vente (dépôts, crédits) sont ignorées sans être signalées.
+pub fn lire_transactions(
+    content: &str,
+    clock: &BrokerClock,
+) -> Result<(Vec<ExecutedDeal>, Vec<String>), String> {
+    let content = content.trim_start_matches('\u{feff}').trim();
+    if content.is_empty() {
+        return Err("Historique des transactions vide".to_string());
+    }
+    Ok(transactions_depuis_lignes(&parse_csv_rows(content)?, clock))
+}
+
+/// Transactions d'un tableau déjà découpé en lignes (clés normalisées, en-tête en ligne 1)
+pub(crate) fn transactions_depuis_lignes(
+    rows: &[Row],
+    clock: &BrokerClock,
+) -> (Vec<ExecutedDeal>, Vec<String>) {
+    let mut deals = Vec::new();
+    let mut skipped = Vec::new();
+    for (idx, row) in rows.iter().enumerate() {
+        match row_to_deal(row, clock) {
+            Ok(Some(deal)) => deals.push(deal),
+            Ok(None) => {}
+            Err(e) => skipped.push(format!("Ligne {}: {}", idx + 2, e)),
+        }
+    }
+    deals.sort_by(|a, b| a.time_utc.cmp(&b.time_utc));
+    (deals, skipped)
+}
+
+fn field<'a>(row: &'a Row, keys: &[&str]) -> Option<&'a str> {
+    keys.iter()
+        .filter_map(|k| row.get(*k))
+        .map(|v| v.trim())
+        .find(|v| !v.is_empty())
+}
+
+fn amount(row: &Row, key: &str) -> Result<f64, String> {
+    match field(row, &[key]) {
+        Some(raw) => raw
+            .replace([' ', '\u{a0}'], "")
+            .replace(',', ".")
+            .parse::<f64>()
+            .map_err(|_| format!("{} invalide: {}", key, raw)),
+        None => Ok(0.0),
+    }
+}
+
+fn row_to_deal(row: &Row, clock: &BrokerClock) -> Result<Option<ExecutedDeal>, String> {
+    let side = field(row, &["type"]).unwrap_or_default().to_lowercase();
+    if side != "buy" && side != "sell" {
+        return Ok(None);
+    }
+    let raw_time = field(row, &["time", "date"]).ok_or("Heure manquante")?;
+    let broker_time = DEAL_TIME_FORMATS
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(raw_time, f).ok())
+        .ok_or_else(|| format!("Heure invalide: {}", raw_time))?;
+    let symbol = field(row, &["symbol"]).ok_or("Symbole manquant")?;
+    let direction = field(row, &["direction", "entry"])
+        .unwrap_or("in")
+        .to_lowercase();
+
+    Ok(Some(ExecutedDeal {
+        time_utc: clock
+            .to_utc(broker_time)
+            .to_rfc3339_opts(SecondsFormat::Secs, true),
+        symbol: symbol.to_string(),
+        side,
+        is_entry: direction == "in",
+        volume: amount(row, "volume")?,
+        price: amount(row, "price")?,
+        net_profit: amount(row, "profit")? + amount(row, "commission")? + amount(row, "swap")?,
+        comment: field(row, &["comment"]).unwrap_or_default().to_string(),
+    }))
+}
+
+pub(crate) fn parse_utc(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Même instrument : symbole courtier exact ou même nom canonique
+fn meme_instrument(entry: &PlanEntry, deal: &ExecutedDeal) -> bool {
+    deal.symbol.eq_ignore_ascii_case(&entry.broker_symbol)
+        || SymbolRegistryService::canonical(&deal.symbol)
+            == SymbolRegistryService::canonical(&entry.pair)
+}
+
+/// Le commentaire d'ordre reprend l'identifiant de l'annonce (ex. "plan#12")
+fn commentaire_cite(entry: &PlanEntry, deal: &ExecutedDeal) -> bool {
+    deal.comment
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|token| token == entry.id)
+}
+
+/// Associe chaque transaction à une annonce : identifiant en commentaire, sinon la fenêtre
+/// [T0 - 5 min, T0 + timeout + 60 min] la plus proche sur le même instrument
+pub fn rapprocher(
+    plan: &TradingPlan,
+    deals: Vec<ExecutedDeal>,
+    skipped: Vec<String>,
+) -> PlanReconciliation {
+    let times: Vec<Option<DateTime<Utc>>> = plan
+        .entries
+        .iter()
+        .map(|e| parse_utc(&e.time_utc))
+        .collect();
+    let mut assigned: Vec<Vec<ExecutedDeal>> = vec![Vec::new(); plan.entries.len()];
+    let mut unplanned = Vec::new();
+
+    for deal in deals {
+        let deal_time = parse_utc(&deal.time_utc);
+        let by_comment = plan
+            .entries
+            .iter()
+            .position(|e| meme_instrument(e, &deal) && commentaire_cite(e, &deal));
+        let by_window = || {
+            let deal_time = deal_time?;
+            plan.entries
+                .iter()
+                .zip(&times)
+                .enumerate()
+                .filter(|(_, (entry, _))| meme_instrument(entry, &deal))
+                .filter_map(|(i, (entry, time))| {
+                    let t0 = (*time)?;
+                    let start = t0 - Duration::minutes(EARLY_TOLERANCE_MINUTES);
+                    let end =
+                        t0 + Duration::minutes(entry.timeout_minutes as i64 + LATE_CLOSE_MINUTES);
+                    (deal_time >= start && deal_time <= end)
+                        .then(|| (i, (deal_time - t0).num_seconds().abs()))
+                })
+                .min_by_key(|(_, distance)| *distance)
+                .map(|(i, _)| i)
+        };
+        match by_comment.or_else(by_window) {
+            Some(i) => assigned[i].push(deal),
+            None => unplanned.push(deal),
+        }
+    }
+
+    let entries: Vec<EntryReconciliation> = plan
+        .entries
+        .iter()
+        .zip(times)
+        .zip(assigned)
+        .map(|((entry, time), deals)| {
+            let entry_delay_seconds = time.and_then(|t0| {
+                deals
+                    .iter()
+                    .filter(|d| d.is_entry)
+                    .find_map(|d| parse_utc(&d.time_utc))
+                    .map(|first| (first - t0).num_seconds())
+            });
+            EntryReconciliation {
+                entry: entry.clone(),
+                status: if deals.is_empty() {
+                    ReconciliationStatus::Missed
+                } else {
+                    ReconciliationStatus::Executed
+                },
+                net_profit: deals.iter().map(|d| d.net_profit).sum(),
+                entry_delay_seconds,
+                deals,
+            }
+        })
+        .collect();
+
+    let executed = entries
+        .iter()
+        .filter(|e| e.status == ReconciliationStatus::Executed)
+        .count();
+    let checksum_valid = PlanExporter::verifier(plan);
+    info!(
+        "🧾 Rapprochement du plan : {} exécutée(s), {} manquée(s), {} hors plan, somme {}",
+        executed,
+        entries.len() - executed,
+        unplanned.len(),
+        if checksum_valid {
+            "valide"
+        } else {
+            "modifiée"
+        }
+    );
+
+    PlanReconciliation {
+        checksum_valid,
+        executed,
+        missed: entries.len() - executed,
+        net_profit: entries.iter().map(|e| e.net_profit).sum(),
+        entries,
+        unplanned,
+        skipped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::trading_plan::{PlanParameters, PLAN_FORMAT_VERSION};
+
+    fn entry(id: &str, pair: &str, time_utc: &str) -> PlanEntry {
+        let params = PlanParameters {
+            offset: 100.0,
+            tp: 200.0,
+            sl: 150.0,
+        };
+        PlanEntry {
+            id: id.to_string(),
+            event: "CPI".to_string(),
+            currency: "USD".to_string(),
+            impact: "HIGH".to_string(),
+            pair: pair.to_string(),
+            broker_symbol: format!("{}.r", pair),
+            time_utc: time_utc.to_string(),
+            time_broker: String::new(),
+            directionnel: params,
+            simultane: Some(params),
+            timeout_minutes: 30,
+            confidence_score: 60.0,
+            significant: true,
+            verdict: None,
+            source: "Live".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_reconcile_mt5_history() {
+        let entries = vec![
+            entry("12", "EURUSD", "2024-06-12T12:30:00Z"),
+            entry("15", "XAUUSD", "2024-06-13T12:30:00Z"),
+        ];
+        let plan = TradingPlan {
+            version: PLAN_FORMAT_VERSION,
+            generated_at: String::new(),
+            period_start: String::new(),
+            period_end: String::new(),
+            broker_clock: BrokerClock::default(),
+            checksum: PlanExporter::checksum(&entries),
+            entries,
+        };
+        // Heures serveur (UTC+3 en juin) ; dépôt ignoré, ordre GBPUSD hors plan
+        let history =
+            "Time;Deal;Symbol;Type;Direction;Volume;Price;Commission;Swap;Profit;Comment\n\
+            2024.06.01 10:00:00;1;;balance;;;;0;0;1 000.00;depot\n\
+            2024.06.12 15:30:02;2;EURUSD.r;buy;in;0.5;1.0812;-2.5;0;0;plan#12\n\
+            2024.06.12 15:41:40;3;EURUSD.r;sell;out;0.5;1.0841;-2.5;0;145.00;\n\
+            2024.06.12 18:00:00;4;GBPUSD.r;sell;in;0.5;1.2700;0;0;0;\n\
+            bad;5;EURUSD.r;buy;in;0.5;1.08;0;0;0;\n";
+        let (deals, skipped) = lire_transactions(history, &plan.broker_clock).expect("historique");
+        assert_eq!(deals.len(), 3);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(deals[0].time_utc, "2024-06-12T12:30:02Z");
+
+        let report = rapprocher(&plan, deals, skipped);
+        assert!(report.checksum_valid);
+        assert_eq!((report.executed, report.missed), (1, 1));
+        let eurusd = &report.entries[0];
+        assert_eq!(eurusd.deals.len(), 2);
+        assert_eq!(eurusd.entry_delay_seconds, Some(2));
+        assert!((eurusd.net_profit - 140.0).abs() < 1e-9);
+        assert_eq!(report.entries[1].status, ReconciliationStatus::Missed);
+        assert_eq!(report.unplanned.len(), 1);
+        assert_eq!(report.unplanned[0].symbol, "GBPUSD.r");
+    }
+}
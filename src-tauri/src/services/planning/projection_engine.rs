@@ -1,16 +1,33 @@
 use crate::db::DbPool;
-use crate::models::planning::ProjectedEvent;
+use crate::models::planning::{PairCandidate, ProjectedEvent};
 use crate::models::calendar_event::CalendarEvent;
 use crate::models::archive::Archive;
+use crate::models::statistics::MIN_SAMPLE_SIZE;
+use crate::models::archive_payload::ArchiveSummary;
+use crate::models::VolatilityRegime;
+use crate::services::archive_schema::ArchiveSchema;
 use crate::services::archive_service::ArchiveService;
+use crate::services::volatility_regime::RegimeClassifier;
+use crate::services::planning::live_candidates::LiveContext;
+use crate::services::planning::live_projection::ProjectionCache;
+use crate::services::planning::projection_score::score_projection;
+use crate::services::{ConfigService, DatabaseLoader, SymbolRegistryService};
+use std::collections::HashMap;
+use std::sync::Mutex;
 use crate::schema::calendar_events;
 use diesel::prelude::*;
 use diesel::SelectableHelper;
 use chrono::{DateTime, Utc};
 
 pub struct ProjectionEngine {
-    calendar_pool: DbPool,
+    pub(super) calendar_pool: DbPool,
     archive_service: ArchiveService,
+    /// Accès à pairs.db : régime actuel et projections calculées de chaque paire
+    pub(super) pairs_loader: Option<DatabaseLoader>,
+    /// Régime actuel par paire (calculé à la première demande)
+    current_regimes: Mutex<HashMap<String, Option<VolatilityRegime>>>,
+    /// Projections calculées à la volée, partagées entre les appels
+    pub(super) live_cache: Option<ProjectionCache>,
 }
 
 impl ProjectionEngine {
@@ -18,9 +35,24 @@ impl ProjectionEngine {
         Self {
             calendar_pool,
             archive_service,
+            pairs_loader: None,
+            current_regimes: Mutex::new(HashMap::new()),
+            live_cache: None,
         }
     }
 
+    /// Privilégie les archives calculées sur le régime actuel de chaque paire
+    pub fn with_pairs_loader(mut self, loader: DatabaseLoader) -> Self {
+        self.pairs_loader = Some(loader);
+        self
+    }
+
+    /// Sans archive, calcule les paramètres sur l'historique stocké (nécessite pairs.db)
+    pub fn with_live_projection(mut self, cache: ProjectionCache) -> Self {
+        self.live_cache = Some(cache);
+        self
+    }
+
     pub async fn project_stats(
         &self,
         start_date: DateTime<Utc>,
@@ -36,16 +68,42 @@ impl ProjectionEngine {
         let known_events = self.fetch_known_event_counts()?;
 
         let mut projected = Vec::new();
+        let mut live_context = LiveContext {
+            decay: ConfigService::time_decay(),
+            costs: ConfigService::execution_costs(),
+            ..LiveContext::default()
+        };
 
         for event in events {
-            let occurrence_count = known_events.get(&event.description).cloned().unwrap_or(0);
+            let occurrence_count = known_events
+                .get(&(event.description.clone(), event.symbol.clone()))
+                .cloned()
+                .unwrap_or(0);
             let has_history = occurrence_count > 0;
+            // Instruments classés sur l'historique, indépendamment des archives
+            let ranked = self.rank_candidates(&event, &mut live_context).await?;
+            let candidates: Vec<PairCandidate> =
+                ranked.iter().map(|live| live.candidate.clone()).collect();
 
             // Find best matching archive
             if let Some(mut match_data) = self.find_best_match(&event, &archives) {
                 match_data.has_history = has_history;
                 match_data.occurrence_count = occurrence_count;
+                match_data.significant = occurrence_count >= MIN_SAMPLE_SIZE as i64
+                    && match_data
+                        .confidence_score_ci
+                        .as_ref()
+                        .is_none_or(|ci| ci.reliable);
+                match_data.candidates = candidates;
                 projected.push(match_data);
+            } else if let Some(best) = ranked.first() {
+                let mut live = self.projected_from_live(&event, best);
+                live.has_history = has_history;
+                live.occurrence_count = occurrence_count;
+                live.significant = occurrence_count >= MIN_SAMPLE_SIZE as i64
+                    && live.confidence_score_ci.as_ref().is_none_or(|ci| ci.reliable);
+                live.candidates = candidates;
+                projected.push(live);
             } else {
                 // Add event without projection
                 projected.push(ProjectedEvent {
@@ -58,13 +116,20 @@ impl ProjectionEngine {
                     offset: 0.0,
                     tp: 0.0,
                     sl: 0.0,
-                    offset_simultaneous: 0.0,
-                    tp_simultaneous: 0.0,
-                    sl_simultaneous: 0.0,
+                    offset_simultaneous: None,
+                    tp_simultaneous: None,
+                    sl_simultaneous: None,
                     confidence_score: 0.0,
                     source: "None".to_string(),
                     has_history,
                     occurrence_count,
+                    confidence_score_ci: None,
+                    significant: false,
+                    regime: None,
+                    regime_matched: false,
+                    timeout_minutes: None,
+                    peak_delay_minutes: None,
+                    candidates,
                 });
             }
         }
@@ -72,7 +137,8 @@ impl ProjectionEngine {
         Ok(projected)
     }
 
-    fn fetch_known_event_counts(&self) -> Result<std::collections::HashMap<String, i64>, String> {
+    /// Occurrences passées par (description, devise)
+    fn fetch_known_event_counts(&self) -> Result<HashMap<(String, String), i64>, String> {
         use diesel::dsl::count;
         
         let mut conn = self.calendar_pool.get().map_err(|e| e.to_string())?;
@@ -81,12 +147,19 @@ impl ProjectionEngine {
         
         let results = calendar_events::table
             .filter(calendar_events::event_time.lt(now))
-            .group_by(calendar_events::description)
-            .select((calendar_events::description, count(calendar_events::id)))
-            .load::<(String, i64)>(&mut conn)
+            .group_by((calendar_events::description, calendar_events::symbol))
+            .select((
+                calendar_events::description,
+                calendar_events::symbol,
+                count(calendar_events::id),
+            ))
+            .load::<(String, String, i64)>(&mut conn)
             .map_err(|e| e.to_string())?;
-            
-        Ok(results.into_iter().collect())
+
+        Ok(results
+            .into_iter()
+            .map(|(description, currency, count)| ((description, currency), count))
+            .collect())
     }
 
     fn fetch_calendar_events(
@@ -111,72 +184,92 @@ impl ProjectionEngine {
 
     fn find_best_match(&self, event: &CalendarEvent, archives: &[Archive]) -> Option<ProjectedEvent> {
         let mut best_match: Option<ProjectedEvent> = None;
-        let mut best_score = -1.0;
+        // (rang de régime, score) : une archive du régime actuel passe devant une
+        // archive tout historique, elle-même devant une archive d'un autre régime
+        let mut best_rank = (0u8, -1.0);
 
         for archive in archives {
-            if let Ok(data) = serde_json::from_str::<serde_json::Value>(&archive.data_json) {
-                let archive_event_type = data.get("event_type").and_then(|v| v.as_str());
-                let archive_pair = data.get("symbol").and_then(|v| v.as_str());
-                
-                if let (Some(arch_evt), Some(pair)) = (archive_event_type, archive_pair) {
-                    if self.events_match(&event.description, arch_evt) {
-                        if self.pair_matches_currency(pair, &event.symbol) {
-                            // Try to get confidence score from various possible locations
-                            let score = data.get("confidence_score").and_then(|v| v.as_f64())
-                                .or_else(|| data.get("recommendation").and_then(|r| r.get("score").and_then(|v| v.as_f64())))
-                                .unwrap_or(0.0);
-                            
-                            if score > best_score {
-                                best_score = score;
-                                
-                                // Extract params (support both legacy straddle_params and new root-level format)
-                                let (offset, tp, sl) = if let Some(params) = data.get("straddle_params") {
-                                    (
-                                        params.get("offset").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                                        params.get("tp").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                                        params.get("sl").and_then(|v| v.as_f64()).unwrap_or(0.0)
-                                    )
-                                } else {
-                                    (
-                                        data.get("offset").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                                        data.get("trailingStop").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                                        data.get("stopLoss").and_then(|v| v.as_f64()).unwrap_or(0.0)
-                                    )
-                                };
-
-                                // Extract simultaneous params
-                                let offset_simultaneous = data.get("offsetSimultaneous").and_then(|v| v.as_f64()).unwrap_or(0.0);
-                                let tp_simultaneous = data.get("trailingStopSimultaneous").and_then(|v| v.as_f64()).unwrap_or(0.0);
-                                let sl_simultaneous = data.get("stopLossSimultaneous").and_then(|v| v.as_f64()).unwrap_or(0.0);
-                                
-                                best_match = Some(ProjectedEvent {
-                                    id: event.id.to_string(),
-                                    time: event.event_time.to_string(),
-                                    name: event.description.clone(),
-                                    currency: event.symbol.clone(),
-                                    impact: event.impact.clone(),
-                                    pair: pair.to_string(),
-                                    offset,
-                                    tp,
-                                    sl,
-                                    offset_simultaneous,
-                                    tp_simultaneous,
-                                    sl_simultaneous,
-                                    confidence_score: score,
-                                    source: "Archive".to_string(),
-                                    has_history: false, // Will be updated in caller
-                                    occurrence_count: 0, // Will be updated in caller
-                                });
-                            }
-                        }
-                    }
+            let summary = match ArchiveSchema::summary(archive) {
+                Ok(ArchiveSummary::EventImpact(summary)) => summary,
+                Ok(_) => continue,
+                Err(e) => {
+                    tracing::debug!("📭 Archive {} ignorée: {}", archive.id, e);
+                    continue;
                 }
+            };
+            if !self.events_match(&event.description, &summary.event_type)
+                || !self.pair_matches_currency(&summary.pair, &event.symbol)
+            {
+                continue;
+            }
+
+            // Les analyses rétrospectives n'enregistrent pas de score : même barème que le live
+            // Sans compte ni hausse de volatilité archivés, aucun score ne peut être établi
+            let score = match (
+                summary.confidence_score,
+                summary.event_count,
+                summary.volatility_increase_percent,
+            ) {
+                (Some(score), _, _) => score,
+                (None, Some(count), Some(increase)) => score_projection(count, count, increase),
+                _ => {
+                    tracing::debug!("📭 Archive {} sans statistiques d'événement", archive.id);
+                    continue;
+                }
+            };
+            let current_regime = self.current_regime(&summary.pair);
+            let regime_matched = current_regime.is_some() && summary.regime == current_regime;
+            let regime_rank = match (current_regime, summary.regime) {
+                (Some(_), Some(_)) if regime_matched => 2,
+                (Some(_), Some(_)) => 0,
+                _ => 1,
+            };
+
+            if best_match.is_none() || (regime_rank, score) > best_rank {
+                best_rank = (regime_rank, score);
+                best_match = Some(ProjectedEvent {
+                    id: event.id.to_string(),
+                    time: event.event_time.to_string(),
+                    name: event.description.clone(),
+                    currency: event.symbol.clone(),
+                    impact: event.impact.clone(),
+                    pair: summary.pair.clone(),
+                    offset: summary.offset,
+                    tp: summary.trailing_stop,
+                    sl: summary.stop_loss,
+                    offset_simultaneous: summary.offset_simultaneous,
+                    tp_simultaneous: summary.trailing_stop_simultaneous,
+                    sl_simultaneous: summary.stop_loss_simultaneous,
+                    confidence_score: score,
+                    source: "Archive".to_string(),
+                    has_history: false, // Will be updated in caller
+                    occurrence_count: 0, // Will be updated in caller
+                    confidence_score_ci: summary.confidence_score_ci,
+                    significant: false, // Will be updated in caller
+                    regime: current_regime,
+                    regime_matched,
+                    timeout_minutes: summary.timeout_minutes,
+                    peak_delay_minutes: None,
+                    candidates: Vec::new(), // Will be updated in caller
+                });
             }
         }
         
         best_match
     }
 
+    /// Régime actuel d'une paire (None sans accès à pairs.db ou historique trop court)
+    pub(super) fn current_regime(&self, pair: &str) -> Option<VolatilityRegime> {
+        let loader = self.pairs_loader.as_ref()?;
+        let mut cache = self.current_regimes.lock().ok()?;
+        *cache.entry(pair.to_string()).or_insert_with(|| {
+            RegimeClassifier::load(loader, pair)
+                .map_err(|e| tracing::warn!("⚠️ Régime actuel {} indisponible: {}", pair, e))
+                .ok()
+                .and_then(|calendar| calendar.current)
+        })
+    }
+
     fn events_match(&self, calendar_name: &str, archive_name: &str) -> bool {
         let c = calendar_name.to_lowercase();
         let a = archive_name.to_lowercase();
@@ -184,6 +277,12 @@ impl ProjectionEngine {
     }
 
     fn pair_matches_currency(&self, pair: &str, currency: &str) -> bool {
-        pair.contains(currency)
+        // Devises du registre si le symbole est connu (indices, symboles suffixés)
+        match SymbolRegistryService::lookup(pair) {
+            Some(spec) if spec.base_currency.is_some() || spec.quote_currency.is_some() => {
+                spec.involves_currency(currency)
+            }
+            _ => pair.contains(currency),
+        }
     }
 }
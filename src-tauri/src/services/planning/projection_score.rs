@@ -0,0 +1,66 @@
+// services/planning/projection_score.rs - Score de confiance d'une projection et son intervalle
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use crate::models::statistics::MIN_SAMPLE_SIZE;
+use crate::models::ConfidenceInterval;
+use crate::services::statistics::bootstrap_interval;
+
+/// Score (0-100) : force de l'impact et part des occurrences mesurées,
+/// réduit proportionnellement sous MIN_SAMPLE_SIZE occurrences mesurées
+pub fn score_projection(
+    occurrences: usize,
+    measured: usize,
+    volatility_increase_percent: f64,
+) -> f64 {
+    if occurrences == 0 {
+        return 0.0;
+    }
+    let coverage = (measured as f64 / occurrences as f64).min(1.0);
+    let strength = (volatility_increase_percent / 200.0).clamp(0.0, 1.0);
+    let sample = (measured as f64 / MIN_SAMPLE_SIZE as f64).min(1.0);
+    (100.0 * (0.5 * strength + 0.5 * coverage) * sample).round()
+}
+
+/// Bootstrap du score sur les occurrences passées (mesurées ou non) ;
+/// la hausse de volatilité n'est disponible qu'agrégée et reste fixe
+pub fn intervalle_score_projection(
+    occurrences: usize,
+    measured: usize,
+    volatility_increase_percent: f64,
+) -> Option<ConfidenceInterval> {
+    let measured = measured.min(occurrences);
+    bootstrap_interval(occurrences, |indices| {
+        let tirees = indices.iter().filter(|&&i| i < measured).count();
+        score_projection(indices.len(), tirees, volatility_increase_percent)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_needs_sample_and_impact() {
+        assert_eq!(score_projection(0, 0, 300.0), 0.0);
+        assert_eq!(score_projection(40, 40, 200.0), 100.0);
+        // 10 occurrences mesurées sur 20 : moitié du score
+        assert_eq!(score_projection(10, 10, 200.0), 50.0);
+        assert!(score_projection(40, 40, 20.0) < score_projection(40, 40, 150.0));
+    }
+
+    #[test]
+    fn test_interval_brackets_live_score() {
+        assert!(intervalle_score_projection(1, 1, 150.0).is_none());
+
+        let ci = intervalle_score_projection(60, 45, 150.0).expect("intervalle");
+        assert_eq!(ci.estimate, score_projection(60, 45, 150.0));
+        assert!(ci.lower <= ci.estimate && ci.estimate <= ci.upper);
+        assert!(ci.upper > ci.lower);
+        assert_eq!(ci.sample_size, 60);
+
+        // Toutes les occurrences mesurées : le score ne varie pas d'un tirage à l'autre
+        let full = intervalle_score_projection(40, 40, 200.0).expect("intervalle");
+        assert_eq!((full.lower, full.upper), (100.0, 100.0));
+        assert!(full.reliable);
+    }
+}
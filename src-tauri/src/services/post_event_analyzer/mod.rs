@@ -0,0 +1,265 @@
+// services/post_event_analyzer/mod.rs - Continuation ou retour à la moyenne après l'impulsion T+1
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::{
+    AssetProperties, CalendarEvent, Candle, PostEventOccurrence, PostEventReport, SurpriseSize,
+    SurpriseSlice, TimeDecay,
+};
+use crate::services::DatabaseLoader;
+use chrono::{DateTime, Duration, Utc};
+use tracing::debug;
+
+mod profile;
+
+/// Horizon le plus long suivi après l'annonce (minutes)
+pub const POST_EVENT_HORIZON_MINUTES: i64 = 60;
+
+pub struct PostEventAnalyzer;
+
+impl PostEventAnalyzer {
+    /// Charge T0 → T+60 pour chaque occurrence et construit le rapport
+    pub fn analyser(
+        pair: &str,
+        event_type: &str,
+        events: &[CalendarEvent],
+        loader: &DatabaseLoader,
+        decay: &TimeDecay,
+    ) -> Result<PostEventReport, String> {
+        let asset = AssetProperties::from_symbol(pair);
+        let surprises = surprises_standardisees(events);
+        let mut details = Vec::new();
+
+        for (event, z) in events.iter().zip(surprises) {
+            let event_time = event.event_time.and_utc();
+            let candles = loader
+                .load_candles_by_pair(
+                    pair,
+                    "M1",
+                    event_time,
+                    event_time + Duration::minutes(POST_EVENT_HORIZON_MINUTES),
+                )
+                .map_err(|e| format!("Bougies {} à {} illisibles: {}", pair, event_time, e))?;
+            if let Some(occurrence) = Self::mesurer_occurrence(event_time, &candles, &asset, z) {
+                details.push(occurrence);
+            }
+        }
+
+        let overall = Self::profiler(&details, decay).ok_or_else(|| {
+            format!(
+                "Aucune impulsion mesurable pour {} sur {}",
+                event_type, pair
+            )
+        })?;
+        let by_surprise = SurpriseSize::ALL
+            .iter()
+            .map(|&surprise| {
+                let slice: Vec<PostEventOccurrence> = details
+                    .iter()
+                    .filter(|o| o.surprise == surprise)
+                    .cloned()
+                    .collect();
+                SurpriseSlice {
+                    surprise,
+                    profile: Self::profiler(&slice, decay),
+                }
+            })
+            .collect();
+
+        debug!(
+            "🔁 Post-annonce {} / {} : {} occurrences, comportement {:?}",
+            pair, event_type, overall.occurrences, overall.behavior
+        );
+        Ok(PostEventReport {
+            pair: pair.to_string(),
+            event_type: event_type.to_string(),
+            unit: asset.unit.clone(),
+            overall,
+            by_surprise,
+            details,
+            fingerprint: None,
+        })
+    }
+
+    /// Mesure la trajectoire d'une occurrence ; None sans impulsion ou sans bougies
+    pub fn mesurer_occurrence(
+        event_time: DateTime<Utc>,
+        candles: &[Candle],
+        asset: &AssetProperties,
+        surprise_z: Option<f64>,
+    ) -> Option<PostEventOccurrence> {
+        let end = event_time + Duration::minutes(POST_EVENT_HORIZON_MINUTES);
+        let path: Vec<&Candle> = candles
+            .iter()
+            .filter(|c| c.datetime >= event_time && c.datetime < end)
+            .collect();
+        let first = path.first()?;
+        let t0_open = first.open;
+        let impulse = first.close - t0_open;
+        if impulse == 0.0 {
+            return None;
+        }
+        let sens = impulse.signum();
+
+        // Prix à T+h : clôture de la dernière bougie avant T0+h (None si aucune cotation)
+        let retained = |minutes: i64| -> Option<f64> {
+            let limit = event_time + Duration::minutes(minutes);
+            path.iter()
+                .take_while(|c| c.datetime < limit)
+                .last()
+                .map(|c| (c.close - t0_open) / impulse)
+        };
+
+        // Pic dans le sens de l'impulsion, puis recul maximal après ce pic
+        let mut peak = 0.0_f64;
+        let mut deepest_after_peak = 0.0_f64;
+        for candle in &path {
+            let favorable = if sens > 0.0 {
+                candle.high - t0_open
+            } else {
+                t0_open - candle.low
+            };
+            let adverse = if sens > 0.0 {
+                candle.low - t0_open
+            } else {
+                t0_open - candle.high
+            };
+            if favorable > peak {
+                peak = favorable;
+                deepest_after_peak = peak;
+            }
+            deepest_after_peak = deepest_after_peak.min(adverse);
+        }
+        let retracement_depth = if peak > 0.0 {
+            ((peak - deepest_after_peak) / peak).max(0.0)
+        } else {
+            0.0
+        };
+        let retained_60 = retained(POST_EVENT_HORIZON_MINUTES);
+
+        Some(PostEventOccurrence {
+            event_date: event_time.date_naive(),
+            event_time: event_time.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            surprise_z,
+            surprise: SurpriseSize::from_z(surprise_z),
+            impulse_pips: asset.normalize(impulse),
+            retained_5: retained(5),
+            retained_15: retained(15),
+            retained_60,
+            peak_pips: asset.normalize(peak),
+            retracement_depth,
+            reversed: retained_60.is_some_and(|r| r < 0.0),
+        })
+    }
+}
+
+/// Surprise (Actual - Forecast) rapportée à l'écart-type des surprises du type d'événement
+fn surprises_standardisees(events: &[CalendarEvent]) -> Vec<Option<f64>> {
+    let raw: Vec<Option<f64>> = events
+        .iter()
+        .map(|e| match (e.actual, e.forecast) {
+            (Some(actual), Some(forecast)) => Some(actual - forecast),
+            _ => None,
+        })
+        .collect();
+    let known: Vec<f64> = raw.iter().flatten().copied().collect();
+    if known.len() < 2 {
+        return vec![None; events.len()];
+    }
+    let mean = known.iter().sum::<f64>() / known.len() as f64;
+    let std =
+        (known.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (known.len() - 1) as f64).sqrt();
+    raw.into_iter()
+        .map(|d| d.map(|d| if std > 0.0 { d / std } else { 0.0 }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::PostEventBehavior;
+    use chrono::TimeZone;
+
+    fn bougie(time: DateTime<Utc>, open: f64, high: f64, low: f64, close: f64) -> Candle {
+        Candle {
+            id: None,
+            symbol: "EURUSD".to_string(),
+            datetime: time,
+            open,
+            high,
+            low,
+            close,
+            volume: 1.0,
+            spread: None,
+        }
+    }
+
+    #[test]
+    fn test_spike_that_fully_reverses() {
+        let t0 = Utc
+            .with_ymd_and_hms(2024, 5, 3, 12, 30, 0)
+            .single()
+            .unwrap_or_default();
+        // T+1 : +10 pips, pic à +20 pips à T+3, puis retour sous l'ouverture à T+60
+        let mut candles = vec![
+            bougie(t0, 1.1000, 1.1010, 1.1000, 1.1010),
+            bougie(t0 + Duration::minutes(1), 1.1010, 1.1015, 1.1008, 1.1012),
+            bougie(t0 + Duration::minutes(2), 1.1012, 1.1020, 1.1010, 1.1015),
+        ];
+        for m in 3..60 {
+            let close = 1.1015 - (m - 2) as f64 * 0.0000351;
+            candles.push(bougie(
+                t0 + Duration::minutes(m),
+                close,
+                close,
+                close,
+                close,
+            ));
+        }
+
+        let asset = AssetProperties::from_symbol("EURUSD");
+        let occ = PostEventAnalyzer::mesurer_occurrence(t0, &candles, &asset, Some(2.0))
+            .expect("occurrence");
+        assert!((occ.impulse_pips - 10.0).abs() < 1e-6);
+        assert!((occ.peak_pips - 20.0).abs() < 1e-6);
+        assert!(occ.retained_5.is_some_and(|r| (r - 1.5).abs() < 0.1));
+        assert!(occ.reversed);
+        assert!(occ.retracement_depth > 1.0);
+        assert_eq!(occ.surprise, SurpriseSize::Large);
+
+        let profile = PostEventAnalyzer::profiler(&[occ], &TimeDecay::default()).expect("profil");
+        assert_eq!(profile.behavior, PostEventBehavior::MeanReversion);
+        assert_eq!(profile.reversal_probability, 1.0);
+    }
+
+    #[test]
+    fn test_unquoted_horizon_is_left_out() {
+        let t0 = Utc
+            .with_ymd_and_hms(2024, 5, 3, 12, 30, 0)
+            .single()
+            .unwrap_or_default();
+        // Impulsion à T0 puis cotation reprise à T+20 seulement
+        let candles = vec![
+            bougie(t0, 1.1000, 1.1010, 1.1000, 1.1010),
+            bougie(t0 + Duration::minutes(20), 1.0990, 1.0995, 1.0985, 1.0990),
+        ];
+        let asset = AssetProperties::from_symbol("EURUSD");
+        let occ =
+            PostEventAnalyzer::mesurer_occurrence(t0, &candles, &asset, None).expect("occurrence");
+        assert_eq!(occ.retained_5, Some(1.0));
+        assert_eq!(occ.retained_15, Some(1.0));
+        assert!(occ.retained_60.is_some_and(|r| r < 0.0));
+
+        let late = PostEventAnalyzer::mesurer_occurrence(
+            t0 - Duration::minutes(10),
+            &candles,
+            &asset,
+            None,
+        )
+        .expect("occurrence");
+        // Première bougie à T+10 : T+5 non mesurable, T+60 oui
+        assert_eq!(late.retained_5, None);
+        let profile = PostEventAnalyzer::profiler(&[late], &TimeDecay::default()).expect("profil");
+        assert_eq!(profile.retained_5, None);
+        assert!(profile.retained_60.is_some());
+    }
+}
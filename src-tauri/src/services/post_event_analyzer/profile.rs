@@ -0,0 +1,66 @@
+// services/post_event_analyzer/profile.rs - Profil agrégé des trajectoires post-annonce
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::PostEventAnalyzer;
+use crate::models::{PostEventBehavior, PostEventOccurrence, PostEventPathProfile, TimeDecay};
+use crate::services::statistics::{weighted_mean, weighted_quantile, wilson_interval};
+
+impl PostEventAnalyzer {
+    /// Agrège des occurrences ; None si l'ensemble est vide
+    pub fn profiler(
+        occurrences: &[PostEventOccurrence],
+        decay: &TimeDecay,
+    ) -> Option<PostEventPathProfile> {
+        if occurrences.is_empty() {
+            return None;
+        }
+        let dates: Vec<_> = occurrences.iter().map(|o| o.event_date).collect();
+        let weights = decay.weights(&dates);
+        let serie = |f: fn(&PostEventOccurrence) -> f64| -> Vec<f64> {
+            occurrences.iter().map(f).collect()
+        };
+        let median = |values: &[f64]| weighted_quantile(values, &weights, 0.5);
+        // Valeurs d'un horizon et leurs poids, limitées aux occurrences mesurées
+        let horizon = |f: fn(&PostEventOccurrence) -> Option<f64>| -> (Vec<f64>, Vec<f64>) {
+            occurrences
+                .iter()
+                .zip(&weights)
+                .filter_map(|(o, w)| f(o).map(|v| (v, *w)))
+                .unzip()
+        };
+        let median_horizon = |f: fn(&PostEventOccurrence) -> Option<f64>| -> Option<f64> {
+            let (values, horizon_weights) = horizon(f);
+            (!values.is_empty()).then(|| weighted_quantile(&values, &horizon_weights, 0.5))
+        };
+
+        let retained_60 = median_horizon(|o| o.retained_60);
+        let (reversals, reversal_weights) =
+            horizon(|o| o.retained_60.map(|r| if r < 0.0 { 1.0 } else { 0.0 }));
+        let reversal_probability = weighted_mean(&reversals, &reversal_weights);
+        let depths = serie(|o| o.retracement_depth);
+        let abs_impulses = serie(|o| o.impulse_pips.abs());
+
+        let behavior = match retained_60 {
+            Some(r) if r >= 1.0 && reversal_probability < 0.3 => PostEventBehavior::Continuation,
+            Some(r) if r < 0.5 || reversal_probability >= 0.4 => PostEventBehavior::MeanReversion,
+            _ => PostEventBehavior::Mixed,
+        };
+
+        Some(PostEventPathProfile {
+            occurrences: occurrences.len(),
+            mean_impulse_pips: weighted_mean(&abs_impulses, &weights),
+            retained_5: median_horizon(|o| o.retained_5),
+            retained_15: median_horizon(|o| o.retained_15),
+            retained_60,
+            reversal_probability,
+            reversal_ci: wilson_interval(
+                occurrences.iter().filter(|o| o.reversed).count(),
+                reversals.len(),
+            ),
+            median_retracement_depth: median(&depths),
+            p75_retracement_depth: weighted_quantile(&depths, &weights, 0.75),
+            median_peak_pips: median(&serie(|o| o.peak_pips)),
+            behavior,
+        })
+    }
+}
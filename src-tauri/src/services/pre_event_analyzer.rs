@@ -0,0 +1,286 @@
+// services/pre_event_analyzer.rs - Dérive et compression du prix avant publication
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::{
+    AssetProperties, CalendarEvent, Candle, PreEventOccurrence, PreEventProfile, TimeDecay,
+};
+use crate::services::statistics::{mean_interval, weighted_mean};
+use crate::services::DatabaseLoader;
+use chrono::{DateTime, Duration, Utc};
+use tracing::debug;
+
+/// Fenêtre pré-annonce mesurée (T-30 → T0)
+pub const PRE_EVENT_WINDOW_MINUTES: i64 = 30;
+/// Fenêtre post-annonce pour la taille du mouvement (T0 → T+30)
+pub const POST_EVENT_WINDOW_MINUTES: i64 = 30;
+/// Début de la fenêtre de référence (T-120 → T-90, même durée que la fenêtre pré-annonce)
+const BASELINE_START_MINUTES: i64 = 120;
+
+pub struct PreEventAnalyzer;
+
+impl PreEventAnalyzer {
+    /// Charge T-120 → T+30 pour chaque occurrence et agrège le profil
+    pub fn analyser(
+        pair: &str,
+        event_type: &str,
+        events: &[CalendarEvent],
+        loader: &DatabaseLoader,
+        decay: &TimeDecay,
+    ) -> Result<PreEventProfile, String> {
+        let asset = AssetProperties::from_symbol(pair);
+        let mut occurrences = Vec::new();
+
+        for event in events {
+            let event_time = event.event_time.and_utc();
+            let candles = loader
+                .load_candles_by_pair(
+                    pair,
+                    "M1",
+                    event_time - Duration::minutes(BASELINE_START_MINUTES),
+                    event_time + Duration::minutes(POST_EVENT_WINDOW_MINUTES),
+                )
+                .unwrap_or_default();
+            if let Some(occurrence) = Self::mesurer_occurrence(event_time, &candles, &asset) {
+                occurrences.push(occurrence);
+            }
+        }
+
+        if occurrences.is_empty() {
+            return Err(format!(
+                "Pas de bougies autour des occurrences de {} pour {}",
+                event_type, pair
+            ));
+        }
+
+        debug!(
+            "📐 Pré-annonce {} / {} : {}/{} occurrences mesurées",
+            pair,
+            event_type,
+            occurrences.len(),
+            events.len()
+        );
+        Ok(Self::agreger(pair, event_type, &asset, occurrences, decay))
+    }
+
+    /// Mesure une occurrence ; None si une des trois fenêtres est vide
+    pub fn mesurer_occurrence(
+        event_time: DateTime<Utc>,
+        candles: &[Candle],
+        asset: &AssetProperties,
+    ) -> Option<PreEventOccurrence> {
+        let baseline_start = event_time - Duration::minutes(BASELINE_START_MINUTES);
+        let baseline_end = baseline_start + Duration::minutes(PRE_EVENT_WINDOW_MINUTES);
+        let pre_start = event_time - Duration::minutes(PRE_EVENT_WINDOW_MINUTES);
+        let post_end = event_time + Duration::minutes(POST_EVENT_WINDOW_MINUTES);
+
+        let fenetre = |debut: DateTime<Utc>, fin: DateTime<Utc>| -> Vec<&Candle> {
+            candles
+                .iter()
+                .filter(|c| c.datetime >= debut && c.datetime < fin)
+                .collect()
+        };
+        let baseline = fenetre(baseline_start, baseline_end);
+        let pre = fenetre(pre_start, event_time);
+        let post = fenetre(event_time, post_end);
+
+        let (first_pre, last_pre) = (pre.first()?, pre.last()?);
+        let (first_post, last_post) = (post.first()?, post.last()?);
+        let baseline_range = range(&baseline)?;
+        let pre_range = range(&pre)?;
+
+        let t0_open = first_post.open;
+        let post_excursion = post
+            .iter()
+            .map(|c| (c.high - t0_open).abs().max((t0_open - c.low).abs()))
+            .fold(0.0_f64, f64::max);
+
+        Some(PreEventOccurrence {
+            event_date: event_time.date_naive(),
+            event_time: event_time.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            drift_pips: asset.normalize(last_pre.close - first_pre.open),
+            pre_range_pips: asset.normalize(pre_range),
+            baseline_range_pips: asset.normalize(baseline_range),
+            compression_ratio: if baseline_range > 0.0 {
+                pre_range / baseline_range
+            } else {
+                1.0
+            },
+            post_move_pips: asset.normalize(post_excursion),
+            post_direction_pips: asset.normalize(last_post.close - t0_open),
+        })
+    }
+
+    /// Agrège les occurrences (moyennes pondérées par ancienneté, IC sur l'échantillon brut)
+    pub fn agreger(
+        pair: &str,
+        event_type: &str,
+        asset: &AssetProperties,
+        occurrences: Vec<PreEventOccurrence>,
+        decay: &TimeDecay,
+    ) -> PreEventProfile {
+        let dates: Vec<_> = occurrences.iter().map(|o| o.event_date).collect();
+        let weights = decay.weights(&dates);
+        let serie =
+            |f: fn(&PreEventOccurrence) -> f64| -> Vec<f64> { occurrences.iter().map(f).collect() };
+        let drifts = serie(|o| o.drift_pips);
+        let pre_ranges = serie(|o| o.pre_range_pips);
+        let ratios = serie(|o| o.compression_ratio);
+        let post_moves = serie(|o| o.post_move_pips);
+        let abs_drifts: Vec<f64> = drifts.iter().map(|d| d.abs()).collect();
+
+        let part = |flags: Vec<bool>| -> f64 {
+            let flags: Vec<f64> = flags
+                .into_iter()
+                .map(|b| if b { 1.0 } else { 0.0 })
+                .collect();
+            weighted_mean(&flags, &weights)
+        };
+        let drift_up_rate = part(drifts.iter().map(|d| *d > 0.0).collect());
+        let compression_rate = part(ratios.iter().map(|r| *r < 1.0).collect());
+
+        // Continuation : le mouvement post-annonce va dans le sens de la dérive
+        let reference = dates.iter().max().copied().unwrap_or_default();
+        let orientes: Vec<(f64, f64)> = occurrences
+            .iter()
+            .filter(|o| o.drift_pips != 0.0 && o.post_direction_pips != 0.0)
+            .map(|o| {
+                let same = o.drift_pips.signum() == o.post_direction_pips.signum();
+                (
+                    if same { 1.0 } else { 0.0 },
+                    decay.weight(o.event_date, reference),
+                )
+            })
+            .collect();
+        let continuation_rate = if orientes.is_empty() {
+            0.0
+        } else {
+            let (flags, w): (Vec<f64>, Vec<f64>) = orientes.into_iter().unzip();
+            weighted_mean(&flags, &w)
+        };
+
+        let drift_ci = mean_interval(&drifts);
+        let drift_significant = drift_ci
+            .as_ref()
+            .is_some_and(|ci| ci.lower > 0.0 || ci.upper < 0.0);
+        let (range_move_correlation, range_move_slope) = regression(&pre_ranges, &post_moves);
+
+        PreEventProfile {
+            pair: pair.to_string(),
+            event_type: event_type.to_string(),
+            unit: asset.unit.clone(),
+            window_minutes: PRE_EVENT_WINDOW_MINUTES,
+            post_window_minutes: POST_EVENT_WINDOW_MINUTES,
+            occurrences: occurrences.len(),
+            mean_drift_pips: weighted_mean(&drifts, &weights),
+            mean_abs_drift_pips: weighted_mean(&abs_drifts, &weights),
+            drift_ci,
+            drift_up_rate,
+            drift_significant,
+            mean_pre_range_pips: weighted_mean(&pre_ranges, &weights),
+            mean_compression_ratio: weighted_mean(&ratios, &weights),
+            compression_ci: mean_interval(&ratios),
+            compression_rate,
+            mean_post_move_pips: weighted_mean(&post_moves, &weights),
+            continuation_rate,
+            range_move_correlation,
+            range_move_slope,
+            details: occurrences,
+        }
+    }
+}
+
+fn range(candles: &[&Candle]) -> Option<f64> {
+    let high = candles
+        .iter()
+        .map(|c| c.high)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let low = candles.iter().map(|c| c.low).fold(f64::INFINITY, f64::min);
+    (high.is_finite() && low.is_finite()).then_some(high - low)
+}
+
+/// Corrélation de Pearson et pente des moindres carrés de y sur x (0 si dégénéré)
+fn regression(x: &[f64], y: &[f64]) -> (f64, f64) {
+    let n = x.len().min(y.len());
+    if n < 3 {
+        return (0.0, 0.0);
+    }
+    let mean_x = x[..n].iter().sum::<f64>() / n as f64;
+    let mean_y = y[..n].iter().sum::<f64>() / n as f64;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (xi, yi) in x[..n].iter().zip(&y[..n]) {
+        cov += (xi - mean_x) * (yi - mean_y);
+        var_x += (xi - mean_x).powi(2);
+        var_y += (yi - mean_y).powi(2);
+    }
+    if var_x <= 0.0 || var_y <= 0.0 {
+        return (0.0, 0.0);
+    }
+    (cov / (var_x * var_y).sqrt(), cov / var_x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn bougie(time: DateTime<Utc>, open: f64, close: f64, spread: f64) -> Candle {
+        Candle {
+            id: None,
+            symbol: "EURUSD".to_string(),
+            datetime: time,
+            open,
+            high: open.max(close) + spread,
+            low: open.min(close) - spread,
+            close,
+            volume: 1.0,
+            spread: None,
+        }
+    }
+
+    #[test]
+    fn test_occurrence_measures_drift_and_compression() {
+        let t0 = Utc
+            .with_ymd_and_hms(2024, 3, 8, 13, 30, 0)
+            .single()
+            .unwrap_or_default();
+        let mut candles = Vec::new();
+        // Référence T-120..T-90 : bougies larges de 10 pips
+        for m in 0..30 {
+            let t = t0 - Duration::minutes(120 - m);
+            candles.push(bougie(t, 1.1000, 1.1000, 0.0005));
+        }
+        // Pré-annonce : montée régulière de 0.1 pip par minute, bougies étroites
+        for m in 0..30 {
+            let t = t0 - Duration::minutes(30 - m);
+            let open = 1.1000 + m as f64 * 0.00001;
+            candles.push(bougie(t, open, open + 0.00001, 0.00005));
+        }
+        // Post-annonce : hausse de 20 pips
+        for m in 0..30 {
+            let t = t0 + Duration::minutes(m);
+            let open = 1.1003 + m as f64 * 0.0001;
+            candles.push(bougie(t, open, open + 0.0001, 0.0));
+        }
+
+        let asset = AssetProperties::from_symbol("EURUSD");
+        let occ = PreEventAnalyzer::mesurer_occurrence(t0, &candles, &asset).expect("occurrence");
+        assert!((occ.drift_pips - 3.0).abs() < 1e-6);
+        assert!(occ.compression_ratio < 0.5);
+        assert!((occ.post_move_pips - 30.0).abs() < 1e-6);
+        assert!(occ.post_direction_pips > 0.0);
+
+        let profile =
+            PreEventAnalyzer::agreger("EURUSD", "NFP", &asset, vec![occ], &TimeDecay::default());
+        assert_eq!(profile.continuation_rate, 1.0);
+        assert_eq!(profile.compression_rate, 1.0);
+        assert!(!profile.drift_significant);
+    }
+
+    #[test]
+    fn test_regression_recovers_linear_relation() {
+        let (r, slope) = regression(&[1.0, 2.0, 3.0, 4.0], &[3.0, 5.0, 7.0, 9.0]);
+        assert!((r - 1.0).abs() < 1e-12);
+        assert!((slope - 2.0).abs() < 1e-12);
+        assert_eq!(regression(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]), (0.0, 0.0));
+    }
+}
@@ -10,7 +10,8 @@ impl ImpactAnalyzer {
         event_type: &str,
         events: &[crate::models::CalendarEvent],
         loader: &crate::services::DatabaseLoader,
-    ) -> Result<super::types::EventImpactResult, String> {
+        decay: &crate::models::TimeDecay,
+    ) -> Result<crate::models::EventImpactResult, String> {
         if events.is_empty() {
             return Err("No events found".into());
         }
@@ -26,7 +27,7 @@ impl ImpactAnalyzer {
             return Err(format!("No candle data for: {}", pair));
         }
 
-        let data = ImpactDataProcessor::process(events, &all_candles);
+        let data = ImpactDataProcessor::process(events, &all_candles, decay);
 
         let event_datetime = chrono::DateTime::<chrono::Utc>::from_timestamp(data.avg_timestamp, 0)
             .map(|dt| dt.format("%Y-%m-%dT%H:%M:%SZ").to_string())
@@ -45,7 +46,7 @@ impl ImpactAnalyzer {
             data.p95_wick,
         );
 
-        Ok(super::types::EventImpactResult {
+        Ok(crate::models::EventImpactResult {
             atr_timeline_before: data.atr_timeline_before,
             atr_timeline_after: data.atr_timeline_after,
             body_timeline_before: data.body_timeline_before,
@@ -71,6 +72,7 @@ impl ImpactAnalyzer {
             offset_simultaneous: bidi_params.8,
             stop_loss_recovery_simultaneous: bidi_params.9,
             point_value,
+            fingerprint: None,
         })
     }
 }
@@ -1,4 +1,10 @@
-use super::helpers::calculer_atr;
+// services/retrospective/impact_data_processor.rs - Timelines ATR/corps et bruit autour des
+// occurrences d'un événement, pondérés par ancienneté
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::TimeDecay;
+use crate::services::indicators::{true_ranges, M1_STEP_MINUTES};
+use crate::services::statistics::weighted_quantile;
 use chrono::Duration;
 
 pub struct ImpactDataProcessor;
@@ -21,21 +27,32 @@ impl ImpactDataProcessor {
     pub fn process(
         events: &[crate::models::CalendarEvent],
         all_candles: &[crate::models::Candle],
+        decay: &TimeDecay,
     ) -> ProcessedImpactData {
         let mut atr_before_sum = vec![0.0; 30];
         let mut atr_after_sum = vec![0.0; 90];
         let mut body_before_sum = vec![0.0; 30];
         let mut body_after_sum = vec![0.0; 90];
-        let mut counts_before = vec![0usize; 30];
-        let mut counts_after = vec![0usize; 90];
+        // Sommes de poids (par ancienneté ; 1.0 par occurrence sans demi-vie)
+        let mut counts_before = vec![0.0; 30];
+        let mut counts_after = vec![0.0; 90];
         let mut noise_before_sum = 0.0;
         let mut noise_during_sum = 0.0;
         let mut noise_after_sum = 0.0;
+        let mut weight_sum = 0.0;
         let mut event_count = 0;
         let mut all_wicks = Vec::new();
+        let mut wick_weights = Vec::new();
+        let reference = events
+            .iter()
+            .map(|e| e.event_time.date())
+            .max()
+            .unwrap_or_default();
 
         for event in events {
             event_count += 1;
+            let weight = decay.weight(event.event_time.date(), reference);
+            weight_sum += weight;
             let event_time = event.event_time.and_utc();
             let window_start = event_time - Duration::minutes(30);
             let window_end = event_time + Duration::minutes(90);
@@ -49,10 +66,10 @@ impl ImpactDataProcessor {
                 continue;
             }
 
-            let mut atrs = Vec::new();
+            // True Range par minute (clôture précédente incluse), comme les autres onglets
+            let atrs = true_ranges(occurrence_candles.iter().copied(), M1_STEP_MINUTES);
             let mut bodies = Vec::new();
             for candle in &occurrence_candles {
-                let atr = calculer_atr(candle.high, candle.low, candle.close);
                 let range = candle.high - candle.low;
                 let body = (candle.close - candle.open).abs();
                 let body_pct = if range > 0.0 {
@@ -60,7 +77,6 @@ impl ImpactDataProcessor {
                 } else {
                     0.0
                 };
-                atrs.push(atr);
                 bodies.push(body_pct);
             }
 
@@ -74,40 +90,46 @@ impl ImpactDataProcessor {
                 if let Some(candle) = occurrence_candles.get(i) {
                     let upper_wick = candle.high - candle.close.max(candle.open);
                     let lower_wick = candle.open.min(candle.close) - candle.low;
-                    if upper_wick > 0.0 { all_wicks.push(upper_wick); }
-                    if lower_wick > 0.0 { all_wicks.push(lower_wick); }
+                    if upper_wick > 0.0 {
+                        all_wicks.push(upper_wick);
+                        wick_weights.push(weight);
+                    }
+                    if lower_wick > 0.0 {
+                        all_wicks.push(lower_wick);
+                        wick_weights.push(weight);
+                    }
                 }
             }
 
             for i in 0..event_index.min(atrs.len()) {
-                atr_before_sum[i] += atrs[i];
-                body_before_sum[i] += bodies[i];
-                counts_before[i] += 1;
+                atr_before_sum[i] += weight * atrs[i];
+                body_before_sum[i] += weight * bodies[i];
+                counts_before[i] += weight;
             }
 
             for i in event_index..atrs.len().min(event_index + 90) {
                 let idx = i - event_index;
-                atr_after_sum[idx] += atrs[i];
-                body_after_sum[idx] += bodies[i];
-                counts_after[idx] += 1;
+                atr_after_sum[idx] += weight * atrs[i];
+                body_after_sum[idx] += weight * bodies[i];
+                counts_after[idx] += weight;
             }
 
             for i in 0..event_index.min(atrs.len()) {
-                noise_before_sum += if bodies[i] > 0.0 {
+                noise_before_sum += weight * if bodies[i] > 0.0 {
                     100.0 / bodies[i]
                 } else {
                     1.0
                 };
             }
             if event_index < atrs.len() {
-                noise_during_sum += if bodies[event_index] > 0.0 {
+                noise_during_sum += weight * if bodies[event_index] > 0.0 {
                     100.0 / bodies[event_index]
                 } else {
                     1.0
                 };
             }
             for i in (event_index + 1)..atrs.len().min(event_index + 90) {
-                noise_after_sum += if bodies[i] > 0.0 {
+                noise_after_sum += weight * if bodies[i] > 0.0 {
                     100.0 / bodies[i]
                 } else {
                     1.0
@@ -121,31 +143,31 @@ impl ImpactDataProcessor {
         let mut body_timeline_after = vec![0.0; 90];
 
         for i in 0..30 {
-            if counts_before[i] > 0 {
-                atr_timeline_before[i] = atr_before_sum[i] / counts_before[i] as f64;
-                body_timeline_before[i] = body_before_sum[i] / counts_before[i] as f64;
+            if counts_before[i] > 0.0 {
+                atr_timeline_before[i] = atr_before_sum[i] / counts_before[i];
+                body_timeline_before[i] = body_before_sum[i] / counts_before[i];
             }
         }
 
         for i in 0..90 {
-            if counts_after[i] > 0 {
-                atr_timeline_after[i] = atr_after_sum[i] / counts_after[i] as f64;
-                body_timeline_after[i] = body_after_sum[i] / counts_after[i] as f64;
+            if counts_after[i] > 0.0 {
+                atr_timeline_after[i] = atr_after_sum[i] / counts_after[i];
+                body_timeline_after[i] = body_after_sum[i] / counts_after[i];
             }
         }
 
-        let noise_before = if event_count > 0 {
-            noise_before_sum / (event_count as f64 * 30.0)
+        let noise_before = if weight_sum > 0.0 {
+            noise_before_sum / (weight_sum * 30.0)
         } else {
             0.0
         };
-        let noise_during = if event_count > 0 {
-            noise_during_sum / event_count as f64
+        let noise_during = if weight_sum > 0.0 {
+            noise_during_sum / weight_sum
         } else {
             0.0
         };
-        let noise_after = if event_count > 0 {
-            noise_after_sum / (event_count as f64 * 89.0)
+        let noise_after = if weight_sum > 0.0 {
+            noise_after_sum / (weight_sum * 89.0)
         } else {
             0.0
         };
@@ -175,12 +197,16 @@ impl ImpactDataProcessor {
         };
 
         // Calculate P95 Wick
-        all_wicks.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-        let p95_idx = ((all_wicks.len() as f64) * 0.95).ceil() as usize;
-        let p95_wick = if !all_wicks.is_empty() && p95_idx < all_wicks.len() {
-            all_wicks[p95_idx]
+        let p95_wick = if decay.is_enabled() {
+            weighted_quantile(&all_wicks, &wick_weights, 0.95)
         } else {
-            0.0
+            all_wicks.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let p95_idx = ((all_wicks.len() as f64) * 0.95).ceil() as usize;
+            if !all_wicks.is_empty() && p95_idx < all_wicks.len() {
+                all_wicks[p95_idx]
+            } else {
+                0.0
+            }
         };
 
         ProcessedImpactData {
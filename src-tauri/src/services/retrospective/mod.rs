@@ -1,5 +1,14 @@
-use super::impact_analyzer::ImpactAnalyzer;
-use super::simple_analyzers::{DecayProfileAnalyzer, PeakDelayAnalyzer};
+// services/retrospective/mod.rs - Analyses rétrospectives d'un type d'événement (délai du pic,
+// décroissance, impact et paramètres Bidi), partagées par les commandes et la planification
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+pub mod bidi_calculator;
+pub mod impact_analyzer;
+pub mod impact_data_processor;
+pub mod simple_analyzers;
+
+use impact_analyzer::ImpactAnalyzer;
+use simple_analyzers::{DatedValues, DecayProfileAnalyzer, PeakDelayAnalyzer};
 
 pub struct RetroAnalysisService;
 
@@ -14,7 +23,7 @@ impl RetroAnalysisService {
         event_type: &str,
         events: &[crate::models::CalendarEvent],
         loader: &crate::services::DatabaseLoader,
-    ) -> Result<(Vec<i16>, Vec<f64>), String> {
+    ) -> Result<(DatedValues<i16>, DatedValues<f64>), String> {
         PeakDelayAnalyzer::calculer(pair, event_type, events, loader).await
     }
 
@@ -22,7 +31,7 @@ impl RetroAnalysisService {
         pair: &str,
         events: &[crate::models::CalendarEvent],
         loader: &crate::services::DatabaseLoader,
-    ) -> Result<(Vec<f64>, Vec<f64>), String> {
+    ) -> Result<(DatedValues<f64>, DatedValues<f64>), String> {
         DecayProfileAnalyzer::calculer(pair, events, loader).await
     }
 
@@ -31,8 +40,9 @@ impl RetroAnalysisService {
         event_type: &str,
         events: &[crate::models::CalendarEvent],
         loader: &crate::services::DatabaseLoader,
-    ) -> Result<super::types::EventImpactResult, String> {
-        ImpactAnalyzer::calculer(pair, event_type, events, loader).await
+        decay: &crate::models::TimeDecay,
+    ) -> Result<crate::models::EventImpactResult, String> {
+        ImpactAnalyzer::calculer(pair, event_type, events, loader, decay).await
     }
 }
 
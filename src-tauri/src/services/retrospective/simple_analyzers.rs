@@ -1,7 +1,23 @@
 /// Analyseurs simples pour peak delay et decay profile
-use super::helpers::calculer_atr;
+use crate::models::TimeDecay;
+use crate::services::indicators::{true_ranges, M1_STEP_MINUTES};
+use crate::services::statistics::{weighted_mean, weighted_quantile};
 use crate::services::VolatilityDurationAnalyzer;
-use chrono::{Duration, Timelike};
+use chrono::{Duration, NaiveDate, Timelike};
+
+/// Valeurs par occurrence, datées pour la pondération par ancienneté
+pub type DatedValues<T> = Vec<(NaiveDate, T)>;
+
+/// Moyenne et médiane pondérées par ancienneté d'une série datée
+pub fn agreger_ponderees(values: &DatedValues<f64>, decay: &TimeDecay) -> (f64, f64) {
+    let dates: Vec<_> = values.iter().map(|(d, _)| *d).collect();
+    let raw: Vec<f64> = values.iter().map(|(_, v)| *v).collect();
+    let weights = decay.weights(&dates);
+    (
+        weighted_mean(&raw, &weights),
+        weighted_quantile(&raw, &weights, 0.5),
+    )
+}
 
 pub struct PeakDelayAnalyzer;
 pub struct DecayProfileAnalyzer;
@@ -12,7 +28,7 @@ impl PeakDelayAnalyzer {
         _event_type: &str,
         events: &[crate::models::CalendarEvent],
         loader: &crate::services::DatabaseLoader,
-    ) -> Result<(Vec<i16>, Vec<f64>), String> {
+    ) -> Result<(DatedValues<i16>, DatedValues<f64>), String> {
         let mut peak_delays = Vec::new();
         let mut peak_atrs = Vec::new();
 
@@ -21,20 +37,20 @@ impl PeakDelayAnalyzer {
             let window_end = event.event_time.and_utc() + Duration::hours(2);
             let candles = loader
                 .load_candles_by_pair(pair, "M1", window_start, window_end)
-                .unwrap_or_default();
+                .map_err(|e| e.to_string())?;
 
             if !candles.is_empty() {
-                let atr_values: Vec<f64> = candles
-                    .iter()
-                    .map(|c| calculer_atr(c.high, c.low, c.close))
-                    .collect();
+                let atr_values = true_ranges(&candles, M1_STEP_MINUTES);
                 if let Ok(pd) = VolatilityDurationAnalyzer::calculer_delai_pic(
                     &atr_values,
                     event.event_time.minute() as u8,
                 ) {
-                    peak_delays.push(pd);
+                    peak_delays.push((event.event_time.date(), pd));
                 }
-                peak_atrs.push(atr_values.iter().copied().fold(f64::NEG_INFINITY, f64::max));
+                peak_atrs.push((
+                    event.event_time.date(),
+                    atr_values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                ));
             }
         }
 
@@ -47,11 +63,22 @@ impl PeakDelayAnalyzer {
 }
 
 impl DecayProfileAnalyzer {
+    /// Vitesse de décroissance et timeout recommandé (minutes) selon le taux moyen
+    pub fn timeout_recommande(decay_rate: f64) -> (String, i16) {
+        if decay_rate > 3.0 {
+            ("Très Rapide".into(), 18)
+        } else if decay_rate > 1.5 {
+            ("Rapide".into(), 25)
+        } else {
+            ("Lent".into(), 32)
+        }
+    }
+
     pub async fn calculer(
         pair: &str,
         events: &[crate::models::CalendarEvent],
         loader: &crate::services::DatabaseLoader,
-    ) -> Result<(Vec<f64>, Vec<f64>), String> {
+    ) -> Result<(DatedValues<f64>, DatedValues<f64>), String> {
         let mut decay_rates = Vec::new();
         let mut peak_atrs = Vec::new();
 
@@ -60,19 +87,19 @@ impl DecayProfileAnalyzer {
             let window_end = event.event_time.and_utc() + Duration::hours(3);
             let candles = loader
                 .load_candles_by_pair(pair, "M1", window_start, window_end)
-                .unwrap_or_default();
+                .map_err(|e| e.to_string())?;
 
             if !candles.is_empty() {
-                let atr_values: Vec<f64> = candles
-                    .iter()
-                    .map(|c| calculer_atr(c.high, c.low, c.close))
-                    .collect();
+                let atr_values = true_ranges(&candles, M1_STEP_MINUTES);
                 if let Ok((rate, _)) =
                     VolatilityDurationAnalyzer::calculer_profil_decroissance(&atr_values)
                 {
-                    decay_rates.push(rate);
+                    decay_rates.push((event.event_time.date(), rate));
                 }
-                peak_atrs.push(atr_values.iter().copied().fold(f64::NEG_INFINITY, f64::max));
+                peak_atrs.push((
+                    event.event_time.date(),
+                    atr_values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                ));
             }
         }
 
@@ -0,0 +1,135 @@
+// services/score_calibration/logistic.rs - Régression logistique (Newton-Raphson, ridge)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+const MAX_ITERATIONS: usize = 50;
+const TOLERANCE: f64 = 1e-8;
+/// Pénalité ridge sur les coefficients (pas sur l'intercept) : stabilise les
+/// composantes quasi constantes sur l'échantillon
+const RIDGE: f64 = 0.1;
+
+#[derive(Debug, Clone)]
+pub(super) struct LogisticModel {
+    pub intercept: f64,
+    pub coefficients: Vec<f64>,
+}
+
+impl LogisticModel {
+    /// Probabilité prédite pour un vecteur de composantes
+    pub fn predict(&self, x: &[f64]) -> f64 {
+        let z = self.intercept
+            + self
+                .coefficients
+                .iter()
+                .zip(x)
+                .map(|(b, v)| b * v)
+                .sum::<f64>();
+        1.0 / (1.0 + (-z).exp())
+    }
+
+    /// Ajuste P(y=1) = σ(b0 + Σ bi·xi) par Newton-Raphson
+    pub fn fit(xs: &[Vec<f64>], ys: &[bool]) -> Result<Self, String> {
+        let dim = xs.first().map(|x| x.len()).ok_or("Aucun échantillon")?;
+        if xs.len() != ys.len() || xs.iter().any(|x| x.len() != dim) {
+            return Err("Échantillons de dimensions incohérentes".to_string());
+        }
+
+        // beta[0] = intercept
+        let mut beta = vec![0.0; dim + 1];
+        for _ in 0..MAX_ITERATIONS {
+            let mut gradient = vec![0.0; dim + 1];
+            let mut hessian = vec![vec![0.0; dim + 1]; dim + 1];
+
+            for (x, &y) in xs.iter().zip(ys) {
+                let row: Vec<f64> = std::iter::once(1.0).chain(x.iter().copied()).collect();
+                let z: f64 = row.iter().zip(&beta).map(|(v, b)| v * b).sum();
+                let p = 1.0 / (1.0 + (-z).exp());
+                let residual = if y { 1.0 } else { 0.0 } - p;
+                let w = p * (1.0 - p);
+                for i in 0..=dim {
+                    gradient[i] += residual * row[i];
+                    for j in 0..=dim {
+                        hessian[i][j] += w * row[i] * row[j];
+                    }
+                }
+            }
+            for i in 1..=dim {
+                gradient[i] -= RIDGE * beta[i];
+                hessian[i][i] += RIDGE;
+            }
+
+            let step = solve(hessian, gradient).ok_or("Système singulier (régression)")?;
+            let max_step = step.iter().fold(0.0_f64, |m, s| m.max(s.abs()));
+            beta.iter_mut().zip(&step).for_each(|(b, s)| *b += s);
+            if max_step < TOLERANCE {
+                break;
+            }
+        }
+
+        Ok(Self {
+            intercept: beta[0],
+            coefficients: beta[1..].to_vec(),
+        })
+    }
+}
+
+/// Résout A·x = b (élimination de Gauss, pivot partiel)
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| {
+            a[i][col]
+                .abs()
+                .partial_cmp(&a[j][col].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in col + 1..n {
+            let factor = a[row][col] / a[col][col];
+            let pivot_row = a[col].clone();
+            for (value, pivot_value) in a[row].iter_mut().zip(&pivot_row).skip(col) {
+                *value -= factor * pivot_value;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_recovers_informative_feature() {
+        // x0 prédit le gain, x1 est du bruit
+        let mut xs = Vec::new();
+        let mut ys = Vec::new();
+        for i in 0..200 {
+            let x0 = (i % 10) as f64 / 10.0;
+            let x1 = ((i * 7) % 10) as f64 / 10.0;
+            xs.push(vec![x0, x1]);
+            ys.push((i % 10) >= 4 || i % 13 == 0);
+        }
+        let model = LogisticModel::fit(&xs, &ys).expect("ajustement");
+
+        assert!(model.coefficients[0] > 1.0, "{:?}", model.coefficients);
+        assert!(model.coefficients[0] > model.coefficients[1].abs());
+        assert!(model.predict(&[0.9, 0.5]) > model.predict(&[0.1, 0.5]));
+    }
+
+    #[test]
+    fn test_fit_rejects_inconsistent_samples() {
+        assert!(LogisticModel::fit(&[], &[]).is_err());
+        assert!(LogisticModel::fit(&[vec![1.0], vec![1.0, 2.0]], &[true, false]).is_err());
+    }
+}
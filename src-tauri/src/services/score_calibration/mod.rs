@@ -0,0 +1,266 @@
+// services/score_calibration/mod.rs - Calibration du score de confiance sur les trades réalisés
+// Conforme .clinerules : < 250L, pas d'unwrap()
+
+mod logistic;
+mod samples;
+
+pub use samples::{collect_samples, realised_sample, CalibrationSample};
+
+use crate::models::asset_class::AssetType;
+use crate::models::{CalibrationBin, ScoreWeightSet, ScoreWeights};
+use crate::services::statistics;
+use crate::services::ConfidenceScorer;
+use logistic::LogisticModel;
+use serde::Serialize;
+use tracing::info;
+
+/// En dessous, une classe d'actifs garde le barème par défaut
+pub const MIN_CALIBRATION_SAMPLES: usize = 50;
+
+/// Points répartis entre les 5 composantes variables (le bonus données reste fixe)
+const FEATURE_POINTS: f64 = 100.0;
+const CURVE_BINS: usize = 10;
+
+/// Effectif par paire et par ancre (événement ou créneau)
+#[derive(Debug, Clone, Serialize)]
+pub struct CalibrationAnchor {
+    pub pair: String,
+    pub anchor: String,
+    pub samples: usize,
+    pub wins: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CalibrationReport {
+    pub total_samples: usize,
+    pub weight_sets: Vec<ScoreWeightSet>,
+    pub anchors: Vec<CalibrationAnchor>,
+    /// Classes d'actifs sans assez d'échantillons (avec la raison)
+    pub skipped: Vec<String>,
+}
+
+pub struct ScoreCalibrationService;
+
+impl ScoreCalibrationService {
+    /// Calibre une pondération par classe d'actifs présente dans les échantillons
+    pub fn calibrate(samples: &[CalibrationSample]) -> CalibrationReport {
+        let mut asset_types: Vec<AssetType> = Vec::new();
+        for sample in samples {
+            if !asset_types.contains(&sample.asset_type) {
+                asset_types.push(sample.asset_type);
+            }
+        }
+
+        let mut weight_sets = Vec::new();
+        let mut skipped = Vec::new();
+        for asset_type in asset_types {
+            let class_samples: Vec<&CalibrationSample> = samples
+                .iter()
+                .filter(|s| s.asset_type == asset_type)
+                .collect();
+            match Self::calibrate_class(asset_type, &class_samples) {
+                Ok(set) => {
+                    info!(
+                        "🎯 Calibration {:?}: {} trades, Brier {:.3}, poids {:?}",
+                        asset_type, set.sample_size, set.brier_score, set.weights
+                    );
+                    weight_sets.push(set);
+                }
+                Err(e) => skipped.push(format!("{:?}: {}", asset_type, e)),
+            }
+        }
+
+        CalibrationReport {
+            total_samples: samples.len(),
+            weight_sets,
+            anchors: Self::anchors(samples),
+            skipped,
+        }
+    }
+
+    fn calibrate_class(
+        asset_type: AssetType,
+        samples: &[&CalibrationSample],
+    ) -> Result<ScoreWeightSet, String> {
+        if samples.len() < MIN_CALIBRATION_SAMPLES {
+            return Err(format!(
+                "{} trades, {} requis",
+                samples.len(),
+                MIN_CALIBRATION_SAMPLES
+            ));
+        }
+        let xs: Vec<Vec<f64>> = samples
+            .iter()
+            .map(|s| s.components.features().to_vec())
+            .collect();
+        let ys: Vec<bool> = samples.iter().map(|s| s.win).collect();
+        let model = LogisticModel::fit(&xs, &ys)?;
+
+        // Seules les composantes qui augmentent la probabilité de gain reçoivent des points
+        let positive: Vec<f64> = model.coefficients.iter().map(|b| b.max(0.0)).collect();
+        let total: f64 = positive.iter().sum();
+        let informative = total > 0.0;
+        let default = ScoreWeights::default();
+        let weights = if informative {
+            let mut features = [0.0; 5];
+            for (w, b) in features.iter_mut().zip(&positive) {
+                *w = FEATURE_POINTS * b / total;
+            }
+            ScoreWeights::from_features(features, default.data)
+        } else {
+            default
+        };
+
+        let wins = ys.iter().filter(|&&y| y).count();
+        let brier_score = xs
+            .iter()
+            .zip(&ys)
+            .map(|(x, &y)| (model.predict(x) - if y { 1.0 } else { 0.0 }).powi(2))
+            .sum::<f64>()
+            / samples.len() as f64;
+
+        let mut coefficients = [0.0; 5];
+        coefficients
+            .iter_mut()
+            .zip(&model.coefficients)
+            .for_each(|(c, b)| *c = *b);
+
+        Ok(ScoreWeightSet {
+            asset_type,
+            weights,
+            intercept: model.intercept,
+            coefficients,
+            sample_size: samples.len(),
+            win_rate: wins as f64 / samples.len() as f64,
+            brier_score,
+            informative,
+            calibrated_at: chrono::Utc::now().to_rfc3339(),
+            curve_default: calibration_curve(samples, &default, &model),
+            curve_calibrated: calibration_curve(samples, &weights, &model),
+        })
+    }
+
+    fn anchors(samples: &[CalibrationSample]) -> Vec<CalibrationAnchor> {
+        let mut anchors: Vec<CalibrationAnchor> = Vec::new();
+        for sample in samples {
+            let pos = anchors
+                .iter()
+                .position(|a| a.pair == sample.pair && a.anchor == sample.anchor);
+            let entry = match pos {
+                Some(i) => &mut anchors[i],
+                None => {
+                    anchors.push(CalibrationAnchor {
+                        pair: sample.pair.clone(),
+                        anchor: sample.anchor.clone(),
+                        samples: 0,
+                        wins: 0,
+                    });
+                    let last = anchors.len() - 1;
+                    &mut anchors[last]
+                }
+            };
+            entry.samples += 1;
+            entry.wins += usize::from(sample.win);
+        }
+        anchors
+    }
+}
+
+/// Taux de gain observé par tranche de 10 points de score
+fn calibration_curve(
+    samples: &[&CalibrationSample],
+    weights: &ScoreWeights,
+    model: &LogisticModel,
+) -> Vec<CalibrationBin> {
+    let mut bins: Vec<Vec<(f64, f64, bool)>> = vec![Vec::new(); CURVE_BINS];
+    for sample in samples {
+        let score = ConfidenceScorer::calculer_score_pondere(&sample.metrics, weights);
+        let predicted = model.predict(&sample.components.features());
+        let idx = ((score / 10.0) as usize).min(CURVE_BINS - 1);
+        bins[idx].push((score, predicted, sample.win));
+    }
+
+    bins.iter()
+        .enumerate()
+        .filter(|(_, bin)| !bin.is_empty())
+        .map(|(idx, bin)| {
+            let n = bin.len();
+            let wins = bin.iter().filter(|(_, _, win)| *win).count();
+            CalibrationBin {
+                score_min: idx as f64 * 10.0,
+                score_max: (idx + 1) as f64 * 10.0,
+                mean_score: bin.iter().map(|(s, _, _)| s).sum::<f64>() / n as f64,
+                predicted_win_rate: bin.iter().map(|(_, p, _)| p).sum::<f64>() / n as f64,
+                observed_win_rate: wins as f64 / n as f64,
+                sample_size: n,
+                observed_ci: statistics::wilson_interval(wins, n),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GlobalMetrics;
+
+    fn sample(pair: &str, atr: f64, noise: f64, win: bool) -> CalibrationSample {
+        let metrics = GlobalMetrics {
+            mean_atr: atr,
+            mean_volatility: 0.15,
+            mean_body_range: 30.0,
+            mean_noise_ratio: noise,
+            mean_volume_imbalance: 0.0,
+            mean_breakout_percentage: 12.0,
+            mean_range: atr,
+            total_candles: 60,
+        };
+        CalibrationSample {
+            pair: pair.to_string(),
+            anchor: "NFP".to_string(),
+            asset_type: AssetType::ForexMajor,
+            components: ConfidenceScorer::composantes(&metrics),
+            metrics,
+            win,
+        }
+    }
+
+    #[test]
+    fn test_calibration_shifts_points_to_predictive_component() {
+        // Le gain dépend uniquement de l'ATR ; le bruit varie sans effet
+        let samples: Vec<CalibrationSample> = (0..120)
+            .map(|i| {
+                let high_atr = i % 2 == 0;
+                let noise = if i % 3 == 0 { 1.5 } else { 3.5 };
+                let win = if high_atr { i % 10 != 0 } else { i % 5 == 1 };
+                sample("EURUSD", if high_atr { 3.0 } else { 0.6 }, noise, win)
+            })
+            .collect();
+
+        let report = ScoreCalibrationService::calibrate(&samples);
+        assert!(report.skipped.is_empty());
+        let set = &report.weight_sets[0];
+        assert!(set.informative);
+        assert!(
+            set.weights.atr > ScoreWeights::default().atr,
+            "{:?}",
+            set.weights
+        );
+        assert!(set.weights.atr > set.weights.noise);
+        assert_eq!(report.anchors.len(), 1);
+        assert_eq!(report.anchors[0].samples, 120);
+
+        let observed: usize = set.curve_calibrated.iter().map(|b| b.sample_size).sum();
+        assert_eq!(observed, 120);
+    }
+
+    #[test]
+    fn test_small_classes_are_skipped() {
+        let samples: Vec<CalibrationSample> = (0..10)
+            .map(|i| sample("EURUSD", 2.0, 2.0, i % 2 == 0))
+            .collect();
+        let report = ScoreCalibrationService::calibrate(&samples);
+        assert!(report.weight_sets.is_empty());
+        assert_eq!(report.skipped.len(), 1);
+    }
+}
@@ -0,0 +1,142 @@
+// services/score_calibration/samples.rs - Échantillons (composantes du score → issue du trade)
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use crate::models::asset_class::AssetType;
+use crate::models::{AssetProperties, CalendarEvent, Candle, GlobalMetrics, ScoreComponents};
+use crate::services::backtest::models::TradeOutcome;
+use crate::services::backtest::simulator::EventSimulator;
+use crate::services::backtest::{BacktestConfig, StrategyMode};
+use crate::services::database_loader::DatabaseLoader;
+use crate::services::{ConfidenceScorer, MetricsCalculator};
+use chrono::{DateTime, Duration, Utc};
+
+/// Fenêtre observée avant l'ancre : c'est ce que le score voyait au moment de décider
+const LOOKBACK_MINUTES: i64 = 60;
+/// ATR(14) et volatilité(20) exigent au moins 21 bougies
+const MIN_LOOKBACK_CANDLES: usize = 21;
+
+/// Une occurrence : composantes mesurées avant l'ancre, issue du trade simulé après
+#[derive(Debug, Clone)]
+pub struct CalibrationSample {
+    pub pair: String,
+    pub anchor: String,
+    pub asset_type: AssetType,
+    pub metrics: GlobalMetrics,
+    pub components: ScoreComponents,
+    pub win: bool,
+}
+
+/// Simule chaque occurrence de l'ancre (événement ou créneau) sur une paire
+pub fn collect_samples(
+    pair: &str,
+    anchor: &str,
+    events: &[CalendarEvent],
+    config: &BacktestConfig,
+    mode: StrategyMode,
+    loader: &DatabaseLoader,
+) -> Result<Vec<CalibrationSample>, String> {
+    let asset_props = AssetProperties::from_symbol(pair);
+    let mut samples = Vec::new();
+
+    for event in events {
+        let t0 = event.event_time.and_utc();
+        let start = t0 - Duration::minutes(LOOKBACK_MINUTES);
+        let end = t0 + Duration::minutes(config.timeout_minutes as i64 + 10);
+        let candles = loader
+            .load_candles_by_pair(pair, "M1", start, end)
+            .map_err(|e| e.to_string())?;
+
+        let lookback: Vec<Candle> = candles
+            .iter()
+            .filter(|c| c.datetime < t0)
+            .cloned()
+            .collect();
+        let Some(metrics) = metriques_fenetre(&lookback, &asset_props) else {
+            continue;
+        };
+
+        let trade = EventSimulator::simulate(event, &candles, config, mode);
+        if trade.outcome == TradeOutcome::NoEntry {
+            continue;
+        }
+
+        samples.push(CalibrationSample {
+            pair: pair.to_string(),
+            anchor: anchor.to_string(),
+            asset_type: asset_props.asset_type,
+            components: ConfidenceScorer::composantes(&metrics),
+            metrics,
+            win: trade.pips_net > 0.0,
+        });
+    }
+
+    Ok(samples)
+}
+
+/// Trade réel du journal : composantes mesurées avant l'annonce, issue réalisée
+pub fn realised_sample(
+    pair: &str,
+    anchor: &str,
+    event_time: DateTime<Utc>,
+    win: bool,
+    loader: &DatabaseLoader,
+) -> Result<Option<CalibrationSample>, String> {
+    let asset_props = AssetProperties::from_symbol(pair);
+    let lookback: Vec<Candle> = loader
+        .load_candles_by_pair(
+            pair,
+            "M1",
+            event_time - Duration::minutes(LOOKBACK_MINUTES),
+            event_time,
+        )
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|c| c.datetime < event_time)
+        .collect();
+    let Some(metrics) = metriques_fenetre(&lookback, &asset_props) else {
+        return Ok(None);
+    };
+    Ok(Some(CalibrationSample {
+        pair: pair.to_string(),
+        anchor: anchor.to_string(),
+        asset_type: asset_props.asset_type,
+        components: ConfidenceScorer::composantes(&metrics),
+        metrics,
+        win,
+    }))
+}
+
+/// Mêmes agrégats que les stats horaires, calculés sur la fenêtre précédant l'ancre
+fn metriques_fenetre(candles: &[Candle], asset_props: &AssetProperties) -> Option<GlobalMetrics> {
+    if candles.len() < MIN_LOOKBACK_CANDLES {
+        return None;
+    }
+    let calc = MetricsCalculator::new(candles);
+    let atr = calc.calculer_atr(14).ok()?;
+    let volatility = calc.calculer_volatilite(20).ok()?;
+    let tr_dist = calc.calculer_distribution_true_range().ok()?;
+
+    let mean_body_range = mean(&calc.calculer_ranges_corps());
+    let breakout_count = tr_dist.is_breakout.iter().filter(|&&b| b).count();
+    let mean_breakout_percentage =
+        breakout_count as f64 / tr_dist.is_breakout.len().max(1) as f64 * 100.0;
+
+    Some(GlobalMetrics {
+        mean_atr: asset_props.normalize(mean(&atr)),
+        mean_volatility: mean(&volatility),
+        mean_body_range,
+        mean_noise_ratio: mean(&calc.calculer_ratio_bruit()),
+        mean_volume_imbalance: (mean_body_range.abs() * mean_breakout_percentage) / 10000.0,
+        mean_breakout_percentage,
+        mean_range: asset_props.normalize(mean(&tr_dist.true_ranges)),
+        total_candles: candles.len(),
+    })
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
@@ -0,0 +1,99 @@
+// services/seasonality_analyzer/calendar.rs - Étiquettes calendaires d'une journée et libellés
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::SeasonalityAnalyzer;
+use crate::models::SeasonalDimension;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+const WEEKDAYS: [&str; 7] = [
+    "Lundi", "Mardi", "Mercredi", "Jeudi", "Vendredi", "Samedi", "Dimanche",
+];
+const MONTHS: [&str; 12] = [
+    "Janvier",
+    "Février",
+    "Mars",
+    "Avril",
+    "Mai",
+    "Juin",
+    "Juillet",
+    "Août",
+    "Septembre",
+    "Octobre",
+    "Novembre",
+    "Décembre",
+];
+
+impl SeasonalityAnalyzer {
+    /// Dimensions auxquelles appartient une date
+    pub fn etiquettes(date: NaiveDate) -> Vec<(SeasonalDimension, u32)> {
+        let mut tags = vec![
+            (
+                SeasonalDimension::Weekday,
+                date.weekday().number_from_monday(),
+            ),
+            (SeasonalDimension::Month, date.month()),
+        ];
+        if date.weekday() == Weekday::Fri && date.day() <= 7 {
+            tags.push((SeasonalDimension::FirstFriday, 1));
+        }
+        if is_month_end(date) {
+            tags.push((SeasonalDimension::MonthEnd, 1));
+            if date.month().is_multiple_of(3) {
+                tags.push((SeasonalDimension::QuarterEnd, 1));
+            }
+        }
+        tags
+    }
+}
+
+/// Dernier jour ouvré (lundi-vendredi) du mois
+fn is_month_end(date: NaiveDate) -> bool {
+    let weekend = |d: NaiveDate| matches!(d.weekday(), Weekday::Sat | Weekday::Sun);
+    if weekend(date) {
+        return false;
+    }
+    let mut next = date + Duration::days(1);
+    while weekend(next) {
+        next += Duration::days(1);
+    }
+    next.month() != date.month()
+}
+
+pub(super) fn libelle(dimension: SeasonalDimension, key: u32) -> String {
+    match dimension {
+        SeasonalDimension::Weekday => WEEKDAYS
+            .get(key as usize - 1)
+            .copied()
+            .unwrap_or("?")
+            .to_string(),
+        SeasonalDimension::Month => MONTHS
+            .get(key as usize - 1)
+            .copied()
+            .unwrap_or("?")
+            .to_string(),
+        SeasonalDimension::FirstFriday => "Premier vendredi".to_string(),
+        SeasonalDimension::MonthEnd => "Fin de mois".to_string(),
+        SeasonalDimension::QuarterEnd => "Fin de trimestre".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_tags() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap_or_default();
+        // Vendredi 5 janvier 2024 : premier vendredi
+        assert!(SeasonalityAnalyzer::etiquettes(date(2024, 1, 5))
+            .contains(&(SeasonalDimension::FirstFriday, 1)));
+        // 29 mars 2024 (vendredi) : dernier jour ouvré de mars → fin de trimestre
+        let tags = SeasonalityAnalyzer::etiquettes(date(2024, 3, 29));
+        assert!(tags.contains(&(SeasonalDimension::QuarterEnd, 1)));
+        // 30 août 2024 (vendredi) : le 31 est un samedi → fin de mois, pas de trimestre
+        let tags = SeasonalityAnalyzer::etiquettes(date(2024, 8, 30));
+        assert!(tags.contains(&(SeasonalDimension::MonthEnd, 1)));
+        assert!(!tags.contains(&(SeasonalDimension::QuarterEnd, 1)));
+        assert!(!is_month_end(date(2024, 8, 29)));
+    }
+}
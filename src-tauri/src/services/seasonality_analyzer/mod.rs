@@ -0,0 +1,277 @@
+// services/seasonality_analyzer/mod.rs - Volatilité et résultats Straddle par saison calendaire
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+mod calendar;
+
+use crate::models::{
+    AssetProperties, Candle, SeasonalBucket, SeasonalDimension, SeasonalWindow, SeasonalityReport,
+};
+use crate::services::significance::{two_proportion_test, welch_t_test};
+use crate::services::statistics::{mean_interval, wilson_interval, CONFIDENCE_LEVEL};
+use crate::services::straddle_simulator::simulate_straddle;
+use crate::services::straddle_simulator_helpers::AssetCost;
+use calendar::libelle;
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use tracing::info;
+
+/// Une journée de l'historique (unité d'échantillonnage des tests)
+struct DaySample {
+    date: NaiveDate,
+    range_pips: f64,
+    trades: usize,
+    wins: usize,
+    whipsaws: usize,
+}
+
+pub struct SeasonalityAnalyzer;
+
+impl SeasonalityAnalyzer {
+    /// Compare chaque groupe calendaire au reste de l'historique
+    pub fn analyser(
+        symbol: &str,
+        candles: &[Candle],
+        window: Option<SeasonalWindow>,
+        costs: &AssetCost,
+    ) -> Result<SeasonalityReport, String> {
+        let asset = AssetProperties::from_symbol(symbol);
+        let samples = Self::echantillons_journaliers(symbol, candles, window, &asset, costs);
+        if samples.len() < 2 {
+            return Err(format!(
+                "Historique trop court pour une analyse saisonnière de {}",
+                symbol
+            ));
+        }
+
+        // Groupes : (dimension, clé) → indices des journées
+        let mut groups: BTreeMap<(SeasonalDimension, u32), Vec<usize>> = BTreeMap::new();
+        for (i, sample) in samples.iter().enumerate() {
+            for tag in Self::etiquettes(sample.date) {
+                groups.entry(tag).or_default().push(i);
+            }
+        }
+        let mut per_dimension: BTreeMap<SeasonalDimension, usize> = BTreeMap::new();
+        for (dimension, _) in groups.keys() {
+            *per_dimension.entry(*dimension).or_default() += 1;
+        }
+
+        let alpha = 1.0 - CONFIDENCE_LEVEL;
+        let buckets = groups
+            .iter()
+            .map(|((dimension, key), members)| {
+                // Correction de Bonferroni par dimension (au moins un groupe : le courant)
+                let tests = per_dimension.get(dimension).map_or(1, |n| (*n).max(1));
+                Self::comparer(&samples, members, *dimension, *key, alpha / tests as f64)
+            })
+            .collect();
+
+        let all: Vec<usize> = (0..samples.len()).collect();
+        let (trades, wins, _) = totaux(&samples, &all);
+        info!(
+            "📅 Saisonnalité {} : {} journées, {} groupes",
+            symbol,
+            samples.len(),
+            groups.len()
+        );
+
+        Ok(SeasonalityReport {
+            symbol: symbol.to_string(),
+            unit: asset.unit.clone(),
+            window,
+            days: samples.len(),
+            mean_range_pips: moyenne(&samples, &all),
+            win_rate_percent: pourcentage(wins, trades),
+            alpha,
+            buckets,
+            fingerprint: None,
+        })
+    }
+
+    /// Une mesure par journée ; les bougies de la plage horaire sont lues en place
+    /// (tranches contiguës, une plage qui traverse minuit en donne deux par date)
+    fn echantillons_journaliers(
+        symbol: &str,
+        candles: &[Candle],
+        window: Option<SeasonalWindow>,
+        asset: &AssetProperties,
+        costs: &AssetCost,
+    ) -> Vec<DaySample> {
+        let mut triees = Vec::new();
+        let candles = if candles.is_sorted_by_key(|c| c.datetime) {
+            candles
+        } else {
+            triees.extend_from_slice(candles);
+            triees.sort_by_key(|c| c.datetime);
+            &triees
+        };
+        let dans_plage = |c: &Candle| window.is_none_or(|w| w.contains(c.hour_utc() as u8));
+
+        candles
+            .chunk_by(|a, b| a.datetime.date_naive() == b.datetime.date_naive())
+            .filter_map(|day| {
+                let first = day.first()?;
+                let tranches: Vec<&[Candle]> = day
+                    .chunk_by(|a, b| dans_plage(a) == dans_plage(b))
+                    .filter(|run| run.first().is_some_and(dans_plage))
+                    .collect();
+                let count: usize = tranches.iter().map(|t| t.len()).sum();
+                if count == 0 {
+                    return None;
+                }
+                let range = tranches
+                    .iter()
+                    .flat_map(|t| t.iter())
+                    .map(|c| c.high - c.low)
+                    .sum::<f64>()
+                    / count as f64;
+                // Résultats Straddle simulés sur chaque tranche de bougies contiguës
+                let (trades, wins, whipsaws) =
+                    tranches.iter().fold((0, 0, 0), |(t, w, ws), tranche| {
+                        let sim = simulate_straddle(tranche, symbol, costs);
+                        (t + sim.total_trades, w + sim.wins, ws + sim.whipsaws)
+                    });
+                Some(DaySample {
+                    date: first.datetime.date_naive(),
+                    range_pips: asset.normalize(range),
+                    trades,
+                    wins,
+                    whipsaws,
+                })
+            })
+            .collect()
+    }
+
+    fn comparer(
+        samples: &[DaySample],
+        members: &[usize],
+        dimension: SeasonalDimension,
+        key: u32,
+        corrected_alpha: f64,
+    ) -> SeasonalBucket {
+        let mut in_bucket = vec![false; samples.len()];
+        for &i in members {
+            in_bucket[i] = true;
+        }
+        let rest: Vec<usize> = (0..samples.len()).filter(|&i| !in_bucket[i]).collect();
+        let ranges =
+            |idx: &[usize]| -> Vec<f64> { idx.iter().map(|&i| samples[i].range_pips).collect() };
+        let (in_ranges, rest_ranges) = (ranges(members), ranges(&rest));
+        let (trades, wins, whipsaws) = totaux(samples, members);
+        let (rest_trades, rest_wins, _) = totaux(samples, &rest);
+
+        let mean_range = moyenne(samples, members);
+        let rest_mean = moyenne(samples, &rest);
+        let volatility_test = welch_t_test(&in_ranges, &rest_ranges);
+        let win_rate_test = two_proportion_test(wins, trades, rest_wins, rest_trades);
+        let significant = [&volatility_test, &win_rate_test]
+            .iter()
+            .any(|t| t.as_ref().is_some_and(|t| t.p_value < corrected_alpha));
+
+        SeasonalBucket {
+            dimension,
+            key,
+            label: libelle(dimension, key),
+            days: members.len(),
+            mean_range_pips: mean_range,
+            range_ci: mean_interval(&in_ranges),
+            range_delta_percent: if rest_mean > 0.0 {
+                (mean_range / rest_mean - 1.0) * 100.0
+            } else {
+                0.0
+            },
+            volatility_test,
+            trades,
+            win_rate_percent: pourcentage(wins, trades),
+            win_rate_ci: wilson_interval(wins, trades).map(|ci| ci.scaled(100.0)),
+            whipsaw_rate_percent: pourcentage(whipsaws, trades),
+            win_rate_test,
+            significant,
+        }
+    }
+}
+
+fn totaux(samples: &[DaySample], idx: &[usize]) -> (usize, usize, usize) {
+    idx.iter().fold((0, 0, 0), |(t, w, ws), &i| {
+        (
+            t + samples[i].trades,
+            w + samples[i].wins,
+            ws + samples[i].whipsaws,
+        )
+    })
+}
+
+fn moyenne(samples: &[DaySample], idx: &[usize]) -> f64 {
+    if idx.is_empty() {
+        return 0.0;
+    }
+    idx.iter().map(|&i| samples[i].range_pips).sum::<f64>() / idx.len() as f64
+}
+
+fn pourcentage(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64 * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::straddle_simulator_helpers::get_asset_cost;
+    use chrono::{Datelike, Duration, TimeZone, Utc, Weekday};
+
+    #[test]
+    fn test_mondays_stand_out() {
+        // 20 semaines : le lundi a des bougies 3× plus larges
+        let start = Utc
+            .with_ymd_and_hms(2024, 1, 1, 8, 0, 0)
+            .single()
+            .unwrap_or_default();
+        let mut candles = Vec::new();
+        for day in 0..140 {
+            let t0 = start + Duration::days(day);
+            if matches!(t0.weekday(), Weekday::Sat | Weekday::Sun) {
+                continue;
+            }
+            let width = if t0.weekday() == Weekday::Mon {
+                0.0030
+            } else {
+                0.0010
+            };
+            for m in 0..30 {
+                let wobble = (m % 3) as f64 * 0.0001 + (day % 5) as f64 * 0.00005;
+                candles.push(Candle {
+                    id: None,
+                    symbol: "EURUSD".to_string(),
+                    datetime: t0 + Duration::minutes(m),
+                    open: 1.1,
+                    high: 1.1 + width / 2.0 + wobble,
+                    low: 1.1 - width / 2.0,
+                    close: 1.1,
+                    volume: 1.0,
+                    spread: None,
+                });
+            }
+        }
+
+        let costs = get_asset_cost("EURUSD");
+        let report =
+            SeasonalityAnalyzer::analyser("EURUSD", &candles, None, &costs).expect("rapport");
+        let monday = report
+            .buckets
+            .iter()
+            .find(|b| b.dimension == SeasonalDimension::Weekday && b.key == 1)
+            .expect("lundi");
+        assert_eq!(monday.label, "Lundi");
+        assert_eq!(monday.days, 20);
+        assert!(monday.range_delta_percent > 100.0);
+        assert!(monday.significant);
+        let tuesday = report
+            .buckets
+            .iter()
+            .find(|b| b.dimension == SeasonalDimension::Weekday && b.key == 2)
+            .expect("mardi");
+        assert!(tuesday.range_delta_percent < 0.0);
+    }
+}
@@ -0,0 +1,79 @@
+// services/significance.rs - Tests de significativité (Welch, deux proportions)
+// Conforme .clinerules : < 100L, pas d'unwrap()
+
+use crate::models::{SignificanceTest, TestMethod};
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+
+/// Test t de Welch (bilatéral) : la moyenne de `a` diffère-t-elle de celle de `b` ?
+pub fn welch_t_test(a: &[f64], b: &[f64]) -> Option<SignificanceTest> {
+    let (n1, n2) = (a.len() as f64, b.len() as f64);
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+    let (m1, m2) = (a.iter().sum::<f64>() / n1, b.iter().sum::<f64>() / n2);
+    let v1 = a.iter().map(|x| (x - m1).powi(2)).sum::<f64>() / (n1 - 1.0);
+    let v2 = b.iter().map(|x| (x - m2).powi(2)).sum::<f64>() / (n2 - 1.0);
+    let (s1, s2) = (v1 / n1, v2 / n2);
+    if s1 + s2 <= 0.0 {
+        return None;
+    }
+    let t = (m1 - m2) / (s1 + s2).sqrt();
+    // Degrés de liberté de Welch-Satterthwaite
+    let df = (s1 + s2).powi(2) / (s1.powi(2) / (n1 - 1.0) + s2.powi(2) / (n2 - 1.0));
+    let dist = StudentsT::new(0.0, 1.0, df).ok()?;
+    Some(SignificanceTest {
+        method: TestMethod::WelchT,
+        statistic: t,
+        p_value: (2.0 * (1.0 - dist.cdf(t.abs()))).clamp(0.0, 1.0),
+    })
+}
+
+/// Test z de deux proportions (bilatéral, variance poolée)
+pub fn two_proportion_test(
+    successes_a: usize,
+    trials_a: usize,
+    successes_b: usize,
+    trials_b: usize,
+) -> Option<SignificanceTest> {
+    if trials_a == 0 || trials_b == 0 {
+        return None;
+    }
+    let (n1, n2) = (trials_a as f64, trials_b as f64);
+    let pooled = (successes_a + successes_b) as f64 / (n1 + n2);
+    let se = (pooled * (1.0 - pooled) * (1.0 / n1 + 1.0 / n2)).sqrt();
+    if se <= 0.0 {
+        return None;
+    }
+    let z = (successes_a as f64 / n1 - successes_b as f64 / n2) / se;
+    let normal = Normal::new(0.0, 1.0).ok()?;
+    Some(SignificanceTest {
+        method: TestMethod::TwoProportionZ,
+        statistic: z,
+        p_value: (2.0 * (1.0 - normal.cdf(z.abs()))).clamp(0.0, 1.0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_significance_tests_detect_shifted_samples() {
+        let base: Vec<f64> = (0..60).map(|i| 10.0 + (i % 7) as f64).collect();
+        let shifted: Vec<f64> = base.iter().map(|v| v + 4.0).collect();
+        let same = welch_t_test(&base, &base).expect("test");
+        let diff = welch_t_test(&shifted, &base).expect("test");
+        assert!(same.p_value > 0.99);
+        assert!(diff.p_value < 0.001 && diff.statistic > 0.0);
+
+        // 70/100 contre 50/100 : z ≈ 2.89, p ≈ 0.004
+        let prop = two_proportion_test(70, 100, 50, 100).expect("test");
+        assert!(
+            (prop.statistic - 2.887).abs() < 0.01,
+            "z={}",
+            prop.statistic
+        );
+        assert!((prop.p_value - 0.0039).abs() < 0.001);
+        assert!(two_proportion_test(0, 0, 5, 10).is_none());
+    }
+}
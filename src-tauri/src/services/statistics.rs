@@ -0,0 +1,245 @@
+// services/statistics.rs - Intervalles de confiance (Wilson, Student, bootstrap) et agrégats pondérés
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::{ConfidenceInterval, IntervalMethod};
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+
+/// Niveau de confiance utilisé par toutes les métriques affichées
+pub const CONFIDENCE_LEVEL: f64 = 0.95;
+
+const BOOTSTRAP_RESAMPLES: usize = 1000;
+/// Graine fixe : deux analyses des mêmes données donnent le même intervalle
+const BOOTSTRAP_SEED: u64 = 0x5EED_2024_CAFE_0001;
+
+/// Intervalle de Wilson d'une proportion (0-1), fiable même pour n petit ou p proche de 0/1
+pub fn wilson_interval(successes: usize, trials: usize) -> Option<ConfidenceInterval> {
+    if trials == 0 || successes > trials {
+        return None;
+    }
+    let z = normal_quantile(1.0 - (1.0 - CONFIDENCE_LEVEL) / 2.0)?;
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z2 = z * z;
+    let denom = 1.0 + z2 / n;
+    let center = (p + z2 / (2.0 * n)) / denom;
+    let margin = z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denom;
+
+    Some(ConfidenceInterval::new(
+        p,
+        (center - margin).max(0.0),
+        (center + margin).min(1.0),
+        trials,
+        CONFIDENCE_LEVEL,
+        IntervalMethod::Wilson,
+    ))
+}
+
+/// Intervalle de Student autour de la moyenne (au moins 2 valeurs)
+pub fn mean_interval(values: &[f64]) -> Option<ConfidenceInterval> {
+    let n = values.len();
+    if n < 2 {
+        return None;
+    }
+    let mean = values.iter().sum::<f64>() / n as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+    let std_err = (variance / n as f64).sqrt();
+
+    let t = StudentsT::new(0.0, 1.0, (n - 1) as f64)
+        .ok()?
+        .inverse_cdf(1.0 - (1.0 - CONFIDENCE_LEVEL) / 2.0);
+
+    Some(ConfidenceInterval::new(
+        mean,
+        mean - t * std_err,
+        mean + t * std_err,
+        n,
+        CONFIDENCE_LEVEL,
+        IntervalMethod::StudentT,
+    ))
+}
+
+/// Bootstrap percentile de la moyenne (gains en pips, scores par événement)
+pub fn bootstrap_mean_interval(values: &[f64]) -> Option<ConfidenceInterval> {
+    bootstrap_interval(values.len(), |indices| {
+        indices.iter().map(|&i| values[i]).sum::<f64>() / indices.len() as f64
+    })
+}
+
+/// Bootstrap percentile d'une statistique quelconque calculée sur un jeu d'indices
+/// (`statistic` reçoit les indices tirés avec remise parmi 0..sample_size)
+pub fn bootstrap_interval<F>(sample_size: usize, statistic: F) -> Option<ConfidenceInterval>
+where
+    F: Fn(&[usize]) -> f64,
+{
+    if sample_size < 2 {
+        return None;
+    }
+    let all: Vec<usize> = (0..sample_size).collect();
+    let estimate = statistic(&all);
+
+    let mut rng = SplitMix64(BOOTSTRAP_SEED ^ sample_size as u64);
+    let mut indices = vec![0usize; sample_size];
+    let mut replicates: Vec<f64> = (0..BOOTSTRAP_RESAMPLES)
+        .map(|_| {
+            for idx in indices.iter_mut() {
+                *idx = (rng.next() % sample_size as u64) as usize;
+            }
+            statistic(&indices)
+        })
+        .filter(|v| v.is_finite())
+        .collect();
+    if replicates.is_empty() {
+        return None;
+    }
+    replicates.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let alpha = 1.0 - CONFIDENCE_LEVEL;
+    Some(ConfidenceInterval::new(
+        estimate,
+        quantile_sorted(&replicates, alpha / 2.0),
+        quantile_sorted(&replicates, 1.0 - alpha / 2.0),
+        sample_size,
+        CONFIDENCE_LEVEL,
+        IntervalMethod::Bootstrap,
+    ))
+}
+
+fn normal_quantile(p: f64) -> Option<f64> {
+    Normal::new(0.0, 1.0).ok().map(|n| n.inverse_cdf(p))
+}
+
+/// Quantile par interpolation linéaire sur des valeurs déjà triées
+fn quantile_sorted(sorted: &[f64], q: f64) -> f64 {
+    let pos = q.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+/// Générateur pseudo-aléatoire minimal (SplitMix64), suffisant pour le rééchantillonnage
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Moyenne pondérée ; les poids s'alignent sur la fin des valeurs quand une série
+/// dérivée (ATR, volatilité glissante) est plus courte que ses bougies
+pub fn weighted_mean(values: &[f64], weights: &[f64]) -> f64 {
+    let weights = tail(weights, values.len());
+    let total: f64 = weights.iter().sum();
+    if values.len() != weights.len() || total <= 0.0 {
+        return if values.is_empty() {
+            0.0
+        } else {
+            values.iter().sum::<f64>() / values.len() as f64
+        };
+    }
+    values.iter().zip(weights).map(|(v, w)| v * w).sum::<f64>() / total
+}
+
+/// Quantile pondéré (q dans [0, 1]) : plus petite valeur dont le poids cumulé atteint q
+pub fn weighted_quantile(values: &[f64], weights: &[f64], q: f64) -> f64 {
+    let weights = tail(weights, values.len());
+    let uniform = vec![1.0; values.len()];
+    let weights = if weights.len() == values.len() {
+        weights
+    } else {
+        &uniform[..]
+    };
+    let mut pairs: Vec<(f64, f64)> = values
+        .iter()
+        .copied()
+        .zip(weights.iter().copied())
+        .filter(|(_, w)| *w > 0.0)
+        .collect();
+    pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    let total: f64 = pairs.iter().map(|(_, w)| w).sum();
+    let target = q.clamp(0.0, 1.0) * total;
+    let mut cumulative = 0.0;
+    for (value, weight) in &pairs {
+        cumulative += weight;
+        if cumulative >= target {
+            return *value;
+        }
+    }
+    pairs.last().map(|(v, _)| *v).unwrap_or(0.0)
+}
+
+/// Taille d'échantillon effective de Kish : (Σw)² / Σw²
+pub fn effective_sample_size(weights: &[f64]) -> f64 {
+    let sum_sq: f64 = weights.iter().map(|w| w * w).sum();
+    if sum_sq <= 0.0 {
+        return 0.0;
+    }
+    weights.iter().sum::<f64>().powi(2) / sum_sq
+}
+
+fn tail(weights: &[f64], len: usize) -> &[f64] {
+    &weights[weights.len().saturating_sub(len)..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wilson_interval_bounds() {
+        let ci = wilson_interval(8, 10).expect("intervalle");
+        assert!((ci.estimate - 0.8).abs() < 1e-9);
+        // Valeurs de référence Wilson 95% pour 8/10 : [0.490, 0.943]
+        assert!((ci.lower - 0.490).abs() < 0.002, "lower={}", ci.lower);
+        assert!((ci.upper - 0.943).abs() < 0.002, "upper={}", ci.upper);
+        assert!(!ci.reliable, "10 essais ne suffisent pas");
+
+        let zero = wilson_interval(0, 50).expect("intervalle");
+        assert_eq!(zero.lower, 0.0);
+        assert!(zero.upper > 0.0);
+        assert!(wilson_interval(1, 0).is_none());
+    }
+
+    #[test]
+    fn test_mean_interval_shrinks_with_sample_size() {
+        let small: Vec<f64> = (0..10).map(|i| 10.0 + (i % 5) as f64).collect();
+        let large: Vec<f64> = (0..400).map(|i| 10.0 + (i % 5) as f64).collect();
+        let ci_small = mean_interval(&small).expect("intervalle");
+        let ci_large = mean_interval(&large).expect("intervalle");
+
+        assert!((ci_small.estimate - 12.0).abs() < 1e-9);
+        assert!(ci_small.lower < 12.0 && ci_small.upper > 12.0);
+        assert!(ci_large.half_width() < ci_small.half_width());
+        assert!(ci_large.reliable);
+        assert!(mean_interval(&[1.0]).is_none());
+    }
+
+    #[test]
+    fn test_bootstrap_is_deterministic_and_brackets_mean() {
+        let pips = [12.0, -8.0, 15.0, -8.0, 22.0, 3.0, -8.0, 9.0, 30.0, -8.0];
+        let a = bootstrap_mean_interval(&pips).expect("intervalle");
+        let b = bootstrap_mean_interval(&pips).expect("intervalle");
+
+        assert_eq!(a, b);
+        assert!((a.estimate - 5.9).abs() < 1e-9);
+        assert!(a.lower < a.estimate && a.estimate < a.upper);
+        assert_eq!(a.method, IntervalMethod::Bootstrap);
+    }
+
+    #[test]
+    fn test_weighted_aggregates_favor_heavy_observations() {
+        let values = [2.0, 4.0, 9.0];
+        let weights = [0.25, 0.25, 1.0];
+        assert!((weighted_mean(&values, &weights) - 7.0).abs() < 1e-9);
+        assert_eq!(weighted_quantile(&values, &weights, 0.5), 9.0);
+        assert_eq!(weighted_quantile(&values, &[1.0; 3], 0.5), 4.0);
+        // Série plus courte que les poids : alignement sur les plus récents
+        assert!((weighted_mean(&[10.0], &weights) - 10.0).abs() < 1e-9);
+        assert!((effective_sample_size(&[1.0; 4]) - 4.0).abs() < 1e-9);
+        assert!(effective_sample_size(&weights) < 3.0);
+    }
+}
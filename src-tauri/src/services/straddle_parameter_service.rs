@@ -1,4 +1,13 @@
-use crate::models::StraddleParameters;
+use crate::models::{PreEventOffsetRule, PreEventProfile, StraddleParameters};
+
+/// Occurrences minimales avant de laisser le profil pré-annonce modifier l'offset
+pub const MIN_PRE_EVENT_OCCURRENCES: usize = 10;
+/// Range pré-annonce sous 80% de la référence = compression
+const COMPRESSION_THRESHOLD: f64 = 0.8;
+/// Range pré-annonce au-delà de 120% de la référence = expansion
+const EXPANSION_THRESHOLD: f64 = 1.2;
+/// Part minimale des occurrences confirmant un comportement
+const REGULARITY_THRESHOLD: f64 = 0.6;
 
 pub struct StraddleParameterService;
 
@@ -84,6 +93,150 @@ impl StraddleParameterService {
             sl_recovery_pips,
             risk_reward_ratio,
             spread_safety_margin_pips: spread_safety,
+            offset_long_pips: None,
+            offset_short_pips: None,
+        }
+    }
+
+    /// Règle d'offset adaptative déduite du comportement pré-annonce
+    ///
+    /// - Compression fiable (range T-30 nettement sous la référence) sans que les petits
+    ///   ranges n'annoncent de petits mouvements : on resserre l'offset (jusqu'à -25%)
+    /// - Expansion (le marché s'agite avant l'annonce) : on l'élargit (jusqu'à +30%)
+    /// - Plancher : les ordres restent hors du demi-range pré-annonce moyen
+    /// - Dérive significative : l'ordre dans le sens attendu du mouvement se rapproche,
+    ///   l'autre s'écarte d'autant (continuation) ou l'inverse (retour)
+    pub fn regle_offset_pre_evenement(profile: &PreEventProfile) -> PreEventOffsetRule {
+        let mut rule = PreEventOffsetRule::neutral();
+        if profile.occurrences < MIN_PRE_EVENT_OCCURRENCES {
+            rule.reasons.push(format!(
+                "Échantillon insuffisant ({} < {} occurrences)",
+                profile.occurrences, MIN_PRE_EVENT_OCCURRENCES
+            ));
+            return rule;
+        }
+
+        let ratio = profile.mean_compression_ratio;
+        if ratio < COMPRESSION_THRESHOLD
+            && profile.compression_rate >= REGULARITY_THRESHOLD
+            && profile.range_move_correlation <= 0.0
+        {
+            rule.offset_factor = ratio.clamp(0.75, 1.0);
+            rule.reasons.push(format!(
+                "Compression pré-annonce ({:.0}% de la référence sur {:.0}% des occurrences)",
+                ratio * 100.0,
+                profile.compression_rate * 100.0
+            ));
+        } else if ratio > EXPANSION_THRESHOLD {
+            rule.offset_factor = ratio.min(1.3);
+            rule.reasons.push(format!(
+                "Expansion pré-annonce ({:.0}% de la référence)",
+                ratio * 100.0
+            ));
+        }
+        rule.min_offset_pips = (profile.mean_pre_range_pips / 2.0).ceil();
+
+        if profile.drift_significant {
+            let bias = (profile.mean_abs_drift_pips / 2.0).ceil();
+            let sens = profile.mean_drift_pips.signum();
+            let attendu = if profile.continuation_rate >= REGULARITY_THRESHOLD {
+                Some(("continuation", sens))
+            } else if profile.continuation_rate <= 1.0 - REGULARITY_THRESHOLD {
+                Some(("retour", -sens))
+            } else {
+                None
+            };
+            if let Some((nature, direction)) = attendu {
+                // direction > 0 : mouvement attendu à la hausse → Buy Stop plus proche
+                rule.long_bias_pips = -direction * bias;
+                rule.short_bias_pips = direction * bias;
+                rule.reasons.push(format!(
+                    "Dérive significative ({:+.1} pips, {} {:.0}%)",
+                    profile.mean_drift_pips,
+                    nature,
+                    profile.continuation_rate * 100.0
+                ));
+            }
+        }
+        rule
+    }
+
+    /// Applique une règle pré-annonce à des paramètres déjà calculés
+    pub fn appliquer_regle_pre_evenement(
+        mut params: StraddleParameters,
+        rule: &PreEventOffsetRule,
+    ) -> StraddleParameters {
+        if rule.is_neutral() {
+            return params;
         }
+        let spread = params.spread_safety_margin_pips;
+        let floor = rule.min_offset_pips + spread;
+        let base = ((params.offset_pips - spread).max(0.0) * rule.offset_factor + spread)
+            .ceil()
+            .max(floor);
+        params.offset_pips = base;
+
+        if rule.long_bias_pips != 0.0 || rule.short_bias_pips != 0.0 {
+            params.offset_long_pips = Some((base + rule.long_bias_pips).max(floor).ceil());
+            params.offset_short_pips = Some((base + rule.short_bias_pips).max(floor).ceil());
+        }
+        let widest = params
+            .offset_long_pips
+            .into_iter()
+            .chain(params.offset_short_pips)
+            .fold(params.offset_pips, f64::max);
+        params.sl_recovery_pips = params.stop_loss_pips.max(widest * 3.0).ceil();
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profil(occurrences: usize, ratio: f64, drift: f64, continuation: f64) -> PreEventProfile {
+        PreEventProfile {
+            pair: "EURUSD".to_string(),
+            event_type: "NFP".to_string(),
+            unit: "pips".to_string(),
+            window_minutes: 30,
+            post_window_minutes: 30,
+            occurrences,
+            mean_drift_pips: drift,
+            mean_abs_drift_pips: drift.abs(),
+            drift_ci: None,
+            drift_up_rate: 0.5,
+            drift_significant: drift != 0.0,
+            mean_pre_range_pips: 4.0,
+            mean_compression_ratio: ratio,
+            compression_ci: None,
+            compression_rate: 0.8,
+            mean_post_move_pips: 30.0,
+            continuation_rate: continuation,
+            range_move_correlation: -0.1,
+            range_move_slope: 0.0,
+            details: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_pre_event_rule_tightens_and_skews_offset() {
+        let params = StraddleParameterService::calculate_parameters(5.0, 1.0, 0.0001, None, None);
+        assert_eq!(params.offset_pips, 13.0);
+
+        let rule =
+            StraddleParameterService::regle_offset_pre_evenement(&profil(24, 0.5, 4.0, 0.75));
+        assert_eq!(rule.offset_factor, 0.75);
+        assert_eq!(rule.long_bias_pips, -2.0);
+
+        let adjusted = StraddleParameterService::appliquer_regle_pre_evenement(params, &rule);
+        // (13 - 3) × 0.75 + 3 = 10.5 → 11 ; Buy Stop rapproché, Sell Stop écarté
+        assert_eq!(adjusted.offset_pips, 11.0);
+        assert_eq!(adjusted.offset_long_pips, Some(9.0));
+        assert_eq!(adjusted.offset_short_pips, Some(13.0));
+        assert_eq!(adjusted.sl_recovery_pips, 39.0);
+
+        let small = StraddleParameterService::regle_offset_pre_evenement(&profil(5, 0.5, 4.0, 0.75));
+        assert!(small.is_neutral());
     }
 }
@@ -1,4 +1,5 @@
 use crate::models::Candle;
+use crate::services::indicators::{atr_of, AtrSmoothing, DEFAULT_ATR_PERIOD, M1_STEP_MINUTES};
 
 #[derive(Debug, Clone)]
 pub struct StraddleScore {
@@ -137,10 +138,6 @@ impl StraddleScoreCalculator {
     }
 
     fn calculer_atr(candles: &[Candle]) -> f64 {
-        if candles.is_empty() {
-            return 0.0;
-        }
-        let sum: f64 = candles.iter().map(|c| c.high - c.low).sum();
-        sum / candles.len() as f64
+        atr_of(candles, M1_STEP_MINUTES, DEFAULT_ATR_PERIOD, AtrSmoothing::Wilder).unwrap_or(0.0)
     }
 }
@@ -3,7 +3,7 @@
 
 use super::straddle_adjustments::AdjustedMetrics;
 use super::straddle_simulator_helpers::{
-    calculate_risk_level, calculer_atr_moyen, get_asset_cost, StraddleSimulationResult,
+    calculate_risk_level, calculer_atr_moyen, AssetCost, StraddleSimulationResult,
     WhipsawDetail,
 };
 use crate::models::Candle;
@@ -13,8 +13,12 @@ use crate::services::pair_data::symbol_properties::normalize_to_pips;
 ///
 /// Stratégie : Place un ordre Buy Stop et Sell Stop à distance égale du prix d'ouverture
 /// Whipsaw pondéré : Chaque whipsaw reçoit un coefficient selon QUAND il se produit
-/// Coûts : Intègre Spread et Slippage pour un résultat réaliste
-pub fn simulate_straddle(candles: &[Candle], symbol: &str) -> StraddleSimulationResult {
+/// Coûts : Intègre Spread et Slippage pour un résultat réaliste (lus une fois par l'appelant)
+pub fn simulate_straddle(
+    candles: &[Candle],
+    symbol: &str,
+    costs: &AssetCost,
+) -> StraddleSimulationResult {
     if candles.is_empty() {
         return StraddleSimulationResult {
             total_trades: 0,
@@ -38,14 +42,9 @@ pub fn simulate_straddle(candles: &[Candle], symbol: &str) -> StraddleSimulation
         };
     }
 
-    // Récupération des coûts pour cet actif
-    let costs = get_asset_cost(symbol);
-    let spread_cost = costs.spread_pips;
-    let slippage_cost = costs.slippage_pips;
-    // Coût total par trade simple (Entrée + Sortie)
-    // Entrée : Slippage + (Spread/2 ou Spread complet selon modèle)
-    // Ici modèle conservateur : On paie le spread à l'exécution + slippage
-    let cost_per_trade = spread_cost + (slippage_cost * 2.0); 
+    // Coût par trade : spread historique de la minute d'entrée, spread constant en repli
+    let cost_per_trade = costs.par_trade();
+    let mut total_costs = 0.0;
 
     // Calculer le percentile 95 des wicks pour déterminer l'offset optimal
     let mut wicks: Vec<f64> = Vec::new();
@@ -93,6 +92,7 @@ pub fn simulate_straddle(candles: &[Candle], symbol: &str) -> StraddleSimulation
     // Boucle sur les bougies pour placer les trades
     for i in 0..candles.len() {
         let entry_price = candles[i].close;
+        let trade_cost = costs.pour_bougie(&candles[i], symbol);
         let buy_stop = entry_price + marge;
         let sell_stop = entry_price - marge;
 
@@ -113,7 +113,7 @@ pub fn simulate_straddle(candles: &[Candle], symbol: &str) -> StraddleSimulation
             if triggered_side.is_none() {
                 // Pas encore déclenché, on surveille les deux bornes
                 // Note: On ajoute le spread au Buy Stop pour simuler l'Ask
-                let _effective_buy_stop = buy_stop + normalize_to_pips(spread_cost, symbol); // Approximation conversion inverse si nécessaire, ici on suppose spread_cost en pips déjà converti ? 
+                let _effective_buy_stop = buy_stop + normalize_to_pips(costs.spread_pips, symbol); // Approximation conversion inverse si nécessaire, ici on suppose spread_cost en pips déjà converti ? 
                 // ATTENTION: spread_cost est en PIPS. buy_stop est en PRIX.
                 // Il faut convertir spread_cost en PRIX pour l'ajouter.
                 // Pour simplifier ici sans pip_value, on va faire l'inverse : tout convertir en Pips à la fin pour le PnL.
@@ -191,6 +191,7 @@ pub fn simulate_straddle(candles: &[Candle], symbol: &str) -> StraddleSimulation
         // Enregistrement des résultats et calcul P&L Net
         if let Some(result) = trade_result {
             total_trades += 1;
+            total_costs += trade_cost;
             
             // Conversion des distances en Pips pour le calcul PnL
             let tp_pips = normalize_to_pips(tp_distance, symbol);
@@ -200,14 +201,14 @@ pub fn simulate_straddle(candles: &[Candle], symbol: &str) -> StraddleSimulation
                 "WIN" => {
                     wins += 1;
                     // Gain Net = TP - Coûts
-                    total_pnl_net += tp_pips - cost_per_trade;
+                    total_pnl_net += tp_pips - trade_cost;
                 },
                 "LOSS" => {
                     // Note: LOSS n'est pas explicitement set dans la boucle (c'est soit WIN soit WHIPSAW pour l'instant dans ce code simplifié)
                     // Mais si on ajoutait un timeout loss, ce serait ici.
                     losses += 1;
                     // Perte Nette = -SL - Coûts
-                    total_pnl_net -= sl_pips + cost_per_trade;
+                    total_pnl_net -= sl_pips + trade_cost;
                 },
                 "WHIPSAW" => {
                     whipsaws += 1;
@@ -216,8 +217,8 @@ pub fn simulate_straddle(candles: &[Candle], symbol: &str) -> StraddleSimulation
                     // Souvent un whipsaw touche le SL d'un côté, déclenche l'autre, et touche le SL de l'autre (pire cas)
                     // Ou touche SL d'un côté et revient au milieu.
                     // Ici on simule le pire cas "Double Touch" : On a payé le spread 2 fois et pris 1 SL complet (au moins).
-                    // Estimation conservatrice : Perte = SL + (2 * cost_per_trade)
-                    let whipsaw_loss = sl_pips + (2.0 * cost_per_trade);
+                    // Estimation conservatrice : Perte = SL + (2 * trade_cost)
+                    let whipsaw_loss = sl_pips + (2.0 * trade_cost);
                     total_pnl_net -= whipsaw_loss;
 
                     whipsaw_details_vec.push(WhipsawDetail {
@@ -236,10 +237,11 @@ pub fn simulate_straddle(candles: &[Candle], symbol: &str) -> StraddleSimulation
             // Déclenché mais pas de résultat (Time out) -> Considéré comme perte ou neutre
             // Pour être conservateur, on compte comme perte si pas de TP
             total_trades += 1;
+            total_costs += trade_cost;
             losses += 1;
             // Perte au timeout = Coûts + (Prix actuel - Prix entrée)
             // On simplifie en comptant juste les coûts + une petite perte moyenne
-            total_pnl_net -= cost_per_trade;
+            total_pnl_net -= trade_cost;
         }
     }
 
@@ -284,7 +286,11 @@ pub fn simulate_straddle(candles: &[Candle], symbol: &str) -> StraddleSimulation
         timeout_adjusted_minutes: adjusted.timeout_adjusted_minutes,
         whipsaw_details: whipsaw_details_vec,
         total_pnl_net_pips: total_pnl_net,
-        avg_trade_cost_pips: cost_per_trade,
+        avg_trade_cost_pips: if total_trades > 0 {
+            total_costs / total_trades as f64
+        } else {
+            cost_per_trade
+        },
         is_profitable_net: total_pnl_net > 0.0,
     }
 }
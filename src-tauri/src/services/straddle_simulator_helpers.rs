@@ -1,93 +1,95 @@
 // services/straddle_simulator_helpers.rs - Helpers pour simulation Straddle
 // Contient les fonctions utilitaires pour éviter de dépasser 300 lignes
 
+use crate::models::asset_class::AssetType;
+use crate::models::trade_journal::ExecutionCost;
 use crate::models::Candle;
+use crate::services::pair_data::symbol_properties::normalize_to_pips;
+use crate::services::indicators::{atr_of, AtrSmoothing, DEFAULT_ATR_PERIOD, M1_STEP_MINUTES};
+use crate::services::SymbolRegistryService;
 
-/// Calcule l'ATR moyen (Average True Range) pour une liste de candles
-/// Utilise une EMA(14) des True Ranges pour être conforme au standard MT5
-/// et donner plus de poids aux mouvements récents
+/// Calcule l'ATR (Average True Range) d'une liste de candles
+/// ATR(14) de Wilder de la bibliothèque d'indicateurs, comme tous les autres onglets
 pub fn calculer_atr_moyen(candles: &[Candle]) -> f64 {
-    let mut tr_values: Vec<f64> = Vec::new();
-
-    // Calcul du True Range pour chaque candle
-    for i in 0..candles.len() {
-        let high = candles[i].high;
-        let low = candles[i].low;
-        let close = if i > 0 {
-            candles[i - 1].close
-        } else {
-            candles[i].close
-        };
-
-        let tr = (high - low)
-            .max((high - close).abs())
-            .max((low - close).abs());
-        tr_values.push(tr);
-    }
+    atr_of(candles, M1_STEP_MINUTES, DEFAULT_ATR_PERIOD, AtrSmoothing::Wilder).unwrap_or(0.0)
+}
 
-    if tr_values.is_empty() {
-        return 0.0;
-    }
+// --- STRUCTURES DE COÛT ---
 
-    // Calcul de l'EMA(14) des True Ranges
-    calculate_ema(&tr_values, 14)
+#[derive(Debug, Clone)]
+pub struct AssetCost {
+    pub spread_pips: f64,
+    pub slippage_pips: f64,
 }
 
-/// Calcule l'EMA (Exponential Moving Average) avec une période donnée
-pub fn calculate_ema(values: &[f64], period: usize) -> f64 {
-    if values.is_empty() {
-        return 0.0;
+impl AssetCost {
+    /// Coût d'un trade simple (entrée + sortie), modèle conservateur :
+    /// spread payé à l'exécution + slippage des deux côtés
+    pub fn par_trade(&self) -> f64 {
+        self.spread_pips + (self.slippage_pips * 2.0)
     }
 
-    let period = period.min(values.len()); // Limiter la période au nombre de valeurs disponibles
-
-    // Coefficient de lissage EMA = 2 / (period + 1)
-    let multiplier = 2.0 / (period as f64 + 1.0);
-
-    // Initialiser avec la SMA des premières valeurs
-    let sma_init: f64 = values[0..period].iter().sum::<f64>() / period as f64;
-    let mut ema = sma_init;
-
-    // Appliquer l'EMA sur les valeurs restantes
-    for i in period..values.len() {
-        ema = values[i] * multiplier + ema * (1.0 - multiplier);
+    /// Coût d'un trade entré sur `candle` : spread historique de la minute,
+    /// spread constant en repli quand la minute n'en a pas
+    pub fn pour_bougie(&self, candle: &Candle, symbol: &str) -> f64 {
+        candle.spread.map_or(self.par_trade(), |spread| {
+            normalize_to_pips(spread, symbol) + (self.slippage_pips * 2.0)
+        })
     }
-
-    ema
 }
 
-// --- STRUCTURES DE COÛT ---
-
-#[derive(Debug, Clone)]
-pub struct AssetCost {
-    pub spread_pips: f64,
-    pub slippage_pips: f64,
+/// Coûts du modèle, glissement remplacé par celui mesuré sur le journal des trades s'il existe.
+/// Les glissements observés sont lus une fois par analyse (`ConfigService::execution_costs`)
+pub fn cout_execution(symbol: &str, observed: &[ExecutionCost]) -> AssetCost {
+    let model = get_asset_cost(symbol);
+    let canonical = SymbolRegistryService::canonical(symbol);
+    match observed.iter().find(|c| c.symbol == canonical) {
+        Some(cost) => AssetCost { slippage_pips: cost.observed_slippage_pips, ..model },
+        None => model,
+    }
 }
 
-/// Récupère les coûts estimés (Spread + Slippage) pour le News Trading selon l'actif
+/// Indices à coûts spécifiques (nom canonique du registre)
+const DAX_NAMES: [&str; 3] = ["DAX", "GER40", "DE40"];
+const DOW_NAMES: [&str; 2] = ["US30", "DJI"];
+
+/// Récupère les coûts estimés (Spread + Slippage) pour le News Trading selon l'actif.
+/// Modèle par défaut, sans les glissements observés
 pub fn get_asset_cost(symbol: &str) -> AssetCost {
-    let s = symbol.to_uppercase();
-    if s.contains("JPY") && (s.contains("GBP") || s.contains("EUR")) {
+    // Classe et devises du registre : "EURUSD.r" ou un alias courtier ont les coûts de EURUSD
+    let (asset_type, base, quote) = SymbolRegistryService::classify(symbol);
+    let has = |currency: &str| {
+        [&base, &quote]
+            .iter()
+            .any(|c| c.as_deref().is_some_and(|c| c.eq_ignore_ascii_case(currency)))
+    };
+    let is_index = |names: &[&str]| {
+        let s = SymbolRegistryService::canonical(symbol);
+        names.iter().any(|n| s.contains(n))
+    };
+    match asset_type {
         // Crosses volatils (GBPJPY, EURJPY)
-        AssetCost { spread_pips: 6.0, slippage_pips: 3.0 }
-    } else if s.contains("GBP") {
+        AssetType::ForexJpy if has("GBP") || has("EUR") => {
+            AssetCost { spread_pips: 6.0, slippage_pips: 3.0 }
+        }
         // Majors volatiles (GBPUSD)
-        AssetCost { spread_pips: 4.0, slippage_pips: 2.0 }
-    } else if s.contains("XAU") || s.contains("GOLD") {
+        AssetType::ForexMajor | AssetType::ForexJpy if has("GBP") => {
+            AssetCost { spread_pips: 4.0, slippage_pips: 2.0 }
+        }
         // Or (Gold)
-        AssetCost { spread_pips: 5.0, slippage_pips: 2.0 }
-    } else if s.contains("BTC") {
+        AssetType::Gold => AssetCost { spread_pips: 5.0, slippage_pips: 2.0 },
         // Crypto (BTC) - Valeurs élevées en points
-        AssetCost { spread_pips: 50.0, slippage_pips: 20.0 }
-    } else if s.contains("DAX") || s.contains("GER40") || s.contains("DE40") {
+        AssetType::Crypto if has("BTC") => AssetCost { spread_pips: 50.0, slippage_pips: 20.0 },
         // DAX
-        AssetCost { spread_pips: 6.0, slippage_pips: 3.0 }
-    } else if s.contains("US30") || s.contains("DJI") {
+        AssetType::Index if is_index(&DAX_NAMES) => {
+            AssetCost { spread_pips: 6.0, slippage_pips: 3.0 }
+        }
         // Dow Jones
-        AssetCost { spread_pips: 8.0, slippage_pips: 5.0 }
-    } else {
+        AssetType::Index if is_index(&DOW_NAMES) => {
+            AssetCost { spread_pips: 8.0, slippage_pips: 5.0 }
+        }
         // Majors liquides (EURUSD, USDJPY) par défaut
-        AssetCost { spread_pips: 2.5, slippage_pips: 1.0 }
+        _ => AssetCost { spread_pips: 2.5, slippage_pips: 1.0 },
     }
 }
 
@@ -153,4 +155,52 @@ mod tests {
         let (level, _) = calculate_risk_level(25.0);
         assert_eq!(level, "Élevé");
     }
+
+    #[test]
+    fn test_observed_slippage_overrides_model() {
+        let observed = vec![ExecutionCost {
+            symbol: "EURUSD".to_string(),
+            samples: 8,
+            observed_slippage_pips: 0.4,
+            model_slippage_pips: 1.0,
+        }];
+        let cost = cout_execution("EURUSD.r", &observed);
+        assert!((cost.slippage_pips - 0.4).abs() < 1e-9);
+        assert!((cost.spread_pips - get_asset_cost("EURUSD").spread_pips).abs() < 1e-9);
+        assert!((cout_execution("GBPUSD", &observed).slippage_pips - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_trade_cost_uses_minute_spread() {
+        let costs = AssetCost { spread_pips: 1.5, slippage_pips: 0.5 };
+        let mut candle = Candle {
+            id: None,
+            symbol: "EURUSD".to_string(),
+            datetime: chrono::Utc::now(),
+            open: 1.1,
+            high: 1.1,
+            low: 1.1,
+            close: 1.1,
+            volume: 0.0,
+            spread: None,
+        };
+        assert!((costs.pour_bougie(&candle, "EURUSD") - 2.5).abs() < 1e-9);
+        candle.spread = Some(0.00002);
+        let attendu = normalize_to_pips(0.00002, "EURUSD") + 1.0;
+        assert!((costs.pour_bougie(&candle, "EURUSD") - attendu).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_asset_cost_by_class_and_currency() {
+        let spread = |symbol: &str| get_asset_cost(symbol).spread_pips;
+        assert_eq!(spread("GBPJPY.r"), 6.0);
+        assert_eq!(spread("EURJPY"), 6.0);
+        assert_eq!(spread("GBPUSD"), 4.0);
+        assert_eq!(spread("XAUUSDm"), 5.0);
+        assert_eq!(spread("BTCUSD"), 50.0);
+        assert_eq!(spread("GER40_cash"), 6.0);
+        assert_eq!(spread("US30"), 8.0);
+        // Devise JPY seule : major liquide
+        assert_eq!(spread("USDJPY"), 2.5);
+    }
 }
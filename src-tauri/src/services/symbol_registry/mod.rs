@@ -0,0 +1,88 @@
+// services/symbol_registry/mod.rs - Registre des symboles courtier (pairs.db + cache partagé)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+mod mt5_import;
+mod shared;
+mod store;
+
+pub use mt5_import::SymbolImportReport;
+pub(crate) use mt5_import::{normalize_key, parse_csv_rows, Row};
+
+use crate::db::DbPool;
+use crate::models::{SymbolRegistry, SymbolSpec};
+use diesel::connection::Connection;
+use tracing::info;
+
+pub struct SymbolRegistryService;
+
+impl SymbolRegistryService {
+    /// Liste les spécifications enregistrées
+    pub fn list(pool: &DbPool) -> Result<Vec<SymbolSpec>, String> {
+        let mut conn = pool
+            .get()
+            .map_err(|e| format!("Connexion pairs.db: {}", e))?;
+        store::load_all(&mut conn)
+    }
+
+    /// Recharge le cache partagé utilisé par `AssetProperties::from_symbol`
+    pub fn refresh_cache(pool: &DbPool) -> Result<usize, String> {
+        let specs = Self::list(pool)?;
+        let count = specs.len();
+        Self::install(SymbolRegistry::new(specs));
+        Ok(count)
+    }
+
+    /// Ajoute ou met à jour une spécification
+    pub fn upsert(pool: &DbPool, spec: &SymbolSpec) -> Result<(), String> {
+        spec.validate()?;
+        let mut conn = pool
+            .get()
+            .map_err(|e| format!("Connexion pairs.db: {}", e))?;
+        store::upsert(&mut conn, spec)?;
+        Self::refresh_cache(pool)?;
+        info!("🏷️ Symbole {} enregistré", spec.symbol);
+        Ok(())
+    }
+
+    /// Supprime une spécification (retourne false si absente)
+    pub fn delete(pool: &DbPool, symbol: &str) -> Result<bool, String> {
+        let mut conn = pool
+            .get()
+            .map_err(|e| format!("Connexion pairs.db: {}", e))?;
+        let deleted = store::delete(&mut conn, symbol)?;
+        Self::refresh_cache(pool)?;
+        Ok(deleted)
+    }
+
+    /// Importe un export de symboles MT5 (CSV ou JSON) en une transaction
+    pub fn import_mt5_export(pool: &DbPool, path: &str) -> Result<SymbolImportReport, String> {
+        let content =
+            std::fs::read_to_string(path).map_err(|e| format!("Erreur lecture {}: {}", path, e))?;
+        let (specs, skipped) = mt5_import::parse_export(&content)?;
+
+        let mut conn = pool
+            .get()
+            .map_err(|e| format!("Connexion pairs.db: {}", e))?;
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            for spec in &specs {
+                store::upsert(conn, spec).map_err(|e| {
+                    tracing::error!("❌ {}", e);
+                    diesel::result::Error::RollbackTransaction
+                })?;
+            }
+            Ok(())
+        })
+        .map_err(|e| format!("Import symboles annulé: {}", e))?;
+
+        Self::refresh_cache(pool)?;
+        info!(
+            "🏷️ Import MT5: {} symboles enregistrés, {} ignorés",
+            specs.len(),
+            skipped.len()
+        );
+        Ok(SymbolImportReport {
+            imported: specs.len(),
+            skipped,
+        })
+    }
+}
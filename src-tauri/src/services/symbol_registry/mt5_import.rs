@@ -0,0 +1,283 @@
+// services/symbol_registry/mt5_import.rs - Lecture d'un export de symboles MT5 (CSV ou JSON)
+// Conforme .clinerules : < 250L, pas d'unwrap()
+
+use crate::models::asset_class::{AssetProperties, AssetType};
+use crate::models::SymbolSpec;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Résultat d'un import de registre
+#[derive(Debug, Clone, Serialize)]
+pub struct SymbolImportReport {
+    pub imported: usize,
+    /// Lignes ignorées avec la raison
+    pub skipped: Vec<String>,
+}
+
+/// Ligne de l'export, clés normalisées ("<SYMBOL>", "Contract Size" → "symbol", "contractsize")
+pub(crate) type Row = HashMap<String, String>;
+
+/// Parse l'export et retourne les spécifications valides et les lignes ignorées
+pub(super) fn parse_export(content: &str) -> Result<(Vec<SymbolSpec>, Vec<String>), String> {
+    let content = content.trim_start_matches('\u{feff}').trim();
+    if content.is_empty() {
+        return Err("Export de symboles vide".to_string());
+    }
+
+    let rows = if content.starts_with('[') || content.starts_with('{') {
+        parse_json_rows(content)?
+    } else {
+        parse_csv_rows(content)?
+    };
+
+    let mut specs = Vec::new();
+    let mut skipped = Vec::new();
+    for (idx, row) in rows.iter().enumerate() {
+        match row_to_spec(row) {
+            Ok(spec) => specs.push(spec),
+            Err(e) => skipped.push(format!("Ligne {}: {}", idx + 1, e)),
+        }
+    }
+
+    if specs.is_empty() {
+        return Err(format!(
+            "Aucun symbole valide dans l'export ({} ligne(s) ignorée(s))",
+            skipped.len()
+        ));
+    }
+    Ok((specs, skipped))
+}
+
+pub(crate) fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| !matches!(c, '<' | '>' | '_' | ' ' | '"'))
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Lignes d'un export CSV MT5 (séparateur détecté sur l'en-tête)
+pub(crate) fn parse_csv_rows(content: &str) -> Result<Vec<Row>, String> {
+    let header = content.lines().next().unwrap_or_default();
+    let delimiter = [b';', b'\t', b',']
+        .into_iter()
+        .max_by_key(|d| header.bytes().filter(|b| b == d).count())
+        .unwrap_or(b',');
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| format!("Headers illisibles: {}", e))?
+        .iter()
+        .map(normalize_key)
+        .collect();
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(|e| format!("Ligne CSV invalide: {}", e))?;
+            Ok(headers
+                .iter()
+                .cloned()
+                .zip(record.iter().map(str::to_string))
+                .collect())
+        })
+        .collect()
+}
+
+fn parse_json_rows(content: &str) -> Result<Vec<Row>, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| format!("JSON invalide: {}", e))?;
+    // Accepte un tableau ou un objet { "symbols": [...] }
+    let items = match &value {
+        serde_json::Value::Array(items) => items.as_slice(),
+        serde_json::Value::Object(map) => map
+            .get("symbols")
+            .and_then(|s| s.as_array())
+            .map(Vec::as_slice)
+            .ok_or("Objet JSON sans tableau 'symbols'")?,
+        _ => return Err("Format JSON non supporté".to_string()),
+    };
+
+    Ok(items
+        .iter()
+        .filter_map(|item| item.as_object())
+        .map(|object| {
+            object
+                .iter()
+                .map(|(key, value)| {
+                    let text = match value {
+                        serde_json::Value::String(s) => s.clone(),
+                        serde_json::Value::Array(list) => list
+                            .iter()
+                            .filter_map(|v| v.as_str())
+                            .collect::<Vec<_>>()
+                            .join(","),
+                        other => other.to_string(),
+                    };
+                    (normalize_key(key), text)
+                })
+                .collect()
+        })
+        .collect())
+}
+
+fn field<'a>(row: &'a Row, keys: &[&str]) -> Option<&'a str> {
+    keys.iter()
+        .filter_map(|k| row.get(*k))
+        .map(|v| v.trim())
+        .find(|v| !v.is_empty())
+}
+
+fn number(row: &Row, keys: &[&str]) -> Result<Option<f64>, String> {
+    field(row, keys)
+        .map(|raw| {
+            raw.replace(',', ".")
+                .parse::<f64>()
+                .map_err(|_| format!("{} invalide: {}", keys[0], raw))
+        })
+        .transpose()
+}
+
+fn row_to_spec(row: &Row) -> Result<SymbolSpec, String> {
+    let symbol = field(row, &["symbol", "name", "symbolname", "ticker"])
+        .ok_or("Colonne symbole absente")?
+        .to_uppercase();
+    let base = field(row, &["currencybase", "basecurrency", "base"]).map(str::to_uppercase);
+    let quote = field(
+        row,
+        &["currencyprofit", "profitcurrency", "quotecurrency", "quote"],
+    )
+    .map(str::to_uppercase);
+    let path = field(row, &["path"]).unwrap_or_default();
+
+    let digits = number(row, &["digits"])?.map(|d| d.max(0.0) as u32);
+    let point = number(row, &["point"])?
+        .or_else(|| digits.map(|d| 10f64.powi(-(d as i32))))
+        .ok_or("Ni point ni digits")?;
+    let digits = digits.unwrap_or_else(|| (-point.log10()).round().max(0.0) as u32);
+    let tick_size = number(row, &["ticksize", "tradeticksize"])?.unwrap_or(point);
+
+    let asset_type = infer_asset_type(path, base.as_deref(), quote.as_deref(), &symbol);
+    let pip_size = number(row, &["pip", "pipsize"])?
+        .unwrap_or_else(|| default_pip_size(asset_type, point, digits));
+    let contract_size = number(row, &["contractsize", "tradecontractsize"])?
+        .unwrap_or_else(|| default_contract_size(asset_type));
+
+    let spec = SymbolSpec {
+        description: field(row, &["description"]).map(str::to_string),
+        asset_type,
+        digits,
+        tick_size,
+        pip_size,
+        contract_size,
+        base_currency: base,
+        quote_currency: quote,
+        trading_hours: field(row, &["sessions", "session", "tradinghours"]).map(str::to_string),
+        aliases: field(row, &["aliases", "alias"])
+            .map(|raw| {
+                raw.split([',', '|', ' '])
+                    .filter(|a| !a.is_empty())
+                    .map(str::to_uppercase)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        source: "mt5".to_string(),
+        symbol,
+    };
+    spec.validate()?;
+    Ok(spec)
+}
+
+/// Classe d'actif : arborescence MT5, puis devises, puis heuristique sur le nom
+fn infer_asset_type(
+    path: &str,
+    base: Option<&str>,
+    quote: Option<&str>,
+    symbol: &str,
+) -> AssetType {
+    let path = path.to_lowercase();
+    let forex = match (base, quote) {
+        (Some(base), Some(quote)) => AssetProperties::forex_type(base, quote),
+        _ => None,
+    };
+
+    if path.contains("metal") {
+        return match base {
+            Some("XAG") => AssetType::Silver,
+            Some("XAU") => AssetType::Gold,
+            _ => AssetProperties::guess_asset_type(symbol),
+        };
+    }
+    if path.contains("crypto") {
+        return AssetType::Crypto;
+    }
+    if path.contains("ind") && forex.is_none() {
+        return AssetType::Index;
+    }
+    forex.unwrap_or_else(|| AssetProperties::guess_asset_type(symbol))
+}
+
+/// Pip = 10 points pour les cotations fractionnées (5/3 digits), sinon convention de la classe
+fn default_pip_size(asset_type: AssetType, point: f64, digits: u32) -> f64 {
+    match asset_type {
+        AssetType::ForexMajor | AssetType::ForexJpy if digits == 3 || digits == 5 => point * 10.0,
+        AssetType::ForexMajor | AssetType::ForexJpy | AssetType::Unknown => point,
+        other => AssetProperties::for_asset_type(other).pip_value,
+    }
+}
+
+fn default_contract_size(asset_type: AssetType) -> f64 {
+    match asset_type {
+        AssetType::ForexMajor | AssetType::ForexJpy => 100_000.0,
+        AssetType::Gold => 100.0,
+        AssetType::Silver => 5_000.0,
+        _ => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_export_with_suffixes_and_exotics() {
+        let csv =
+            "Symbol;Description;Digits;Point;Contract Size;Currency Base;Currency Profit;Path\n\
+                   EURUSD.r;Euro vs US Dollar;5;0.00001;100000;EUR;USD;Forex\\Majors\\EURUSD.r\n\
+                   USDTRY.r;US Dollar vs Lira;5;0.00001;100000;USD;TRY;Forex\\Exotics\\USDTRY.r\n\
+                   XAUUSDm;Gold;2;0.01;100;XAU;USD;Metals\\XAUUSDm\n\
+                   GER40;Germany 40;1;0.1;1;EUR;EUR;Indices\\GER40\n\
+                   BROKEN;;;;;;;\n";
+
+        let (specs, skipped) = parse_export(csv).expect("export CSV");
+        assert_eq!(specs.len(), 4);
+        assert_eq!(skipped.len(), 1);
+
+        assert_eq!(specs[0].asset_type, AssetType::ForexMajor);
+        assert!((specs[0].pip_size - 0.0001).abs() < 1e-12);
+        assert_eq!(specs[1].asset_type, AssetType::ForexMajor);
+        assert_eq!(specs[2].asset_type, AssetType::Gold);
+        assert!((specs[2].pip_size - 0.1).abs() < 1e-12);
+        assert_eq!(specs[3].asset_type, AssetType::Index);
+        assert_eq!(specs[3].quote_currency.as_deref(), Some("EUR"));
+    }
+
+    #[test]
+    fn test_parse_json_export() {
+        let json = r#"{"symbols": [
+            {"name": "USDJPY", "digits": 3, "point": 0.001, "currency_base": "USD",
+             "currency_profit": "JPY", "aliases": ["USDJPY.pro"], "sessions": "Mon-Fri 00:05-23:55"}
+        ]}"#;
+
+        let (specs, skipped) = parse_export(json).expect("export JSON");
+        assert!(skipped.is_empty());
+        assert_eq!(specs[0].asset_type, AssetType::ForexJpy);
+        assert!((specs[0].pip_size - 0.01).abs() < 1e-12);
+        assert_eq!(specs[0].aliases, vec!["USDJPY.PRO".to_string()]);
+        assert_eq!(specs[0].contract_size, 100_000.0);
+    }
+}
@@ -0,0 +1,61 @@
+// services/symbol_registry/shared.rs - Registre partagé par tout le backend (cache de pairs.db)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use super::SymbolRegistryService;
+use crate::models::asset_class::{AssetProperties, AssetType};
+use crate::models::asset_detection::strip_broker_suffix;
+use crate::models::{SymbolRegistry, SymbolSpec};
+use std::sync::{OnceLock, RwLock};
+
+/// Registre partagé (chargé depuis pairs.db au démarrage, remplacé après chaque modification)
+static REGISTRY: OnceLock<RwLock<SymbolRegistry>> = OnceLock::new();
+
+impl SymbolRegistryService {
+    /// Remplace le registre partagé
+    pub(super) fn install(registry: SymbolRegistry) {
+        let lock = REGISTRY.get_or_init(|| RwLock::new(SymbolRegistry::default()));
+        match lock.write() {
+            Ok(mut guard) => *guard = registry,
+            Err(poisoned) => *poisoned.into_inner() = registry,
+        }
+    }
+
+    /// Cherche un symbole dans le registre partagé
+    pub fn lookup(symbol: &str) -> Option<SymbolSpec> {
+        let guard = REGISTRY.get()?.read().ok()?;
+        guard.resolve(symbol).cloned()
+    }
+
+    /// Nom canonique : symbole enregistré, sinon symbole sans suffixe courtier
+    pub fn canonical(symbol: &str) -> String {
+        Self::lookup(symbol)
+            .map(|spec| spec.symbol.to_uppercase())
+            .unwrap_or_else(|| strip_broker_suffix(symbol))
+    }
+
+    /// Classe et devises (base, cotation) du registre ; hors registre, classe devinée
+    /// sur le nom et devises lues dans un nom de 6 lettres
+    pub fn classify(symbol: &str) -> (AssetType, Option<String>, Option<String>) {
+        if let Some(spec) = Self::lookup(symbol) {
+            return (spec.asset_type, spec.base_currency, spec.quote_currency);
+        }
+        let canonical = strip_broker_suffix(symbol);
+        let asset_type = AssetProperties::guess_asset_type(&canonical);
+        if canonical.len() == 6 && canonical.is_ascii() {
+            let (base, quote) = canonical.split_at(3);
+            (asset_type, Some(base.to_string()), Some(quote.to_string()))
+        } else {
+            (asset_type, None, None)
+        }
+    }
+}
+
+impl AssetProperties {
+    /// Propriétés d'un symbole : registre courtier d'abord, heuristique sur le nom sinon
+    pub fn from_symbol(symbol: &str) -> Self {
+        match SymbolRegistryService::lookup(symbol) {
+            Some(spec) => Self::from_spec(&spec),
+            None => Self::for_asset_type(Self::guess_asset_type(symbol)),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// services/symbol_registry/store.rs - Persistance du registre dans pairs.db
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use crate::models::asset_class::AssetType;
+use crate::models::SymbolSpec;
+use diesel::prelude::*;
+use diesel::sql_types::{Double, Integer, Nullable, Text};
+use diesel::sqlite::SqliteConnection;
+
+#[derive(QueryableByName)]
+struct SymbolRow {
+    #[diesel(sql_type = Text)]
+    symbol: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    description: Option<String>,
+    #[diesel(sql_type = Text)]
+    asset_type: String,
+    #[diesel(sql_type = Integer)]
+    digits: i32,
+    #[diesel(sql_type = Double)]
+    tick_size: f64,
+    #[diesel(sql_type = Double)]
+    pip_size: f64,
+    #[diesel(sql_type = Double)]
+    contract_size: f64,
+    #[diesel(sql_type = Nullable<Text>)]
+    base_currency: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    quote_currency: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    trading_hours: Option<String>,
+    #[diesel(sql_type = Text)]
+    aliases: String,
+    #[diesel(sql_type = Text)]
+    source: String,
+}
+
+impl From<SymbolRow> for SymbolSpec {
+    fn from(row: SymbolRow) -> Self {
+        SymbolSpec {
+            symbol: row.symbol,
+            description: row.description,
+            asset_type: parse_asset_type(&row.asset_type),
+            digits: row.digits.max(0) as u32,
+            tick_size: row.tick_size,
+            pip_size: row.pip_size,
+            contract_size: row.contract_size,
+            base_currency: row.base_currency,
+            quote_currency: row.quote_currency,
+            trading_hours: row.trading_hours,
+            aliases: row
+                .aliases
+                .split(',')
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .map(str::to_string)
+                .collect(),
+            source: row.source,
+        }
+    }
+}
+
+/// Nom sérialisé de la classe d'actif ("ForexMajor", "Index"...)
+fn asset_type_name(asset_type: AssetType) -> String {
+    format!("{:?}", asset_type)
+}
+
+fn parse_asset_type(name: &str) -> AssetType {
+    serde_json::from_value(serde_json::Value::String(name.to_string()))
+        .unwrap_or(AssetType::Unknown)
+}
+
+pub(super) fn load_all(conn: &mut SqliteConnection) -> Result<Vec<SymbolSpec>, String> {
+    diesel::sql_query(
+        "SELECT symbol, description, asset_type, digits, tick_size, pip_size, contract_size,
+                base_currency, quote_currency, trading_hours, aliases, source
+         FROM symbol_registry ORDER BY symbol",
+    )
+    .load::<SymbolRow>(conn)
+    .map(|rows| rows.into_iter().map(SymbolSpec::from).collect())
+    .map_err(|e| format!("Erreur lecture symbol_registry: {}", e))
+}
+
+pub(super) fn upsert(conn: &mut SqliteConnection, spec: &SymbolSpec) -> Result<(), String> {
+    diesel::sql_query(
+        "INSERT INTO symbol_registry (symbol, description, asset_type, digits, tick_size, pip_size,
+                                      contract_size, base_currency, quote_currency, trading_hours,
+                                      aliases, source, updated_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+         ON CONFLICT(symbol) DO UPDATE SET
+            description = excluded.description,
+            asset_type = excluded.asset_type,
+            digits = excluded.digits,
+            tick_size = excluded.tick_size,
+            pip_size = excluded.pip_size,
+            contract_size = excluded.contract_size,
+            base_currency = excluded.base_currency,
+            quote_currency = excluded.quote_currency,
+            trading_hours = excluded.trading_hours,
+            aliases = excluded.aliases,
+            source = excluded.source,
+            updated_at = excluded.updated_at",
+    )
+    .bind::<Text, _>(spec.symbol.to_uppercase())
+    .bind::<Nullable<Text>, _>(spec.description.as_deref())
+    .bind::<Text, _>(asset_type_name(spec.asset_type))
+    .bind::<Integer, _>(spec.digits as i32)
+    .bind::<Double, _>(spec.tick_size)
+    .bind::<Double, _>(spec.pip_size)
+    .bind::<Double, _>(spec.contract_size)
+    .bind::<Nullable<Text>, _>(spec.base_currency.as_deref())
+    .bind::<Nullable<Text>, _>(spec.quote_currency.as_deref())
+    .bind::<Nullable<Text>, _>(spec.trading_hours.as_deref())
+    .bind::<Text, _>(spec.aliases.join(","))
+    .bind::<Text, _>(&spec.source)
+    .execute(conn)
+    .map(|_| ())
+    .map_err(|e| format!("Erreur écriture symbol_registry ({}): {}", spec.symbol, e))
+}
+
+pub(super) fn delete(conn: &mut SqliteConnection, symbol: &str) -> Result<bool, String> {
+    diesel::sql_query("DELETE FROM symbol_registry WHERE symbol = ?")
+        .bind::<Text, _>(symbol.to_uppercase())
+        .execute(conn)
+        .map(|affected| affected > 0)
+        .map_err(|e| format!("Erreur suppression symbol_registry: {}", e))
+}
@@ -0,0 +1,252 @@
+// services/trade_journal/divergence.rs - Écarts entre trades réels et simulation EventSimulator
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::feedback::{couts_observes, issues};
+use super::{evenement_du_trade, points_realises, reconstituer_trades};
+use crate::models::trade_journal::{
+    JournalTrade, PredictedExecution, TradeDivergence, TradeJournalReport,
+};
+use crate::models::trading_plan::{ExecutedDeal, PlanEntry, TradingPlan};
+use crate::models::{AssetProperties, CalendarEvent};
+use crate::services::backtest::models::TradeOutcome;
+use crate::services::backtest::simulator::EventSimulator;
+use crate::services::backtest::{BacktestConfig, StrategyMode};
+use crate::services::pair_data::get_point_value;
+use crate::services::planning::candidate_ranking::CandidateRanker;
+use crate::services::planning::plan_reconciliation::{parse_utc, rapprocher};
+use crate::services::straddle_simulator_helpers::get_asset_cost;
+use crate::services::DatabaseLoader;
+use crate::services::SymbolRegistryService;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeMap;
+use tracing::info;
+
+/// Rapproche les transactions du plan (si fourni) puis des annonces du calendrier,
+/// simule chaque annonce tradée avec les paramètres du plan et mesure les écarts
+pub fn analyser_journal(
+    deals: Vec<ExecutedDeal>,
+    skipped: Vec<String>,
+    plan: Option<&TradingPlan>,
+    events: &[CalendarEvent],
+    loader: &DatabaseLoader,
+) -> TradeJournalReport {
+    let deal_count = deals.len();
+    let mut divergences = Vec::new();
+    let unplanned = match plan {
+        Some(plan) => {
+            let reconciliation = rapprocher(plan, deals, Vec::new());
+            for entry in reconciliation.entries {
+                let trades = reconstituer_trades(&entry.deals);
+                if !trades.is_empty() {
+                    divergences.push(divergence_planifiee(&entry.entry, trades, loader));
+                }
+            }
+            reconciliation.unplanned
+        }
+        None => deals,
+    };
+
+    // Hors plan : annonce la plus proche sur une devise de l'instrument, sans simulation
+    let mut by_event: BTreeMap<(i32, String), (&CalendarEvent, Vec<JournalTrade>)> =
+        BTreeMap::new();
+    let mut unmatched = Vec::new();
+    for trade in reconstituer_trades(&unplanned) {
+        let related = events
+            .iter()
+            .filter(|e| CandidateRanker::relation(&trade.symbol, &e.symbol).is_some());
+        match evenement_du_trade(&trade, related) {
+            Some(event) => by_event
+                .entry((event.id, SymbolRegistryService::canonical(&trade.symbol)))
+                .or_insert_with(|| (event, Vec::new()))
+                .1
+                .push(trade),
+            None => unmatched.push(trade),
+        }
+    }
+    for ((_, pair), (event, trades)) in by_event {
+        let mut divergence = assembler(event, &pair, None, trades, None);
+        divergence
+            .notes
+            .push("Hors plan : paramètres d'ordre inconnus, pas de simulation".to_string());
+        divergences.push(divergence);
+    }
+    divergences.sort_by(|a, b| a.event_time_utc.cmp(&b.event_time_utc));
+
+    let trades = divergences.iter().map(|d| d.trades.len()).sum::<usize>() + unmatched.len();
+    info!(
+        "📒 Journal : {} transactions, {} trades, {} annonces tradées, {} trades sans annonce",
+        deal_count,
+        trades,
+        divergences.len(),
+        unmatched.len()
+    );
+    TradeJournalReport {
+        deals: deal_count,
+        trades,
+        execution_costs: couts_observes(&divergences),
+        outcome_feedback: issues(&divergences),
+        divergences,
+        unmatched,
+        costs_applied: false,
+        skipped,
+    }
+}
+
+fn divergence_planifiee(
+    entry: &PlanEntry,
+    trades: Vec<JournalTrade>,
+    loader: &DatabaseLoader,
+) -> TradeDivergence {
+    let event = CalendarEvent {
+        id: entry.id.parse().unwrap_or_default(),
+        symbol: entry.currency.clone(),
+        event_time: parse_utc(&entry.time_utc).unwrap_or_default().naive_utc(),
+        impact: entry.impact.clone(),
+        description: entry.event.clone(),
+        actual: None,
+        forecast: None,
+        previous: None,
+        created_at: Utc::now().naive_utc(),
+        calendar_import_id: 0,
+    };
+    let prediction =
+        parse_utc(&entry.time_utc).and_then(|t0| simuler(entry, &event, t0, &trades, loader));
+    let mut divergence = assembler(
+        &event,
+        &entry.pair,
+        Some(entry.id.clone()),
+        trades,
+        prediction,
+    );
+    if divergence.predicted.is_none() {
+        divergence
+            .notes
+            .push("Historique absent autour de l'annonce : pas de simulation".to_string());
+    }
+    divergence
+}
+
+/// Mode déduit des ouvertures réelles : les deux sens ouverts = Simultané
+fn mode_execute(trades: &[JournalTrade]) -> StrategyMode {
+    let both = trades.iter().any(|t| t.side == "buy") && trades.iter().any(|t| t.side == "sell");
+    if both {
+        StrategyMode::Simultane
+    } else {
+        StrategyMode::Directionnel
+    }
+}
+
+/// Simulation avec les paramètres du plan ; glissement de la première ouverture
+fn simuler(
+    entry: &PlanEntry,
+    event: &CalendarEvent,
+    t0: DateTime<Utc>,
+    trades: &[JournalTrade],
+    loader: &DatabaseLoader,
+) -> Option<(PredictedExecution, Option<f64>)> {
+    let mode = mode_execute(trades);
+    let params = match mode {
+        StrategyMode::Simultane => entry.simultane?,
+        StrategyMode::Directionnel => entry.directionnel,
+    };
+    let asset = AssetProperties::from_symbol(&entry.pair);
+    let point = get_point_value(&entry.pair);
+    let config = BacktestConfig {
+        offset_pips: params.offset,
+        stop_loss_pips: params.sl,
+        trailing_stop_pips: params.tp,
+        timeout_minutes: entry.timeout_minutes,
+        sl_recovery_pips: None,
+        // Coûts exprimés en pips, simulation en points (comme le classement des candidats)
+        spread_pips: get_asset_cost(&entry.pair).spread_pips * asset.pip_value / point,
+        point_value: point,
+    };
+    let candles = loader
+        .load_candles_by_pair(
+            &entry.pair,
+            "M1",
+            t0 - Duration::minutes(5),
+            t0 + Duration::minutes(entry.timeout_minutes as i64 + 10),
+        )
+        .unwrap_or_default();
+    let t0_candle = candles.iter().find(|c| c.datetime >= t0)?;
+    let result = EventSimulator::simulate(event, &candles, &config, mode);
+
+    // Mêmes niveaux que le simulateur : Buy Stop spread compris, Sell Stop au bid
+    let spread = t0_candle.spread_or(config.spread_pips * point);
+    let buy_trigger = t0_candle.open + params.offset * point + spread;
+    let sell_trigger = t0_candle.open - params.offset * point;
+    let first = trades.first()?;
+    let (trigger, slippage) = if first.side == "buy" {
+        (buy_trigger, (first.entry_price - buy_trigger) / point)
+    } else {
+        (sell_trigger, (sell_trigger - first.entry_price) / point)
+    };
+    let no_entry = result.outcome == TradeOutcome::NoEntry;
+    Some((
+        PredictedExecution {
+            outcome: format!("{:?}", result.outcome),
+            entry_price: (!no_entry).then_some(trigger),
+            points: result.pips_net,
+        },
+        Some(slippage),
+    ))
+}
+
+fn assembler(
+    event: &CalendarEvent,
+    pair: &str,
+    plan_entry_id: Option<String>,
+    trades: Vec<JournalTrade>,
+    prediction: Option<(PredictedExecution, Option<f64>)>,
+) -> TradeDivergence {
+    let t0 = event.event_time.and_utc();
+    let point = get_point_value(pair);
+    let closed: Vec<f64> = trades
+        .iter()
+        .filter_map(|t| points_realises(t, point))
+        .collect();
+    let net_profit: f64 = trades.iter().map(|t| t.net_profit).sum();
+    let entry_delay_seconds = trades
+        .iter()
+        .filter_map(|t| parse_utc(&t.open_time_utc))
+        .min()
+        .map(|first| (first - t0).num_seconds());
+    let (predicted, slippage_points) = match prediction {
+        Some((predicted, slippage)) => (Some(predicted), slippage),
+        None => (None, None),
+    };
+    // Simulation sans déclenchement alors qu'un ordre a été exécuté : issue divergente
+    let outcome_match = predicted.as_ref().and_then(|p| {
+        if p.entry_price.is_none() {
+            Some(false)
+        } else {
+            (!closed.is_empty()).then(|| (p.points > 0.0) == (net_profit > 0.0))
+        }
+    });
+    let mut notes = Vec::new();
+    let still_open = trades.iter().filter(|t| t.exit_price.is_none()).count();
+    if still_open > 0 {
+        notes.push(format!(
+            "{} position(s) encore ouverte(s) à la fin du relevé",
+            still_open
+        ));
+    }
+
+    TradeDivergence {
+        event: event.description.clone(),
+        currency: event.symbol.clone(),
+        pair: pair.to_string(),
+        event_time_utc: t0.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        plan_entry_id,
+        mode: format!("{:?}", mode_execute(&trades)),
+        trades,
+        predicted,
+        realised_points: closed.iter().sum(),
+        net_profit,
+        slippage_points,
+        entry_delay_seconds,
+        outcome_match,
+        notes,
+    }
+}
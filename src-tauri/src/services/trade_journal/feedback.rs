@@ -0,0 +1,152 @@
+// services/trade_journal/feedback.rs - Glissements observés et taux de réussite réels du journal
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use crate::models::trade_journal::{
+    ExecutionCost, OutcomeFeedback, TradeDivergence, TradeJournalReport,
+};
+use crate::models::AssetProperties;
+use crate::services::pair_data::get_point_value;
+use crate::services::straddle_simulator_helpers::get_asset_cost;
+use crate::services::SymbolRegistryService;
+use std::collections::BTreeMap;
+
+/// Glissements nécessaires avant de remplacer le coût du modèle
+pub const MIN_COST_SAMPLES: usize = 5;
+
+/// Coûts observés assez souvent pour remplacer le glissement du modèle
+pub fn couts_applicables(report: &TradeJournalReport) -> Vec<ExecutionCost> {
+    report
+        .execution_costs
+        .iter()
+        .filter(|c| c.samples >= MIN_COST_SAMPLES)
+        .cloned()
+        .collect()
+}
+
+/// Glissement moyen (pips) par instrument ; une amélioration de prix ne rend pas le coût négatif
+pub(super) fn couts_observes(divergences: &[TradeDivergence]) -> Vec<ExecutionCost> {
+    let mut samples: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+    for d in divergences {
+        if let Some(slippage) = d.slippage_points {
+            let pips = slippage * get_point_value(&d.pair)
+                / AssetProperties::from_symbol(&d.pair).pip_value;
+            samples
+                .entry(SymbolRegistryService::canonical(&d.pair))
+                .or_default()
+                .push(pips);
+        }
+    }
+    samples
+        .into_iter()
+        .map(|(symbol, values)| ExecutionCost {
+            samples: values.len(),
+            observed_slippage_pips: (values.iter().sum::<f64>() / values.len() as f64).max(0.0),
+            model_slippage_pips: get_asset_cost(&symbol).slippage_pips,
+            symbol,
+        })
+        .collect()
+}
+
+/// Taux de réussite simulé et réel (paire canonique, événement) sur les annonces simulées et clôturées
+pub(super) fn issues(divergences: &[TradeDivergence]) -> Vec<OutcomeFeedback> {
+    let mut groups: BTreeMap<(String, String), (usize, usize, usize)> = BTreeMap::new();
+    for d in divergences {
+        let Some(predicted) = &d.predicted else {
+            continue;
+        };
+        if d.trades.iter().all(|t| t.exit_price.is_none()) {
+            continue;
+        }
+        let entry = groups
+            .entry((SymbolRegistryService::canonical(&d.pair), d.event.clone()))
+            .or_insert((0, 0, 0));
+        entry.0 += 1;
+        entry.1 += usize::from(predicted.points > 0.0);
+        entry.2 += usize::from(d.net_profit > 0.0);
+    }
+    groups
+        .into_iter()
+        .map(
+            |((pair, event), (n, predicted, realised))| OutcomeFeedback {
+                pair,
+                event,
+                trades: n,
+                predicted_win_rate: predicted as f64 * 100.0 / n as f64,
+                realised_win_rate: realised as f64 * 100.0 / n as f64,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::trade_journal::{JournalTrade, PredictedExecution};
+
+    fn divergence(pair: &str, slippage: Option<f64>, predicted: f64, net: f64) -> TradeDivergence {
+        TradeDivergence {
+            event: "CPI m/m".to_string(),
+            currency: "USD".to_string(),
+            pair: pair.to_string(),
+            event_time_utc: "2024-06-12T12:30:00Z".to_string(),
+            plan_entry_id: Some("12".to_string()),
+            mode: "Directionnel".to_string(),
+            trades: vec![JournalTrade {
+                symbol: pair.to_string(),
+                side: "buy".to_string(),
+                volume: 0.5,
+                open_time_utc: "2024-06-12T12:30:02Z".to_string(),
+                entry_price: 1.0812,
+                close_time_utc: Some("2024-06-12T12:41:40Z".to_string()),
+                exit_price: Some(1.0841),
+                net_profit: net,
+                comment: String::new(),
+            }],
+            predicted: Some(PredictedExecution {
+                outcome: "TakeProfit".to_string(),
+                entry_price: Some(1.0810),
+                points: predicted,
+            }),
+            realised_points: 290.0,
+            net_profit: net,
+            slippage_points: slippage,
+            entry_delay_seconds: Some(2),
+            outcome_match: Some((predicted > 0.0) == (net > 0.0)),
+            notes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_costs_and_outcome_feedback() {
+        // EURUSD : point 0.00001, pip 0.0001 → 10 points = 1 pip
+        let divergences = vec![
+            divergence("EURUSD.r", Some(20.0), 300.0, 140.0),
+            divergence("EURUSD", Some(-4.0), 300.0, -40.0),
+            divergence("EURUSD", None, -100.0, 25.0),
+        ];
+        let costs = couts_observes(&divergences);
+        assert_eq!(costs.len(), 1);
+        assert_eq!(costs[0].symbol, "EURUSD");
+        assert_eq!(costs[0].samples, 2);
+        assert!((costs[0].observed_slippage_pips - 0.8).abs() < 1e-9);
+
+        let feedback = issues(&divergences);
+        assert_eq!(feedback.len(), 1);
+        assert_eq!(feedback[0].trades, 3);
+        assert!((feedback[0].predicted_win_rate - 200.0 / 3.0).abs() < 1e-9);
+        assert!((feedback[0].realised_win_rate - 200.0 / 3.0).abs() < 1e-9);
+
+        // Deux glissements seulement : le modèle de coûts reste inchangé
+        let report = TradeJournalReport {
+            deals: 6,
+            trades: 3,
+            divergences,
+            unmatched: Vec::new(),
+            execution_costs: costs,
+            outcome_feedback: feedback,
+            costs_applied: false,
+            skipped: Vec::new(),
+        };
+        assert!(couts_applicables(&report).is_empty());
+    }
+}
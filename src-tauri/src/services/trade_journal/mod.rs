@@ -0,0 +1,118 @@
+// services/trade_journal/mod.rs - Journal des trades réels : positions reconstituées et annonces associées
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+mod divergence;
+mod feedback;
+mod statement;
+
+pub use divergence::analyser_journal;
+pub use feedback::{couts_applicables, MIN_COST_SAMPLES};
+pub use statement::{decoder_releve, lire_releve};
+
+use crate::models::trade_journal::JournalTrade;
+use crate::models::trading_plan::ExecutedDeal;
+use crate::models::CalendarEvent;
+use crate::services::planning::plan_reconciliation::{
+    parse_utc, EARLY_TOLERANCE_MINUTES, LATE_CLOSE_MINUTES,
+};
+use crate::services::SymbolRegistryService;
+use chrono::Duration;
+
+/// Associe ouvertures et clôtures (premier entré, premier sorti par instrument).
+/// Les clôtures d'une position ouverte avant le relevé sont ignorées.
+pub fn reconstituer_trades(deals: &[ExecutedDeal]) -> Vec<JournalTrade> {
+    let mut open: Vec<JournalTrade> = Vec::new();
+    let mut trades = Vec::new();
+    for deal in deals {
+        if deal.is_entry {
+            open.push(JournalTrade {
+                symbol: deal.symbol.clone(),
+                side: deal.side.clone(),
+                volume: deal.volume,
+                open_time_utc: deal.time_utc.clone(),
+                entry_price: deal.price,
+                close_time_utc: None,
+                exit_price: None,
+                net_profit: deal.net_profit,
+                comment: deal.comment.clone(),
+            });
+            continue;
+        }
+        let canonical = SymbolRegistryService::canonical(&deal.symbol);
+        let position = open.iter().position(|t| {
+            t.side != deal.side && SymbolRegistryService::canonical(&t.symbol) == canonical
+        });
+        if let Some(idx) = position {
+            let mut trade = open.remove(idx);
+            trade.close_time_utc = Some(deal.time_utc.clone());
+            trade.exit_price = Some(deal.price);
+            trade.net_profit += deal.net_profit;
+            trades.push(trade);
+        }
+    }
+    trades.extend(open);
+    trades.sort_by(|a, b| a.open_time_utc.cmp(&b.open_time_utc));
+    trades
+}
+
+/// Résultat d'une position clôturée, en points MT5
+pub fn points_realises(trade: &JournalTrade, point: f64) -> Option<f64> {
+    let exit = trade.exit_price?;
+    let sens = if trade.side == "buy" { 1.0 } else { -1.0 };
+    Some(sens * (exit - trade.entry_price) / point)
+}
+
+/// Annonce la plus proche dont la fenêtre [T0 - 5 min, T0 + 60 min] contient l'ouverture
+/// (même tolérance que le rapprochement du plan)
+pub fn evenement_du_trade<'a>(
+    trade: &JournalTrade,
+    events: impl IntoIterator<Item = &'a CalendarEvent>,
+) -> Option<&'a CalendarEvent> {
+    let opened = parse_utc(&trade.open_time_utc)?;
+    events
+        .into_iter()
+        .filter_map(|event| {
+            let t0 = event.event_time.and_utc();
+            let inside = opened >= t0 - Duration::minutes(EARLY_TOLERANCE_MINUTES)
+                && opened <= t0 + Duration::minutes(LATE_CLOSE_MINUTES);
+            inside.then(|| (event, (opened - t0).num_seconds().abs()))
+        })
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(event, _)| event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deal(time: &str, symbol: &str, side: &str, is_entry: bool, price: f64) -> ExecutedDeal {
+        ExecutedDeal {
+            time_utc: time.to_string(),
+            symbol: symbol.to_string(),
+            side: side.to_string(),
+            is_entry,
+            volume: 0.5,
+            price,
+            net_profit: if is_entry { -2.5 } else { 50.0 },
+            comment: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_trades_rebuilt_fifo() {
+        let deals = vec![
+            deal("2024-06-12T12:29:00Z", "EURUSD.r", "sell", false, 1.0800),
+            deal("2024-06-12T12:30:02Z", "EURUSD.r", "buy", true, 1.0812),
+            deal("2024-06-12T12:30:05Z", "EURUSD.r", "sell", true, 1.0790),
+            deal("2024-06-12T12:41:40Z", "EURUSD", "sell", false, 1.0842),
+        ];
+        let trades = reconstituer_trades(&deals);
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].exit_price, Some(1.0842));
+        assert!((trades[0].net_profit - 47.5).abs() < 1e-9);
+        assert_eq!(trades[1].close_time_utc, None);
+        let points = points_realises(&trades[0], 0.00001).expect("clôturé");
+        assert!((points - 300.0).abs() < 1e-6);
+        assert_eq!(points_realises(&trades[1], 0.00001), None);
+    }
+}
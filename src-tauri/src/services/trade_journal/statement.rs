@@ -0,0 +1,130 @@
+// services/trade_journal/statement.rs - Lecture d'un relevé de compte MT5 (rapport HTML ou export CSV)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+
+use crate::models::trading_plan::ExecutedDeal;
+use crate::models::BrokerClock;
+use crate::services::planning::plan_reconciliation::{
+    lire_transactions, transactions_depuis_lignes,
+};
+use crate::services::symbol_registry::{normalize_key, Row};
+use scraper::{ElementRef, Html, Selector};
+
+/// Colonnes qui distinguent le tableau des transactions des tableaux positions et ordres
+const DEAL_HEADER_KEYS: [&str; 3] = ["deal", "symbol", "direction"];
+
+/// Texte du relevé : les rapports HTML de MT5 sont enregistrés en UTF-16
+pub fn decoder_releve(bytes: &[u8]) -> Result<String, String> {
+    match bytes {
+        [0xFF, 0xFE, rest @ ..] => utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => utf16(rest, u16::from_be_bytes),
+        _ => String::from_utf8(bytes.to_vec())
+            .map_err(|_| "Relevé illisible : UTF-8 ou UTF-16 attendu".to_string()),
+    }
+}
+
+fn utf16(bytes: &[u8], read: fn([u8; 2]) -> u16) -> Result<String, String> {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| read([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16(&units).map_err(|e| format!("Relevé UTF-16 invalide: {}", e))
+}
+
+/// Transactions du relevé (heures serveur converties en UTC) et lignes ignorées
+pub fn lire_releve(
+    content: &str,
+    clock: &BrokerClock,
+) -> Result<(Vec<ExecutedDeal>, Vec<String>), String> {
+    let trimmed = content.trim_start_matches('\u{feff}').trim_start();
+    if !trimmed.starts_with('<') {
+        return lire_transactions(content, clock);
+    }
+    let rows = lignes_transactions(trimmed)?;
+    if rows.is_empty() {
+        return Err("Aucune transaction dans le rapport HTML".to_string());
+    }
+    Ok(transactions_depuis_lignes(&rows, clock))
+}
+
+/// Lignes du tableau "Transactions" (Deals) d'un rapport HTML : de l'en-tête à la
+/// première ligne au nombre de colonnes différent (totaux, section suivante)
+fn lignes_transactions(html: &str) -> Result<Vec<Row>, String> {
+    let document = Html::parse_document(html);
+    let row_selector = Selector::parse("tr").map_err(|e| format!("Sélecteur invalide: {}", e))?;
+    let cell_selector =
+        Selector::parse("td, th").map_err(|e| format!("Sélecteur invalide: {}", e))?;
+
+    let mut header: Option<Vec<String>> = None;
+    let mut rows = Vec::new();
+    for tr in document.select(&row_selector) {
+        let cells: Vec<String> = tr
+            .select(&cell_selector)
+            .filter(|c| !c.value().classes().any(|class| class == "hidden"))
+            .map(texte)
+            .collect();
+        match &header {
+            None => {
+                let keys: Vec<String> = cells.iter().map(|c| normalize_key(c)).collect();
+                if DEAL_HEADER_KEYS
+                    .iter()
+                    .all(|k| keys.iter().any(|key| key == k))
+                {
+                    header = Some(keys);
+                }
+            }
+            Some(keys) if cells.len() == keys.len() => {
+                rows.push(keys.iter().cloned().zip(cells).collect());
+            }
+            Some(_) => break,
+        }
+    }
+    if header.is_none() {
+        return Err("Tableau des transactions introuvable dans le rapport HTML".to_string());
+    }
+    Ok(rows)
+}
+
+fn texte(cell: ElementRef) -> String {
+    cell.text()
+        .collect::<String>()
+        .replace('\u{a0}', " ")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_report_deals_table() {
+        // Extrait d'un rapport MT5 : positions, puis transactions suivies des totaux
+        let html = r#"<!DOCTYPE html><html><body><table>
+            <tr><th colspan="13"><b>Positions</b></th></tr>
+            <tr><td>Time</td><td>Position</td><td>Symbol</td><td>Type</td><td class="hidden"></td></tr>
+            <tr><th colspan="13"><b>Deals</b></th></tr>
+            <tr><td>Time</td><td>Deal</td><td>Symbol</td><td>Type</td><td>Direction</td><td>Volume</td><td>Price</td><td>Order</td><td>Commission</td><td>Fee</td><td>Swap</td><td>Profit</td><td>Balance</td><td>Comment</td></tr>
+            <tr><td>2024.06.01 10:00:00</td><td>1</td><td></td><td>balance</td><td></td><td></td><td></td><td></td><td>0.00</td><td>0.00</td><td>0.00</td><td>1&nbsp;000.00</td><td>1&nbsp;000.00</td><td>depot</td></tr>
+            <tr><td>2024.06.12 15:30:02</td><td>2</td><td>EURUSD.r</td><td>buy</td><td>in</td><td>0.5</td><td>1.0812</td><td>7</td><td>-2.50</td><td>0.00</td><td>0.00</td><td>0.00</td><td>997.50</td><td>plan#12</td></tr>
+            <tr><td>2024.06.12 15:41:40</td><td>3</td><td>EURUSD.r</td><td>sell</td><td>out</td><td>0.5</td><td>1.0841</td><td>8</td><td>-2.50</td><td>0.00</td><td>0.00</td><td>145.00</td><td>1&nbsp;140.00</td><td></td></tr>
+            <tr><td colspan="8"></td><td>-5.00</td><td>0.00</td><td>0.00</td><td>145.00</td><td>140.00</td></tr>
+            <tr><td>2024.06.20 10:00:00</td><td>9</td><td>GBPUSD</td><td>buy</td><td>in</td><td>1</td><td>1.27</td><td>9</td><td>0</td><td>0</td><td>0</td><td>0</td><td>0</td><td></td></tr>
+            </table></body></html>"#;
+        let (deals, skipped) = lire_releve(html, &BrokerClock::default()).expect("rapport");
+        assert!(skipped.is_empty());
+        assert_eq!(deals.len(), 2);
+        assert_eq!(deals[0].time_utc, "2024-06-12T12:30:02Z");
+        assert_eq!(deals[0].comment, "plan#12");
+        assert!((deals[1].net_profit - 142.5).abs() < 1e-9);
+
+        assert!(lire_releve("<html><table></table></html>", &BrokerClock::default()).is_err());
+    }
+
+    #[test]
+    fn test_decode_utf16_report() {
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend("<html>é</html>".encode_utf16().flat_map(u16::to_le_bytes));
+        assert_eq!(decoder_releve(&bytes).expect("utf16"), "<html>é</html>");
+        assert_eq!(decoder_releve(b"Time;Deal").expect("utf8"), "Time;Deal");
+    }
+}
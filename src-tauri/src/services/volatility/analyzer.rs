@@ -9,6 +9,7 @@ use super::quarterly_aggregator::QuarterlyAggregator;
 use super::stats_15min::Stats15MinCalculator;
 use super::volatility_heuristics::VolatilityHeuristics;
 use crate::db::DbPool;
+use crate::services::ConfigService;
 use crate::models::{
     AnalysisResult, AssetProperties, Candle, Result, RiskLevel, TradingRecommendation,
     VolatilityError,
@@ -128,16 +129,23 @@ impl VolatilityAnalyzer {
             "M1_DEFAULT".to_string()
         };
 
+        // Pondération par ancienneté configurée (aucune par défaut)
+        let decay = ConfigService::time_decay();
+        if let Some(half_life) = decay.half_life_days.filter(|_| decay.is_enabled()) {
+            info!("⏳ Pondération temporelle: demi-vie {:.0} jours", half_life);
+        }
+
         // 1. Calcule les statistiques par heure
-        let calculator = HourlyStatsCalculator::new(&self.candles, symbol.to_string());
+        let calculator = HourlyStatsCalculator::new(&self.candles, symbol.to_string(), decay);
         let mut hourly_stats = calculator.calculer()?;
 
         // 1.5 Calcule les statistiques par tranche de 15 minutes (pour scalping)
-        let calculator_15min = Stats15MinCalculator::new(&self.candles);
+        let calculator_15min = Stats15MinCalculator::new(&self.candles, decay);
         let mut stats_15min = calculator_15min.calculer()?;
 
         // Détection des propriétés de l'actif (Unités, Pips)
         let asset_props = AssetProperties::from_symbol(symbol);
+        let score_weights = ConfigService::score_weights_for(asset_props.asset_type);
         let point_value = asset_props.pip_value;
         let unit = asset_props.unit;
 
@@ -198,7 +206,13 @@ impl VolatilityAnalyzer {
             MetricsAggregator::calculer_metriques_globales(&hourly_stats, self.candles.len());
 
         // 4. Calcule le score de confiance
-        let confidence_score = MetricsAggregator::calculer_score_confiance(&global_metrics);
+        let confidence_score =
+            MetricsAggregator::calculer_score_confiance(&global_metrics, &score_weights);
+        let confidence_score_ci = MetricsAggregator::intervalle_score_confiance(
+            &hourly_stats,
+            self.candles.len(),
+            &score_weights,
+        );
 
         // 5. Génère la recommandation
         let recommendation = TradingRecommendation::from_confidence(confidence_score);
@@ -240,11 +254,14 @@ impl VolatilityAnalyzer {
             stats_15min,
             best_quarter,
             confidence_score,
+            confidence_score_ci,
             recommendation,
             risk_level,
             global_metrics,
             point_value,
             unit,
+            regime: None,
+            fingerprint: None,
         })
     }
 }
@@ -1,11 +1,11 @@
 // services/volatility/confidence_scorer.rs - Calcul du score de confiance
 // Module séparé pour respecter la limite de taille (metrics.rs < 300L)
 
-use crate::models::GlobalMetrics;
+use crate::models::{GlobalMetrics, ScoreComponents, ScoreWeights};
 
 /// Calculateur du score de confiance GLOBAL (0-100)
 #[allow(clippy::doc_lazy_continuation)]
-pub(super) struct ConfidenceScorer;
+pub struct ConfidenceScorer;
 
 impl ConfidenceScorer {
     /// Calcule le score de confiance GLOBAL (0-100)
@@ -20,6 +20,8 @@ impl ConfidenceScorer {
     /// - Scalping = décisions rapides, volatilité CONSTANTE > spike isolé
     ///
     /// FORMULE (max 100 points) :
+    /// Barème par défaut (`ScoreWeights::default`) ; une pondération calibrée par classe
+    /// d'actifs peut le remplacer via `calculer_score_pondere`.
     ///
     /// 1. ATR (30 pts) - Volatilité soutenue
     ///    > 25 pips (0.00025) = 30 pts : volatilité excellente
@@ -74,90 +76,115 @@ impl ConfidenceScorer {
     /// > - BreakoutPct 18% → 10 pts (beaucoup de cassures)
     /// > - Bonus → 5 pts (données suffisantes)
     /// > = TOTAL 105 → capped à 100 = "EXCELLENT, scalpe agressif"
-    pub(super) fn calculer_score_confiance(metrics: &GlobalMetrics) -> f64 {
-        let mut score: f64 = 0.0;
+    pub fn calculer_score_confiance(metrics: &GlobalMetrics) -> f64 {
+        Self::calculer_score_pondere(metrics, &ScoreWeights::default())
+    }
+
+    /// Score avec une pondération calibrée (les pénalités restent fixes)
+    pub fn calculer_score_pondere(metrics: &GlobalMetrics, weights: &ScoreWeights) -> f64 {
+        let mut score = weights.apply(&Self::composantes(metrics));
 
-        // 1. Score ATR (30 points max) - Seuils normalisés (1.0 = 1 Pip/Point)
-        // ATR Forex M1 typique : 1.0 - 3.0 pips
-        if metrics.mean_atr > 2.5 {
-            score += 30.0; // Excellent : > 2.5 pips/points
+        // 7. PÉNALITÉ: ATR élevé MAIS Noise élevé (contradiction)
+        // Volatilité chaotique = mauvais pour scalping propre
+        if metrics.mean_atr > 0.0002 && metrics.mean_noise_ratio > 3.0 {
+            score -= 15.0; // Volatilité mais signal chaotique = danger
+        }
+
+        // 8. PÉNALITÉ: BodyRange fort MAIS peu de Breakouts (indécision)
+        // Bougies directionnelles mais pas de cassures = signal faible
+        if metrics.mean_body_range > 40.0 && metrics.mean_breakout_percentage < 8.0 {
+            score -= 10.0; // Contrainte = trading moins net
+        }
+
+        // 10. PÉNALITÉ: Trop de fausses cassures (volatilité erratique)
+        // Breakout % très élevé + BodyRange faible = chaos, pas de direction
+        if metrics.mean_breakout_percentage > 25.0 && metrics.mean_body_range < 30.0 {
+            score -= 8.0; // Volatilité instable/chaotique = à éviter
+        }
+
+        score.clamp(0.0, 100.0) // Clamp entre 0 et 100
+    }
+
+    /// Paliers de chaque composante, en fraction de ses points maximum
+    pub fn composantes(metrics: &GlobalMetrics) -> ScoreComponents {
+        // 1. ATR - Seuils normalisés (1.0 = 1 Pip/Point), ATR Forex M1 typique : 1.0 - 3.0 pips
+        let atr = if metrics.mean_atr > 2.5 {
+            1.0 // Excellent : > 2.5 pips/points
         } else if metrics.mean_atr > 1.5 {
-            score += 25.0; // Très bon : 1.5-2.5 pips/points
+            25.0 / 30.0 // Très bon : 1.5-2.5 pips/points
         } else if metrics.mean_atr > 1.0 {
-            score += 20.0; // Bon : 1.0-1.5 pips/points
+            20.0 / 30.0 // Bon : 1.0-1.5 pips/points
         } else if metrics.mean_atr > 0.5 {
-            score += 10.0; // Acceptable : 0.5-1.0 pips/points
-        }
+            10.0 / 30.0 // Acceptable : 0.5-1.0 pips/points
+        } else {
+            0.0
+        };
 
-        // 2. Score Body Range (25 points max) - Seuils réalistes
-        // Body Range Forex : 25-45% est normal, >45% est excellent
-        if metrics.mean_body_range > 45.0 {
-            score += 25.0; // Excellent : mouvements directionnels forts
+        // 2. Body Range - Forex : 25-45% est normal, >45% est excellent
+        let body_range = if metrics.mean_body_range > 45.0 {
+            1.0 // Excellent : mouvements directionnels forts
         } else if metrics.mean_body_range > 35.0 {
-            score += 20.0; // Très bon
+            20.0 / 25.0 // Très bon
         } else if metrics.mean_body_range > 25.0 {
-            score += 15.0; // Bon
+            15.0 / 25.0 // Bon
         } else if metrics.mean_body_range > 15.0 {
-            score += 8.0; // Acceptable
-        }
+            8.0 / 25.0 // Acceptable
+        } else {
+            0.0
+        };
 
-        // 3. Score Volatilité (25 points max) - BONUS si volatile
-        // Plus c'est volatil, MIEUX c'est pour le scalping !
-        if metrics.mean_volatility > 0.30 {
-            score += 25.0; // Excellent : cryptos, exotiques
+        // 3. Volatilité - BONUS si volatile, plus c'est volatil MIEUX c'est pour le scalping
+        let volatility = if metrics.mean_volatility > 0.30 {
+            1.0 // Excellent : cryptos, exotiques
         } else if metrics.mean_volatility > 0.20 {
-            score += 20.0; // Très bon : paires majeures volatiles
+            20.0 / 25.0 // Très bon : paires majeures volatiles
         } else if metrics.mean_volatility > 0.10 {
-            score += 15.0; // Bon : volatilité correcte
+            15.0 / 25.0 // Bon : volatilité correcte
         } else if metrics.mean_volatility > 0.05 {
-            score += 8.0; // Acceptable
-        }
+            8.0 / 25.0 // Acceptable
+        } else {
+            0.0
+        };
 
-        // 4. Score Noise Ratio (10 points max) - Signal/Bruit
-        if metrics.mean_noise_ratio < 2.0 {
-            score += 10.0; // Excellent : signal propre
+        // 4. Noise Ratio - Signal/Bruit
+        let noise = if metrics.mean_noise_ratio < 2.0 {
+            1.0 // Excellent : signal propre
         } else if metrics.mean_noise_ratio < 3.0 {
-            score += 7.0; // Bon
+            0.7 // Bon
         } else if metrics.mean_noise_ratio < 4.0 {
-            score += 4.0; // Acceptable
-        }
+            0.4 // Acceptable
+        } else {
+            0.0
+        };
 
-        // 5. Score Breakout % (10 points max) - CRITIQUE pour Straddle
-        // % de bougies qui cassent significativement (>P80 ATR)
-        if metrics.mean_breakout_percentage > 15.0 {
-            score += 10.0; // Excellent : mouvements forts fréquents
+        // 5. Breakout % - CRITIQUE pour Straddle : bougies qui cassent significativement (>P80 ATR)
+        let breakout = if metrics.mean_breakout_percentage > 15.0 {
+            1.0 // Excellent : mouvements forts fréquents
         } else if metrics.mean_breakout_percentage > 10.0 {
-            score += 7.0; // Très bon
+            0.7 // Très bon
         } else if metrics.mean_breakout_percentage > 5.0 {
-            score += 4.0; // Acceptable
-        }
+            0.4 // Acceptable
+        } else {
+            0.0
+        };
 
-        // 6. Bonus données suffisantes (5 points max)
-        if metrics.total_candles > 100000 {
-            score += 5.0; // Données suffisantes pour fiabilité
+        // 6. Bonus données suffisantes
+        let data = if metrics.total_candles > 100000 {
+            1.0 // Données suffisantes pour fiabilité
         } else if metrics.total_candles > 50000 {
-            score += 3.0;
-        }
-
-        // 7. PÉNALITÉ: ATR élevé MAIS Noise élevé (contradiction)
-        // Volatilité chaotique = mauvais pour scalping propre
-        if metrics.mean_atr > 0.0002 && metrics.mean_noise_ratio > 3.0 {
-            score -= 15.0; // Volatilité mais signal chaotique = danger
-        }
-
-        // 8. PÉNALITÉ: BodyRange fort MAIS peu de Breakouts (indécision)
-        // Bougies directionnelles mais pas de cassures = signal faible
-        if metrics.mean_body_range > 40.0 && metrics.mean_breakout_percentage < 8.0 {
-            score -= 10.0; // Contrainte = trading moins net
-        }
+            0.6
+        } else {
+            0.0
+        };
 
-        // 10. PÉNALITÉ: Trop de fausses cassures (volatilité erratique)
-        // Breakout % très élevé + BodyRange faible = chaos, pas de direction
-        if metrics.mean_breakout_percentage > 25.0 && metrics.mean_body_range < 30.0 {
-            score -= 8.0; // Volatilité instable/chaotique = à éviter
+        ScoreComponents {
+            atr,
+            body_range,
+            volatility,
+            noise,
+            breakout,
+            data,
         }
-
-        score.clamp(0.0, 100.0) // Clamp entre 0 et 100
     }
 }
 
@@ -207,6 +234,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_weighted_score_follows_calibrated_weights() {
+        let metrics = GlobalMetrics {
+            mean_atr: 3.0,
+            mean_volatility: 0.0,
+            mean_body_range: 0.0,
+            mean_noise_ratio: 3.0, // 4 pts, sans pénalité ATR/bruit
+            mean_breakout_percentage: 0.0,
+            mean_volume_imbalance: 0.0,
+            mean_range: 0.0,
+            total_candles: 1000,
+        };
+        let default = ConfidenceScorer::calculer_score_confiance(&metrics);
+        assert!((default - 34.0).abs() < 1e-9);
+
+        let weights = ScoreWeights::from_features([60.0, 10.0, 10.0, 10.0, 10.0], 5.0);
+        let calibrated = ConfidenceScorer::calculer_score_pondere(&metrics, &weights);
+        assert!((calibrated - 64.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_confidence_bounds() {
         let test_cases = vec![
@@ -1,8 +1,9 @@
 // services/volatility/hourly_stats.rs - Calcul des statistiques horaires
 // Conforme .clinerules : < 150L, pas d'unwrap()
 
-use super::utils::{max, mean};
-use crate::models::{AssetProperties, Candle, HourlyStats, Result};
+use super::utils::{candle_weights, max, weighted_share};
+use crate::models::{AssetProperties, Candle, HourlyStats, Result, TimeDecay};
+use crate::services::statistics::{self, weighted_mean, weighted_quantile};
 use crate::services::MetricsCalculator;
 use std::collections::HashMap;
 use tracing::debug;
@@ -11,11 +12,16 @@ use tracing::debug;
 pub(super) struct HourlyStatsCalculator<'a> {
     candles: &'a [Candle],
     symbol: String,
+    decay: TimeDecay,
 }
 
 impl<'a> HourlyStatsCalculator<'a> {
-    pub(super) fn new(candles: &'a [Candle], symbol: String) -> Self {
-        Self { candles, symbol }
+    pub(super) fn new(candles: &'a [Candle], symbol: String, decay: TimeDecay) -> Self {
+        Self {
+            candles,
+            symbol,
+            decay,
+        }
     }
 
     /// Calcule les statistiques pour chaque heure (en UTC)
@@ -54,6 +60,8 @@ impl<'a> HourlyStatsCalculator<'a> {
                     noise_ratio_mean: 0.0,
                     breakout_percentage: 0.0,
                     events: Vec::new(),
+                    atr_mean_ci: None,
+                    breakout_percentage_ci: None,
                 });
             }
         }
@@ -81,31 +89,44 @@ impl<'a> HourlyStatsCalculator<'a> {
         // Normalisation des valeurs (Pips/Points)
         let asset_props = AssetProperties::from_symbol(&self.symbol);
 
+        // Poids par ancienneté (1.0 partout sans demi-vie configurée)
+        let weights = candle_weights(candles, self.candles, &self.decay);
+
         // Calcule les moyennes
-        let raw_atr_mean = mean(&atr_values); // FIX-01: Moyenne au lieu de last()
+        let raw_atr_mean = weighted_mean(&atr_values, &weights); // FIX-01: Moyenne au lieu de last()
         let raw_atr_max = max(&atr_values);
-        let raw_range_mean = mean(&tr_dist.true_ranges);
-        let raw_max_true_range = tr_dist.percentile_95; // FIX-01: Max Spike stabilisé (95e percentile)
+        let raw_range_mean = weighted_mean(&tr_dist.true_ranges, &weights);
+        let raw_max_true_range = if self.decay.is_enabled() {
+            weighted_quantile(&tr_dist.true_ranges, &weights, 0.95)
+        } else {
+            tr_dist.percentile_95 // FIX-01: Max Spike stabilisé (95e percentile)
+        };
 
         let atr_mean = asset_props.normalize(raw_atr_mean);
         let atr_max = asset_props.normalize(raw_atr_max);
         let max_true_range = asset_props.normalize(raw_max_true_range);
-        let volatility_mean = mean(&volatility_values); // En % (ne pas normaliser)
+        let volatility_mean = weighted_mean(&volatility_values, &weights); // En % (ne pas normaliser)
         let range_mean = asset_props.normalize(raw_range_mean);
         
-        let body_range_mean = mean(&body_ranges);
-        let shadow_ratio_mean = mean(&shadow_ratios);
-        let noise_ratio_mean = mean(&noise_ratios);
+        let body_range_mean = weighted_mean(&body_ranges, &weights);
+        let shadow_ratio_mean = weighted_mean(&shadow_ratios, &weights);
+        let noise_ratio_mean = weighted_mean(&noise_ratios, &weights);
 
         // Calculate breakout percentage first
         let breakout_count = tr_dist.is_breakout.iter().filter(|&&b| b).count();
-        let breakout_percentage =
-            (breakout_count as f64 / tr_dist.is_breakout.len() as f64) * 100.0;
+        let breakout_percentage = weighted_share(&tr_dist.is_breakout, &weights);
 
         // Direction Strength: Force directionnelle = (|directionalite| * cassures) / 10000
         // Note: Both values are percentages (0-100), so divide by 10000 to get result in 0-100 range
         let direction_strength = (body_range_mean.abs() * breakout_percentage) / 10000.0;
 
+        // Intervalles de confiance (unité d'échantillonnage : la bougie)
+        let normalized_atr: Vec<f64> = atr_values.iter().map(|v| asset_props.normalize(*v)).collect();
+        let atr_mean_ci = statistics::mean_interval(&normalized_atr);
+        let breakout_percentage_ci =
+            statistics::wilson_interval(breakout_count, tr_dist.is_breakout.len())
+                .map(|ci| ci.scaled(100.0));
+
         Ok(HourlyStats {
             hour,
             candle_count,
@@ -120,6 +141,8 @@ impl<'a> HourlyStatsCalculator<'a> {
             noise_ratio_mean,
             breakout_percentage,
             events: Vec::new(), // Sera rempli après par l'analyseur
+            atr_mean_ci,
+            breakout_percentage_ci,
         })
     }
 }
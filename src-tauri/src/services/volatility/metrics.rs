@@ -2,7 +2,8 @@
 // Conforme .clinerules : < 300L, pas d'unwrap()
 
 use super::confidence_scorer::ConfidenceScorer;
-use crate::models::{GlobalMetrics, HourlyStats};
+use crate::models::{ConfidenceInterval, GlobalMetrics, HourlyStats, ScoreWeights};
+use crate::services::statistics;
 
 /// Calculateur de métriques globales
 pub(super) struct MetricsAggregator;
@@ -29,6 +30,25 @@ impl MetricsAggregator {
             };
         }
 
+        Self::moyennes(&stats_with_data, total_candles)
+    }
+
+    /// Intervalle bootstrap du score de confiance (rééchantillonnage des heures avec données)
+    pub(super) fn intervalle_score_confiance(
+        hourly_stats: &[HourlyStats],
+        total_candles: usize,
+        weights: &ScoreWeights,
+    ) -> Option<ConfidenceInterval> {
+        let stats_with_data: Vec<&HourlyStats> =
+            hourly_stats.iter().filter(|h| h.candle_count > 0).collect();
+
+        statistics::bootstrap_interval(stats_with_data.len(), |indices| {
+            let sample: Vec<&HourlyStats> = indices.iter().map(|&i| stats_with_data[i]).collect();
+            Self::calculer_score_confiance(&Self::moyennes(&sample, total_candles), weights)
+        })
+    }
+
+    fn moyennes(stats_with_data: &[&HourlyStats], total_candles: usize) -> GlobalMetrics {
         let count = stats_with_data.len() as f64;
 
         GlobalMetrics {
@@ -64,7 +84,7 @@ impl MetricsAggregator {
     }
 
     /// Calcule le score de confiance - DÉLÉGUÉ au ConfidenceScorer
-    pub(super) fn calculer_score_confiance(metrics: &GlobalMetrics) -> f64 {
-        ConfidenceScorer::calculer_score_confiance(metrics)
+    pub(super) fn calculer_score_confiance(metrics: &GlobalMetrics, weights: &ScoreWeights) -> f64 {
+        ConfidenceScorer::calculer_score_pondere(metrics, weights)
     }
 }
@@ -10,15 +10,22 @@ mod hourly_stats;
 mod metrics;
 mod quarterly_aggregator;
 mod stats_15min;
+mod straddle_inputs;
+mod symbol_analysis;
 mod utils;
 mod volatility_duration_analyzer;
 mod volatility_heuristics;
 mod whipsaw_detector;
-mod whipsaw_simulator;
+mod whipsaw_stats;
 mod win_rate_calculator;
 
 // Ré-exporte l'analyseur principal
 pub use analyzer::VolatilityAnalyzer;
+pub use confidence_scorer::ConfidenceScorer;
 pub use volatility_duration_analyzer::VolatilityDurationAnalyzer;
-pub use whipsaw_detector::calculer_frequence_whipsaw;
+pub use symbol_analysis::{analyser_symbole, charger_historique_m1};
+pub use straddle_inputs::{config_whipsaw_par_defaut, noise_ratio_moyen, percentile_95_meches};
+pub use whipsaw_detector::{
+    agreger_whipsaws, calculer_frequence_whipsaw, frequence_whipsaw_evenements, WhipsawAnalysis,
+};
 pub use win_rate_calculator::simuler_taux_reussite_straddle;
@@ -2,7 +2,7 @@
 // Calcule les moyennes historiques de peak_duration, half_life et trade_exp
 
 use crate::models::Stats15Min;
-use crate::services::StraddleParameterService;
+use crate::services::{statistics, StraddleParameterService};
 
 /// Agrégateur de statistiques par quarter
 pub(super) struct QuarterlyAggregator;
@@ -56,6 +56,8 @@ impl QuarterlyAggregator {
                             straddle_parameters: None,
                             volatility_profile: None,
                             optimal_entry_minute: None,
+                            atr_mean_ci: None,
+                            breakout_percentage_ci: None,
                         });
                     } else {
                         // Calculer les moyennes des métriques ordinaires
@@ -123,6 +125,18 @@ impl QuarterlyAggregator {
                             None
                         };
 
+                        // Une seule instance : on garde ses intervalles (calculés par bougie),
+                        // sinon intervalle sur les moyennes des instances
+                        let (atr_mean_ci, breakout_percentage_ci) =
+                            if let [single] = instances.as_slice() {
+                                (single.atr_mean_ci.clone(), single.breakout_percentage_ci.clone())
+                            } else {
+                                let atr: Vec<f64> = instances.iter().map(|s| s.atr_mean).collect();
+                                let breakout: Vec<f64> =
+                                    instances.iter().map(|s| s.breakout_percentage).collect();
+                                (statistics::mean_interval(&atr), statistics::mean_interval(&breakout))
+                            };
+
                         // Compter les candles totaux sur la période
                         let total_candle_count: usize =
                             instances.iter().map(|s| s.candle_count).sum();
@@ -195,6 +209,8 @@ impl QuarterlyAggregator {
                             straddle_parameters: Some(straddle_params),
                             volatility_profile: final_profile,
                             optimal_entry_minute,
+                            atr_mean_ci,
+                            breakout_percentage_ci,
                         });
                     }
                 } else {
@@ -223,6 +239,8 @@ impl QuarterlyAggregator {
                         straddle_parameters: None,
                         volatility_profile: None,
                         optimal_entry_minute: None,
+                        atr_mean_ci: None,
+                        breakout_percentage_ci: None,
                     });
                 }
             }
@@ -1,8 +1,9 @@
 // services/volatility/stats_15min.rs - Calcul des statistiques pour 15 minutes (scalping)
 // Conforme .clinerules : < 150L, pas d'unwrap()
 
-use super::utils::{max, mean};
-use crate::models::{AssetProperties, Candle, Result, Stats15Min};
+use super::utils::{candle_weights, max, mean, weighted_share};
+use crate::models::{AssetProperties, Candle, Result, Stats15Min, TimeDecay};
+use crate::services::statistics::{self, weighted_mean, weighted_quantile};
 use crate::services::{MetricsCalculator, StraddleParameterService, VolatilityDurationAnalyzer};
 use chrono::Timelike;
 use std::collections::HashMap;
@@ -11,11 +12,12 @@ use tracing::debug;
 /// Calculateur de statistiques pour tranches de 15 minutes
 pub(super) struct Stats15MinCalculator<'a> {
     candles: &'a [Candle],
+    decay: TimeDecay,
 }
 
 impl<'a> Stats15MinCalculator<'a> {
-    pub(super) fn new(candles: &'a [Candle]) -> Self {
-        Self { candles }
+    pub(super) fn new(candles: &'a [Candle], decay: TimeDecay) -> Self {
+        Self { candles, decay }
     }
 
     /// Calcule les statistiques pour chaque tranche de 15 minutes (en UTC)
@@ -72,6 +74,8 @@ impl<'a> Stats15MinCalculator<'a> {
                         straddle_parameters: None,
                         volatility_profile: None,
                         optimal_entry_minute: None,
+                        atr_mean_ci: None,
+                        breakout_percentage_ci: None,
                     });
                 }
             }
@@ -118,6 +122,8 @@ impl<'a> Stats15MinCalculator<'a> {
                 straddle_parameters: None,
                 volatility_profile: None,
                 optimal_entry_minute: None,
+                atr_mean_ci: None,
+                breakout_percentage_ci: None,
             });
         }
 
@@ -141,33 +147,46 @@ impl<'a> Stats15MinCalculator<'a> {
             .unwrap_or("EURUSD");
         let asset_props = AssetProperties::from_symbol(symbol);
 
+        // Poids par ancienneté (1.0 partout sans demi-vie configurée)
+        let weights = candle_weights(candles, self.candles, &self.decay);
+
         // Calcule les moyennes
-        let raw_atr_mean = mean(&atr_values); // FIX-01: Moyenne au lieu de last()
+        let raw_atr_mean = weighted_mean(&atr_values, &weights); // FIX-01: Moyenne au lieu de last()
         let raw_atr_max = max(&atr_values);
-        let volatility_mean = mean(&volatility_values);
+        let volatility_mean = weighted_mean(&volatility_values, &weights);
         // TÂCHE 3: Utiliser True Range au lieu de simple H-L
-        let raw_range_mean = mean(&tr_dist.true_ranges);
-        let raw_max_true_range = tr_dist.percentile_95; // FIX-01: Max Spike stabilisé (95e percentile)
+        let raw_range_mean = weighted_mean(&tr_dist.true_ranges, &weights);
+        let raw_max_true_range = if self.decay.is_enabled() {
+            weighted_quantile(&tr_dist.true_ranges, &weights, 0.95)
+        } else {
+            tr_dist.percentile_95 // FIX-01: Max Spike stabilisé (95e percentile)
+        };
         
         let atr_mean = asset_props.normalize(raw_atr_mean);
         let atr_max = asset_props.normalize(raw_atr_max);
         let range_mean = asset_props.normalize(raw_range_mean);
         let max_true_range = asset_props.normalize(raw_max_true_range);
 
-        let body_range_mean = mean(&body_ranges);
-        let shadow_ratio_mean = mean(&shadow_ratios);
+        let body_range_mean = weighted_mean(&body_ranges, &weights);
+        let shadow_ratio_mean = weighted_mean(&shadow_ratios, &weights);
         let _tick_quality_mean = mean(&tick_qualities);
-        let noise_ratio_mean = mean(&noise_ratios);
+        let noise_ratio_mean = weighted_mean(&noise_ratios, &weights);
 
         // Calculate breakout percentage first
         let breakout_count = tr_dist.is_breakout.iter().filter(|&&b| b).count();
-        let breakout_percentage =
-            (breakout_count as f64 / tr_dist.is_breakout.len() as f64) * 100.0;
+        let breakout_percentage = weighted_share(&tr_dist.is_breakout, &weights);
 
         // Direction Strength: Force directionnelle = (|directionalite| * cassures) / 10000
         // Note: Both values are percentages (0-100), so divide by 10000 to get result in 0-100 range
         let direction_strength = (body_range_mean.abs() * breakout_percentage) / 10000.0;
 
+        // Intervalles de confiance (unité d'échantillonnage : la bougie)
+        let normalized_atr: Vec<f64> = atr_values.iter().map(|v| asset_props.normalize(*v)).collect();
+        let atr_mean_ci = statistics::mean_interval(&normalized_atr);
+        let breakout_percentage_ci =
+            statistics::wilson_interval(breakout_count, tr_dist.is_breakout.len())
+                .map(|ci| ci.scaled(100.0));
+
         // TÂCHE 4: Analyse réelle de décroissance de volatilité
         let (peak_duration, half_life, trade_exp) =
             match VolatilityDurationAnalyzer::analyser_depuis_bougies(hour, quarter, candles) {
@@ -198,24 +217,21 @@ impl<'a> Stats15MinCalculator<'a> {
 
         // Calcul du profil de volatilité minute par minute (0-14) pour le graphique
         let mut minute_ranges: Vec<Vec<f64>> = vec![Vec::new(); 15];
-        for candle in candles {
+        let mut minute_weights: Vec<Vec<f64>> = vec![Vec::new(); 15];
+        for (candle, weight) in candles.iter().zip(&weights) {
             let minute_idx = (candle.datetime.minute() % 15) as usize;
             if minute_idx < 15 {
                 let raw_range = candle.high - candle.low;
                 let normalized_range = asset_props.normalize(raw_range);
                 minute_ranges[minute_idx].push(normalized_range);
+                minute_weights[minute_idx].push(*weight);
             }
         }
 
         let volatility_profile: Vec<f64> = minute_ranges
             .iter()
-            .map(|ranges| {
-                if ranges.is_empty() {
-                    0.0
-                } else {
-                    ranges.iter().sum::<f64>() / ranges.len() as f64
-                }
-            })
+            .zip(&minute_weights)
+            .map(|(ranges, weights)| weighted_mean(ranges, weights))
             .collect();
 
         // Détermination de la minute optimale (début de l'accélération ou pic)
@@ -250,6 +266,8 @@ impl<'a> Stats15MinCalculator<'a> {
             straddle_parameters: Some(straddle_params),
             volatility_profile: Some(volatility_profile),
             optimal_entry_minute,
+            atr_mean_ci,
+            breakout_percentage_ci,
         })
     }
 }
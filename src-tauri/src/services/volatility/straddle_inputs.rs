@@ -0,0 +1,105 @@
+// services/volatility/straddle_inputs.rs - Paramètres et mesures Straddle dérivés des bougies
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::{AssetProperties, Candle};
+use crate::services::backtest::BacktestConfig;
+use crate::services::straddle_simulator_helpers::{calculer_atr_moyen, get_asset_cost};
+use crate::services::StraddleParameterService;
+
+/// Config backtest dérivée de l'ATR des bougies (même logique que l'offset optimal)
+pub fn config_whipsaw_par_defaut(
+    candles: &[Candle],
+    offset_pips: f64,
+    symbol: &str,
+) -> BacktestConfig {
+    let asset_props = AssetProperties::from_symbol(symbol);
+    let atr_mean = asset_props.normalize(calculer_atr_moyen(candles));
+    let params = StraddleParameterService::calculate_parameters(
+        atr_mean,
+        noise_ratio_moyen(candles, asset_props.pip_value),
+        asset_props.pip_value,
+        None,
+        None,
+    );
+
+    BacktestConfig {
+        offset_pips,
+        stop_loss_pips: params.stop_loss_pips,
+        trailing_stop_pips: params.trailing_stop_pips,
+        timeout_minutes: params.timeout_minutes,
+        sl_recovery_pips: Some(params.sl_recovery_pips),
+        spread_pips: get_asset_cost(symbol).spread_pips,
+        point_value: asset_props.pip_value,
+    }
+}
+
+/// 95e percentile des mèches (hautes et basses, en prix) des bougies
+pub fn percentile_95_meches(candles: &[Candle]) -> f64 {
+    let mut wicks: Vec<f64> = candles
+        .iter()
+        .flat_map(|c| [c.high - c.close.max(c.open), c.open.min(c.close) - c.low])
+        .filter(|w| *w > 0.0)
+        .collect();
+    wicks.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let p95_index = ((wicks.len() as f64) * 0.95).ceil() as usize;
+    let p95_index = p95_index.min(wicks.len().saturating_sub(1));
+    wicks.get(p95_index).copied().unwrap_or(0.0)
+}
+
+/// Noise Ratio moyen (range / corps) des bougies
+pub fn noise_ratio_moyen(candles: &[Candle], pip_value: f64) -> f64 {
+    if candles.is_empty() {
+        return 1.0;
+    }
+    let sum: f64 = candles
+        .iter()
+        .map(|c| {
+            let range = c.high - c.low;
+            let body = (c.open - c.close).abs();
+            if body < pip_value * 0.1 {
+                if range < pip_value * 0.1 {
+                    1.0
+                } else {
+                    5.0
+                }
+            } else {
+                range / body
+            }
+        })
+        .sum();
+    sum / candles.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn candle(open: f64, high: f64, low: f64, close: f64) -> Candle {
+        Candle {
+            id: None,
+            symbol: "EURUSD".to_string(),
+            datetime: Utc
+                .with_ymd_and_hms(2024, 1, 2, 14, 30, 0)
+                .single()
+                .expect("date"),
+            open,
+            high,
+            low,
+            close,
+            volume: 1000.0,
+            spread: Some(0.0),
+        }
+    }
+
+    #[test]
+    fn test_percentile_95_meches_ignores_missing_wicks() {
+        // Bougie pleine (aucune mèche) puis mèche haute de 5 pips
+        let candles = vec![
+            candle(1.1000, 1.1010, 1.1000, 1.1010),
+            candle(1.1000, 1.1015, 1.1000, 1.1010),
+        ];
+        assert!((percentile_95_meches(&candles) - 0.0005).abs() < 1e-9);
+        assert_eq!(percentile_95_meches(&[]), 0.0);
+    }
+}
@@ -0,0 +1,108 @@
+// services/volatility/symbol_analysis.rs - Analyse de volatilité d'un symbole sur tout son historique
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use super::VolatilityAnalyzer;
+use crate::db::DbPool;
+use crate::models::{AnalysisResult, Candle, RegimeFilter, Result, VolatilityError};
+use crate::services::input_fingerprint::Fingerprinter;
+use crate::services::volatility_regime::RegimeClassifier;
+use crate::services::{CsvLoader, DatabaseLoader};
+use chrono::{DateTime, Utc};
+use tracing::{error, info};
+
+/// Charge tout l'historique M1 d'un symbole (DatabaseLoader, sinon CsvLoader)
+pub fn charger_historique_m1(symbol: &str, pairs_pool: Option<&DbPool>) -> Result<Vec<Candle>> {
+    let mut candles = Vec::new();
+    if let Some(pool) = pairs_pool {
+        let db_loader = DatabaseLoader::new(pool.clone());
+        let start = DateTime::<Utc>::from_timestamp(0, 0).ok_or_else(|| {
+            VolatilityError::ValidationError("Invalid Unix timestamp 0 for date range".to_string())
+        })?;
+        match db_loader.load_candles_by_pair(symbol, "M1", start, Utc::now()) {
+            Ok(loaded) => {
+                candles = loaded;
+                info!(
+                    "Loaded {} candles for {} from DatabaseLoader",
+                    candles.len(),
+                    symbol
+                );
+            }
+            Err(e) => {
+                info!(
+                    "DatabaseLoader failed for {}: {}, falling back to CsvLoader",
+                    symbol, e
+                );
+            }
+        }
+    }
+
+    if candles.is_empty() {
+        candles = CsvLoader::new().load_candles(symbol).map_err(|e| {
+            error!(
+                "Failed to load candles for {} from both DB and CSV: {}",
+                symbol, e
+            );
+            e
+        })?;
+        info!(
+            "Loaded {} candles for {} from CsvLoader",
+            candles.len(),
+            symbol
+        );
+    }
+
+    info!("Total candles loaded for {}: {}", symbol, candles.len());
+    Ok(candles)
+}
+
+/// Analyse de volatilité d'un symbole, restreinte au régime demandé, empreinte des entrées comprise
+pub fn analyser_symbole(
+    symbol: &str,
+    calendar_id: Option<i32>,
+    regime: Option<RegimeFilter>,
+    calendar_pool: Option<DbPool>,
+    pairs_pool: Option<DbPool>,
+) -> Result<AnalysisResult> {
+    let mut candles = charger_historique_m1(symbol, pairs_pool.as_ref())?;
+    let mut target_regime = None;
+    if let Some(filter) = regime {
+        let (restreintes, target) = RegimeClassifier::restreindre(symbol, &candles, filter)
+            .map_err(VolatilityError::InsufficientData)?;
+        candles = restreintes;
+        target_regime = Some(target);
+    }
+
+    let mut result = VolatilityAnalyzer::new(candles)
+        .analyze(symbol, calendar_pool)
+        .map_err(|e| {
+            error!("Failed to analyze {}: {}", symbol, e);
+            e
+        })?;
+    result.regime = target_regime;
+    if let Some(pairs_pool) = pairs_pool {
+        let candles = DatabaseLoader::new(pairs_pool)
+            .candle_range(symbol, "M1")
+            .map_err(|e| VolatilityError::DatabaseError(e.to_string()))?;
+        let parameters = [(
+            "regime".to_string(),
+            Fingerprinter::parametre(&regime).map_err(VolatilityError::ValidationError)?,
+        )]
+        .into();
+        result.fingerprint = Some(
+            Fingerprinter::construire(
+                vec![candles],
+                calendar_id.into_iter().collect(),
+                None,
+                0,
+                parameters,
+            )
+            .map_err(VolatilityError::ValidationError)?,
+        );
+    }
+
+    info!(
+        "Analysis complete for {}: confidence={:.1}",
+        symbol, result.confidence_score
+    );
+    Ok(result)
+}
@@ -1,5 +1,8 @@
 // services/volatility/utils.rs - Fonctions utilitaires
-// Conforme .clinerules : helpers simples, < 50L
+// Conforme .clinerules : helpers simples, < 100L
+
+use crate::models::{Candle, TimeDecay};
+use crate::services::statistics::weighted_mean;
 
 /// Calcule la moyenne d'un vecteur
 pub(crate) fn mean(values: &[f64]) -> f64 {
@@ -16,6 +19,24 @@ pub(crate) fn max(values: &[f64]) -> f64 {
         .fold(0.0, |acc, &x| if x > acc { x } else { acc })
 }
 
+/// Poids par ancienneté des bougies d'un groupe, référence = dernière bougie de l'historique
+pub(crate) fn candle_weights(group: &[&Candle], history: &[Candle], decay: &TimeDecay) -> Vec<f64> {
+    let reference = history
+        .last()
+        .map(|c| c.datetime.date_naive())
+        .unwrap_or_default();
+    group
+        .iter()
+        .map(|c| decay.weight(c.datetime.date_naive(), reference))
+        .collect()
+}
+
+/// Part pondérée (en %) des observations vraies
+pub(crate) fn weighted_share(flags: &[bool], weights: &[f64]) -> f64 {
+    let values: Vec<f64> = flags.iter().map(|&f| if f { 100.0 } else { 0.0 }).collect();
+    weighted_mean(&values, weights)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
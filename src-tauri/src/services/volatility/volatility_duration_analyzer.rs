@@ -183,6 +183,7 @@ mod tests {
             low,
             close,
             volume: 1000.0,
+            spread: None,
         }
     }
 
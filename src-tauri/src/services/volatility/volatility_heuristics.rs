@@ -126,6 +126,8 @@ mod tests {
             straddle_parameters: None,
             volatility_profile: None,
             optimal_entry_minute: None,
+            atr_mean_ci: None,
+            breakout_percentage_ci: None,
         }
     }
 
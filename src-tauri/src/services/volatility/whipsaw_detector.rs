@@ -1,254 +1,230 @@
 // services/volatility/whipsaw_detector.rs - Détection de fausses cassures (whipsaw)
-// CORRECTION PHASE 8: Simulation réaliste d'un Straddle
-use super::whipsaw_simulator::{simulate_straddle_trade, TradeResult};
-use crate::models::Candle;
-use tracing::{debug, info};
-
-/// Calcule la fréquence des whipsaws de manière réaliste
-///
-/// Logique corrigée :
-/// 1. Test UN SEUL trade par jour (à l'heure optimale)
-/// 2. Fenêtre : quarter optimal + 1 heure (60 candles M1)
-/// 3. Whipsaw = Buy + Sell triggers + au moins 1 SL hit
-/// 4. Compte win/loss réels avec TP/SL dynamiques
-pub fn calculer_frequence_whipsaw(candles: &[Candle], offset_pips: f64, symbol: &str) -> WhipsawAnalysis {
-    if candles.len() < 61 {
-        // Besoin au moins 61 candles (1 min entry + 60 min test window)
-        return WhipsawAnalysis::default();
-    }
-
-    let asset_props = crate::models::AssetProperties::from_symbol(symbol);
-
-    info!("🔄 Calculant whipsaw frequency (mode réaliste) pour {}", symbol);
-    info!("   - Offset: {} pips", offset_pips);
-    info!("   - Fenêtre: 60 candles (quarter + 1h)");
-
-    let mut daily_groups: std::collections::HashMap<String, Vec<&Candle>> =
-        std::collections::HashMap::new();
-
-    // Grouper par jour
-    for candle in candles.iter() {
-        let day_key = candle.datetime.format("%Y-%m-%d").to_string();
-        daily_groups
-            .entry(day_key)
-            .or_insert_with(Vec::new)
-            .push(candle);
-    }
-
-    let mut win_count = 0;
-    let mut loss_count = 0;
-    let mut whipsaws = Vec::new();
-    let mut simulated_trades = Vec::new();
-
-    // Pour chaque jour, simuler UN trade au quarter optimal
-    for (day_key, day_candles) in daily_groups.iter() {
-        if day_candles.len() < 61 {
-            continue; // Pas assez de candles dans la journée
-        }
-
-        // Tester le trade à l'index 0 de la journée (quarter optimal)
-        // Dans une vraie implémentation, on choisirait l'ATR peak de la journée
-        let entry_index = 0;
-        let entry_candle = day_candles[entry_index];
-        let entry_price = entry_candle.close;
-
-        // Fenêtre de test : 60 candles suivantes (quarter + 1h)
-        let window_end = (entry_index + 61).min(day_candles.len());
-        if window_end - entry_index < 61 {
-            continue; // Pas assez de candles après l'entrée
-        }
+// Conforme .clinerules : < 300L, pas d'unwrap()
+//
+// Ancrage sur l'heure réelle des événements (ou un créneau HH:MM) et même modèle
+// d'ordres que le backtest : un whipsaw est un trade dont la jambe opposée se déclenche.
+use super::whipsaw_stats::{delay_distribution, delay_stats, niveau_risque};
+pub use super::whipsaw_stats::{WhipsawDelayBucket, WhipsawDelayStats, WhipsawRiskLevel};
+use crate::models::input_fingerprint::InputFingerprint;
+use crate::models::{CalendarEvent, Candle, ConfidenceInterval};
+use crate::services::backtest::models::{TradeOutcome, TradeResult};
+use crate::services::backtest::simulator::EventSimulator;
+use crate::services::backtest::{BacktestConfig, BacktestEngine, StrategyMode};
+use crate::services::statistics;
+use chrono::NaiveTime;
+use serde::Serialize;
+use tracing::info;
+
+/// Analyse des whipsaws ancrée sur des événements (ou un créneau horaire)
+#[derive(Debug, Clone, Serialize)]
+pub struct WhipsawAnalysis {
+    pub symbol: String,
+    /// Événement ou créneau d'ancrage ("Non-Farm Payrolls", "14:30")
+    pub anchor: String,
+    pub anchors_simulated: usize,
+    /// Ancres où au moins une jambe a été déclenchée
+    pub triggered_trades: usize,
+    pub whipsaw_count: usize,
+    /// whipsaw_count / triggered_trades
+    pub whipsaw_frequency: f64,
+    /// Intervalle de Wilson de la fréquence (None sans trade déclenché)
+    pub whipsaw_frequency_ci: Option<ConfidenceInterval>,
+    /// Whipsaws dont la jambe opposée a fini en gain net
+    pub recovered_count: usize,
+    pub double_loss_count: usize,
+    pub delay: Option<WhipsawDelayStats>,
+    pub delay_distribution: Vec<WhipsawDelayBucket>,
+    pub risk_level: WhipsawRiskLevel,
+    pub occurrences: Vec<WhipsawOccurrence>,
+    /// Entrées du calcul (reproductibilité)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<InputFingerprint>,
+}
 
-        let test_window = &day_candles[entry_index + 1..window_end];
-
-        // Paramètres dynamiques basés sur ATR (simplifié pour cette version)
-        // ATR moyen = offset_pips / 1.5 (estimation)
-        let atr_estimate = offset_pips / 1.5;
-        let sl_pips = atr_estimate * 0.5; // SL = ATR * 0.5
-        let tp_pips = atr_estimate * 1.0; // TP = ATR * 1.0
-
-        // Simuler le Straddle
-        let result =
-            simulate_straddle_trade(entry_price, offset_pips, sl_pips, tp_pips, test_window, asset_props.pip_value);
-
-        debug!(
-            "📊 {}: Trade simulation - entry={:.4}, offset={}, SL={}, TP={}, result={:?}",
-            day_key, entry_price, offset_pips, sl_pips, tp_pips, result
-        );
-
-        match result {
-            TradeResult::Win => win_count += 1,
-            TradeResult::Loss => {
-                loss_count += 1;
-                whipsaws.push(WhipsawDetail {
-                    entry_index,
-                    entry_price,
-                    buy_stop: entry_price + asset_props.denormalize(offset_pips),
-                    sell_stop: entry_price - asset_props.denormalize(offset_pips),
-                    buy_trigger_index: 0,
-                    sell_trigger_index: 0,
-                });
-            }
-            TradeResult::Timeout => {
-                // Timeout = pas de décision, ne compte pas
-            }
-        }
+/// Whipsaw survenu sur une ancre
+#[derive(Debug, Clone, Serialize)]
+pub struct WhipsawOccurrence {
+    pub event_date: String,
+    pub delay_minutes: i32,
+    pub pips_net: f64,
+    pub outcome: TradeOutcome,
+}
 
-        simulated_trades.push((day_key.clone(), result));
-    }
+/// Whipsaws sur un créneau HH:MM de chaque jour présent dans les bougies fournies.
+/// Chaque jour est simulé avec le modèle du backtest (mode Simultané : la jambe
+/// opposée est activée quand la première est stoppée) sur une fenêtre de bougies.
+pub fn calculer_frequence_whipsaw(
+    candles: &[Candle],
+    slot: NaiveTime,
+    config: &BacktestConfig,
+    symbol: &str,
+) -> WhipsawAnalysis {
+    let anchor = slot.format("%H:%M").to_string();
+    let (Some(first), Some(last)) = (
+        candles.iter().map(|c| c.datetime).min(),
+        candles.iter().map(|c| c.datetime).max(),
+    ) else {
+        return agreger_whipsaws(symbol, &anchor, &[]);
+    };
+    let events = BacktestEngine::time_slot_events(slot, first.date_naive(), last.date_naive());
+    frequence_whipsaw_evenements(candles, &events, &anchor, config, symbol)
+}
 
-    let total_trades = win_count + loss_count;
-    let whipsaw_frequency = if total_trades > 0 {
-        loss_count as f64 / total_trades as f64
+/// Whipsaws ancrés sur des événements datés (ex. une annonce précise du calendrier)
+pub fn frequence_whipsaw_evenements(
+    candles: &[Candle],
+    events: &[CalendarEvent],
+    anchor: &str,
+    config: &BacktestConfig,
+    symbol: &str,
+) -> WhipsawAnalysis {
+    // Fenêtres par recherche dichotomique : bougies triées une seule fois si besoin
+    let mut triees = Vec::new();
+    let candles = if candles.is_sorted_by_key(|c| c.datetime) {
+        candles
     } else {
-        0.0
+        triees.extend_from_slice(candles);
+        triees.sort_by_key(|c| c.datetime);
+        &triees
     };
 
     info!(
-        "✅ Résultats: {} wins, {} losses ({:.1}% whipsaw), {} timeouts",
-        win_count,
-        loss_count,
-        whipsaw_frequency * 100.0,
-        daily_groups.len() - total_trades
+        "🔄 Whipsaw ancré sur {} pour {} (offset {} pips, timeout {} min)",
+        anchor, symbol, config.offset_pips, config.timeout_minutes
     );
 
-    WhipsawAnalysis {
-        total_trades,
-        whipsaw_count: loss_count,
-        whipsaw_frequency,
-        offset_pips,
-        candles_analyzed: candles.len(),
-        whipsaws,
-        risk_level: calculer_niveau_risque(whipsaw_frequency),
-    }
+    let window = chrono::Duration::minutes(config.timeout_minutes as i64 + 10);
+    let trades: Vec<TradeResult> = events
+        .iter()
+        .filter_map(|event| {
+            let start = event.event_time.and_utc();
+            let lo = candles.partition_point(|c| c.datetime < start);
+            let hi = candles.partition_point(|c| c.datetime <= start + window);
+            let fenetre = candles.get(lo..hi).filter(|f| !f.is_empty())?;
+            Some(EventSimulator::simulate(
+                event,
+                fenetre,
+                config,
+                StrategyMode::Simultane,
+            ))
+        })
+        .collect();
+    agreger_whipsaws(symbol, anchor, &trades)
 }
 
-/// Évalue le niveau de risque basé sur la fréquence de whipsaw (réaliste)
-/// CORRECTION: Seuils ajustés pour simulation réaliste (pas 1425 tests/jour)
-fn calculer_niveau_risque(whipsaw_frequency: f64) -> WhipsawRiskLevel {
-    if whipsaw_frequency < 0.05 {
-        WhipsawRiskLevel::VeryLow // < 5%
-    } else if whipsaw_frequency < 0.15 {
-        WhipsawRiskLevel::Low // 5-15%
-    } else if whipsaw_frequency < 0.30 {
-        WhipsawRiskLevel::Moderate // 15-30%
-    } else if whipsaw_frequency < 0.50 {
-        WhipsawRiskLevel::High // 30-50%
+/// Agrège les trades simulés : fréquence, délais de la jambe opposée et issue
+pub fn agreger_whipsaws(symbol: &str, anchor: &str, trades: &[TradeResult]) -> WhipsawAnalysis {
+    let triggered: Vec<&TradeResult> = trades
+        .iter()
+        .filter(|t| t.outcome != TradeOutcome::NoEntry)
+        .collect();
+    let occurrences: Vec<WhipsawOccurrence> = triggered
+        .iter()
+        .filter_map(|t| {
+            Some(WhipsawOccurrence {
+                event_date: t.event_date.clone(),
+                delay_minutes: t.opposite_leg_delay_minutes?,
+                pips_net: t.pips_net,
+                outcome: t.outcome.clone(),
+            })
+        })
+        .collect();
+
+    let whipsaw_frequency = if triggered.is_empty() {
+        0.0
     } else {
-        WhipsawRiskLevel::VeryHigh // > 50%
-    }
-}
-
-/// Résultat de l'analyse des whipsaws
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub struct WhipsawAnalysis {
-    pub total_trades: usize,
-    pub whipsaw_count: usize,
-    pub whipsaw_frequency: f64,
-    pub offset_pips: f64,
-    pub candles_analyzed: usize,
-    pub whipsaws: Vec<WhipsawDetail>,
-    pub risk_level: WhipsawRiskLevel,
-}
-
-impl Default for WhipsawAnalysis {
-    fn default() -> Self {
-        Self {
-            total_trades: 0,
-            whipsaw_count: 0,
-            whipsaw_frequency: 0.0,
-            offset_pips: 0.0,
-            candles_analyzed: 0,
-            whipsaws: Vec::new(),
-            risk_level: WhipsawRiskLevel::Unknown,
-        }
-    }
-}
-
-/// Détail d'un whipsaw survenu
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub struct WhipsawDetail {
-    pub entry_index: usize,
-    pub entry_price: f64,
-    pub buy_stop: f64,
-    pub sell_stop: f64,
-    pub buy_trigger_index: usize,
-    pub sell_trigger_index: usize,
-}
+        occurrences.len() as f64 / triggered.len() as f64
+    };
 
-/// Niveau de risque basé sur la fréquence de whipsaw
-/// CORRECTION PHASE 8: Seuils réalistes (simulation 1 trade/jour, 60 min window)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum WhipsawRiskLevel {
-    VeryLow,  // < 5%   - Excellent, très peu de fausses sorties
-    Low,      // 5-15%  - Bon, fausses sorties rares
-    Moderate, // 15-30% - Acceptable, quelques fausses sorties
-    High,     // 30-50% - Risqué, beaucoup de fausses sorties
-    VeryHigh, // > 50%  - Très risqué, la majorité sont des whipsaws
-    Unknown,  // Données insuffisantes
-}
+    let mut delays: Vec<i32> = occurrences.iter().map(|o| o.delay_minutes).collect();
+    delays.sort_unstable();
 
-impl WhipsawRiskLevel {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            WhipsawRiskLevel::VeryLow => "Très Bas",
-            WhipsawRiskLevel::Low => "Bas",
-            WhipsawRiskLevel::Moderate => "Modéré",
-            WhipsawRiskLevel::High => "Élevé",
-            WhipsawRiskLevel::VeryHigh => "Très Élevé",
-            WhipsawRiskLevel::Unknown => "Inconnu",
-        }
-    }
-
-    pub fn color(&self) -> &'static str {
-        match self {
-            WhipsawRiskLevel::VeryLow => "#22c55e",  // Green
-            WhipsawRiskLevel::Low => "#84cc16",      // Lime
-            WhipsawRiskLevel::Moderate => "#eab308", // Yellow
-            WhipsawRiskLevel::High => "#f97316",     // Orange
-            WhipsawRiskLevel::VeryHigh => "#ef4444", // Red
-            WhipsawRiskLevel::Unknown => "#6b7280",  // Gray
-        }
+    WhipsawAnalysis {
+        symbol: symbol.to_string(),
+        anchor: anchor.to_string(),
+        anchors_simulated: trades.len(),
+        triggered_trades: triggered.len(),
+        whipsaw_count: occurrences.len(),
+        whipsaw_frequency,
+        whipsaw_frequency_ci: statistics::wilson_interval(occurrences.len(), triggered.len()),
+        recovered_count: occurrences.iter().filter(|o| o.pips_net > 0.0).count(),
+        double_loss_count: occurrences
+            .iter()
+            .filter(|o| o.outcome == TradeOutcome::DoubleLoss)
+            .count(),
+        delay: delay_stats(&delays),
+        delay_distribution: delay_distribution(&delays),
+        risk_level: if triggered.is_empty() {
+            WhipsawRiskLevel::Unknown
+        } else {
+            niveau_risque(whipsaw_frequency)
+        },
+        occurrences,
+        fingerprint: None,
     }
 }
 
-/// PHASE 7a-2: Whipsaw Root Cause Analysis
-/// Classifier whipsaws en "early" (avant peak) et "late" (après peak)
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
+    use chrono::{TimeZone, Utc};
 
-    fn create_test_candle(i: usize, high: f64, low: f64) -> Candle {
+    fn candle(minute: i64, open: f64, high: f64, low: f64, close: f64) -> Candle {
         Candle {
             id: None,
             symbol: "EURUSD".to_string(),
-            datetime: Utc::now() + chrono::Duration::minutes(i as i64),
-            open: 1.0855,
+            datetime: Utc
+                .with_ymd_and_hms(2024, 1, 2, 14, 30, 0)
+                .single()
+                .expect("date")
+                + chrono::Duration::minutes(minute),
+            open,
             high,
             low,
-            close: 1.0855 + (high - 1.0855) / 2.0,
+            close,
             volume: 1000.0,
+            spread: Some(0.0),
         }
     }
 
-    #[test]
-    fn test_whipsaw_detection_basic() {
-        let mut candles = Vec::new();
-        for i in 0..100 {
-            // Range large pour déclencher des trades avec un petit offset
-            candles.push(create_test_candle(i, 1.0895, 1.0815));
+    fn config() -> BacktestConfig {
+        BacktestConfig {
+            offset_pips: 5.0,
+            stop_loss_pips: 5.0,
+            trailing_stop_pips: 20.0,
+            timeout_minutes: 30,
+            sl_recovery_pips: Some(5.0),
+            spread_pips: 0.0,
+            point_value: 0.0001,
         }
-        let result = calculer_frequence_whipsaw(&candles, 10.0, "EURUSD");
-        assert!(result.total_trades > 0);
+    }
+
+    #[test]
+    fn test_whipsaw_anchored_on_slot_reports_opposite_leg_delay() {
+        // 14:29 hors créneau, 14:30 cassure haussière, 14:34 retour sous le SL
+        let mut candles = vec![candle(-1, 1.1000, 1.1100, 1.0900, 1.1000)];
+        candles.push(candle(0, 1.1000, 1.1006, 1.1001, 1.1005));
+        candles.extend((1..4).map(|m| candle(m, 1.1005, 1.1006, 1.1003, 1.1005)));
+        candles.push(candle(4, 1.1005, 1.1005, 1.0995, 1.0996));
+        candles.extend((5..40).map(|m| candle(m, 1.0996, 1.0997, 1.0995, 1.0996)));
+
+        let slot = NaiveTime::from_hms_opt(14, 30, 0).expect("heure");
+        let analysis = calculer_frequence_whipsaw(&candles, slot, &config(), "EURUSD");
+
+        assert_eq!(analysis.anchor, "14:30");
+        assert_eq!(analysis.triggered_trades, 1);
+        assert_eq!(analysis.whipsaw_count, 1);
+        assert_eq!(analysis.occurrences[0].delay_minutes, 4);
+        assert_eq!(analysis.delay_distribution[2].count, 1);
+        assert_eq!(analysis.risk_level, WhipsawRiskLevel::VeryHigh);
     }
 
     #[test]
     fn test_whipsaw_empty_data() {
-        let candles = vec![];
-        let result = calculer_frequence_whipsaw(&candles, 50.0, "EURUSD");
-        assert_eq!(result.total_trades, 0);
+        let slot = NaiveTime::from_hms_opt(14, 30, 0).expect("heure");
+        let analysis = calculer_frequence_whipsaw(&[], slot, &config(), "EURUSD");
+        assert_eq!(analysis.triggered_trades, 0);
+        assert!(analysis.delay.is_none());
+        assert_eq!(analysis.risk_level, WhipsawRiskLevel::Unknown);
     }
 }
@@ -0,0 +1,112 @@
+// services/volatility/whipsaw_stats.rs - Délais de la jambe opposée et niveau de risque whipsaw
+// Conforme .clinerules : < 200L, pas d'unwrap()
+
+use serde::Serialize;
+
+/// Tranches de délai (minutes après l'entrée) pour la distribution des whipsaws
+const DELAY_BUCKETS: [(i32, i32, &str); 7] = [
+    (0, 1, "≤ 1 min"),
+    (2, 3, "2-3 min"),
+    (4, 5, "4-5 min"),
+    (6, 10, "6-10 min"),
+    (11, 15, "11-15 min"),
+    (16, 30, "16-30 min"),
+    (31, i32::MAX, "> 30 min"),
+];
+
+/// Délai entre la première entrée et la jambe opposée (minutes)
+#[derive(Debug, Clone, Serialize)]
+pub struct WhipsawDelayStats {
+    pub min: i32,
+    pub median: f64,
+    pub mean: f64,
+    pub p90: i32,
+    pub max: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WhipsawDelayBucket {
+    pub label: String,
+    pub count: usize,
+}
+
+/// Répartition des délais par tranche
+pub(super) fn delay_distribution(delays: &[i32]) -> Vec<WhipsawDelayBucket> {
+    DELAY_BUCKETS
+        .iter()
+        .map(|(min, max, label)| WhipsawDelayBucket {
+            label: label.to_string(),
+            count: delays.iter().filter(|d| (*min..=*max).contains(*d)).count(),
+        })
+        .collect()
+}
+
+/// Statistiques sur des délais déjà triés
+pub(super) fn delay_stats(sorted: &[i32]) -> Option<WhipsawDelayStats> {
+    let (&min, &max) = (sorted.first()?, sorted.last()?);
+    let n = sorted.len();
+    let median = if n.is_multiple_of(2) {
+        (sorted[n / 2 - 1] + sorted[n / 2]) as f64 / 2.0
+    } else {
+        sorted[n / 2] as f64
+    };
+    // Rang le plus proche
+    let p90_rank = ((0.9 * n as f64).ceil() as usize).clamp(1, n);
+    Some(WhipsawDelayStats {
+        min,
+        median,
+        mean: sorted.iter().map(|d| *d as f64).sum::<f64>() / n as f64,
+        p90: sorted[p90_rank - 1],
+        max,
+    })
+}
+
+/// Évalue le niveau de risque basé sur la fréquence de whipsaw (réaliste)
+pub(super) fn niveau_risque(whipsaw_frequency: f64) -> WhipsawRiskLevel {
+    if whipsaw_frequency < 0.05 {
+        WhipsawRiskLevel::VeryLow // < 5%
+    } else if whipsaw_frequency < 0.15 {
+        WhipsawRiskLevel::Low // 5-15%
+    } else if whipsaw_frequency < 0.30 {
+        WhipsawRiskLevel::Moderate // 15-30%
+    } else if whipsaw_frequency < 0.50 {
+        WhipsawRiskLevel::High // 30-50%
+    } else {
+        WhipsawRiskLevel::VeryHigh // > 50%
+    }
+}
+
+/// Niveau de risque basé sur la fréquence de whipsaw
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum WhipsawRiskLevel {
+    VeryLow,  // < 5%   - Excellent, très peu de fausses sorties
+    Low,      // 5-15%  - Bon, fausses sorties rares
+    Moderate, // 15-30% - Acceptable, quelques fausses sorties
+    High,     // 30-50% - Risqué, beaucoup de fausses sorties
+    VeryHigh, // > 50%  - Très risqué, la majorité sont des whipsaws
+    Unknown,  // Données insuffisantes
+}
+
+impl WhipsawRiskLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WhipsawRiskLevel::VeryLow => "Très Bas",
+            WhipsawRiskLevel::Low => "Bas",
+            WhipsawRiskLevel::Moderate => "Modéré",
+            WhipsawRiskLevel::High => "Élevé",
+            WhipsawRiskLevel::VeryHigh => "Très Élevé",
+            WhipsawRiskLevel::Unknown => "Inconnu",
+        }
+    }
+
+    pub fn color(&self) -> &'static str {
+        match self {
+            WhipsawRiskLevel::VeryLow => "#22c55e",  // Green
+            WhipsawRiskLevel::Low => "#84cc16",      // Lime
+            WhipsawRiskLevel::Moderate => "#eab308", // Yellow
+            WhipsawRiskLevel::High => "#f97316",     // Orange
+            WhipsawRiskLevel::VeryHigh => "#ef4444", // Red
+            WhipsawRiskLevel::Unknown => "#6b7280",  // Gray
+        }
+    }
+}
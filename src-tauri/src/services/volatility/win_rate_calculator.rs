@@ -217,6 +217,7 @@ mod tests {
                 low: 1.0840,
                 close: 1.0855,
                 volume: 1000.0,
+                spread: None,
             });
         }
 
@@ -231,6 +232,7 @@ mod tests {
                 low: 1.0850 + (i as f64 - 5.0) * 0.0001,
                 close: 1.0860 + (i as f64 - 5.0) * 0.0001,
                 volume: 1000.0,
+                spread: None,
             });
         }
 
@@ -0,0 +1,238 @@
+// services/volatility_regime.rs - Classement des journées en régimes de volatilité
+// Conforme .clinerules : < 300L, pas d'unwrap()
+
+use crate::models::{
+    Candle, DailyBar, RegimeCalendar, RegimeDay, RegimeFilter, RegimeThresholds, VolatilityRegime,
+};
+use crate::services::indicators::{rolling_atr, true_range, AtrSmoothing};
+use crate::services::DatabaseLoader;
+use std::collections::{BTreeMap, HashMap};
+use tracing::info;
+
+/// Période de l'ATR journalier (moyenne simple des true ranges)
+pub const DAILY_ATR_PERIOD: usize = 14;
+
+/// Nombre minimal d'ATR connus avant de classer une journée (bandes trop instables en deçà)
+pub const MIN_REGIME_HISTORY: usize = 20;
+
+pub struct RegimeClassifier;
+
+impl RegimeClassifier {
+    /// Regroupe des bougies (triées ou non) en barres journalières UTC
+    pub fn daily_bars(candles: &[Candle]) -> Vec<DailyBar> {
+        let mut by_day: BTreeMap<chrono::NaiveDate, Vec<Candle>> = BTreeMap::new();
+        for candle in candles {
+            by_day
+                .entry(candle.datetime.date_naive())
+                .or_default()
+                .push(candle.clone());
+        }
+        by_day
+            .into_values()
+            .filter_map(|mut day| {
+                day.sort_by_key(|c| c.datetime);
+                DailyBar::from_candles(&day)
+            })
+            .collect()
+    }
+
+    /// Classe chaque journée selon le percentile de son ATR parmi les ATR connus à cette date
+    /// (fenêtre croissante) : aucune journée n'est classée avec des bandes tirées de son futur
+    pub fn classify(symbol: &str, bars: &[DailyBar]) -> RegimeCalendar {
+        let trs: Vec<f64> = bars
+            .iter()
+            .enumerate()
+            .map(|(i, bar)| {
+                let prev_close = i.checked_sub(1).map(|p| bars[p].close);
+                true_range(bar.high, bar.low, prev_close)
+            })
+            .collect();
+
+        let atrs: Vec<(chrono::NaiveDate, f64)> = bars
+            .iter()
+            .skip(DAILY_ATR_PERIOD - 1)
+            .map(|bar| bar.date)
+            .zip(rolling_atr(&trs, DAILY_ATR_PERIOD, AtrSmoothing::Sma))
+            .collect();
+
+        let mut known: Vec<f64> = Vec::with_capacity(atrs.len());
+        let mut days: Vec<RegimeDay> = Vec::new();
+        for (date, atr) in atrs {
+            let pos = known.partition_point(|v| *v < atr);
+            known.insert(pos, atr);
+            if known.len() < MIN_REGIME_HISTORY {
+                continue;
+            }
+            days.push(RegimeDay {
+                date,
+                atr,
+                regime: bands(&known).classify(atr),
+            });
+        }
+        // Bandes au dernier jour connu (celles du régime actuel)
+        let thresholds = bands(&known);
+        let current = days.last().map(|d| d.regime);
+
+        RegimeCalendar {
+            symbol: symbol.to_string(),
+            atr_period: DAILY_ATR_PERIOD,
+            thresholds,
+            days,
+            current,
+        }
+    }
+
+    /// Calendrier des régimes d'un symbole depuis pairs.db
+    pub fn load(loader: &DatabaseLoader, symbol: &str) -> Result<RegimeCalendar, String> {
+        let bars = loader
+            .load_daily_bars(symbol)
+            .map_err(|e| format!("Barres journalières {}: {}", symbol, e))?;
+        let calendar = Self::classify(symbol, &bars);
+        info!(
+            "🌡️ Régimes {}: {} jours classés, régime actuel {:?}",
+            symbol,
+            calendar.days.len(),
+            calendar.current
+        );
+        Ok(calendar)
+    }
+
+    /// Garde les bougies dont la journée relève du régime demandé
+    pub fn filter_candles(
+        candles: &[Candle],
+        calendar: &RegimeCalendar,
+        regime: VolatilityRegime,
+    ) -> Vec<Candle> {
+        candles
+            .iter()
+            .filter(|c| calendar.regime_on(c.datetime.date_naive()) == Some(regime))
+            .cloned()
+            .collect()
+    }
+
+    /// Restreint des bougies au régime visé par le filtre (régimes classés sur ces bougies)
+    pub fn restreindre(
+        symbol: &str,
+        candles: &[Candle],
+        filter: RegimeFilter,
+    ) -> Result<(Vec<Candle>, VolatilityRegime), String> {
+        let calendar = Self::classify(symbol, &Self::daily_bars(candles));
+        let target = filter.resolve(&calendar).ok_or_else(|| {
+            format!(
+                "Historique trop court pour classer les régimes de {}",
+                symbol
+            )
+        })?;
+        let restreintes = Self::filter_candles(candles, &calendar, target);
+        info!(
+            "🌡️ Analyse {} restreinte au régime {:?}: {} candles",
+            symbol,
+            target,
+            restreintes.len()
+        );
+        Ok((restreintes, target))
+    }
+
+    /// Calendrier et régime visé par paire ; les paires à l'historique trop court sont absentes
+    pub fn cibles(
+        pairs: &[String],
+        filter: RegimeFilter,
+        daily_bars: impl Fn(&str) -> Vec<DailyBar>,
+    ) -> HashMap<String, (RegimeCalendar, VolatilityRegime)> {
+        pairs
+            .iter()
+            .filter_map(|pair| {
+                let calendar = Self::classify(pair, &daily_bars(pair));
+                let target = filter.resolve(&calendar)?;
+                Some((pair.clone(), (calendar, target)))
+            })
+            .collect()
+    }
+}
+
+/// Bandes P25/P75/P95 d'un échantillon d'ATR trié
+fn bands(sorted: &[f64]) -> RegimeThresholds {
+    RegimeThresholds {
+        p25: percentile(sorted, 0.25),
+        p75: percentile(sorted, 0.75),
+        p95: percentile(sorted, 0.95),
+    }
+}
+
+/// Percentile par interpolation linéaire sur un échantillon trié
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        1 => sorted[0],
+        n => {
+            let pos = q * (n - 1) as f64;
+            let lower = pos.floor() as usize;
+            let upper = (lower + 1).min(n - 1);
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn bar(day: u32, range: f64) -> DailyBar {
+        DailyBar {
+            date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap_or_default()
+                + chrono::Duration::days(day as i64),
+            high: 1.0 + range,
+            low: 1.0,
+            close: 1.0 + range / 2.0,
+        }
+    }
+
+    /// Période calme irrégulière puis choc de volatilité
+    fn quiet_then_stressed(days: u32) -> Vec<DailyBar> {
+        (0..days)
+            .map(|d| {
+                let range = if d < 70 {
+                    0.001 * (1 + (d * 7) % 5) as f64
+                } else {
+                    0.02
+                };
+                bar(d, range)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_classify_bands_quiet_and_stressed_periods() {
+        let bars = quiet_then_stressed(90);
+        let calendar = RegimeClassifier::classify("EURUSD", &bars);
+
+        assert_eq!(
+            calendar.days.len(),
+            90 - DAILY_ATR_PERIOD + 1 - (MIN_REGIME_HISTORY - 1)
+        );
+        assert!(calendar.thresholds.p25 <= calendar.thresholds.p75);
+        assert!(calendar.thresholds.p75 <= calendar.thresholds.p95);
+        assert_eq!(calendar.current, Some(VolatilityRegime::Extreme));
+        assert!(calendar.day_count(VolatilityRegime::Calm) > 0);
+        assert!(calendar.day_count(VolatilityRegime::Normal) > 0);
+    }
+
+    #[test]
+    fn test_classify_ignores_later_days() {
+        // Le choc qui suit ne doit pas reclasser les journées calmes déjà passées
+        let before = RegimeClassifier::classify("EURUSD", &quiet_then_stressed(70));
+        let after = RegimeClassifier::classify("EURUSD", &quiet_then_stressed(90));
+
+        assert!(!before.days.is_empty());
+        assert_eq!(before.days, after.days[..before.days.len()]);
+    }
+
+    #[test]
+    fn test_classify_short_history_is_empty() {
+        let bars: Vec<DailyBar> = (0..5).map(|d| bar(d, 0.01)).collect();
+        let calendar = RegimeClassifier::classify("EURUSD", &bars);
+        assert!(calendar.days.is_empty());
+        assert_eq!(calendar.current, None);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::models::{Candle, Result, VolatilityError};
+use crate::services::indicators::{atr_at, AtrSmoothing, DEFAULT_ATR_PERIOD, M1_STEP_MINUTES};
 
 /// Résultat d'un trade simulé
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -86,21 +87,8 @@ pub fn track_trade(
 
 /// Calcule l'ATR à un index donné
 pub fn calculer_atr_a_index(candles: &[Candle], index: usize) -> Result<f64> {
-    use crate::services::metrics::MetricsCalculator;
-
-    if index < 14 {
-        return Err(VolatilityError::InsufficientData(
-            "Not enough candles for ATR".to_string(),
-        ));
-    }
-
-    let window = &candles[index - 14..=index];
-    let calc = MetricsCalculator::new(window);
-    let atrs = calc.calculer_atr(14)?;
-
-    atrs.last()
-        .copied()
-        .ok_or_else(|| VolatilityError::InsufficientData("No ATR calculated".to_string()))
+    atr_at(candles, index, M1_STEP_MINUTES, DEFAULT_ATR_PERIOD, AtrSmoothing::Wilder)
+        .ok_or_else(|| VolatilityError::InsufficientData("Not enough candles for ATR".to_string()))
 }
 
 /// Trouve l'index de la bougie
@@ -0,0 +1,26 @@
+// utils/checksum.rs - Somme de contrôle CRC-32 (plan EA, paquets d'archives, empreintes)
+// Conforme .clinerules : < 50L, pas d'unwrap()
+
+/// CRC-32 IEEE (polynôme de zip/PNG), simple à recalculer côté EA
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_reference() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+}
@@ -0,0 +1,25 @@
+// utils/event_time.rs - Lecture de l'heure d'un événement projeté
+// Conforme .clinerules : < 50L, pas d'unwrap()
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// Heure d'un événement projeté ("AAAA-MM-JJ HH:MM:SS" UTC ou RFC 3339)
+pub fn parse_event_time(value: &str) -> Result<DateTime<Utc>, String> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+        .map(|t| t.and_utc())
+        .or_else(|_| DateTime::parse_from_rfc3339(value).map(|t| t.with_timezone(&Utc)))
+        .map_err(|e| format!("Heure d'événement invalide '{}': {}", value, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_both_formats_parse_to_utc() {
+        let naive = parse_event_time("2024-06-07 12:30:00").expect("naïf");
+        let rfc = parse_event_time("2024-06-07T14:30:00+02:00").expect("rfc3339");
+        assert_eq!(naive, rfc);
+        assert!(parse_event_time("07/06/2024").is_err());
+    }
+}
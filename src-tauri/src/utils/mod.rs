@@ -0,0 +1,8 @@
+// utils/mod.rs - Utilitaires partagés sans dépendance (niveau 1)
+// Conforme .clinerules : < 50L, pas d'unwrap()
+
+pub mod checksum;
+pub mod event_time;
+
+pub use checksum::crc32;
+pub use event_time::parse_event_time;
@@ -44,12 +44,29 @@ export interface OptimalTimeWindowGlobal {
   affected_pairs: string[]
 }
 
+export interface EventImpactGlobal {
+  event_name: string
+  currency: string
+  avg_impact_pips: number
+  occurrence_count: number
+  impact_level: string
+}
+
+export interface GlobalAnalysisProgress {
+  symbol: string
+  processed: number
+  total: number
+  percent: number
+  done: boolean
+}
+
 export interface GlobalAnalysisResult {
   total_analyses: number
   total_days_analyzed: number
   global_stats: GlobalStats
   best_pairs: BestPairGlobal[]
   golden_hours: GoldenHourGlobal[]
+  event_impacts: EventImpactGlobal[]
   tradable_events: TradableEventGlobal[]
   pair_straddle_rates: PairStraddleRateGlobal[]
   optimal_time_windows: OptimalTimeWindowGlobal[]
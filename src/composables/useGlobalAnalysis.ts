@@ -1,8 +1,9 @@
 import { ref, computed, onMounted } from 'vue'
 import { invoke } from '@tauri-apps/api/core'
-import type { GlobalAnalysisResult } from './globalAnalysisTypes'
+import { listen } from '@tauri-apps/api/event'
+import type { GlobalAnalysisProgress, GlobalAnalysisResult } from './globalAnalysisTypes'
 
-export { type GlobalStats, type BestPairGlobal, type GoldenHourGlobal, type TradableEventGlobal, type PairStraddleRateGlobal, type OptimalTimeWindowGlobal, type GlobalAnalysisResult } from './globalAnalysisTypes'
+export { type GlobalStats, type BestPairGlobal, type GoldenHourGlobal, type TradableEventGlobal, type PairStraddleRateGlobal, type OptimalTimeWindowGlobal, type EventImpactGlobal, type GlobalAnalysisProgress, type GlobalAnalysisResult } from './globalAnalysisTypes'
 
 export function useGlobalAnalysis() {
   const loading = ref(false)
@@ -15,6 +16,8 @@ export function useGlobalAnalysis() {
   const endDate = ref('')
   const selectedPairs = ref<string[]>([])
   const availablePairs = ref<string[]>([])
+  // true : calcul sur tout l'historique (bougies + calendrier) au lieu des archives
+  const fromHistory = ref(false)
 
   const sortedGoldenHours = computed(() => {
     if (!result.value) return []
@@ -66,7 +69,7 @@ export function useGlobalAnalysis() {
     progress.value = 0
     logs.value = []
 
-    if (animate) {
+    if (animate && !fromHistory.value) {
       const steps = [
         { msg: 'Lecture des archives...', p: 10 },
         { msg: 'Désérialisation des données JSON...', p: 30 },
@@ -84,17 +87,28 @@ export function useGlobalAnalysis() {
       }
     }
 
+    const unlisten = fromHistory.value
+      ? await listen<GlobalAnalysisProgress>('global-analysis-progress', (event) => {
+          const p = event.payload
+          progress.value = Math.round(p.percent)
+          loadingStep.value = p.done ? 'Analyse terminée' : `Paire ${p.processed}/${p.total} : ${p.symbol}`
+          addLog(loadingStep.value)
+        })
+      : null
+
     try {
       const filters = {
         start_date: startDate.value || null,
         end_date: endDate.value || null,
         pairs: selectedPairs.value.length > 0 ? selectedPairs.value : null
       }
-      const data = await invoke<GlobalAnalysisResult>('analyze_all_archives', { filters })
+      const command = fromHistory.value ? 'analyze_global_history' : 'analyze_all_archives'
+      const data = await invoke<GlobalAnalysisResult>(command, { filters })
       result.value = data
     } catch (e: Error | unknown) {
       error.value = typeof e === 'string' ? e : (e instanceof Error ? e.message : "Erreur inconnue lors de l'analyse")
     } finally {
+      unlisten?.()
       loading.value = false
     }
   }
@@ -110,6 +124,7 @@ export function useGlobalAnalysis() {
     endDate,
     selectedPairs,
     availablePairs,
+    fromHistory,
     sortedGoldenHours,
     bestHour,
     bestHourReliability,
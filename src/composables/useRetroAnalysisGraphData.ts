@@ -30,6 +30,7 @@ export interface RetroGraphData {
   stop_loss_recovery_simultaneous: number
 
   point_value: number                // Valeur d'un point pour normalisation
+  fingerprint?: Record<string, unknown> // Entrées du calcul (reproductibilité)
 }
 
 export function useRetroAnalysisGraphData() {